@@ -3,44 +3,70 @@
 //! Handles canvas interactions, zoom/pan logic and connects to the
 //! WebSocket stream providing market data.
 
-use futures::{channel::oneshot, lock::Mutex};
-use js_sys;
-use leptos::html::Canvas;
+use futures::channel::oneshot;
+use leptos::html::{Canvas, Div};
 use leptos::spawn_local_with_current_owner;
 use leptos::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::time::Duration;
 use wasm_bindgen::JsCast;
 
-use crate::event_utils::{EventOptions, wheel_event_options, window_event_listener_with_options};
+use crate::event_utils::{
+    Debouncer, EventOptions, ResizeObserverHandle, observe_resize, wheel_event_options,
+    window_event_listener_with_options,
+};
 use crate::global_signals;
 use crate::global_state::{
-    connection_id, domain_state, ensure_chart, get_chart_signal, set_chart_in_ecs, view_state,
+    domain_state, ecs_world, ensure_chart, get_chart_signal, has_symbol_view,
+    is_current_connection, next_connection_id, restore_symbol_view, save_symbol_view,
+    set_chart_in_ecs, view_state,
 };
+use crate::touch_gestures::{TouchAction, TouchGesture, TouchPoint};
+use crate::view_state::ViewState;
 use crate::{
     domain::{
-        chart::Chart,
-        logging::{LogComponent, get_logger},
+        AppError, NetworkResult,
+        chart::{
+            Chart, ReplayController, ReplaySpeed, ReplayState,
+            value_objects::{ChartType, FibRetracement, PriceMeasurement, Viewport},
+        },
+        logging::{LogComponent, get_logger, get_time_provider},
         market_data::{
-            Candle, TimeInterval,
+            Candle, CandleOrder, DEFAULT_PRICE_DECIMALS, LargestMoves, MaConfig, TimeInterval,
+            Timestamp, csv_file_name, decimals_for_tick_size, export_csv, format_price,
+            services::SessionLevelKind,
             value_objects::{Symbol, default_symbols},
         },
     },
     infrastructure::rendering::renderer::{
-        EDGE_GAP, LineVisibility, MAX_ELEMENT_WIDTH, MIN_ELEMENT_WIDTH, enqueue_render_task,
-        init_render_queue, set_global_renderer, spacing_ratio_for, with_global_renderer,
+        CandleOutline, CandleSizing, EDGE_GAP, ExtremeLabel, FPS_CAP_OPTIONS, FibLabel,
+        GRID_DIVISIONS, LineVisibility, MAX_USER_ELEMENT_WIDTH, MAX_USER_SPACING_RATIO,
+        MIN_USER_ELEMENT_WIDTH, PerfStats, PriceAxisRange, PriceDisplayMode, PriceScaleMode,
+        SessionLabel, Theme, color_from_hex, color_to_hex, download_data_url, enqueue_render_task,
+        export_chart_png, init_render_queue, max_fps, register_renderer, remove_renderer,
+        set_global_renderer, set_max_fps, with_global_renderer, with_renderer,
     },
     infrastructure::{
-        http::binance_rest_client::BinanceRestClient, rendering::WebGpuRenderer,
-        websocket::BinanceWebSocketClient,
+        history_cache::with_history_cache,
+        http::binance_rest_client::BinanceRestClient,
+        rate_limiter::with_history_rate_limiter,
+        rendering::WebGpuRenderer,
+        websocket::{
+            BinanceWebSocketClient, CandleCoalescer, Exchange, ReconnectBackoff, StreamHealth,
+            create_market_data_client,
+        },
+    },
+    time_utils::{
+        TimeZone, format_countdown, format_elapsed, format_full_datetime_in, format_time_label_in,
     },
-    time_utils::format_time_label,
 };
 use gloo_timers::future::sleep;
 
+/// Canvas id of the chart drawn next to the main one for comparison
+pub const COMPARE_CANVAS_ID: &str = "compare-canvas";
 /// Maximum number of candles visible at 1x zoom
 const MAX_VISIBLE_CANDLES: f64 = 32.0;
 /// Minimum number of candles that must remain visible
@@ -51,11 +77,76 @@ const MIN_ZOOM_LEVEL: f64 = MAX_VISIBLE_CANDLES / 300.0;
 /// Maximum allowed zoom level
 const MAX_ZOOM_LEVEL: f64 = 32.0;
 
+/// Change in pixels per candle of one wheel notch at sensitivity 1
+pub const WHEEL_ZOOM_STEP: f32 = 1.0;
+/// Lowest zoom sensitivity, for fine zooming
+pub const MIN_ZOOM_SENSITIVITY: f64 = 0.25;
+/// Highest zoom sensitivity, for coarse zooming
+pub const MAX_ZOOM_SENSITIVITY: f64 = 4.0;
+/// Zoom sensitivity matching the original wheel step and keyboard factors
+pub const DEFAULT_ZOOM_SENSITIVITY: f64 = 1.0;
+
+/// 🔍 Change in pixels per candle for a wheel event scrolling `delta_y`
+pub fn wheel_zoom_delta(delta_y: f64, sensitivity: f64) -> f32 {
+    let direction = if delta_y < 0.0 { -1.0 } else { 1.0 };
+    direction * WHEEL_ZOOM_STEP * sensitivity as f32
+}
+
+/// 🔍 Keyboard zoom `factor` scaled by `sensitivity`, so zooming in and out stay symmetric
+pub fn keyboard_zoom_factor(factor: f64, sensitivity: f64) -> f64 {
+    factor.powf(sensitivity)
+}
+
 /// Index threshold to trigger history backfill
 pub const HISTORY_PRELOAD_THRESHOLD: usize = 200;
 
-/// Maximum candles per backfill request
-const HISTORY_FETCH_LIMIT: u32 = 1000;
+/// Most candles one history request may ask for (Binance caps `limit` at 1000)
+pub const MAX_HISTORY_FETCH_LIMIT: u32 = 1000;
+/// Most candles kept in memory per series
+pub const MAX_HISTORY_BUFFER_SIZE: usize = 10_000;
+
+/// 📚 How much history is requested and kept, smaller for slow connections
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HistoryWindow {
+    /// Candles requested by the first load and by each backfill page
+    pub fetch_limit: u32,
+    /// Candles kept per series
+    pub buffer_size: usize,
+}
+
+impl Default for HistoryWindow {
+    fn default() -> Self {
+        Self { fetch_limit: MAX_HISTORY_FETCH_LIMIT, buffer_size: 1000 }
+    }
+}
+
+impl HistoryWindow {
+    /// Window clamped to what the exchange serves, with room for at least one page
+    pub fn new(fetch_limit: u32, buffer_size: usize) -> Self {
+        let fetch_limit = fetch_limit.clamp(1, MAX_HISTORY_FETCH_LIMIT);
+        let buffer_size = buffer_size.clamp(fetch_limit as usize, MAX_HISTORY_BUFFER_SIZE);
+        Self { fetch_limit, buffer_size }
+    }
+
+    /// Time spanned by half a page of `interval` candles
+    pub fn half_page_ms(&self, interval: TimeInterval) -> u64 {
+        self.fetch_limit as u64 / 2 * interval.duration_ms()
+    }
+}
+
+/// ⏪ Replay of the loaded history of `symbol`, owning its stream connection
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaySession {
+    pub symbol: Symbol,
+    /// Connection id taken from the live stream; a newer stream ends the replay
+    pub connection: u64,
+    pub controller: ReplayController,
+}
+
+/// Candle a replay of `len` candles starts from; blank input starts halfway
+pub fn replay_start_index(input: &str, len: usize) -> usize {
+    input.trim().parse::<usize>().map_or(len / 2, |index| index.min(len))
+}
 
 /// Check if more historical data should be fetched
 pub fn should_fetch_history(left_index: usize) -> bool {
@@ -73,12 +164,136 @@ pub fn visible_range(len: usize, zoom: f64, pan: f64) -> (usize, usize) {
     (start as usize, visible as usize)
 }
 
+/// Zoom level of a freshly loaded chart
+pub const DEFAULT_ZOOM_LEVEL: f64 = 1.0;
+
+/// Candles scrolled by one arrow key press
+pub const ARROW_PAN_CANDLES: f64 = 5.0;
+/// Candles scrolled by one arrow key press with Shift held
+pub const ARROW_PAN_CANDLES_FAST: f64 = 20.0;
+
+/// Viewport pan factor (a fraction of the visible time range) that scrolls by `candles` bars
+pub fn pan_step_factor(candles: f64, visible_count: usize) -> f32 {
+    if visible_count == 0 {
+        return 0.0;
+    }
+    (candles / visible_count as f64) as f32
+}
+
+/// Canvas width before its container has been measured
+pub const DEFAULT_CANVAS_WIDTH: u32 = 800;
+/// Canvas height before its container has been measured
+pub const DEFAULT_CANVAS_HEIGHT: u32 = 500;
+/// Smallest canvas width the chart shrinks to
+const MIN_CANVAS_WIDTH: f64 = 320.0;
+/// Smallest canvas height the chart shrinks to
+const MIN_CANVAS_HEIGHT: f64 = 200.0;
+/// Horizontal room left for the price axes beside the canvas
+const CANVAS_SIDE_GUTTER_PX: f64 = 140.0;
+/// Vertical room left for the header, toolbars and time scale
+const CANVAS_VERTICAL_CHROME_PX: f64 = 320.0;
+/// Quiet period after which a burst of resize events is applied
+pub const RESIZE_DEBOUNCE_MS: u64 = 150;
+/// Hover time on a timeframe button before its preview is drawn
+const TIMEFRAME_PREVIEW_DELAY_MS: u64 = 250;
+/// Quiet period after a pan or zoom before the overlay fetches the range it misses
+const OVERLAY_FETCH_DEBOUNCE_MS: u64 = 300;
+
+/// Canvas size for a container `container_width` wide in a window `window_height` tall.
+///
+/// Keeps the default aspect ratio unless the window is too short for it.
+pub fn fit_canvas_size(container_width: f64, window_height: f64) -> (u32, u32) {
+    let width = (container_width - CANVAS_SIDE_GUTTER_PX).max(MIN_CANVAS_WIDTH);
+    let aspect = DEFAULT_CANVAS_HEIGHT as f64 / DEFAULT_CANVAS_WIDTH as f64;
+    let height =
+        (width * aspect).min(window_height - CANVAS_VERTICAL_CHROME_PX).max(MIN_CANVAS_HEIGHT);
+    (width.round() as u32, height.round() as u32)
+}
+
+/// Default view for `candle_count` candles: `(zoom, pan, first visible index)`.
+///
+/// The default zoom with no pan shows the latest candles.
+pub fn reset_view_params(candle_count: usize) -> (f64, f64, usize) {
+    let (start, _) = visible_range(candle_count, DEFAULT_ZOOM_LEVEL, 0.0);
+    (DEFAULT_ZOOM_LEVEL, 0.0, start)
+}
+
+/// Zoom level that shows `count` candles per screen, clamped to the zoom limits.
+///
+/// Inverse of the visible-count formula in [`visible_range`]; half a candle of
+/// headroom keeps the truncating division from landing on `count - 1`.
+pub fn zoom_for_visible_count(count: usize) -> f64 {
+    (MAX_VISIBLE_CANDLES / (count.max(1) as f64 + 0.5)).clamp(MIN_ZOOM_LEVEL, MAX_ZOOM_LEVEL)
+}
+
+/// Most candles one screen shows, at the minimum zoom
+pub const MAX_CANDLES_PER_SCREEN: usize = (MAX_VISIBLE_CANDLES / MIN_ZOOM_LEVEL) as usize;
+
+/// 🗓️ Span of recent history shown by a quick-range button, whatever the timeframe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickRange {
+    Day,
+    Week,
+    Month,
+}
+
+impl QuickRange {
+    pub const ALL: [QuickRange; 3] = [QuickRange::Day, QuickRange::Week, QuickRange::Month];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            QuickRange::Day => "1D",
+            QuickRange::Week => "1W",
+            QuickRange::Month => "1M",
+        }
+    }
+
+    pub fn duration_ms(self) -> u64 {
+        const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+        match self {
+            QuickRange::Day => DAY_MS,
+            QuickRange::Week => 7 * DAY_MS,
+            QuickRange::Month => 30 * DAY_MS,
+        }
+    }
+}
+
+/// Candles of `interval` covering `duration_ms`.
+///
+/// Ranges wider than one screen at the minimum zoom are fitted by zooming out
+/// further, and the renderer downsamples them to the canvas width.
+pub fn quick_range_candle_count(duration_ms: u64, interval: TimeInterval) -> usize {
+    let candles = duration_ms.div_ceil(interval.duration_ms().max(1)).max(1);
+    usize::try_from(candles).unwrap_or(usize::MAX)
+}
+
+/// Range `start..end` of `visible` candles out of `len` with `index` in the middle,
+/// shifted to stay inside the data
+pub fn centered_range(len: usize, index: usize, visible: usize) -> (usize, usize) {
+    let visible = visible.clamp(1, len.max(1));
+    let start = index.saturating_sub(visible / 2).min(len.saturating_sub(visible));
+    (start, (start + visible).min(len))
+}
+
 /// Check if the viewport is already at the latest candle
 pub fn should_auto_scroll(len: usize, zoom: f64, pan: f64) -> bool {
     let (start, visible) = visible_range(len, zoom, pan);
     start + visible >= len
 }
 
+/// Whether the chart's viewport shows the latest `interval` candle
+pub fn at_live_edge(chart: &Chart, interval: TimeInterval) -> bool {
+    chart.get_series(interval).is_some_and(|series| {
+        let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &chart.viewport);
+        should_auto_scroll(series.count(), zoom, pan)
+    })
+}
+
+/// ▶️ Follow live candles after a view change only if it left the view at the edge
+fn sync_follow_live(chart: &Chart) {
+    follow_live().set(at_live_edge(chart, current_interval().get_untracked()));
+}
+
 /// Determine visible range using timestamps from the viewport
 pub fn visible_range_by_time(
     candles: &[Candle],
@@ -104,9 +319,99 @@ pub fn visible_range_by_time(
 }
 
 /// Calculate price axis levels based on the viewport
-pub fn price_levels(viewport: &crate::domain::chart::value_objects::Viewport) -> Vec<f64> {
-    let step = (viewport.max_price - viewport.min_price) as f64 / 8.0;
-    (0..=8).rev().map(|i| viewport.min_price as f64 + i as f64 * step).collect()
+pub fn price_levels(
+    viewport: &crate::domain::chart::value_objects::Viewport,
+    mode: PriceScaleMode,
+) -> Vec<f64> {
+    price_levels_between(
+        viewport.min_price as f64,
+        viewport.max_price as f64,
+        mode,
+        grid_price_divisions(),
+    )
+}
+
+/// Axis labels from the top of the canvas down; an inverted axis starts at the lowest price
+pub fn axis_price_levels(
+    viewport: &crate::domain::chart::value_objects::Viewport,
+    mode: PriceScaleMode,
+    inverted: bool,
+) -> Vec<f64> {
+    let mut levels = price_levels(viewport, mode);
+    if inverted {
+        levels.reverse();
+    }
+    levels
+}
+
+/// Axis labels as (price, percent from the top of the canvas) at the grid rows of the
+/// drawn candle area, so they stay aligned when sub-panels shrink it
+pub fn axis_label_positions(range: &PriceAxisRange, divisions: usize) -> Vec<(f64, f64)> {
+    price_levels_between(range.min_price, range.max_price, range.scale, divisions)
+        .into_iter()
+        .filter_map(|price| Some((price, range.percent_from_top(price)?)))
+        .collect()
+}
+
+/// `divisions + 1` price levels from `max` down to `min`, shared by the axis and the grid
+pub fn price_levels_between(
+    min: f64,
+    max: f64,
+    mode: PriceScaleMode,
+    divisions: usize,
+) -> Vec<f64> {
+    // Evenly spaced in scaled space, so log mode yields geometric steps
+    let divisions = divisions.max(1);
+    let min = mode.to_scale(min);
+    let max = mode.to_scale(max);
+    let step = (max - min) / divisions as f64;
+    (0..=divisions).rev().map(|i| mode.from_scale(min + i as f64 * step)).collect()
+}
+
+/// Decimals prices of `symbol` are shown with, from its tick size once fetched
+pub fn symbol_price_decimals(symbol: &Symbol) -> u32 {
+    price_decimals().with(|m| m.get(symbol).copied()).unwrap_or(DEFAULT_PRICE_DECIMALS)
+}
+
+/// `price` with the precision of the current symbol
+fn symbol_price(price: f64) -> String {
+    format_price(price, symbol_price_decimals(&current_symbol().get()))
+}
+
+/// Axis label of `price` in the current display mode
+fn price_label(price: f64) -> String {
+    let decimals = symbol_price_decimals(&current_symbol().get());
+    price_display_mode().get().label(price, percent_base().get(), decimals)
+}
+
+/// 🔢 Fetch the tick size of a Binance symbol once and keep its price precision
+fn load_price_decimals(symbol: &Symbol) {
+    if Exchange::for_symbol(symbol) != Exchange::Binance
+        || price_decimals().with_untracked(|m| m.contains_key(symbol))
+    {
+        return;
+    }
+    let symbol = symbol.clone();
+    spawn_local(async move {
+        let client = BinanceRestClient::new(symbol.clone(), TimeInterval::OneMinute);
+        match client.fetch_tick_size().await {
+            Ok(tick_size) => {
+                let decimals = decimals_for_tick_size(tick_size);
+                price_decimals().update(|m| {
+                    m.insert(symbol, decimals);
+                });
+            }
+            Err(e) => get_logger().warn(
+                LogComponent::Presentation("PricePrecision"),
+                &format!("⚠️ Tick size unavailable for {}: {e}", symbol.value()),
+            ),
+        }
+    });
+}
+
+/// Price rows of the renderer grid, so axis labels line up with its lines
+fn grid_price_divisions() -> usize {
+    with_global_renderer(|r| r.grid_divisions().1).unwrap_or(GRID_DIVISIONS.1)
 }
 
 /// Calculate zoom level and pan offset based on the viewport
@@ -144,15 +449,421 @@ global_signals! {
     global_candle_count => candle_count: usize,
     global_is_streaming => is_streaming: bool,
     global_max_volume => max_volume: f64,
-    loading_more => loading_more: bool,
+    pub loading_more => loading_more: bool,
     tooltip_data => tooltip_data: Option<TooltipData>,
     tooltip_visible => tooltip_visible: bool,
+    crosshair_labels => crosshair_labels: Option<CrosshairLabels>,
     is_dragging => is_dragging: bool,
     last_mouse_x => last_mouse_x: f64,
     pub current_interval => current_interval: TimeInterval,
     pub current_symbol => current_symbol: Symbol,
+    pub price_decimals => price_decimals: HashMap<Symbol, u32>,
     pub stream_abort_handles => stream_abort_handles: HashMap<Symbol, futures::future::AbortHandle>,
     pub global_line_visibility => line_visibility: LineVisibility,
+    pub global_price_scale_mode => price_scale_mode: PriceScaleMode,
+    pub price_display_mode => price_display_mode: PriceDisplayMode,
+    pub percent_base => percent_base: Option<f64>,
+    pub global_chart_type => chart_type: ChartType,
+    fib_mode => fib_mode: bool,
+    fib_anchor => fib_anchor: Option<(Timestamp, f64)>,
+    pub fib_labels => fib_labels: Vec<FibLabel>,
+    pub session_labels => session_labels: Vec<SessionLabel>,
+    pub extreme_labels => extreme_labels: Vec<ExtremeLabel>,
+    pub price_axis_range => price_axis_range: Option<PriceAxisRange>,
+    pub context_menu => context_menu: Option<ContextMenu>,
+    pub grid_visible => grid_visible: bool,
+    pub follow_live => follow_live: bool,
+    pub replay => replay: Option<ReplaySession>,
+    pub compare_symbol => compare_symbol: Option<Symbol>,
+    pub overlay_symbol => overlay_symbol: Option<Symbol>,
+    pub overlay_candles => overlay_candles: Vec<Candle>,
+    pub history_window => history_window: HistoryWindow,
+    pub perf_hud_visible => perf_hud_visible: bool,
+    pub perf_stats => perf_stats: PerfStats,
+    measure_mode => measure_mode: bool,
+    pub measure_overlay => measure_overlay: Option<MeasureOverlay>,
+    pub zoom_box => zoom_box: Option<ZoomBox>,
+    pub canvas_size => canvas_size: (u32, u32),
+    stream_last_message => stream_last_message: HashMap<Symbol, u64>,
+    pub stream_health => stream_health: StreamHealth,
+    pub webgpu_error => webgpu_error: Option<String>,
+    pub demo_mode => demo_mode: bool,
+    pub rest_poll_ms => rest_poll_ms: u64,
+    pub shortcut_help_visible => shortcut_help_visible: bool,
+    pub invert_y => invert_y: bool,
+    pub crosshair_magnet => crosshair_magnet: bool,
+    pub chart_theme => chart_theme: Theme,
+    pub show_wicks => show_wicks: bool,
+    pub candle_sizing => candle_sizing: CandleSizing,
+    pub settings_panel_open => settings_panel_open: bool,
+    pub sparklines => sparklines: HashMap<Symbol, Vec<f64>>,
+    pub time_zone => time_zone: TimeZone,
+    pub zoom_sensitivity => zoom_sensitivity: f64,
+    pub live_paused => live_paused: bool,
+}
+
+/// Live canvas size in CSS pixels
+fn canvas_dims() -> (f64, f64) {
+    let (width, height) = canvas_size().get_untracked();
+    (width as f64, height as f64)
+}
+
+/// 📏 Resize the canvas, renderer and current chart viewport, then redraw
+fn apply_canvas_size(size: (u32, u32)) {
+    if canvas_size().get_untracked() == size {
+        return;
+    }
+    let (width, height) = size;
+    canvas_size().set(size);
+    with_global_renderer(|r| r.resize(width, height));
+
+    let symbol = current_symbol().get_untracked();
+    let Some(chart) = get_chart_signal(&symbol) else {
+        return;
+    };
+    chart.update(|ch| (ch.viewport.width, ch.viewport.height) = size);
+    set_chart_in_ecs(&symbol, chart.get_untracked());
+    chart.with_untracked(|ch| {
+        if ch.get_candle_count() > 0 {
+            request_render();
+        }
+    });
+}
+
+/// Pan by a horizontal pixel delta, matching mouse drag behavior
+fn pan_chart_by(chart_signal: RwSignal<Chart>, delta_x: f64, set_status: WriteSignal<String>) {
+    view_state().update(|v| v.pan(delta_x as f32));
+
+    enqueue_render_task(Box::new(|r| {
+        if let Some(chart_signal) = get_chart_signal(&current_symbol().get_untracked()) {
+            chart_signal.with_untracked(|ch| {
+                if ch.get_candle_count() > 0 {
+                    let len = ch.get_candle_count();
+                    view_state().with(|v| {
+                        let (start, vis) = v.visible_range(len, canvas_dims().0 as f32);
+                        let zoom = MAX_VISIBLE_CANDLES / vis as f64;
+                        let pan = start as f64 / len.max(1) as f64;
+                        r.set_zoom_params(zoom, pan);
+                        let _ = r.render(ch);
+                    });
+                }
+            });
+        }
+    }));
+    let need_history = chart_signal.with_untracked(|ch| {
+        let len = ch.get_candle_count();
+        let (start, visible) = view_state().with(|v| v.visible_range(len, canvas_dims().0 as f32));
+        follow_live().set(start + visible >= len);
+        start
+    });
+    if should_fetch_history(need_history) {
+        fetch_more_history(set_status);
+    }
+}
+
+/// Zoom by `delta_ppc` pixels per candle around `cursor_ratio` of the width, as the wheel does
+fn zoom_chart_at(
+    chart_signal: RwSignal<Chart>,
+    delta_ppc: f32,
+    cursor_ratio: f32,
+    set_status: WriteSignal<String>,
+) {
+    let old_ppc = view_state().with(|v| v.pixels_per_candle);
+    view_state().update(|v| v.zoom_at(delta_ppc, cursor_ratio, canvas_dims().0 as f32));
+    let new_ppc = view_state().with(|v| v.pixels_per_candle);
+    let factor = new_ppc / old_ppc;
+
+    chart_signal.update(|ch| ch.zoom(factor, cursor_ratio));
+    let symbol = current_symbol().get_untracked();
+    chart_signal.with_untracked(|c| set_chart_in_ecs(&symbol, c.clone()));
+
+    let start_idx = chart_signal.with_untracked(|ch| {
+        let interval = current_interval().get_untracked();
+        if let Some(series) = ch.get_series(interval) {
+            let candles = series.get_candles();
+            let (zoom, pan) = viewport_zoom_pan(candles, &ch.viewport);
+            with_global_renderer(|r| {
+                r.set_zoom_params(zoom, pan);
+                request_render();
+            });
+            sync_follow_live(ch);
+            visible_range(candles.len(), zoom, pan).0
+        } else {
+            0
+        }
+    });
+    if should_fetch_history(start_idx) {
+        fetch_more_history(set_status);
+    }
+    get_logger().info(LogComponent::Presentation("ChartZoom"), "🔍 Zoom applied");
+}
+
+/// 👀 Draw the chart aggregated to `interval` without switching to it; `None` or the
+/// current timeframe draws the chart as it is again
+fn preview_timeframe(chart_signal: RwSignal<Chart>, interval: Option<TimeInterval>) {
+    let interval = interval.filter(|i| *i != current_interval().get_untracked());
+    if chart_signal.with_untracked(|ch| ch.get_candle_count() == 0) {
+        return;
+    }
+    with_global_renderer(|r| {
+        if r.preview_interval() != interval {
+            r.set_preview_interval(interval);
+            request_render();
+        }
+    });
+}
+
+/// ⌨️ Scroll the viewport by `candles` bars; negative values move back in time
+fn pan_chart_by_candles(
+    chart_signal: RwSignal<Chart>,
+    candles: f64,
+    set_status: WriteSignal<String>,
+) {
+    let interval = current_interval().get_untracked();
+    let visible_count = chart_signal.with_untracked(|ch| {
+        ch.get_series(interval).map_or(0, |series| {
+            let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &ch.viewport);
+            visible_range(series.get_candles().len(), zoom, pan).1
+        })
+    });
+    let factor = pan_step_factor(candles, visible_count);
+    if factor == 0.0 {
+        return;
+    }
+
+    chart_signal.update(|ch| ch.pan(factor, 0.0));
+    set_chart_in_ecs(&current_symbol().get_untracked(), chart_signal.get_untracked());
+
+    let start_idx = chart_signal.with_untracked(|ch| {
+        let Some(series) = ch.get_series(interval) else { return usize::MAX };
+        let candles = series.get_candles();
+        let (zoom, pan) = viewport_zoom_pan(candles, &ch.viewport);
+        with_global_renderer(|r| {
+            r.set_zoom_params(zoom, pan);
+            request_render();
+        });
+        sync_follow_live(ch);
+        visible_range(candles.len(), zoom, pan).0
+    });
+    if should_fetch_history(start_idx) {
+        fetch_more_history(set_status);
+    }
+}
+
+/// Index into the visible candles under a horizontal NDC position; may fall outside them
+fn visible_index_at_ndc_x(visible_len: usize, ndc_x: f64) -> i64 {
+    // Use the same sizing as the renderer's candle_x_position
+    let sizing = with_global_renderer(|r| r.candle_sizing()).unwrap_or_default();
+    index_at_ndc_x(sizing, visible_len, ndc_x)
+}
+
+/// Index under a horizontal NDC position for candles drawn with `sizing`
+fn index_at_ndc_x(sizing: CandleSizing, visible_len: usize, ndc_x: f64) -> i64 {
+    let step_size = 2.0 / visible_len as f64;
+    let half_width = sizing.element_width(visible_len) as f64 / 2.0;
+    // Inverse formula matching candle_x_position
+    // index = visible_len - 1 - (1.0 - EDGE_GAP as f64 - half_width - ndc_x) / step_size
+    let index_float =
+        visible_len as f64 - 1.0 - (1.0 - EDGE_GAP as f64 - half_width - ndc_x) / step_size;
+    index_float.round() as i64
+}
+
+/// Time of the nearest visible candle and the price under a canvas pixel position
+fn chart_point_at(ch: &Chart, mouse_x: f64, mouse_y: f64) -> Option<(Timestamp, f64)> {
+    let (index, price) = candle_point_at(ch, mouse_x, mouse_y)?;
+    let candles = ch.get_series(current_interval().get_untracked())?.get_candles();
+    Some((candles.get(index)?.timestamp, price))
+}
+
+/// Series index of the nearest visible candle and the price under a canvas pixel position
+fn candle_point_at(ch: &Chart, mouse_x: f64, mouse_y: f64) -> Option<(usize, f64)> {
+    let (canvas_width, canvas_height) = canvas_dims();
+    let ndc_x = (mouse_x / canvas_width) * 2.0 - 1.0;
+    let ndc_y = (1.0 - (mouse_y / canvas_height) * 2.0) as f32;
+
+    let candles = ch.get_series(current_interval().get_untracked())?.get_candles();
+    if candles.is_empty() {
+        return None;
+    }
+    let (zoom, pan) = viewport_zoom_pan(candles, &ch.viewport);
+    let (start_idx, visible_count) = visible_range(candles.len(), zoom, pan);
+    let idx = visible_index_at_ndc_x(visible_count, ndc_x).clamp(0, visible_count as i64 - 1);
+    let price = with_global_renderer(|r| r.price_at_ndc_y(ndc_y)).flatten()?;
+    Some((start_idx + idx as usize, price))
+}
+
+/// 📐 Fibonacci tool: the first point sets the anchor, later points move the end.
+///
+/// With `finish` the drawing is completed and the tool disarmed.
+fn place_fib_point(chart_signal: RwSignal<Chart>, mouse_x: f64, mouse_y: f64, finish: bool) {
+    let Some(point) = chart_signal.with_untracked(|ch| chart_point_at(ch, mouse_x, mouse_y)) else {
+        return;
+    };
+    let anchor = fib_anchor().get_untracked().unwrap_or_else(|| {
+        fib_anchor().set(Some(point));
+        point
+    });
+    if finish {
+        fib_anchor().set(None);
+        fib_mode().set(false);
+    }
+
+    chart_signal.update(|ch| ch.fib_retracement = Some(FibRetracement::new(anchor, point)));
+    set_chart_in_ecs(&current_symbol().get_untracked(), chart_signal.get_untracked());
+    request_render();
+}
+
+/// 📐 Remove the Fibonacci drawing and disarm the tool
+fn clear_fib(chart_signal: RwSignal<Chart>) {
+    fib_mode().set(false);
+    fib_anchor().set(None);
+    chart_signal.update(|ch| ch.fib_retracement = None);
+    set_chart_in_ecs(&current_symbol().get_untracked(), chart_signal.get_untracked());
+    request_render();
+}
+
+/// 📏 Measure tool: start a box at the cursor, or move the end of the box being dragged.
+///
+/// With `finish` the box stays on screen and the tool is disarmed.
+fn place_measure_point(chart_signal: RwSignal<Chart>, mouse_x: f64, mouse_y: f64, finish: bool) {
+    let Some(point) = chart_signal.with_untracked(|ch| candle_point_at(ch, mouse_x, mouse_y))
+    else {
+        return;
+    };
+    let overlay = match measure_overlay().get_untracked() {
+        Some(overlay) if overlay.dragging => MeasureOverlay {
+            measurement: PriceMeasurement::new(overlay.measurement.start, point),
+            to: (mouse_x, mouse_y),
+            dragging: !finish,
+            ..overlay
+        },
+        _ => MeasureOverlay {
+            measurement: PriceMeasurement::new(point, point),
+            from: (mouse_x, mouse_y),
+            to: (mouse_x, mouse_y),
+            dragging: true,
+        },
+    };
+    if finish {
+        measure_mode().set(false);
+    }
+    measure_overlay().set(Some(overlay));
+}
+
+/// 📏 Remove the measurement box and disarm the tool
+fn clear_measure() {
+    measure_mode().set(false);
+    measure_overlay().set(None);
+}
+
+/// 🔲 Zoom both axes to the box dragged with Ctrl held.
+///
+/// The price axis stays pinned to the box until the view is reset or another
+/// symbol or timeframe is selected.
+fn apply_zoom_box(chart_signal: RwSignal<Chart>, zoom_box: ZoomBox) {
+    let interval = current_interval().get_untracked();
+    let Some(range) = price_axis_range().get_untracked() else {
+        return;
+    };
+    let sizing = with_global_renderer(|r| r.candle_sizing()).unwrap_or_default();
+    let viewport = chart_signal.with_untracked(|ch| {
+        let candles = ch.get_series(interval)?.get_candles();
+        let (zoom, pan) = viewport_zoom_pan(candles, &ch.viewport);
+        let (start, visible) = visible_range(candles.len(), zoom, pan);
+        let visible: Vec<Candle> = candles.range(start..start + visible).cloned().collect();
+        zoom_box.viewport(&visible, &range, canvas_dims(), sizing)
+    });
+    let Some(viewport) = viewport else {
+        return;
+    };
+
+    chart_signal.update(|ch| {
+        ch.viewport.start_time = viewport.start_time;
+        ch.viewport.end_time = viewport.end_time;
+        ch.viewport.min_price = viewport.min_price;
+        ch.viewport.max_price = viewport.max_price;
+    });
+    let symbol = current_symbol().get_untracked();
+    chart_signal.with_untracked(|ch| {
+        set_chart_in_ecs(&symbol, ch.clone());
+        sync_follow_live(ch);
+        let Some(series) = ch.get_series(interval) else {
+            return;
+        };
+        let candles = series.get_candles();
+        let (_, pan) = viewport_zoom_pan(candles, &ch.viewport);
+        let first = candles.partition_point(|c| (c.timestamp.value() as f64) < viewport.start_time);
+        let last = candles.partition_point(|c| (c.timestamp.value() as f64) <= viewport.end_time);
+        let zoom = zoom_for_visible_count(last.saturating_sub(first));
+        with_global_renderer(|r| {
+            r.set_zoom_params(zoom, pan);
+            r.set_price_range(Some((viewport.min_price as f64, viewport.max_price as f64)));
+            request_render();
+        });
+    });
+    get_logger().info(
+        LogComponent::Presentation("BoxZoom"),
+        &format!("🔲 Zoomed to {:.2} - {:.2}", viewport.min_price, viewport.max_price),
+    );
+}
+
+/// 🔔 Shift+click: remove an alert near the cursor, or add one at the cursor price
+fn toggle_price_alert_at(chart_signal: RwSignal<Chart>, mouse_y: f64) {
+    const HIT_PX: f64 = 4.0;
+    let canvas_height = canvas_dims().1;
+    let to_ndc = |y: f64| (1.0 - (y / canvas_height) * 2.0) as f32;
+    let Some((price, tolerance)) = with_global_renderer(|r| {
+        let price = r.price_at_ndc_y(to_ndc(mouse_y))?;
+        let above = r.price_at_ndc_y(to_ndc(mouse_y - HIT_PX)).unwrap_or(price);
+        Some((price, (above - price).abs()))
+    })
+    .flatten() else {
+        return;
+    };
+
+    chart_signal.update(|ch| {
+        if !ch.remove_price_alert(price, tolerance) {
+            ch.add_price_alert(price);
+        }
+    });
+    save_alerts_and_render(chart_signal);
+}
+
+/// 🔔 Add a price alert at `price`
+fn add_price_alert(chart_signal: RwSignal<Chart>, price: f64) {
+    chart_signal.update(|ch| {
+        ch.add_price_alert(price);
+    });
+    save_alerts_and_render(chart_signal);
+}
+
+/// Persist the current symbol's alerts and redraw their lines
+fn save_alerts_and_render(chart_signal: RwSignal<Chart>) {
+    let symbol = current_symbol().get_untracked();
+    chart_signal.with_untracked(|ch| {
+        set_chart_in_ecs(&symbol, ch.clone());
+        crate::infrastructure::storage::save_price_alerts(&symbol, &ch.price_alerts);
+        request_render();
+    });
+}
+
+/// Active touches of a canvas touch event in canvas pixel coordinates
+fn canvas_touches(event: &web_sys::TouchEvent) -> Vec<TouchPoint> {
+    let (left, top) = event
+        .current_target()
+        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        .map(|el| {
+            let rect = el.get_bounding_client_rect();
+            (rect.left(), rect.top())
+        })
+        .unwrap_or_default();
+    let touches = event.touches();
+    (0..touches.length())
+        .filter_map(|i| touches.get(i))
+        .map(|t| {
+            TouchPoint::new(t.identifier(), t.client_x() as f64 - left, t.client_y() as f64 - top)
+        })
+        .collect()
 }
 
 /// 📈 Fetch additional history and prepend it to the list
@@ -172,52 +883,23 @@ fn fetch_more_history(set_status: WriteSignal<String>) {
         Some(ts) if ts > 0 => ts - 1,
         _ => return,
     };
+    // Pans beyond the request budget are dropped; the next pan after the wait loads more
+    let wait = history_request_wait_ms();
+    if wait > 0 {
+        set_status.set(rate_limited_status(wait));
+        return;
+    }
 
     loading_more().set(true);
 
     let symbol = current_symbol().get_untracked();
     let _ = spawn_local_with_current_owner(async move {
         let interval = current_interval().get_untracked();
-        let client = BinanceRestClient::new(symbol.clone(), interval);
-        let result = client.fetch_historical_before(end_time, HISTORY_FETCH_LIMIT).await;
-        match result {
-            Ok(mut new_candles) => {
-                new_candles.sort_by_key(|c| c.timestamp.value());
-                new_candles.dedup_by_key(|c| c.timestamp.value());
-                chart.update(|ch| {
-                    for candle in new_candles.iter() {
-                        ch.add_candle(candle.clone());
-                    }
-                });
-                chart.with_untracked(|c| set_chart_in_ecs(&symbol, c.clone()));
-                chart.with_untracked(|c| {
-                    if c.get_candle_count() > 0
-                        && with_global_renderer(|r| {
-                            let interval = current_interval().get_untracked();
-                            let series = c.get_series(interval).unwrap();
-                            let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
-                            r.set_zoom_params(zoom, pan);
-                            let _ = r.render(c);
-                        })
-                        .is_none()
-                    {
-                        // renderer not available
-                    }
-                });
-
-                let new_count = chart.with(|c| c.get_candle_count());
-                let max_volume = chart.with(|c| {
-                    c.get_series(current_interval().get_untracked())
-                        .unwrap()
-                        .get_candles()
-                        .iter()
-                        .map(|c| c.ohlcv.volume.value())
-                        .fold(0.0f64, |a, b| a.max(b))
-                });
-                global_candle_count().set(new_count);
-                global_max_volume().set(max_volume);
-
-                set_status.set(format!("📈 Loaded {} older candles", new_candles.len()));
+        match fetch_history_page(&symbol, interval, end_time).await {
+            Ok(new_candles) => {
+                let loaded = new_candles.len();
+                prepend_history_page(&symbol, chart, new_candles);
+                set_status.set(format!("📈 Loaded {loaded} older candles"));
             }
             Err(e) => set_status.set(format!("❌ Failed to load more data: {e}")),
         }
@@ -227,50 +909,505 @@ fn fetch_more_history(set_status: WriteSignal<String>) {
     });
 }
 
-/// 🎯 Data for the tooltip
-#[derive(Clone, Debug)]
-pub struct TooltipData {
-    pub candle: Candle,
-    pub x: f64,
-    pub y: f64,
-    pub formatted_text: String,
+/// 📈 Merge a page of older candles into the chart of `symbol` and redraw it
+fn prepend_history_page(symbol: &Symbol, chart: RwSignal<Chart>, mut new_candles: Vec<Candle>) {
+    new_candles.sort_by_key(|c| c.timestamp.value());
+    new_candles.dedup_by_key(|c| c.timestamp.value());
+    chart.update(|ch| {
+        for candle in new_candles.iter() {
+            ch.add_candle(candle.clone());
+        }
+    });
+    chart.with_untracked(|c| set_chart_in_ecs(symbol, c.clone()));
+    chart.with_untracked(|c| {
+        if c.get_candle_count() > 0
+            && with_global_renderer(|r| {
+                let interval = current_interval().get_untracked();
+                let series = c.get_series(interval).unwrap();
+                let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
+                r.set_zoom_params(zoom, pan);
+                request_render();
+            })
+            .is_none()
+        {
+            // renderer not available
+        }
+    });
+
+    let new_count = chart.with(|c| c.get_candle_count());
+    let max_volume = chart.with(|c| {
+        c.get_series(current_interval().get_untracked())
+            .unwrap()
+            .get_candles()
+            .iter()
+            .map(|c| c.ohlcv.volume.value())
+            .fold(0.0f64, |a, b| a.max(b))
+    });
+    global_candle_count().set(new_count);
+    global_max_volume().set(max_volume);
 }
 
-impl TooltipData {
-    pub fn new(candle: Candle, x: f64, y: f64) -> Self {
-        let change = candle.ohlcv.close.value() - candle.ohlcv.open.value();
-        let change_pct = (change / candle.ohlcv.open.value()) * 100.0;
-        let trend = if change >= 0.0 { "🟢" } else { "🔴" };
+/// Pause between backfill requests to stay well inside exchange rate limits
+pub const BACKFILL_PAGE_DELAY_MS: u64 = 300;
 
-        // Format time from the timestamp
-        let time_str = format!("Time: {}", candle.timestamp.value());
+/// 📚 Where a full-history backfill stops
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackfillGoal {
+    /// Load until the oldest candle opens at or before this time (ms)
+    StartTime(u64),
+    /// Load until this many candles are kept
+    CandleCount(usize),
+}
 
-        let symbol = current_symbol().get_untracked();
-        let formatted_text = format!(
-            "{} {}\n📈 Open:   ${:.2}\n📊 High:   ${:.2}\n📉 Low:    ${:.2}\n💰 Close:  ${:.2}\n📈 Change: ${:.2} ({:.2}%)\n📊 Volume: {:.4}\n{}",
-            trend,
-            symbol.value(),
-            candle.ohlcv.open.value(),
-            candle.ohlcv.high.value(),
-            candle.ohlcv.low.value(),
-            candle.ohlcv.close.value(),
-            change,
-            change_pct,
-            candle.ohlcv.volume.value(),
-            time_str
-        );
+/// Next move of a backfill after a page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackfillStep {
+    /// Request the page of candles opening at or before this time (ms)
+    Fetch(u64),
+    /// The goal is met
+    Reached,
+    /// The exchange returned nothing older
+    Exhausted,
+    /// The series holds as many candles as it may keep
+    Full,
+}
 
-        Self { candle, x, y, formatted_text }
+impl BackfillGoal {
+    /// Step after a page that added `last_added` candles (`None` before the first request),
+    /// with `loaded` of at most `capacity` candles kept and the oldest opening at `oldest`
+    pub fn next(
+        self,
+        oldest: Option<u64>,
+        loaded: usize,
+        last_added: Option<usize>,
+        capacity: usize,
+    ) -> BackfillStep {
+        if last_added == Some(0) {
+            return BackfillStep::Exhausted;
+        }
+        let Some(oldest) = oldest else { return BackfillStep::Exhausted };
+        let reached = match self {
+            BackfillGoal::StartTime(start) => oldest <= start,
+            BackfillGoal::CandleCount(count) => loaded >= count,
+        };
+        if reached {
+            BackfillStep::Reached
+        } else if loaded >= capacity {
+            BackfillStep::Full
+        } else if oldest == 0 {
+            BackfillStep::Exhausted
+        } else {
+            BackfillStep::Fetch(oldest - 1)
+        }
     }
 }
 
-/// 🦀 Main Crypto Chart component built with Leptos
-#[component]
-pub fn app() -> impl IntoView {
-    // 🚀 Initialize the global logger on application start
-    use crate::domain::logging::get_logger;
+/// 📚 Page older history into the current chart until `goal` is met.
+///
+/// Requests are spaced by [`BACKFILL_PAGE_DELAY_MS`], which also yields to the
+/// UI; the loop stops on an error, when the exchange runs out of candles, or
+/// when the symbol or timeframe changes.
+pub async fn backfill_history(goal: BackfillGoal, set_status: WriteSignal<String>) {
+    if loading_more().get_untracked() {
+        return;
+    }
+    let symbol = current_symbol().get_untracked();
+    let interval = current_interval().get_untracked();
+    let chart = ensure_chart(&symbol);
+    let oldest = move || {
+        chart.with_untracked(|c| {
+            let series = c.get_series(interval)?;
+            Some((series.get_candles().front()?.timestamp.value(), series.count()))
+        })
+    };
 
-    // Extra console.log for diagnostics
+    // 📚 The series keeps no more than the configured history window
+    let capacity = history_window().get_untracked().buffer_size;
+    loading_more().set(true);
+    chart.update(|ch| ch.set_capacity(capacity));
+    let format = |ts: u64| format_candle_time(ts, interval);
+    let mut last_added = None;
+    let view_changed = || {
+        symbol != current_symbol().get_untracked() || interval != current_interval().get_untracked()
+    };
+    let step = loop {
+        // Checked again after every wait, so a switch costs no request for the old view
+        if view_changed() {
+            break Err("view changed".to_string());
+        }
+        let (first, loaded) = oldest().map_or((None, 0), |(ts, count)| (Some(ts), count));
+        let end_time = match goal.next(first, loaded, last_added, capacity) {
+            BackfillStep::Fetch(end_time) => end_time,
+            step => break Ok(step),
+        };
+        // Pages beyond the request budget wait for it to refill
+        let wait = history_request_wait_ms();
+        if wait > 0 {
+            set_status.set(rate_limited_status(wait));
+            sleep(Duration::from_millis(wait)).await;
+            continue;
+        }
+        set_status.set(format!(
+            "📚 Backfilling: {loaded} candles, back to {}...",
+            first.map(format).unwrap_or_default()
+        ));
+        let page = match fetch_history_page(&symbol, interval, end_time).await {
+            Ok(page) => page,
+            Err(e) => break Err(e),
+        };
+        if view_changed() {
+            break Err("view changed".to_string());
+        }
+        prepend_history_page(&symbol, chart, page);
+        last_added = Some(oldest().map_or(0, |(_, count)| count.saturating_sub(loaded)));
+        sleep(Duration::from_millis(BACKFILL_PAGE_DELAY_MS)).await;
+    };
+
+    // Older candles were merged behind the averages; rebuild them over the whole series
+    chart.update(|ch| ch.set_ma_configs(&ch.ma_configs()));
+    set_chart_in_ecs(&symbol, chart.get_untracked());
+    render_if_current(&symbol);
+    loading_more().set(false);
+    // The status line belongs to the new view now
+    if view_changed() {
+        return;
+    }
+
+    let loaded = oldest().map_or(0, |(_, count)| count);
+    set_status.set(match step {
+        Ok(BackfillStep::Reached) => format!("✅ Backfill complete: {loaded} candles"),
+        Ok(BackfillStep::Full) => {
+            format!("✅ Backfill stopped at the {capacity} candle limit")
+        }
+        Ok(_) => format!("✅ No older history: {loaded} candles loaded"),
+        Err(e) => format!("❌ Backfill stopped after {loaded} candles: {e}"),
+    });
+}
+
+/// Milliseconds until the next history request may go to the network
+fn history_request_wait_ms() -> u64 {
+    let now = get_time_provider().current_timestamp();
+    with_history_rate_limiter(|limiter| limiter.retry_after_ms(now))
+}
+
+/// Status shown while history requests are throttled
+pub fn rate_limited_status(wait_ms: u64) -> String {
+    format!("⏳ Rate limited: next history request in {:.1}s", wait_ms as f64 / 1000.0)
+}
+
+/// Up to [`HistoryWindow::fetch_limit`] candles opening at or before `end_time`, from
+/// the history cache or the symbol's exchange
+async fn fetch_history_page(
+    symbol: &Symbol,
+    interval: TimeInterval,
+    end_time: u64,
+) -> Result<Vec<Candle>, String> {
+    let limit = history_window().get_untracked().fetch_limit;
+    let now = get_time_provider().current_timestamp();
+    let cached =
+        with_history_cache(|cache| cache.before(symbol, interval, end_time, limit as usize, now));
+    if let Some(candles) = cached {
+        return Ok(candles);
+    }
+    if !with_history_rate_limiter(|limiter| limiter.try_acquire(now)) {
+        return Err(rate_limited_status(history_request_wait_ms()));
+    }
+    let result = match Exchange::for_symbol(symbol) {
+        Exchange::Binance => {
+            let client = BinanceRestClient::new(symbol.clone(), interval);
+            client.fetch_historical_before(end_time, limit).await
+        }
+        Exchange::Coinbase => {
+            let client = create_market_data_client(symbol, interval);
+            let client = client.lock().await;
+            client.fetch_historical_data_before(end_time, limit).await
+        }
+    };
+    if let Ok(candles) = &result {
+        with_history_cache(|cache| cache.insert(symbol, interval, candles, now));
+    }
+    result
+}
+
+/// 🕐 Date and time of a candle opened at `timestamp`, in the zone picked in the settings
+pub fn format_candle_time(timestamp: u64, interval: TimeInterval) -> String {
+    format_full_datetime_in(timestamp, interval, time_zone().get_untracked())
+}
+
+/// 📅 Backfill goal of a jump to `target` (ms) before the loaded candles; half a page
+/// of context before the target keeps it away from the left edge
+pub fn date_jump_goal(target: u64, window: &HistoryWindow, interval: TimeInterval) -> BackfillGoal {
+    BackfillGoal::StartTime(target.saturating_sub(window.half_page_ms(interval)))
+}
+
+/// 📅 Scroll to the candle nearest `target` (ms), keeping the zoom.
+///
+/// A date before the loaded candles backfills older pages in front of them
+/// until the target is covered, the exchange runs out or the buffer is full.
+async fn jump_to_date(target: u64, set_status: WriteSignal<String>) {
+    let symbol = current_symbol().get_untracked();
+    let interval = current_interval().get_untracked();
+    let chart = ensure_chart(&symbol);
+    let format = |ts: u64| format_candle_time(ts, interval);
+
+    let bounds = chart.with_untracked(|c| c.get_series(interval).and_then(|s| s.time_bounds()));
+    let Some((first, last)) = bounds else {
+        set_status.set("⚠️ No candles loaded yet".to_string());
+        return;
+    };
+    if target >= last + interval.duration_ms() {
+        set_status.set(format!("⚠️ {} is after the latest candle", format(target)));
+        return;
+    }
+
+    if target < first {
+        if loading_more().get_untracked() {
+            return;
+        }
+        let goal = date_jump_goal(target, &history_window().get_untracked(), interval);
+        backfill_history(goal, set_status).await;
+        if symbol != current_symbol().get_untracked()
+            || interval != current_interval().get_untracked()
+        {
+            return;
+        }
+    }
+
+    let Some(index) = chart.with_untracked(|c| c.get_series(interval)?.nearest_index(target))
+    else {
+        return;
+    };
+    let found = center_on_index(chart, &symbol, interval, index);
+    match found {
+        Some(ts) if target < ts && ts - target >= interval.duration_ms() => set_status
+            .set(format!("⚠️ History starts at {}; showing the oldest candle", format(ts))),
+        Some(ts) => set_status.set(format!("📅 Jumped to {}", format(ts))),
+        None => {}
+    }
+}
+
+/// Scroll so the candle at `index` of the `interval` series is centered, keeping the
+/// zoom, and return its timestamp
+fn center_on_index(
+    chart: RwSignal<Chart>,
+    symbol: &Symbol,
+    interval: TimeInterval,
+    index: usize,
+) -> Option<u64> {
+    chart.update(|c| {
+        let Some(series) = c.get_series(interval) else {
+            return;
+        };
+        let candles = series.get_candles();
+        let (zoom, _) = viewport_zoom_pan(candles, &c.viewport);
+        let visible = visible_range(candles.len(), zoom, 0.0).1;
+        let (start, end) = centered_range(candles.len(), index, visible);
+        let start_ts = candles[start].timestamp.value() as f64;
+        let end_ts = candles[end - 1].timestamp.value() as f64;
+        c.viewport.start_time = start_ts;
+        c.viewport.end_time = end_ts;
+    });
+    chart.with_untracked(|c| {
+        set_chart_in_ecs(symbol, c.clone());
+        sync_follow_live(c);
+        let series = c.get_series(interval)?;
+        let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
+        with_global_renderer(|r| {
+            r.set_zoom_params(zoom, pan);
+            request_render();
+        });
+        series.get_candles().get(index).map(|c| c.timestamp.value())
+    })
+}
+
+/// ⚡ Which of the largest close-to-close moves to jump to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveKind {
+    /// Largest change in price
+    Absolute,
+    /// Largest change relative to the previous close
+    Percent,
+}
+
+impl MoveKind {
+    /// Series index of the candle with this kind of largest move
+    pub fn index_in(self, moves: LargestMoves) -> Option<usize> {
+        match self {
+            MoveKind::Absolute => moves.absolute,
+            MoveKind::Percent => moves.percent,
+        }
+    }
+}
+
+/// ⚡ Scroll to the candle with the largest move of `kind` and report its change
+fn jump_to_largest_move(kind: MoveKind, set_status: WriteSignal<String>) {
+    let symbol = current_symbol().get_untracked();
+    let interval = current_interval().get_untracked();
+    let chart = ensure_chart(&symbol);
+    let found = chart.with_untracked(|c| {
+        let candles = c.get_series(interval)?.get_candles();
+        let index = kind.index_in(c.get_series(interval)?.largest_moves())?;
+        let prev = candles[index - 1].ohlcv.close.value();
+        let close = candles[index].ohlcv.close.value();
+        Some((index, close - prev, (close - prev) / prev * 100.0))
+    });
+    let Some((index, change, percent)) = found else {
+        set_status.set("⚠️ Not enough candles to find a move".to_string());
+        return;
+    };
+    if let Some(ts) = center_on_index(chart, &symbol, interval, index) {
+        let time = format_candle_time(ts, interval);
+        set_status.set(format!("⚡ Largest move {change:+.2} ({percent:+.2}%) at {time}"));
+    }
+}
+
+/// ➕ Axis readouts for the crosshair
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrosshairLabels {
+    /// Price under the cursor, if it is over the candle area
+    pub price: Option<f64>,
+    /// Time of the hovered candle
+    pub time: Option<String>,
+    /// Cursor position in canvas pixels
+    pub x: f64,
+    pub y: f64,
+}
+
+/// 📋 Right-click menu opened over the canvas
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContextMenu {
+    /// Cursor position in canvas pixels
+    pub x: f64,
+    pub y: f64,
+    /// Price under the cursor, if it is over the candle area
+    pub price: Option<f64>,
+}
+
+/// 📏 Box drawn by the measure tool from A to B
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasureOverlay {
+    pub measurement: PriceMeasurement,
+    /// Corners A and B in canvas pixels
+    pub from: (f64, f64),
+    pub to: (f64, f64),
+    /// Whether the mouse button is still held
+    pub dragging: bool,
+}
+
+impl MeasureOverlay {
+    /// Readout such as `+120.50 (+1.25%) • 12 bars • 12m`
+    pub fn label(&self, interval: TimeInterval) -> String {
+        let m = &self.measurement;
+        let percent = m.percent_change().map(|p| format!(" ({p:+.2}%)")).unwrap_or_default();
+        format!(
+            "{:+.2}{percent} • {} bars • {}",
+            m.price_change(),
+            m.bar_count(),
+            format_elapsed(m.elapsed_ms(interval))
+        )
+    }
+}
+
+/// Smallest box side in pixels that counts as a selection rather than a click
+pub const MIN_ZOOM_BOX_PX: f64 = 4.0;
+
+/// 🔲 Rectangle dragged with Ctrl held to zoom into a price/time region
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomBox {
+    /// Corners in canvas pixels
+    pub from: (f64, f64),
+    pub to: (f64, f64),
+}
+
+impl ZoomBox {
+    /// Viewport spanning the candles and prices inside the box.
+    ///
+    /// `visible` are the candles on screen, drawn with `sizing` in the price window
+    /// `range`; corners below the candle area are pinned to its bottom edge. `None`
+    /// when the box is too small to be a selection.
+    pub fn viewport(
+        &self,
+        visible: &[Candle],
+        range: &PriceAxisRange,
+        canvas: (f64, f64),
+        sizing: CandleSizing,
+    ) -> Option<Viewport> {
+        let (width, height) = canvas;
+        let (left, right) = (self.from.0.min(self.to.0), self.from.0.max(self.to.0));
+        let (top, bottom) = (self.from.1.min(self.to.1), self.from.1.max(self.to.1));
+        if visible.is_empty() || right - left < MIN_ZOOM_BOX_PX || bottom - top < MIN_ZOOM_BOX_PX {
+            return None;
+        }
+
+        let candle_at = |x: f64| {
+            let index = index_at_ndc_x(sizing, visible.len(), x / width * 2.0 - 1.0);
+            &visible[index.clamp(0, visible.len() as i64 - 1) as usize]
+        };
+        let price_at = |y: f64| {
+            let ndc_y = (1.0 - y / height * 2.0) as f32;
+            range.price_at_ndc_y(ndc_y.clamp(range.main_bottom, 1.0))
+        };
+        // An inverted axis puts the lower price at the top edge
+        let (top_price, bottom_price) = (price_at(top)?, price_at(bottom)?);
+
+        Some(Viewport {
+            start_time: candle_at(left).timestamp.value() as f64,
+            end_time: candle_at(right).timestamp.value() as f64,
+            min_price: top_price.min(bottom_price) as f32,
+            max_price: top_price.max(bottom_price) as f32,
+            width: width as u32,
+            height: height as u32,
+        })
+    }
+}
+
+/// 🎯 Data for the tooltip
+#[derive(Clone, Debug)]
+pub struct TooltipData {
+    pub candle: Candle,
+    pub x: f64,
+    pub y: f64,
+    pub formatted_text: String,
+}
+
+impl TooltipData {
+    pub fn new(candle: Candle, x: f64, y: f64) -> Self {
+        let change = candle.ohlcv.close.value() - candle.ohlcv.open.value();
+        let change_pct = (change / candle.ohlcv.open.value()) * 100.0;
+        let trend = if change >= 0.0 { "🟢" } else { "🔴" };
+
+        let time_str = format!(
+            "Time: {}",
+            format_candle_time(candle.timestamp.value(), current_interval().get_untracked())
+        );
+
+        let symbol = current_symbol().get_untracked();
+        let price = |value: f64| format_price(value, symbol_price_decimals(&symbol));
+        let formatted_text = format!(
+            "{} {}\n📈 Open:   ${}\n📊 High:   ${}\n📉 Low:    ${}\n💰 Close:  ${}\n📈 Change: ${} ({:.2}%)\n📊 Volume: {:.4}\n{}",
+            trend,
+            symbol.value(),
+            price(candle.ohlcv.open.value()),
+            price(candle.ohlcv.high.value()),
+            price(candle.ohlcv.low.value()),
+            price(candle.ohlcv.close.value()),
+            price(change),
+            change_pct,
+            candle.ohlcv.volume.value(),
+            time_str
+        );
+
+        Self { candle, x, y, formatted_text }
+    }
+}
+
+/// 🦀 Main Crypto Chart component built with Leptos
+#[component]
+pub fn app() -> impl IntoView {
+    // 🚀 Initialize the global logger on application start
+    use crate::domain::logging::get_logger;
+
+    // Extra console.log for diagnostics
     web_sys::console::log_1(&"🚀 Starting Crypto Chart App".into());
 
     get_logger().info(LogComponent::Presentation("App"), "🚀 Starting Crypto Chart App");
@@ -386,6 +1523,12 @@ pub fn app() -> impl IntoView {
                 font-size: 14px;
                 text-align: center;
             }
+
+            .chart-control:focus-visible,
+            canvas:focus-visible {
+                outline: 2px solid #ffff00;
+                outline-offset: 2px;
+            }
             
 
             "#}
@@ -403,9 +1546,40 @@ fn header() -> impl IntoView {
     // Use global signals for real data
     let current_price = global_current_price();
     let candle_count = global_candle_count();
-    let is_streaming = global_is_streaming();
     let max_volume = global_max_volume();
 
+    // ⏳ Time until the live candle closes
+    let countdown = create_rw_signal(None::<u64>);
+    let refresh_countdown = move || {
+        let interval = current_interval().get_untracked();
+        let latest = ensure_chart(&current_symbol().get_untracked()).with_untracked(|c| {
+            c.get_series(interval).and_then(|s| s.get_candles().back().map(|c| c.timestamp.value()))
+        });
+        let now = get_time_provider().current_timestamp();
+        countdown.set(latest.map(|open| interval.remaining_ms(open, now)));
+    };
+
+    // 📶 Messages stop arriving silently, so the stream health is re-evaluated on a timer
+    create_effect(move |_| {
+        current_symbol().track();
+        current_interval().track();
+        refresh_stream_health();
+    });
+    // New candles restart the countdown right away
+    create_effect(move |_| {
+        current_symbol().track();
+        current_interval().track();
+        current_price.track();
+        refresh_countdown();
+    });
+    spawn_local(async move {
+        loop {
+            sleep(Duration::from_millis(STREAM_HEALTH_REFRESH_MS)).await;
+            refresh_stream_health();
+            refresh_countdown();
+        }
+    });
+
     let zoom_level = move || {
         let chart = ensure_chart(&current_symbol().get_untracked());
         //let chart = get_chart_signal(ch).unwrap();
@@ -415,6 +1589,15 @@ fn header() -> impl IntoView {
             viewport_zoom_pan(series.get_candles(), &c.viewport).0
         })
     };
+    let candles_per_screen = move || {
+        let chart = ensure_chart(&current_symbol().get_untracked());
+        chart.with(|c| {
+            let interval = current_interval().get_untracked();
+            let series = c.get_series(interval).unwrap();
+            let (zoom, _) = viewport_zoom_pan(series.get_candles(), &c.viewport);
+            visible_range(series.get_candles().len(), zoom, 0.0).1
+        })
+    };
     /*
     let zoom_level = move || {
         match get_chart_signal(&current_symbol().get_untracked()) {
@@ -436,7 +1619,7 @@ fn header() -> impl IntoView {
             <div class="price-info">
                 <div class="price-item">
                     <div class="price-value">
-                        {move || format!("${:.2}", current_price.get())}
+                        {move || format!("${}", symbol_price(current_price.get()))}
                     </div>
                     <div class="price-label">"Current Price"</div>
                 </div>
@@ -448,10 +1631,21 @@ fn header() -> impl IntoView {
                 </div>
                 <div class="price-item">
                     <div class="price-value">
-                        {move || if is_streaming.get() { "🟢 LIVE" } else { "🔴 OFF" }}
+                        {move || stream_health().get().label()}
                     </div>
                     <div class="price-label">"WebSocket"</div>
                 </div>
+                <div class="price-item">
+                    <div class="price-value">
+                        {move || {
+                            countdown
+                                .get()
+                                .map(|ms| format!("Closes in {}", format_countdown(ms)))
+                                .unwrap_or_else(|| "—".to_string())
+                        }}
+                    </div>
+                    <div class="price-label">"⏳ Candle"</div>
+                </div>
                 <div class="price-item">
                     <div class="price-value">
                         {move || format!("{:.2}", max_volume.get())}
@@ -464,25 +1658,327 @@ fn header() -> impl IntoView {
                     </div>
                     <div class="price-label">"🔍 Zoom"</div>
                 </div>
+                <div class="price-item">
+                    <input
+                        class="price-value"
+                        type="number"
+                        min="1"
+                        max="300"
+                        prop:value=move || candles_per_screen().to_string()
+                        on:change=move |ev| {
+                            if let Ok(count) = event_target_value(&ev).trim().parse::<usize>() {
+                                set_candles_per_screen(count);
+                            }
+                        }
+                    />
+                    <div class="price-label">"🕯️ Candles / Screen"</div>
+                </div>
+                <div class="price-item" title="Applies to the next history load">
+                    <input
+                        class="price-value"
+                        type="number"
+                        min="1"
+                        max=MAX_HISTORY_FETCH_LIMIT
+                        prop:value=move || history_window().get().fetch_limit.to_string()
+                        on:change=move |ev| {
+                            if let Ok(limit) = event_target_value(&ev).trim().parse::<u32>() {
+                                history_window()
+                                    .update(|w| *w = HistoryWindow::new(limit, w.buffer_size));
+                            }
+                        }
+                    />
+                    <div class="price-label">"📚 History Load"</div>
+                </div>
+                <div class="price-item" title="Applies to the next history load">
+                    <input
+                        class="price-value"
+                        type="number"
+                        min="1"
+                        max=MAX_HISTORY_BUFFER_SIZE
+                        prop:value=move || history_window().get().buffer_size.to_string()
+                        on:change=move |ev| {
+                            if let Ok(size) = event_target_value(&ev).trim().parse::<usize>() {
+                                history_window()
+                                    .update(|w| *w = HistoryWindow::new(w.fetch_limit, size));
+                            }
+                        }
+                    />
+                    <div class="price-label">"🗄️ History Buffer"</div>
+                </div>
             </div>
         </div>
     }
 }
 
+/// 🕯️ Apply new candle sizing and redraw
+fn update_candle_sizing(update: impl FnOnce(CandleSizing) -> CandleSizing) {
+    with_global_renderer(|r| {
+        r.set_candle_sizing(update(r.candle_sizing()));
+        request_render();
+    });
+}
+
+/// 🏠 Jump back to the latest candles at the default zoom
+fn reset_view() {
+    let symbol = current_symbol().get_untracked();
+    let chart = ensure_chart(&symbol);
+    let interval = current_interval().get_untracked();
+
+    loading_more().set(false);
+    follow_live().set(true);
+    view_state().update(|v| *v = ViewState::default());
+    chart.update(|c| {
+        c.update_viewport_for_data();
+        let Some(candles) = c.get_series(interval).map(|s| s.get_candles()) else {
+            return;
+        };
+        let (_, _, start) = reset_view_params(candles.len());
+        if let Some(first) = candles.get(start) {
+            c.viewport.start_time = first.timestamp.value() as f64;
+        }
+    });
+    chart.with_untracked(|c| {
+        set_chart_in_ecs(&symbol, c.clone());
+        if c.get_candle_count() == 0 {
+            return;
+        }
+        let len = c.get_series(interval).map_or(0, |s| s.count());
+        let (zoom, pan, _) = reset_view_params(len);
+        with_global_renderer(|r| {
+            r.set_zoom_params(zoom, pan);
+            r.set_price_range(None);
+            request_render();
+        });
+    });
+    get_logger().info(LogComponent::Presentation("ResetView"), "🏠 View reset to latest candles");
+}
+
+/// #️⃣ Show or hide the background grid
+fn toggle_grid() {
+    let visible = !grid_visible().get_untracked();
+    grid_visible().set(visible);
+    with_global_renderer(|r| {
+        r.set_grid_visible(visible);
+        request_render();
+    });
+}
+
+/// 🕯️ Show or hide candle wicks
+pub fn toggle_wicks() {
+    with_global_renderer(|r| {
+        r.set_show_wicks(!r.show_wicks());
+        request_render();
+    });
+}
+
+/// 🌓 Switch between the dark and light themes
+pub fn toggle_light_theme() {
+    with_global_renderer(|r| {
+        let theme = if r.theme().is_light() { Theme::dark() } else { Theme::light() };
+        r.set_theme(theme);
+        request_render();
+    });
+}
+
+/// ⏸️ Pause live updates, or resume and apply the candles received meanwhile
+pub fn toggle_live_paused() {
+    live_paused().update(|paused| *paused = !*paused);
+    let paused = live_paused().get_untracked();
+    let resumed: Vec<Rc<dyn Fn()>> = LIVE_STREAM_BUFFERS.with(|buffers| {
+        buffers
+            .borrow()
+            .values()
+            .filter(|buffer| buffer.coalescer.borrow_mut().set_paused(paused))
+            .map(|buffer| buffer.schedule_flush.clone())
+            .collect()
+    });
+    for schedule_flush in resumed {
+        schedule_flush();
+    }
+}
+
+/// ⚙️ Expand or collapse the settings panel
+pub fn toggle_settings_panel() {
+    settings_panel_open().update(|open| *open = !*open);
+}
+
+/// Largest distance between the cursor and an OHLC level that still snaps to it (px)
+pub const MAGNET_THRESHOLD_PX: f64 = 8.0;
+
+/// 🧲 The open, high, low or close of `candle` drawn closest to a cursor `mouse_y` pixels
+/// from the top, as `(price, its y in pixels)`; `None` when none is within
+/// [`MAGNET_THRESHOLD_PX`]
+pub fn snap_to_ohlc(
+    candle: &Candle,
+    range: &PriceAxisRange,
+    mouse_y: f64,
+    canvas_height: f64,
+) -> Option<(f64, f64)> {
+    let ohlc = &candle.ohlcv;
+    [ohlc.open, ohlc.high, ohlc.low, ohlc.close]
+        .into_iter()
+        .filter_map(|price| {
+            let ndc_y = range.ndc_y(price.value())?;
+            Some((price.value(), (1.0 - ndc_y as f64) / 2.0 * canvas_height))
+        })
+        .filter(|(_, y)| (y - mouse_y).abs() <= MAGNET_THRESHOLD_PX)
+        .min_by(|(_, a), (_, b)| (a - mouse_y).abs().total_cmp(&(b - mouse_y).abs()))
+}
+
+/// Price under a cursor `mouse_y` pixels from the top of a canvas `canvas_height` tall
+pub fn cursor_price(range: &PriceAxisRange, mouse_y: f64, canvas_height: f64) -> Option<f64> {
+    if canvas_height <= 0.0 {
+        return None;
+    }
+    range.price_at_ndc_y((1.0 - (mouse_y / canvas_height) * 2.0) as f32)
+}
+
+/// 📋 Write `text` to the system clipboard
+fn copy_to_clipboard(text: &str) -> Result<(), wasm_bindgen::JsValue> {
+    let navigator =
+        web_sys::window().ok_or_else(|| wasm_bindgen::JsValue::from_str("No window"))?.navigator();
+    // `navigator.clipboard` is behind web-sys's unstable APIs, so call it dynamically
+    let clipboard = js_sys::Reflect::get(&navigator, &"clipboard".into())?;
+    let write_text: js_sys::Function =
+        js_sys::Reflect::get(&clipboard, &"writeText".into())?.dyn_into()?;
+    write_text.call1(&clipboard, &text.into())?;
+    Ok(())
+}
+
+/// 🐞 Active chart with the view drawing it, serialized for bug reports
+#[derive(serde::Serialize)]
+struct ChartDebugState<'a> {
+    symbol: &'a Symbol,
+    interval: TimeInterval,
+    zoom: f64,
+    pan: f64,
+    line_visibility: &'a LineVisibility,
+    chart: &'a Chart,
+}
+
+/// 🐞 JSON dump of the current symbol's chart with its zoom, pan and indicator settings
+pub fn chart_state_json() -> Result<String, String> {
+    let symbol = current_symbol().get_untracked();
+    let chart =
+        get_chart_signal(&symbol).ok_or_else(|| format!("No chart for {}", symbol.value()))?;
+    let (zoom, pan) = with_global_renderer(|r| r.zoom_params()).unwrap_or((1.0, 0.0));
+    let line_visibility = global_line_visibility().get_untracked();
+    chart.with_untracked(|chart| {
+        let state = ChartDebugState {
+            symbol: &symbol,
+            interval: current_interval().get_untracked(),
+            zoom,
+            pan,
+            line_visibility: &line_visibility,
+            chart,
+        };
+        serde_json::to_string_pretty(&state).map_err(|e| e.to_string())
+    })
+}
+
+/// 🗓️ Show the latest `range` of history, backfilling first when fewer candles are loaded
+async fn show_quick_range(range: QuickRange, set_status: WriteSignal<String>) {
+    let symbol = current_symbol().get_untracked();
+    let interval = current_interval().get_untracked();
+    let chart = ensure_chart(&symbol);
+    let count = quick_range_candle_count(range.duration_ms(), interval);
+
+    let loaded = chart.with_untracked(|c| c.get_series(interval).map_or(0, |s| s.count()));
+    if loaded < count {
+        backfill_history(BackfillGoal::CandleCount(count), set_status).await;
+        if symbol != current_symbol().get_untracked()
+            || interval != current_interval().get_untracked()
+        {
+            return;
+        }
+    }
+
+    chart.update(|c| {
+        let Some(candles) = c.get_series(interval).map(|s| s.get_candles()) else {
+            return;
+        };
+        let Some(last) = candles.back() else {
+            return;
+        };
+        let start = candles.len().saturating_sub(count);
+        let start_ts = candles[start].timestamp.value() as f64;
+        let end_ts = last.timestamp.value() as f64;
+        c.viewport.start_time = start_ts;
+        c.viewport.end_time = end_ts;
+    });
+    let shown = chart.with_untracked(|c| {
+        set_chart_in_ecs(&symbol, c.clone());
+        sync_follow_live(c);
+        let series = c.get_series(interval)?;
+        let candles = series.get_candles();
+        let (zoom, pan) = viewport_zoom_pan(candles, &c.viewport);
+        with_global_renderer(|r| {
+            r.set_zoom_params(zoom, pan);
+            request_render();
+        });
+        Some(visible_range(candles.len(), zoom, pan).1)
+    });
+    if let Some(shown) = shown {
+        set_status.set(format!("🗓️ Last {}: {shown} candles", range.label()));
+    }
+}
+
+/// Zoom so that `count` candles fit on screen, keeping the right edge in place
+fn set_candles_per_screen(count: usize) {
+    let symbol = current_symbol().get_untracked();
+    let chart = ensure_chart(&symbol);
+    let interval = current_interval().get_untracked();
+    let zoom = zoom_for_visible_count(count);
+
+    chart.update(|c| {
+        let Some(series) = c.get_series(interval) else {
+            return;
+        };
+        let candles = series.get_candles();
+        let visible = visible_range(candles.len(), zoom, 0.0).1;
+        if visible == 0 {
+            return;
+        }
+        let end_ts = c.viewport.end_time as u64;
+        let end_idx = candles.partition_point(|k| k.timestamp.value() <= end_ts).max(visible);
+        let start_ts = candles[end_idx - visible].timestamp.value() as f64;
+        let end_ts = candles[end_idx - 1].timestamp.value() as f64;
+        c.viewport.start_time = start_ts;
+        c.viewport.end_time = end_ts;
+    });
+    chart.with_untracked(|c| {
+        set_chart_in_ecs(&symbol, c.clone());
+        sync_follow_live(c);
+        if c.get_candle_count() == 0 {
+            return;
+        }
+        with_global_renderer(|r| {
+            let series = c.get_series(interval).unwrap();
+            let (_, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
+            r.set_zoom_params(zoom, pan);
+            request_render();
+        });
+    });
+    get_logger().info(
+        LogComponent::Presentation("CandlesPerScreen"),
+        &format!("🕯️ Showing {count} candles per screen (zoom {zoom:.2}x)"),
+    );
+}
+
 #[component]
 fn PriceAxisLeft(chart: RwSignal<Chart>) -> impl IntoView {
     let labels = move || {
         let vp = chart.with(|c| c.viewport.clone());
-        price_levels(&vp)
+        axis_price_levels(&vp, global_price_scale_mode().get(), invert_y().get())
     };
 
     view! {
-        <div style="width: 60px; height: 500px; background: #222; display: flex; flex-direction: column; justify-content: space-between; align-items: flex-end; margin-right: 8px;">
+        <div style:height=move || format!("{}px", canvas_size().get().1) style="width: 60px; background: #222; display: flex; flex-direction: column; justify-content: space-between; align-items: flex-end; margin-right: 8px;">
             <For
                 each=labels
                 key=|v| (*v * 100.0) as i64
                 children=|v| view! {
-                    <div style="font-size: 12px; color: #fff;">{format!("{v:.2}")}</div>
+                    <div style="font-size: 12px; color: #fff;">{move || price_label(v)}</div>
                 }
             />
         </div>
@@ -507,6 +2003,7 @@ fn TimeScale(chart: RwSignal<Chart>) -> impl IntoView {
         // Show 5 time labels
         let num_labels = 5;
         let mut labels = Vec::new();
+        let zone = time_zone().get();
 
         for i in 0..num_labels {
             let index = (i * visible) / (num_labels - 1);
@@ -514,7 +2011,7 @@ fn TimeScale(chart: RwSignal<Chart>) -> impl IntoView {
                 candles.iter().skip(start_idx).nth(index.min(visible.saturating_sub(1)))
             {
                 let timestamp = candle.timestamp.value();
-                let time_str = format_time_label(timestamp, zoom);
+                let time_str = format_time_label_in(timestamp, zoom, zone);
                 let position_percent = (i as f64 / (num_labels as f64 - 1.0)) * 100.0;
                 labels.push((time_str, position_percent));
             }
@@ -524,7 +2021,7 @@ fn TimeScale(chart: RwSignal<Chart>) -> impl IntoView {
     };
 
     view! {
-        <div style="width: 800px; height: 30px; background: #222; display: flex; align-items: center; justify-content: space-between; padding: 0 10px; margin-top: 5px; border-radius: 5px;">
+        <div style:width=move || format!("{}px", canvas_size().get().0) style="height: 30px; background: #222; display: flex; align-items: center; justify-content: space-between; padding: 0 10px; margin-top: 5px; border-radius: 5px;">
             <For
                 each=time_labels
                 key=|(time, _pos)| time.clone()
@@ -538,25 +2035,270 @@ fn TimeScale(chart: RwSignal<Chart>) -> impl IntoView {
     }
 }
 
-/// 🎨 Container for the WebGPU chart
-#[component]
-fn ChartContainer() -> impl IntoView {
-    ensure_chart(&current_symbol().get_untracked());
-    create_effect(move |_| {
-        let sym = current_symbol().get();
-        ensure_chart(&sym);
-    });
-    let chart_memo = create_memo(move |_| {
-        let sym = current_symbol().get();
-        get_chart_signal(&sym).unwrap_or_else(|| ensure_chart(&sym))
-    });
-    let chart = move || chart_memo.get();
-    let (_renderer, set_renderer) = create_signal::<Option<Rc<RefCell<WebGpuRenderer>>>>(None);
-    let (status, set_status) = create_signal("Initializing...".to_string());
+/// Height of the overview strip below the chart (px)
+pub const MINIMAP_HEIGHT: f64 = 40.0;
+/// Distance from a window edge within which a drag resizes instead of moving (px)
+pub const MINIMAP_EDGE_PX: f64 = 6.0;
+
+/// Part of the minimap window grabbed by a drag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinimapHandle {
+    /// Inside the window: pan
+    Window,
+    /// Left edge: move the first visible candle
+    Left,
+    /// Right edge: move the last visible candle
+    Right,
+}
 
-    // Reference to the canvas element
-    let canvas_ref = create_node_ref::<Canvas>();
-    let (initialized, set_initialized) = create_signal(false);
+/// 🧭 A drag on the minimap and the visible candles when it started
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimapDrag {
+    pub handle: MinimapHandle,
+    /// Pointer position at the press (px)
+    pub from_x: f64,
+    pub start: usize,
+    pub visible: usize,
+}
+
+impl MinimapDrag {
+    /// Visible `(start, count)` after dragging to `x` on a strip `width` px wide
+    /// showing `len` candles
+    pub fn range_at(&self, x: f64, width: f64, len: usize) -> (usize, usize) {
+        if width <= 0.0 || len == 0 {
+            return (self.start, self.visible);
+        }
+        let delta = ((x - self.from_x) / width * len as f64).round() as i64;
+        let (start, end) = (self.start as i64, (self.start + self.visible) as i64);
+        let (min_count, max_count) =
+            (MIN_VISIBLE_CANDLES as i64, MAX_CANDLES_PER_SCREEN.min(len) as i64);
+        match self.handle {
+            MinimapHandle::Window => {
+                let count = end - start;
+                let start = (start + delta).clamp(0, (len as i64 - count).max(0));
+                (start as usize, count as usize)
+            }
+            MinimapHandle::Left => {
+                let start = (start + delta).clamp((end - max_count).max(0), end - min_count);
+                (start as usize, (end - start) as usize)
+            }
+            MinimapHandle::Right => {
+                let end =
+                    (end + delta).clamp(start + min_count, (start + max_count).min(len as i64));
+                (start as usize, (end - start) as usize)
+            }
+        }
+    }
+}
+
+/// Horizontal `(left, width)` of the viewport window on a strip `width` px wide
+pub fn minimap_window(start: usize, visible: usize, len: usize, width: f64) -> (f64, f64) {
+    if len == 0 {
+        return (0.0, width);
+    }
+    let px_per_candle = width / len as f64;
+    (start as f64 * px_per_candle, visible as f64 * px_per_candle)
+}
+
+/// Handle under `x`; `None` outside the window
+pub fn minimap_handle_at(x: f64, window: (f64, f64)) -> Option<MinimapHandle> {
+    let (left, right) = (window.0, window.0 + window.1);
+    if (x - left).abs() <= MINIMAP_EDGE_PX {
+        Some(MinimapHandle::Left)
+    } else if (x - right).abs() <= MINIMAP_EDGE_PX {
+        Some(MinimapHandle::Right)
+    } else {
+        (left..=right).contains(&x).then_some(MinimapHandle::Window)
+    }
+}
+
+/// Points tracing every `step`-th of `closes` across a `width` x `height` box, highest
+/// close at the top; a flat series runs through the middle
+fn trace_closes(closes: &[f64], width: f64, height: f64, step: usize) -> Vec<(f64, f64)> {
+    let (min, max) = closes
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &c| (lo.min(c), hi.max(c)));
+    let range = max - min;
+    let last = closes.len().saturating_sub(1).max(1) as f64;
+    closes
+        .iter()
+        .enumerate()
+        .step_by(step)
+        .map(|(i, &c)| {
+            let x = i as f64 / last * width;
+            let y = if range > 0.0 { height - (c - min) / range * height } else { height / 2.0 };
+            (x, y)
+        })
+        .collect()
+}
+
+/// SVG polyline points tracing `closes` across a `width` x `height` strip, at most one per pixel
+pub fn minimap_points(closes: &[f64], width: f64, height: f64) -> String {
+    if closes.is_empty() || width <= 0.0 {
+        return String::new();
+    }
+    let step = (closes.len() as f64 / width).ceil().max(1.0) as usize;
+    trace_closes(closes, width, height, step)
+        .into_iter()
+        .map(|(x, y)| format!("{x:.1},{y:.1}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 🧭 Show candles `start..start + visible` of the current series
+fn show_candle_range(chart_signal: RwSignal<Chart>, start: usize, visible: usize) {
+    let interval = current_interval().get_untracked();
+    chart_signal.update(|c| {
+        let Some(candles) = c.get_series(interval).map(|s| s.get_candles()) else {
+            return;
+        };
+        let end = (start + visible).min(candles.len());
+        if start >= end {
+            return;
+        }
+        let (start_ts, end_ts) =
+            (candles[start].timestamp.value() as f64, candles[end - 1].timestamp.value() as f64);
+        c.viewport.start_time = start_ts;
+        c.viewport.end_time = end_ts;
+    });
+    chart_signal.with_untracked(|c| {
+        set_chart_in_ecs(&current_symbol().get_untracked(), c.clone());
+        sync_follow_live(c);
+        let Some(series) = c.get_series(interval) else {
+            return;
+        };
+        let (_, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
+        with_global_renderer(|r| {
+            r.set_zoom_params(zoom_for_visible_count(visible), pan);
+            request_render();
+        });
+    });
+}
+
+/// Horizontal position of a mouse event in the element handling it; unlike `offset_x`
+/// it does not depend on which child was hit
+fn current_target_x(event: &web_sys::MouseEvent) -> f64 {
+    let left = event
+        .current_target()
+        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        .map_or(0.0, |el| el.get_bounding_client_rect().left());
+    event.client_x() as f64 - left
+}
+
+/// 🧭 Overview of all loaded closes with the visible window, dragged to pan and
+/// resized at its edges to zoom
+#[component]
+fn Minimap(chart: RwSignal<Chart>) -> impl IntoView {
+    let drag = create_rw_signal(None::<MinimapDrag>);
+    let cursor = create_rw_signal("pointer");
+    let width = move || canvas_size().get().0 as f64;
+    // (start, visible, len) of the current series
+    let view_range = move || {
+        let interval = current_interval().get();
+        chart.with(|c| {
+            let candles = c.get_series(interval)?.get_candles();
+            let (zoom, pan) = viewport_zoom_pan(candles, &c.viewport);
+            let (start, visible) = visible_range(candles.len(), zoom, pan);
+            Some((start, visible, candles.len()))
+        })
+    };
+    let points = move || {
+        let interval = current_interval().get();
+        let closes: Vec<f64> = chart.with(|c| {
+            c.get_series(interval).map_or_else(Vec::new, |s| {
+                s.get_candles().iter().map(|k| k.ohlcv.close.value()).collect()
+            })
+        });
+        minimap_points(&closes, width(), MINIMAP_HEIGHT)
+    };
+    let window = move || {
+        view_range().map_or((0.0, 0.0), |(start, visible, len)| {
+            minimap_window(start, visible, len, width())
+        })
+    };
+
+    let on_mousedown = move |ev: web_sys::MouseEvent| {
+        let Some((_, visible, len)) = view_range() else { return };
+        let x = current_target_x(&ev);
+        let handle = minimap_handle_at(x, window()).unwrap_or_else(|| {
+            // A press outside the window centers it there, then keeps dragging it
+            let center = (x / width() * len as f64) as i64 - visible as i64 / 2;
+            show_candle_range(chart, center.clamp(0, (len - visible) as i64) as usize, visible);
+            MinimapHandle::Window
+        });
+        let Some((start, visible, _)) = view_range() else { return };
+        drag.set(Some(MinimapDrag { handle, from_x: x, start, visible }));
+    };
+    let on_mousemove = move |ev: web_sys::MouseEvent| {
+        let x = current_target_x(&ev);
+        cursor.set(
+            match drag.get_untracked().map(|d| d.handle).or(minimap_handle_at(x, window())) {
+                Some(MinimapHandle::Left | MinimapHandle::Right) => "ew-resize",
+                Some(MinimapHandle::Window) => "grab",
+                None => "pointer",
+            },
+        );
+        let Some(d) = drag.get_untracked() else { return };
+        let Some((start, visible, len)) = view_range() else { return };
+        let next = d.range_at(x, width(), len);
+        if next != (start, visible) {
+            show_candle_range(chart, next.0, next.1);
+        }
+    };
+
+    view! {
+        <svg
+            class="minimap"
+            width=move || width().to_string()
+            height=MINIMAP_HEIGHT.to_string()
+            style="background: #253242; border-radius: 5px;"
+            style:cursor=move || cursor.get()
+            on:mousedown=on_mousedown
+            on:mousemove=on_mousemove
+            on:mouseup=move |_| drag.set(None)
+            on:mouseleave=move |_| drag.set(None)
+        >
+            <polyline points=points fill="none" stroke="#888" stroke-width="1" />
+            <rect
+                x=move || window().0.to_string()
+                y="0"
+                width=move || window().1.max(1.0).to_string()
+                height=MINIMAP_HEIGHT.to_string()
+                fill="rgba(255, 255, 0, 0.15)"
+                stroke="#ffff00"
+                stroke-width="1"
+            />
+        </svg>
+    }
+}
+
+/// 🎨 Container for the WebGPU chart
+#[component]
+fn ChartContainer() -> impl IntoView {
+    ensure_chart(&current_symbol().get_untracked());
+    // 🧭 Each symbol keeps the zoom and pan it was left at
+    create_effect(move |previous: Option<Symbol>| {
+        let sym = current_symbol().get();
+        ensure_chart(&sym);
+        if let Some(previous) = previous.filter(|p| *p != sym) {
+            save_symbol_view(&previous);
+            restore_symbol_view(&sym);
+            // Depth of the previous symbol must not linger until the new book arrives
+            with_global_renderer(|r| r.set_order_book(None));
+        }
+        sym
+    });
+    let chart_memo = create_memo(move |_| {
+        let sym = current_symbol().get();
+        get_chart_signal(&sym).unwrap_or_else(|| ensure_chart(&sym))
+    });
+    let chart = move || chart_memo.get();
+    let (_renderer, set_renderer) = create_signal::<Option<Rc<RefCell<WebGpuRenderer>>>>(None);
+    let (status, set_status) = create_signal("Initializing...".to_string());
+
+    // Reference to the canvas element
+    let canvas_ref = create_node_ref::<Canvas>();
+    let (initialized, set_initialized) = create_signal(false);
 
     // Initialize WebGPU once the canvas is available
     create_effect(move |_| {
@@ -580,80 +2322,30 @@ fn ChartContainer() -> impl IntoView {
 
                 web_sys::console::log_1(&"⚡ About to call WebGpuRenderer::new...".into());
 
-                match WebGpuRenderer::new(canvas_id.as_str(), 800, 500).await {
-                    Ok(webgpu_renderer) => {
-                        get_logger().info(
-                            LogComponent::Infrastructure("WebGPU"),
-                            "✅ WebGPU renderer created successfully",
-                        );
-
-                        let renderer_rc = Rc::new(RefCell::new(webgpu_renderer));
-                        set_renderer.set(Some(renderer_rc.clone()));
-                        set_global_renderer(renderer_rc.clone());
-                        init_render_queue();
-                        let _ = renderer_rc.borrow().log_gpu_memory_usage();
-                        set_status.set("✅ WebGPU renderer ready".to_string());
-
-                        // Start WebSocket after the renderer is initialized
-                        get_logger().info(
-                            LogComponent::Infrastructure("WebSocket"),
-                            "🌐 Starting WebSocket stream...",
-                        );
-                        start_websocket_stream(set_status).await;
-                    }
-                    Err(e) => {
-                        let msg = e.as_string().unwrap_or_else(|| format!("{e:?}"));
-                        web_sys::console::error_1(
-                            &format!("❌ WebGPU initialization error: {msg}").into(),
-                        );
-                        get_logger().error(
-                            LogComponent::Infrastructure("WebGPU"),
-                            &format!("❌ WebGPU initialization failed: {msg}"),
-                        );
-                        set_status.set(format!(
-                            "❌ WebGPU failed: {msg}\n💡 Try Chrome Canary with --enable-unsafe-webgpu flag",
-                        ));
-
-                        // Fallback: show data even without the chart
-                        get_logger().info(
-                            LogComponent::Infrastructure("Fallback"),
-                            "🔄 Starting fallback mode without WebGPU...",
-                        );
+                let (width, height) = canvas_size().get_untracked();
+                let Some(webgpu_renderer) =
+                    create_renderer_or_demo(canvas_id.as_str(), width, height, set_status).await
+                else {
+                    return;
+                };
+                get_logger().info(
+                    LogComponent::Infrastructure("WebGPU"),
+                    "✅ WebGPU renderer created successfully",
+                );
 
-                        // Generate sample data for demo purposes
-                        let mut test_candles = Vec::new();
-                        let base_price = 90000.0;
-                        let base_time = js_sys::Date::now() as u64;
-
-                        for i in 0..50 {
-                            let price_variation = (i as f64 * 0.1).sin() * 1000.0;
-                            let open = base_price + price_variation;
-                            let close = open + (i as f64 % 3.0 - 1.0) * 200.0;
-                            let high = open.max(close) + 100.0;
-                            let low = open.min(close) - 100.0;
-                            let volume = 100.0 + (i as f64 * 0.2).cos() * 50.0;
-
-                            let candle = Candle::new(
-                                crate::domain::market_data::Timestamp::from(base_time + i * 60000),
-                                crate::domain::market_data::OHLCV::new(
-                                    crate::domain::market_data::Price::from(open),
-                                    crate::domain::market_data::Price::from(high),
-                                    crate::domain::market_data::Price::from(low),
-                                    crate::domain::market_data::Price::from(close),
-                                    crate::domain::market_data::Volume::from(volume),
-                                ),
-                            );
-                            test_candles.push(candle);
-                        }
+                let renderer_rc = Rc::new(RefCell::new(webgpu_renderer));
+                set_renderer.set(Some(renderer_rc.clone()));
+                set_global_renderer(renderer_rc.clone());
+                init_render_queue();
+                let _ = renderer_rc.borrow().log_gpu_memory_usage();
+                set_status.set("✅ WebGPU renderer ready".to_string());
 
-                        chart().update(|ch| ch.set_historical_data(test_candles));
-                        let symbol = current_symbol().get_untracked();
-                        chart().with_untracked(|c| set_chart_in_ecs(&symbol, c.clone()));
-                        set_status.set(format!(
-                            "🎯 Demo mode: Using test data (WebSocket disabled)\nReason: {msg}",
-                        ));
-                    }
-                }
+                // Start WebSocket after the renderer is initialized
+                get_logger().info(
+                    LogComponent::Infrastructure("WebSocket"),
+                    "🌐 Starting WebSocket stream...",
+                );
+                start_websocket_stream(set_status).await;
             });
         }
     });
@@ -670,65 +2362,57 @@ fn ChartContainer() -> impl IntoView {
             let dragging = is_dragging().get_untracked();
             if dragging {
                 let last_x = last_mouse_x().get_untracked();
-                let delta_x = mouse_x - last_x;
-                view_state().update(|v| v.pan(delta_x as f32));
                 last_mouse_x().set(mouse_x);
-
-                enqueue_render_task(Box::new(|r| {
-                    if let Some(chart_signal) = get_chart_signal(&current_symbol().get_untracked())
-                    {
-                        chart_signal.with_untracked(|ch| {
-                            if ch.get_candle_count() > 0 {
-                                let len = ch.get_candle_count();
-                                view_state().with(|v| {
-                                    let (start, vis) = v.visible_range(len, 800.0);
-                                    let zoom = MAX_VISIBLE_CANDLES / vis as f64;
-                                    let pan = start as f64 / len.max(1) as f64;
-                                    r.set_zoom_params(zoom, pan);
-                                    let _ = r.render(ch);
-                                });
-                            }
-                        });
-                    }
-                }));
-                let need_history = chart_signal().with_untracked(|ch| {
-                    let len = ch.get_candle_count();
-                    view_state().with(|v| v.visible_range(len, 800.0).0)
-                });
-                if should_fetch_history(need_history) {
-                    fetch_more_history(status_clone);
-                }
+                pan_chart_by(chart_signal(), mouse_x - last_x, status_clone);
             } else {
-                // Convert to NDC coordinates (assuming an 800x500 canvas)
-                let canvas_width = 800.0;
-                let canvas_height = 500.0;
+                // 📐 The end of a Fibonacci drawing follows the cursor
+                if fib_anchor().get_untracked().is_some() {
+                    place_fib_point(chart_signal(), mouse_x, mouse_y, false);
+                }
+                // 📏 So does the end of a measurement box
+                if measure_overlay().with_untracked(|m| m.as_ref().is_some_and(|m| m.dragging)) {
+                    place_measure_point(chart_signal(), mouse_x, mouse_y, false);
+                }
+                // 🔲 And the free corner of a zoom box
+                if zoom_box().with_untracked(Option::is_some) {
+                    zoom_box().update(|b| {
+                        if let Some(b) = b {
+                            b.to = (mouse_x, mouse_y);
+                        }
+                    });
+                }
+
+                // Convert to NDC coordinates of the live canvas size
+                let (canvas_width, canvas_height) = canvas_dims();
                 let ndc_x = (mouse_x / canvas_width) * 2.0 - 1.0;
-                let _ndc_y = 1.0 - (mouse_y / canvas_height) * 2.0;
+                let ndc_y = 1.0 - (mouse_y / canvas_height) * 2.0;
 
                 chart_signal().with_untracked(|ch| {
                     let interval = current_interval().get_untracked();
                     let candles = ch.get_series(interval).unwrap().get_candles();
+                    let mut time_label = None;
+                    let mut snapped = None;
                     if !candles.is_empty() {
                         let (zoom, pan) = viewport_zoom_pan(candles, &ch.viewport);
                         let (start_idx, visible_count) = visible_range(candles.len(), zoom, pan);
                         let visible: Vec<_> =
                             candles.iter().skip(start_idx).take(visible_count).collect();
 
-                        // Use the same logic as in candle_x_position
-                        let step_size = 2.0 / visible.len() as f64;
-                        let spacing = spacing_ratio_for(visible.len()) as f64;
-                        let width = (step_size * (1.0 - spacing))
-                            .clamp(MIN_ELEMENT_WIDTH as f64, MAX_ELEMENT_WIDTH as f64);
-                        let half_width = width / 2.0;
-                        // Inverse formula matching candle_x_position
-                        // index = visible_len - 1 - (1.0 - EDGE_GAP as f64 - half_width - ndc_x) / step_size
-                        let index_float = visible.len() as f64
-                            - 1.0
-                            - (1.0 - EDGE_GAP as f64 - half_width - ndc_x) / step_size;
-                        let candle_idx = index_float.round() as i32;
+                        let candle_idx = visible_index_at_ndc_x(visible.len(), ndc_x);
 
                         if candle_idx >= 0 && (candle_idx as usize) < visible.len() {
                             let candle = visible[candle_idx as usize];
+                            time_label = Some(format_time_label_in(
+                                candle.timestamp.value(),
+                                zoom,
+                                time_zone().get_untracked(),
+                            ));
+                            // 🧲 Pull the crosshair onto the nearest OHLC level
+                            if crosshair_magnet().get_untracked()
+                                && let Some(range) = price_axis_range().get_untracked()
+                            {
+                                snapped = snap_to_ohlc(candle, &range, mouse_y, canvas_height);
+                            }
                             let data = TooltipData::new(candle.clone(), mouse_x, mouse_y);
 
                             tooltip_data().set(Some(data));
@@ -739,14 +2423,45 @@ fn ChartContainer() -> impl IntoView {
                     } else {
                         tooltip_visible().set(false);
                     }
+
+                    // ➕ Crosshair with price and time readouts
+                    let (line_y, ndc_y) = snapped
+                        .map_or((mouse_y, ndc_y), |(_, y)| (y, 1.0 - (y / canvas_height) * 2.0));
+                    let price = with_global_renderer(|r| {
+                        r.set_crosshair(Some((ndc_x as f32, ndc_y as f32)));
+                        if ch.get_candle_count() > 0 {
+                            request_render();
+                        }
+                        r.price_at_ndc_y(ndc_y as f32)
+                    })
+                    .flatten();
+                    crosshair_labels().set(Some(CrosshairLabels {
+                        // The snapped level is shown exactly, not read back from the pixel
+                        price: snapped.map(|(price, _)| price).or(price),
+                        time: time_label,
+                        x: mouse_x,
+                        y: line_y,
+                    }));
                 });
             }
         }
     };
 
-    let handle_mouse_leave = move |_event: web_sys::MouseEvent| {
-        tooltip_visible().set(false);
-        is_dragging().set(false);
+    let handle_mouse_leave = {
+        let chart_signal = chart;
+        move |_event: web_sys::MouseEvent| {
+            tooltip_visible().set(false);
+            is_dragging().set(false);
+            crosshair_labels().set(None);
+            chart_signal().with_untracked(|ch| {
+                with_global_renderer(|r| {
+                    r.set_crosshair(None);
+                    if ch.get_candle_count() > 0 {
+                        request_render();
+                    }
+                });
+            });
+        }
     };
 
     // 🔍 Mouse wheel zoom - simplified without effects
@@ -760,60 +2475,115 @@ fn ChartContainer() -> impl IntoView {
             web_sys::console::log_1(&format!("🖱️ Wheel event: delta_y={}", event.delta_y()).into());
             event.prevent_default();
 
-            let delta_y = event.delta_y();
-            let delta_ppc = if delta_y < 0.0 { -1.0 } else { 1.0 };
-            let cursor_ratio = event.offset_x() as f32 / 800.0;
-            let old_ppc = view_state().with(|v| v.pixels_per_candle);
-            view_state().update(|v| v.zoom_at(delta_ppc, cursor_ratio, 800.0));
-            let new_ppc = view_state().with(|v| v.pixels_per_candle);
-            let factor = new_ppc / old_ppc;
-
-            chart_signal().update(|ch| ch.zoom(factor, cursor_ratio));
-            let symbol = current_symbol().get_untracked();
-            chart_signal().with_untracked(|c| set_chart_in_ecs(&symbol, c.clone()));
+            let delta_ppc = wheel_zoom_delta(event.delta_y(), zoom_sensitivity().get_untracked());
+            let cursor_ratio = (event.offset_x() as f64 / canvas_dims().0) as f32;
+            zoom_chart_at(chart_signal(), delta_ppc, cursor_ratio, status_clone);
+        }
+    };
 
-            let start_idx = chart_signal().with_untracked(|ch| {
-                let interval = current_interval().get_untracked();
-                if let Some(series) = ch.get_series(interval) {
-                    let candles = series.get_candles();
-                    let (zoom, pan) = viewport_zoom_pan(candles, &ch.viewport);
-                    with_global_renderer(|r| {
-                        r.set_zoom_params(zoom, pan);
-                        let _ = r.render(ch);
-                    });
-                    visible_range(candles.len(), zoom, pan).0
-                } else {
-                    0
+    // 👆 Touch: one finger pans, two fingers pinch-zoom around their midpoint
+    let touch_gesture = Rc::new(RefCell::new(TouchGesture::Idle));
+    let handle_touch_reset = {
+        let touch_gesture = touch_gesture.clone();
+        move |event: web_sys::TouchEvent| {
+            *touch_gesture.borrow_mut() = TouchGesture::from_touches(&canvas_touches(&event));
+        }
+    };
+    let handle_touch_move = {
+        let chart_signal = chart;
+        let status_clone = set_status;
+        let touch_gesture = touch_gesture.clone();
+        move |event: web_sys::TouchEvent| {
+            event.prevent_default();
+            let action = touch_gesture.borrow_mut().update(&canvas_touches(&event));
+            match action {
+                Some(TouchAction::Pan(delta_x)) => {
+                    pan_chart_by(chart_signal(), delta_x, status_clone);
                 }
-            });
-            if should_fetch_history(start_idx) {
-                fetch_more_history(status_clone);
+                Some(TouchAction::Zoom { factor, center_x }) => {
+                    let old_ppc = view_state().with_untracked(|v| v.pixels_per_candle);
+                    let delta_ppc = old_ppc * (factor - 1.0);
+                    let cursor_ratio = (center_x / canvas_dims().0).clamp(0.0, 1.0) as f32;
+                    zoom_chart_at(chart_signal(), delta_ppc, cursor_ratio, status_clone);
+                }
+                None => {}
             }
-            get_logger().info(LogComponent::Presentation("ChartZoom"), "🔍 Zoom applied");
         }
     };
 
+    // 📐 Where the Fibonacci anchor was pressed, to finish the drawing on a drag release
+    let fib_press = Rc::new(Cell::new(None::<(f64, f64)>));
+
     // 🖱️ Start panning
-    let handle_mouse_down = move |event: web_sys::MouseEvent| {
-        if event.button() == 0 {
-            // Left mouse button
-            web_sys::console::log_1(&"🖱️ Mouse down".into());
-            is_dragging().set(true);
-            last_mouse_x().set(event.offset_x() as f64);
-
-            // Give the canvas focus for keyboard events
-            if let Some(target) = event.target()
-                && let Ok(canvas) = target.dyn_into::<web_sys::HtmlCanvasElement>()
-            {
-                let _ = canvas.focus();
+    let handle_mouse_down = {
+        let fib_press = fib_press.clone();
+        move |event: web_sys::MouseEvent| {
+            if event.button() == 0 && event.shift_key() {
+                toggle_price_alert_at(chart(), event.offset_y() as f64);
+                return;
+            }
+            if event.button() == 0 && event.ctrl_key() {
+                let corner = (event.offset_x() as f64, event.offset_y() as f64);
+                zoom_box().set(Some(ZoomBox { from: corner, to: corner }));
+                return;
+            }
+            if event.button() == 0 && fib_mode().get_untracked() {
+                let (x, y) = (event.offset_x() as f64, event.offset_y() as f64);
+                let finish = fib_anchor().get_untracked().is_some();
+                fib_press.set((!finish).then_some((x, y)));
+                place_fib_point(chart(), x, y, finish);
+                return;
+            }
+            if event.button() == 0 && measure_mode().get_untracked() {
+                measure_overlay().set(None);
+                place_measure_point(
+                    chart(),
+                    event.offset_x() as f64,
+                    event.offset_y() as f64,
+                    false,
+                );
+                return;
+            }
+            if event.button() == 0 {
+                // Left mouse button
+                web_sys::console::log_1(&"🖱️ Mouse down".into());
+                is_dragging().set(true);
+                last_mouse_x().set(event.offset_x() as f64);
+
+                // Give the canvas focus for keyboard events
+                if let Some(target) = event.target()
+                    && let Ok(canvas) = target.dyn_into::<web_sys::HtmlCanvasElement>()
+                {
+                    let _ = canvas.focus();
+                }
             }
         }
     };
 
     // 🖱️ End panning
-    let handle_mouse_up = move |_event: web_sys::MouseEvent| {
+    let handle_mouse_up = move |event: web_sys::MouseEvent| {
         web_sys::console::log_1(&"🖱️ Mouse up".into());
         is_dragging().set(false);
+
+        // 📐 Releasing a drag away from the anchor completes the Fibonacci drawing
+        if let Some((x, y)) = fib_press.take() {
+            let (mx, my) = (event.offset_x() as f64, event.offset_y() as f64);
+            if fib_anchor().get_untracked().is_some() && (mx - x).hypot(my - y) > 4.0 {
+                place_fib_point(chart(), mx, my, true);
+            }
+        }
+
+        // 🔲 Releasing the button zooms to the selected box
+        if let Some(selection) = zoom_box().get_untracked() {
+            zoom_box().set(None);
+            let to = (event.offset_x() as f64, event.offset_y() as f64);
+            apply_zoom_box(chart(), ZoomBox { to, ..selection });
+        }
+
+        // 📏 Releasing the button completes the measurement
+        if measure_overlay().with_untracked(|m| m.as_ref().is_some_and(|m| m.dragging)) {
+            place_measure_point(chart(), event.offset_x() as f64, event.offset_y() as f64, true);
+        }
     };
 
     // ⌨️ Zoom keys (+/- and PageUp/PageDown)
@@ -823,6 +2593,63 @@ fn ChartContainer() -> impl IntoView {
         move |event: web_sys::KeyboardEvent| {
             let key = event.key();
 
+            if key == "Home" {
+                event.prevent_default();
+                reset_view();
+                return;
+            }
+
+            if key == "Escape" {
+                clear_fib(chart_signal());
+                clear_measure();
+                zoom_box().set(None);
+                return;
+            }
+
+            // ⬅️➡️ Scroll by a few candles, further with Shift
+            if key == "ArrowLeft" || key == "ArrowRight" {
+                event.prevent_default();
+                let step =
+                    if event.shift_key() { ARROW_PAN_CANDLES_FAST } else { ARROW_PAN_CANDLES };
+                let candles = if key == "ArrowLeft" { -step } else { step };
+                pan_chart_by_candles(chart_signal(), candles, status_clone);
+                return;
+            }
+
+            // 📈 Performance HUD
+            if key == "f" || key == "F" {
+                event.prevent_default();
+                perf_hud_visible().update(|v| *v = !*v);
+                return;
+            }
+
+            // 🔀 Previous or next symbol of the watchlist
+            if key == "[" || key == "]" {
+                event.prevent_default();
+                let step = if key == "[" { -1 } else { 1 };
+                let current = current_symbol().get_untracked();
+                let symbols = Exchange::for_symbol(&current).symbols();
+                if let Some(symbol) = cycle_symbol(&symbols, &current, step)
+                    && symbol != current
+                {
+                    switch_symbol(symbol, status_clone);
+                }
+                return;
+            }
+
+            if key == "d" || key == "D" {
+                event.prevent_default();
+                chart_signal().with_untracked(|c| {
+                    with_global_renderer(|r| {
+                        r.toggle_debug_mode();
+                        if c.get_candle_count() > 0 {
+                            request_render();
+                        }
+                    });
+                });
+                return;
+            }
+
             let factor = match key.as_str() {
                 "+" | "=" => {
                     event.prevent_default();
@@ -840,9 +2667,18 @@ fn ChartContainer() -> impl IntoView {
                     event.prevent_default();
                     Some(0.67)
                 }
+                "ArrowUp" => {
+                    event.prevent_default();
+                    Some(if event.shift_key() { 1.5 } else { 1.2 })
+                }
+                "ArrowDown" => {
+                    event.prevent_default();
+                    Some(if event.shift_key() { 0.67 } else { 0.8 })
+                }
                 _ => None,
             };
             if let Some(factor) = factor {
+                let factor = keyboard_zoom_factor(factor, zoom_sensitivity().get_untracked());
                 let (old_zoom, _) = chart_signal().with_untracked(|c| {
                     let interval = current_interval().get_untracked();
                     let candles = c.get_series(interval).unwrap().get_candles();
@@ -862,7 +2698,7 @@ fn ChartContainer() -> impl IntoView {
                             let series = c.get_series(interval).unwrap();
                             let (_, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
                             r.set_zoom_params(new_zoom, pan);
-                            let _ = r.render(c);
+                            request_render();
                             get_logger().info(
                                 LogComponent::Infrastructure("KeyboardZoom"),
                                 &format!(
@@ -884,7 +2720,7 @@ fn ChartContainer() -> impl IntoView {
                     let series = c.get_series(interval).unwrap();
                     view_state().with(|v| {
                         let len = series.get_candles().len();
-                        let (start, _) = v.visible_range(len, 800.0);
+                        let (start, _) = v.visible_range(len, canvas_dims().0 as f32);
                         should_fetch_history(start)
                     })
                 });
@@ -895,6 +2731,51 @@ fn ChartContainer() -> impl IntoView {
         }
     };
 
+    // 📋 Right-click opens the chart menu at the cursor
+    let handle_context_menu = move |event: web_sys::MouseEvent| {
+        event.prevent_default();
+        let (x, y) = (event.offset_x() as f64, event.offset_y() as f64);
+        let price = price_axis_range()
+            .get_untracked()
+            .and_then(|range| cursor_price(&range, y, canvas_dims().1));
+        context_menu().set(Some(ContextMenu { x, y, price }));
+    };
+
+    // The menu closes on any click outside its items and on Escape
+    let menu_click_listener =
+        window_event_listener_with_options(ev::click, &EventOptions::default(), move |_| {
+            if context_menu().with_untracked(Option::is_some) {
+                context_menu().set(None);
+            }
+        });
+    on_cleanup(move || menu_click_listener.remove());
+    let menu_key_listener = window_event_listener_with_options(
+        ev::keydown,
+        &EventOptions::default(),
+        move |event: web_sys::KeyboardEvent| {
+            if event.key() == "Escape" && context_menu().with_untracked(Option::is_some) {
+                context_menu().set(None);
+            }
+        },
+    );
+    on_cleanup(move || menu_key_listener.remove());
+
+    // ❔ The shortcut overlay answers to ? anywhere on the page except in text fields
+    let help_key_listener = window_event_listener_with_options(
+        ev::keydown,
+        &EventOptions::default(),
+        move |event: web_sys::KeyboardEvent| {
+            let typing = event
+                .target()
+                .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+                .is_some_and(|el| matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA"));
+            if !typing && handle_shortcut_help_key(&event.key()) {
+                event.prevent_default();
+            }
+        },
+    );
+    on_cleanup(move || help_key_listener.remove());
+
     // Attach wheel event listener to the window
     let wheel_listener = window_event_listener_with_options(
         ev::wheel,
@@ -910,18 +2791,59 @@ fn ChartContainer() -> impl IntoView {
         });
     on_cleanup(move || mouseup_listener.remove());
 
+    // 📏 Follow the container size, applying only the last of a burst of resize events
+    let container_ref = create_node_ref::<Div>();
+    let resize_observer = Rc::new(RefCell::new(None::<ResizeObserverHandle>));
+    container_ref.on_load({
+        let resize_observer = resize_observer.clone();
+        move |container| {
+            let debouncer = Rc::new(RefCell::new(Debouncer::new()));
+            let handle = observe_resize(&container, move |width, _| {
+                let window_height = web_sys::window()
+                    .and_then(|w| w.inner_height().ok())
+                    .and_then(|h| h.as_f64())
+                    .unwrap_or(f64::INFINITY);
+                let token = debouncer.borrow_mut().schedule(fit_canvas_size(width, window_height));
+                let debouncer = debouncer.clone();
+                spawn_local(async move {
+                    sleep(Duration::from_millis(RESIZE_DEBOUNCE_MS)).await;
+                    let size = debouncer.borrow_mut().take(token);
+                    if let Some(size) = size {
+                        apply_canvas_size(size);
+                    }
+                });
+            });
+            *resize_observer.borrow_mut() = handle;
+        }
+    });
+    on_cleanup(move || {
+        if let Some(handle) = resize_observer.borrow_mut().take() {
+            handle.remove();
+        }
+    });
+
     // Zoom effect removed - handled directly in the wheel handler
 
     view! {
-        <div class="chart-container">
-            <div style="display:flex;justify-content:space-between;margin-bottom:8px;width:800px;">
+        <div class="chart-container" node_ref=container_ref>
+            <WebGpuErrorBanner />
+            <div style:width=move || format!("{}px", canvas_size().get().0) style="display:flex;justify-content:space-between;margin-bottom:8px;">
                 <AssetSelector set_status=set_status />
                 <div style="display:flex;gap:6px;">
                     <TimeframeSelector chart=chart() set_status=set_status />
+                    <QuickRangeSelector set_status=set_status />
+                    <ChartTypeSelector chart=chart() />
+                    <JumpToDate set_status=set_status />
+                    <LargestMoveButtons set_status=set_status />
+                    <ReplayControls set_status=set_status />
+                    <CompareSelector set_status=set_status />
+                    <OverlaySelector set_status=set_status />
+                    <FpsCapSelector />
+                    <RestPollSelector />
                 </div>
             </div>
 
-            <div style="display: flex; flex-direction: row; align-items: flex-start;">
+            <div style="display: flex; flex-direction: row; flex-wrap: wrap; gap: 10px; align-items: flex-start;">
                 <PriceAxisLeft chart=chart() />
                 <div style="position: relative;">
                     <Show when=move || loading_more().get()>
@@ -930,20 +2852,46 @@ fn ChartContainer() -> impl IntoView {
                     <canvas
                         id="chart-canvas"
                         node_ref=canvas_ref
+                        role="img"
+                        aria-label=move || {
+                            chart_aria_label(
+                                &current_symbol().get(),
+                                current_interval().get(),
+                                global_chart_type().get(),
+                            )
+                        }
                         use:wheel_event_options=&EventOptions { passive: false, capture: false, once: false }
-                        width="800"
-                        height="500"
+                        style:width=move || format!("{}px", canvas_size().get().0)
+                        style:height=move || format!("{}px", canvas_size().get().1)
                         tabindex="0"
-                        style="border: 2px solid #4a5d73; border-radius: 10px; background: #253242; cursor: crosshair; outline: none;"
+                        style="border: 2px solid #4a5d73; border-radius: 10px; background: #253242; cursor: crosshair; outline: none; touch-action: none;"
                         on:mousemove=handle_mouse_move
                         on:mouseleave=handle_mouse_leave
                         on:mousedown=handle_mouse_down
                         on:mouseup=handle_mouse_up
                         on:keydown=handle_keydown
+                        on:dblclick=move |_| reset_view()
+                        on:contextmenu=handle_context_menu
+                        on:touchstart=handle_touch_reset.clone()
+                        on:touchend=handle_touch_reset.clone()
+                        on:touchcancel=handle_touch_reset
+                        on:touchmove=handle_touch_move
                     />
                     <PriceScale chart=chart() />
+                    <FibLevelLabels />
+                    <SessionLevelLabels />
+                    <ExtremeLevelLabels />
+                    <MeasureBox />
+                    <ZoomBoxOverlay />
+                    <CrosshairReadout />
                     <ChartTooltip />
+                    <ChartContextMenu chart=chart() />
+                    <PerfHud />
+                    <DemoModeBadge />
                 </div>
+                <Show when=move || compare_symbol().with(|s| s.is_some())>
+                    <CompareChart set_status=set_status />
+                </Show>
             </div>
 
             <Legend chart=chart() />
@@ -952,6 +2900,9 @@ fn ChartContainer() -> impl IntoView {
             <div style="display: flex; justify-content: center; margin-top: 10px;">
                 <TimeScale chart=chart() />
             </div>
+            <div style="display: flex; justify-content: center; margin-top: 5px;">
+                <Minimap chart=chart() />
+            </div>
 
             <div class="status">
                 {move || status.get()}
@@ -959,63 +2910,562 @@ fn ChartContainer() -> impl IntoView {
 
             // Control hints
             <div style="text-align: center; margin-top: 10px; font-size: 12px; color: #888;">
-                "🔍 Zoom: Mouse wheel, +/- keys, PageUp/PageDown | 🖱️ Pan: Left click + drag | 🎯 Tooltip: Mouse hover"
+                "🔍 Zoom: Mouse wheel | 🖱️ Pan: Left click + drag | ⌨️ All controls: ?"
             </div>
+            <ShortcutHelp />
         </div>
     }
 }
 
-/// 💰 Price scale on the right side of the chart
-#[component]
-fn PriceScale(chart: RwSignal<Chart>) -> impl IntoView {
-    let current_price = global_current_price();
-
-    // Calculate price levels for display (same as in the grid)
-    let price_levels = move || {
-        let vp = chart.with(|c| c.viewport.clone());
-        let levels = price_levels(&vp);
-        let step = 100.0 / 8.0;
-        levels
-            .into_iter()
-            .enumerate()
-            .map(|(i, level_price)| (level_price, i as f64 * step))
-            .collect::<Vec<_>>()
-    };
+/// ⌨️ Keyboard and mouse controls listed by the shortcut overlay
+pub const SHORTCUTS: &[(&str, &str)] = &[
+    ("Mouse wheel, + / -, PageUp / PageDown", "Zoom"),
+    ("↑ / ↓", "Zoom, Shift for bigger steps"),
+    ("Left click + drag, ← / →", "Pan, Shift for bigger steps"),
+    ("Ctrl + drag", "Zoom to a box"),
+    ("Double-click, Home", "Reset view"),
+    ("Drag, pinch", "Pan and zoom on touch screens"),
+    ("Hover", "Candle tooltip"),
+    ("Shift + click", "Add a price alert"),
+    ("Right-click", "Chart menu"),
+    ("FIB, then click two points", "Fibonacci retracement"),
+    ("MEASURE, then drag", "Measure a price and time range"),
+    ("Escape", "Clear drawings, close menus and this overlay"),
+    ("[ / ]", "Previous or next symbol"),
+    ("F", "Performance HUD"),
+    ("D", "Debug outlines"),
+    ("?", "Show or hide this list"),
+];
+
+/// ❔ Toggle the shortcut overlay on ? and close it on Escape; true when the key was used
+pub fn handle_shortcut_help_key(key: &str) -> bool {
+    match key {
+        "?" => {
+            shortcut_help_visible().update(|v| *v = !*v);
+            true
+        }
+        "Escape" if shortcut_help_visible().get_untracked() => {
+            shortcut_help_visible().set(false);
+            true
+        }
+        _ => false,
+    }
+}
 
+/// ⌨️ Modal listing every control; a click outside the panel closes it
+#[component]
+fn ShortcutHelp() -> impl IntoView {
     view! {
-        <div class="price-scale">
-            // Display price levels
-            <For
-                each=price_levels
-                key=|(_price, pos)| (*pos * 100.0) as i64
-                children=|(price, position)| view! {
-                    <div
-                        class="price-level"
-                        style=format!("position: absolute; top: {}%; right: 5px; transform: translateY(-50%); font-size: 11px; color: #888; background: rgba(0,0,0,0.7); padding: 2px 4px; border-radius: 2px;", position)
-                    >
-                        {format!("{price:.2}")}
-                    </div>
-                }
-            />
-
-            // Display the current price (highlighted)
-            <div class="current-price-label" style=format!("top: 50%")>
-                <span class="price-value">{move || format!("${:.2}", current_price.get())}</span>
+        <Show when=move || shortcut_help_visible().get()>
+            <div
+                class="shortcut-help-backdrop"
+                style="position:fixed;inset:0;z-index:20;display:flex;align-items:center;justify-content:center;background:rgba(0,0,0,0.5);"
+                on:click=move |_| shortcut_help_visible().set(false)
+            >
+                <div
+                    class="shortcut-help"
+                    role="dialog"
+                    aria-label="Keyboard and mouse controls"
+                    style="padding:16px 20px;border:1px solid #4a5d73;border-radius:8px;background:#283547;color:white;font-size:13px;"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <div style="margin-bottom:8px;font-weight:bold;">"⌨️ Controls"</div>
+                    <table style="border-collapse:collapse;">
+                        {SHORTCUTS
+                            .iter()
+                            .map(|(keys, action)| {
+                                view! {
+                                    <tr>
+                                        <td style="padding:2px 16px 2px 0;color:#ffff00;white-space:nowrap;">
+                                            {*keys}
+                                        </td>
+                                        <td style="padding:2px 0;">{*action}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </table>
+                </div>
             </div>
-        </div>
+        </Show>
     }
 }
 
-/// 🎯 Chart Tooltip component inside the chart wrapper
-#[component]
-fn ChartTooltip() -> impl IntoView {
-    let tooltip_visible = tooltip_visible();
-    let tooltip_data = tooltip_data();
+/// Accessible description of a chart canvas
+pub fn chart_aria_label(symbol: &Symbol, interval: TimeInterval, chart_type: ChartType) -> String {
+    format!(
+        "{chart_type} chart of {} on the {} timeframe. Arrow keys pan and zoom, Home resets the view",
+        symbol.value(),
+        interval.as_ref()
+    )
+}
 
-    view! {
-        <div
-            class="tooltip"
-            style:display=move || if tooltip_visible.get() { "block" } else { "none" }
+/// Let Enter toggle a focused checkbox; browsers only toggle it with Space
+fn toggle_checkbox_on_enter(ev: ev::KeyboardEvent) {
+    if ev.key() == "Enter" {
+        ev.prevent_default();
+        event_target::<web_sys::HtmlInputElement>(&ev).click();
+    }
+}
+
+/// Browser support table with instructions for turning WebGPU on
+pub const WEBGPU_HELP_URL: &str = "https://github.com/gpuweb/gpuweb/wiki/Implementation-Status";
+
+/// Publish why the WebGPU renderer could not be created and return the reason
+pub fn report_webgpu_failure(error: &wasm_bindgen::JsValue) -> String {
+    let msg = error.as_string().unwrap_or_else(|| format!("{error:?}"));
+    webgpu_error().set(Some(msg.clone()));
+    msg
+}
+
+/// 🚀 Create the WebGPU renderer for `canvas_id`, or report why it failed and fill the
+/// current chart with labelled demo data instead
+pub async fn create_renderer_or_demo(
+    canvas_id: &str,
+    width: u32,
+    height: u32,
+    set_status: WriteSignal<String>,
+) -> Option<WebGpuRenderer> {
+    let e = match WebGpuRenderer::new(canvas_id, width, height).await {
+        Ok(renderer) => return Some(renderer),
+        Err(e) => e,
+    };
+    let msg = report_webgpu_failure(&e);
+    web_sys::console::error_1(&format!("❌ WebGPU initialization error: {msg}").into());
+    get_logger().error(
+        LogComponent::Infrastructure("WebGPU"),
+        &format!("❌ WebGPU initialization failed: {msg}"),
+    );
+    set_status.set("❌ WebGPU unavailable".to_string());
+
+    // Fallback: show data even without the chart
+    get_logger().info(
+        LogComponent::Infrastructure("Fallback"),
+        "🔄 Starting fallback mode without WebGPU...",
+    );
+
+    // Generate sample data for demo purposes
+    let mut test_candles = Vec::new();
+    let base_price = 90000.0;
+    let base_time = crate::domain::logging::get_time_provider().current_timestamp();
+
+    for i in 0..50 {
+        let price_variation = (i as f64 * 0.1).sin() * 1000.0;
+        let open = base_price + price_variation;
+        let close = open + (i as f64 % 3.0 - 1.0) * 200.0;
+        let high = open.max(close) + 100.0;
+        let low = open.min(close) - 100.0;
+        let volume = 100.0 + (i as f64 * 0.2).cos() * 50.0;
+
+        let candle = Candle::new(
+            crate::domain::market_data::Timestamp::from(base_time + i * 60000),
+            crate::domain::market_data::OHLCV::new(
+                crate::domain::market_data::Price::from(open),
+                crate::domain::market_data::Price::from(high),
+                crate::domain::market_data::Price::from(low),
+                crate::domain::market_data::Price::from(close),
+                crate::domain::market_data::Volume::from(volume),
+            ),
+        );
+        test_candles.push(candle);
+    }
+
+    let symbol = current_symbol().get_untracked();
+    let chart = ensure_chart(&symbol);
+    chart.update(|ch| ch.set_historical_data(test_candles));
+    chart.with_untracked(|c| set_chart_in_ecs(&symbol, c.clone()));
+    demo_mode().set(true);
+    set_status.set("🎯 Demo mode: generated sample data, not live prices".to_string());
+    None
+}
+
+/// ⚠️ Explains that WebGPU is unavailable and the chart shows demo data
+#[component]
+fn WebGpuErrorBanner() -> impl IntoView {
+    view! {
+        <Show when=move || webgpu_error().with(|e| e.is_some())>
+            <div
+                class="webgpu-error"
+                role="alert"
+                style="display:flex;align-items:center;gap:12px;margin-bottom:8px;padding:8px 12px;border:1px solid #e16c48;border-radius:6px;background:#253242;color:white;font-size:13px;"
+            >
+                <span style="flex:1;">
+                    "WebGPU is not available in this browser, so the chart shows generated demo data instead of live prices. "
+                    <a href=WEBGPU_HELP_URL target="_blank" rel="noopener" style="color:#ffff00;">
+                        "How to enable WebGPU"
+                    </a>
+                    <br />
+                    <span style="color:#888;font-size:11px;">
+                        {move || webgpu_error().get().unwrap_or_default()}
+                    </span>
+                </span>
+                <button
+                    aria-label="Dismiss WebGPU warning"
+                    style="padding:2px 8px;border:none;border-radius:4px;background:#444;color:white;"
+                    on:click=move |_| webgpu_error().set(None)
+                >
+                    "✕"
+                </button>
+            </div>
+        </Show>
+    }
+}
+
+/// 🎯 Marks a chart drawn from generated sample data
+#[component]
+fn DemoModeBadge() -> impl IntoView {
+    view! {
+        <Show when=move || demo_mode().get()>
+            <div
+                class="demo-badge"
+                style="position:absolute;top:8px;left:8px;padding:4px 8px;font-size:12px;font-weight:bold;color:#283547;background:#e16c48;border-radius:4px;pointer-events:none;"
+            >
+                "DEMO DATA — NOT LIVE"
+            </div>
+        </Show>
+    }
+}
+
+/// 📈 Frame rate, candle count and render pass time in the top-right corner
+#[component]
+fn PerfHud() -> impl IntoView {
+    view! {
+        <Show when=move || perf_hud_visible().get()>
+            <div
+                class="perf-hud"
+                style="position:absolute;top:8px;right:8px;padding:4px 8px;font-size:11px;font-family:monospace;color:#ffff00;background:rgba(40,53,71,0.8);border-radius:4px;pointer-events:none;"
+            >
+                {move || {
+                    let stats = perf_stats().get();
+                    let cap = match max_fps() {
+                        0 => String::new(),
+                        fps => format!(" (cap {fps})"),
+                    };
+                    format!(
+                        "FPS {:.1}{cap} | {} candles | {:.2} ms",
+                        stats.avg_fps, stats.candle_count, stats.render_ms
+                    )
+                }}
+            </div>
+        </Show>
+    }
+}
+
+/// 📋 Chart actions offered on right-click
+#[component]
+fn ChartContextMenu(chart: RwSignal<Chart>) -> impl IntoView {
+    let menu = context_menu();
+    let price = move || menu.with(|m| m.as_ref().and_then(|m| m.price));
+    let item = move |label: &'static str, action: Box<dyn Fn()>, enabled: Box<dyn Fn() -> bool>| {
+        view! {
+            <div
+                class="context-menu-item"
+                style:opacity=move || if enabled() { "1" } else { "0.4" }
+                style="padding:4px 12px;cursor:pointer;white-space:nowrap;"
+                on:click=move |_| {
+                    action();
+                    menu.set(None);
+                }
+            >
+                {label}
+            </div>
+        }
+    };
+
+    view! {
+        <Show when=move || menu.with(Option::is_some)>
+            <div
+                class="context-menu"
+                style:left=move || format!("{}px", menu.with(|m| m.as_ref().map_or(0.0, |m| m.x)))
+                style:top=move || format!("{}px", menu.with(|m| m.as_ref().map_or(0.0, |m| m.y)))
+                style="position:absolute;z-index:10;padding:4px 0;font-size:12px;background:#283547;color:white;border:1px solid #4a5d73;border-radius:4px;"
+            >
+                {item("Reset view", Box::new(reset_view), Box::new(|| true))}
+                {item(
+                    "Toggle grid",
+                    Box::new(toggle_grid),
+                    Box::new(|| true),
+                )}
+                {item(
+                    "Copy price",
+                    Box::new(move || {
+                        let Some(price) = price() else { return };
+                        let text = format_price(
+                            price,
+                            symbol_price_decimals(&current_symbol().get_untracked()),
+                        );
+                        if let Err(e) = copy_to_clipboard(&text) {
+                            get_logger().error(
+                                LogComponent::Presentation("ContextMenu"),
+                                &format!("Copy failed: {e:?}"),
+                            );
+                        }
+                    }),
+                    Box::new(move || price().is_some()),
+                )}
+                {item(
+                    "Add alert here",
+                    Box::new(move || {
+                        if let Some(price) = price() {
+                            add_price_alert(chart, price);
+                        }
+                    }),
+                    Box::new(move || price().is_some()),
+                )}
+            </div>
+        </Show>
+    }
+}
+
+/// 🔲 Selection rectangle while a box zoom is dragged
+#[component]
+fn ZoomBoxOverlay() -> impl IntoView {
+    let rect = move || {
+        zoom_box().with(|b| {
+            b.map(|b| {
+                let (left, top) = (b.from.0.min(b.to.0), b.from.1.min(b.to.1));
+                (left, top, (b.from.0 - b.to.0).abs(), (b.from.1 - b.to.1).abs())
+            })
+        })
+    };
+
+    view! {
+        <Show when=move || zoom_box().with(Option::is_some)>
+            <div
+                style:left=move || format!("{}px", rect().map_or(0.0, |r| r.0))
+                style:top=move || format!("{}px", rect().map_or(0.0, |r| r.1))
+                style:width=move || format!("{}px", rect().map_or(0.0, |r| r.2))
+                style:height=move || format!("{}px", rect().map_or(0.0, |r| r.3))
+                style="position:absolute;border:1px dashed #ffff00;background:#ffff0014;pointer-events:none;"
+            ></div>
+        </Show>
+    }
+}
+
+/// 📐 Ratio and price of each Fibonacci level, pinned to the left edge of its line
+#[component]
+fn FibLevelLabels() -> impl IntoView {
+    view! {
+        <For
+            each=move || fib_labels().get()
+            key=|label| ((label.ratio * 1000.0) as i64, (label.price * 100.0) as i64, label.y.to_bits())
+            children=move |label| {
+                let top = move || {
+                    format!("{}px", (1.0 - label.y as f64) / 2.0 * canvas_size().get().1 as f64 - 14.0)
+                };
+                view! {
+                    <div
+                        style:top=top
+                        style="position:absolute;left:6px;font-size:10px;color:#66b3ff;pointer-events:none;"
+                    >
+                        {move || format!("{:.1}% {}", label.ratio * 100.0, symbol_price(label.price))}
+                    </div>
+                }
+            }
+        />
+    }
+}
+
+/// 🕘 Name and price of each session line, pinned to the right edge above it
+#[component]
+fn SessionLevelLabels() -> impl IntoView {
+    view! {
+        <For
+            each=move || session_labels().get()
+            key=|label| (label.kind, label.price.to_bits(), label.y.to_bits())
+            children=move |label| {
+                let top = move || {
+                    format!("{}px", (1.0 - label.y as f64) / 2.0 * canvas_size().get().1 as f64 - 14.0)
+                };
+                let color = match label.kind {
+                    SessionLevelKind::Open => "#ffff00",
+                    SessionLevelKind::High => "#74c787",
+                    SessionLevelKind::Low => "#e16c48",
+                };
+                view! {
+                    <div
+                        class="session-label"
+                        style:top=top
+                        style:color=color
+                        style="position:absolute;right:6px;font-size:10px;pointer-events:none;"
+                    >
+                        {move || format!("Session {} {}", label.kind.label(), symbol_price(label.price))}
+                    </div>
+                }
+            }
+        />
+    }
+}
+
+/// 🔺 Prices of the visible high and low beside their markers
+#[component]
+fn ExtremeLevelLabels() -> impl IntoView {
+    view! {
+        <For
+            each=move || extreme_labels().get()
+            key=|label| (label.index, label.high, label.x.to_bits(), label.y.to_bits())
+            children=move |label| {
+                let left = move || {
+                    format!("{}px", (label.x as f64 + 1.0) / 2.0 * canvas_size().get().0 as f64 + 8.0)
+                };
+                let top = move || {
+                    let offset = if label.high { -16.0 } else { 4.0 };
+                    format!("{}px", (1.0 - label.y as f64) / 2.0 * canvas_size().get().1 as f64 + offset)
+                };
+                view! {
+                    <div
+                        class="extreme-label"
+                        style:left=left
+                        style:top=top
+                        style:color=if label.high { "#74c787" } else { "#e16c48" }
+                        style="position:absolute;font-size:10px;pointer-events:none;"
+                    >
+                        {move || symbol_price(label.price)}
+                    </div>
+                }
+            }
+        />
+    }
+}
+
+/// 📏 Measurement box with its price, percent, bar and time readout
+#[component]
+fn MeasureBox() -> impl IntoView {
+    let overlay = measure_overlay();
+    let rect = move || {
+        overlay.with(|o| {
+            o.as_ref().map(|o| {
+                let (left, right) = (o.from.0.min(o.to.0), o.from.0.max(o.to.0));
+                let (top, bottom) = (o.from.1.min(o.to.1), o.from.1.max(o.to.1));
+                (left, top, right - left, bottom - top, o.measurement.price_change() >= 0.0)
+            })
+        })
+    };
+
+    view! {
+        <Show when=move || overlay.with(|o| o.is_some())>
+            <div
+                style:left=move || format!("{}px", rect().map_or(0.0, |r| r.0))
+                style:top=move || format!("{}px", rect().map_or(0.0, |r| r.1))
+                style:width=move || format!("{}px", rect().map_or(0.0, |r| r.2))
+                style:height=move || format!("{}px", rect().map_or(0.0, |r| r.3))
+                style:background=move || {
+                    if rect().is_none_or(|r| r.4) { "#74c78733" } else { "#e16c4833" }
+                }
+                style:border-color=move || {
+                    if rect().is_none_or(|r| r.4) { "#74c787" } else { "#e16c48" }
+                }
+                style="position:absolute;border:1px solid;pointer-events:none;"
+            >
+                <div style="position:absolute;left:0;top:-18px;padding:1px 4px;font-size:11px;white-space:nowrap;background:#4a5d73;color:white;border-radius:3px;">
+                    {move || {
+                        overlay.with(|o| {
+                            o.as_ref()
+                                .map(|o| o.label(current_interval().get()))
+                                .unwrap_or_default()
+                        })
+                    }}
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+/// ➕ Price and time labels at the crosshair, pinned to the right and bottom edges
+#[component]
+fn CrosshairReadout() -> impl IntoView {
+    let labels = crosshair_labels();
+    let label_style = "position:absolute;padding:1px 4px;font-size:11px;background:#4a5d73;color:white;border-radius:3px;pointer-events:none;";
+
+    view! {
+        <Show when=move || labels.with(|l| l.as_ref().is_some_and(|l| l.price.is_some()))>
+            <div style=label_style style:right="0px" style:top=move || {
+                labels.with(|l| format!("{}px", l.as_ref().map_or(0.0, |l| l.y - 8.0)))
+            }>
+                {move || {
+                    labels.with(|l| {
+                        l.as_ref().and_then(|l| l.price).map(price_label).unwrap_or_default()
+                    })
+                }}
+            </div>
+        </Show>
+        <Show when=move || labels.with(|l| l.as_ref().is_some_and(|l| l.time.is_some()))>
+            <div style=label_style style:bottom="0px" style:left=move || {
+                labels.with(|l| format!("{}px", l.as_ref().map_or(0.0, |l| l.x - 20.0)))
+            }>
+                {move || labels.with(|l| l.as_ref().and_then(|l| l.time.clone()).unwrap_or_default())}
+            </div>
+        </Show>
+    }
+}
+
+/// 💰 Price scale on the right side of the chart
+#[component]
+fn PriceScale(chart: RwSignal<Chart>) -> impl IntoView {
+    let current_price = global_current_price();
+
+    // Calculate price levels for display (same as in the grid)
+    let price_levels = move || {
+        if let Some(range) = price_axis_range().get() {
+            return axis_label_positions(&range, grid_price_divisions());
+        }
+        let vp = chart.with(|c| c.viewport.clone());
+        let levels = axis_price_levels(&vp, global_price_scale_mode().get(), invert_y().get());
+        let step = 100.0 / (levels.len() - 1) as f64;
+        levels
+            .into_iter()
+            .enumerate()
+            .map(|(i, level_price)| (level_price, i as f64 * step))
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="price-scale">
+            // Display price levels
+            <For
+                each=price_levels
+                key=|(_price, pos)| (*pos * 100.0) as i64
+                children=|(price, position)| view! {
+                    <div
+                        class="price-level"
+                        style=format!("position: absolute; top: {}%; right: 5px; transform: translateY(-50%); font-size: 11px; color: #888; background: rgba(0,0,0,0.7); padding: 2px 4px; border-radius: 2px;", position)
+                    >
+                        {move || price_label(price)}
+                    </div>
+                }
+            />
+
+            // Current price tag on the price line, following the renderer's price window
+            <div
+                class="current-price-label"
+                style:top=move || {
+                    price_axis_range()
+                        .get()
+                        .and_then(|r| r.percent_from_top(current_price.get()))
+                        .map_or("50%".to_string(), |p| format!("{p}%"))
+                }
+                style:display=move || {
+                    if price_axis_range().with(Option::is_some) { "block" } else { "none" }
+                }
+            >
+                <span class="price-value">
+                    {move || format!("${}", symbol_price(current_price.get()))}
+                </span>
+            </div>
+        </div>
+    }
+}
+
+/// 🎯 Chart Tooltip component inside the chart wrapper
+#[component]
+fn ChartTooltip() -> impl IntoView {
+    let tooltip_visible = tooltip_visible();
+    let tooltip_data = tooltip_data();
+
+    view! {
+        <div
+            class="tooltip"
+            style:display=move || if tooltip_visible.get() { "block" } else { "none" }
             style:left=move || {
                 tooltip_data.with(|data| {
                     if let Some(tooltip) = data {
@@ -1050,16 +3500,21 @@ fn ChartTooltip() -> impl IntoView {
 
 #[component]
 fn TimeframeSelector(chart: RwSignal<Chart>, set_status: WriteSignal<String>) -> impl IntoView {
+    // 👀 Hovering a button previews its timeframe once the pointer rests there
+    let preview = Rc::new(RefCell::new(Debouncer::<Option<TimeInterval>>::new()));
     let options = vec![
         TimeInterval::TwoSeconds,
         TimeInterval::OneMinute,
         TimeInterval::FiveMinutes,
         TimeInterval::FifteenMinutes,
         TimeInterval::OneHour,
+        TimeInterval::FourHours,
+        TimeInterval::OneDay,
+        TimeInterval::OneWeek,
     ];
 
     view! {
-        <div style="display:flex;gap:6px;margin-top:8px;">
+        <div style="display:flex;gap:6px;margin-top:8px;" role="toolbar" aria-label="Timeframe">
             <For
                 each=move || options.clone()
                 key=|i| i.as_ref().to_string()
@@ -1067,23 +3522,37 @@ fn TimeframeSelector(chart: RwSignal<Chart>, set_status: WriteSignal<String>) ->
                     let label = interval.as_ref().to_string();
                     let chart_signal = chart;
                     let status_signal = set_status;
+                    let preview_enter = preview.clone();
+                    let preview_leave = preview.clone();
+                    let preview_click = preview.clone();
                     view! {
                         <button
+                            class="chart-control"
                             style="padding:4px 6px;border:none;border-radius:4px;background:#74c787;color:black;"
-                            on:click=move |_| {
-                                current_interval().set(interval);
-                                if let Some(handle) = stream_abort_handles()
-                                    .with(|m| m.get(&current_symbol().get_untracked()).cloned())
-                                {
-                                    handle.abort();
-                                    stream_abort_handles().update(|m| {
-                                        m.remove(&current_symbol().get_untracked());
-                                    });
-                                }
-                                let status = status_signal;
-                                let _ = spawn_local_with_current_owner(async move {
-                                    start_websocket_stream(status).await;
+                            aria-label=format!("{label} timeframe")
+                            aria-pressed=move || (current_interval().get() == interval).to_string()
+                            disabled=move || {
+                                !Exchange::for_symbol(&current_symbol().get()).supports_interval(interval)
+                            }
+                            on:mouseenter=move |_| {
+                                let token = preview_enter.borrow_mut().schedule(Some(interval));
+                                let preview = preview_enter.clone();
+                                spawn_local(async move {
+                                    sleep(Duration::from_millis(TIMEFRAME_PREVIEW_DELAY_MS)).await;
+                                    let pending = preview.borrow_mut().take(token);
+                                    if let Some(interval) = pending {
+                                        preview_timeframe(chart_signal, interval);
+                                    }
                                 });
+                            }
+                            on:mouseleave=move |_| {
+                                preview_leave.borrow_mut().schedule(None);
+                                preview_timeframe(chart_signal, None);
+                            }
+                            on:click=move |_| {
+                                preview_click.borrow_mut().schedule(None);
+                                select_interval(interval);
+                                restart_streams(status_signal);
                                 chart_signal.update(|c| c.update_viewport_for_data());
                                 chart_signal.with_untracked(|c| {
                                     if c.get_candle_count() > 0 && with_global_renderer(|r| {
@@ -1091,7 +3560,7 @@ fn TimeframeSelector(chart: RwSignal<Chart>, set_status: WriteSignal<String>) ->
                                             let series = c.get_series(interval).unwrap();
                                             let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
                                             r.set_zoom_params(zoom, pan);
-                                            let _ = r.render(c);
+                                            request_render();
                                         }).is_none() {
                                         // renderer not available
                                     }
@@ -1107,139 +3576,1877 @@ fn TimeframeSelector(chart: RwSignal<Chart>, set_status: WriteSignal<String>) ->
     }
 }
 
+/// 🗓️ Buttons showing the last day, week or month of history
 #[component]
-fn LegendIndicatorToggle(name: &'static str, chart: RwSignal<Chart>) -> impl IntoView {
-    let id = name;
-    let label = name.to_uppercase();
-    let checked = move || {
-        global_line_visibility().with(|v| match name {
-            "sma20" => v.sma_20,
-            "sma50" => v.sma_50,
-            "sma200" => v.sma_200,
-            "ema12" => v.ema_12,
-            "ema26" => v.ema_26,
-            _ => true,
-        })
+fn QuickRangeSelector(set_status: WriteSignal<String>) -> impl IntoView {
+    view! {
+        <div style="display:flex;gap:4px;margin-top:8px;" role="toolbar" aria-label="Quick range">
+            {QuickRange::ALL
+                .into_iter()
+                .map(|range| {
+                    view! {
+                        <button
+                            class="chart-control"
+                            id=format!("quick-range-{}", range.label().to_lowercase())
+                            style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+                            title=format!("Show the last {} of history", range.label())
+                            aria-label=format!("Show the last {}", range.label())
+                            on:click=move |_| {
+                                let _ = spawn_local_with_current_owner(async move {
+                                    show_quick_range(range, set_status).await;
+                                });
+                            }
+                        >
+                            {range.label()}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+/// 📅 Date input that scrolls the chart to the chosen moment
+#[component]
+fn JumpToDate(set_status: WriteSignal<String>) -> impl IntoView {
+    let (date, set_date) = create_signal(String::new());
+    let submit = move || {
+        let value = date.get_untracked();
+        let target = js_sys::Date::parse(&value);
+        if value.is_empty() || target.is_nan() || target < 0.0 {
+            set_status.set(format!("⚠️ Invalid date: {value}"));
+            return;
+        }
+        let _ = spawn_local_with_current_owner(async move {
+            jump_to_date(target as u64, set_status).await;
+        });
     };
+
     view! {
-        <label style="display:flex;align-items:center;gap:4px;">
+        <div style="display:flex;gap:4px;margin-top:8px;">
             <input
-                type="checkbox"
+                type="datetime-local"
+                id="jump-to-date"
+                title="Scroll to the candle nearest this date"
+                style="padding:2px 4px;border:none;border-radius:4px;background:#444;color:white;"
+                prop:value=date
+                on:input=move |ev| set_date.set(event_target_value(&ev))
+                on:keydown=move |ev: web_sys::KeyboardEvent| {
+                    if ev.key() == "Enter" {
+                        submit();
+                    }
+                }
+            />
+            <button
+                style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+                on:click=move |_| submit()
+            >
+                "GO"
+            </button>
+            <button
+                id="backfill-history"
+                style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+                title="Load all history back to this date, or as much as the chart keeps when blank"
+                on:click=move |_| {
+                    let value = date.get_untracked();
+                    let target = js_sys::Date::parse(&value);
+                    let goal = if value.is_empty() {
+                        BackfillGoal::CandleCount(history_window().get_untracked().buffer_size)
+                    } else if target.is_nan() || target < 0.0 {
+                        set_status.set(format!("⚠️ Invalid date: {value}"));
+                        return;
+                    } else {
+                        BackfillGoal::StartTime(target as u64)
+                    };
+                    let _ = spawn_local_with_current_owner(async move {
+                        backfill_history(goal, set_status).await;
+                    });
+                }
+            >
+                "FILL"
+            </button>
+        </div>
+    }
+}
+
+/// ⚡ Buttons that scroll to the candles with the largest close-to-close moves
+#[component]
+fn LargestMoveButtons(set_status: WriteSignal<String>) -> impl IntoView {
+    let button = |kind: MoveKind, id: &'static str, label: &'static str, title: &'static str| {
+        view! {
+            <button
                 id=id
-                prop:checked=checked
-                on:change=move |_| {
-                    chart.with_untracked(|c| {
-                        if with_global_renderer(|r| {
-                            r.toggle_line_visibility(name);
-                            let _ = r.render(c);
-                        }).is_none() {
-                            // renderer not available
+                style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+                title=title
+                on:click=move |_| jump_to_largest_move(kind, set_status)
+            >
+                {label}
+            </button>
+        }
+    };
+
+    view! {
+        <div style="display:flex;gap:4px;margin-top:8px;">
+            {button(
+                MoveKind::Absolute,
+                "largest-move-abs",
+                "MAX Δ",
+                "Scroll to the candle with the largest price change",
+            )}
+            {button(
+                MoveKind::Percent,
+                "largest-move-pct",
+                "MAX %",
+                "Scroll to the candle with the largest percent change",
+            )}
+        </div>
+    }
+}
+
+/// ⏪ Replay history candle by candle with play/pause, step and speed controls
+#[component]
+fn ReplayControls(set_status: WriteSignal<String>) -> impl IntoView {
+    let (start, set_start) = create_signal(String::new());
+    let button_style = |active: bool| {
+        format!(
+            "padding:4px 6px;border:none;border-radius:4px;color:white;background:{};",
+            if active { "#74c787" } else { "#444" }
+        )
+    };
+
+    view! {
+        <div style="display:flex;gap:4px;margin-top:8px;">
+            <Show
+                when=move || replay().with(|r| r.is_some())
+                fallback=move || view! {
+                    <input
+                        type="number"
+                        id="replay-start"
+                        min="0"
+                        placeholder="from #"
+                        title="Candle index the replay starts from; blank starts halfway"
+                        style="width:64px;padding:2px 4px;border:none;border-radius:4px;background:#444;color:white;"
+                        prop:value=start
+                        on:input=move |ev| set_start.set(event_target_value(&ev))
+                    />
+                    <button
+                        id="replay-start-button"
+                        style=button_style(false)
+                        title="Replay the loaded history candle by candle"
+                        on:click=move |_| {
+                            let len = global_candle_count().get_untracked();
+                            start_replay(replay_start_index(&start.get_untracked(), len), set_status);
                         }
-                    });
+                    >
+                        "REPLAY"
+                    </button>
                 }
-            />
-            {label}
-        </label>
+            >
+                <button
+                    id="replay-play"
+                    style=button_style(false)
+                    on:click=move |_| replay().update(|r| {
+                        if let Some(session) = r {
+                            session.controller.toggle();
+                        }
+                    })
+                >
+                    {move || {
+                        let playing = replay().with(|r| {
+                            r.as_ref().is_some_and(|s| s.controller.state() == ReplayState::Playing)
+                        });
+                        if playing { "⏸" } else { "▶" }
+                    }}
+                </button>
+                <button
+                    id="replay-step"
+                    style=button_style(false)
+                    title="Advance exactly one candle"
+                    on:click=move |_| advance_replay(|controller| controller.step())
+                >
+                    "STEP"
+                </button>
+                {ReplaySpeed::ALL
+                    .into_iter()
+                    .map(|speed| {
+                        view! {
+                            <button
+                                style=move || button_style(
+                                    replay().with(|r| r.as_ref().is_some_and(|s| s.controller.speed() == speed)),
+                                )
+                                on:click=move |_| replay().update(|r| {
+                                    if let Some(session) = r {
+                                        session.controller.set_speed(speed);
+                                    }
+                                })
+                            >
+                                {speed.to_string()}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+                <span style="font-size:12px;color:#888;align-self:center;">
+                    {move || replay().with(|r| {
+                        r.as_ref()
+                            .map(|s| format!("{}/{}", s.controller.position(), s.controller.len()))
+                            .unwrap_or_default()
+                    })}
+                </span>
+                <button
+                    id="replay-stop"
+                    style=button_style(false)
+                    title="Stop the replay and return to live data"
+                    on:click=move |_| stop_replay(set_status)
+                >
+                    "STOP"
+                </button>
+            </Show>
+        </div>
     }
 }
 
+/// 🕯️ Switch between regular candlesticks and Heikin-Ashi bars
 #[component]
-fn Legend(chart: RwSignal<Chart>) -> impl IntoView {
-    let names = vec!["sma20", "sma50", "sma200", "ema12", "ema26"];
+fn ChartTypeSelector(chart: RwSignal<Chart>) -> impl IntoView {
+    let options =
+        vec![ChartType::Candlestick, ChartType::HeikinAshi, ChartType::Line, ChartType::Area];
+
     view! {
         <div style="display:flex;gap:6px;margin-top:8px;">
             <For
-                each=move || names.clone()
-                key=|name| name.to_string()
-                children=move |name| view! { <LegendIndicatorToggle name=name chart=chart /> }
+                each=move || options.clone()
+                key=|t| t.as_ref().to_string()
+                children=move |chart_type| {
+                    let label = chart_type.to_string();
+                    view! {
+                        <button
+                            style=move || {
+                                let bg = if global_chart_type().get() == chart_type {
+                                    "#74c787"
+                                } else {
+                                    "#444"
+                                };
+                                format!(
+                                    "padding:4px 6px;border:none;border-radius:4px;background:{bg};color:black;"
+                                )
+                            }
+                            on:click=move |_| {
+                                global_chart_type().set(chart_type);
+                                chart.update(|c| c.chart_type = chart_type);
+                                chart.with_untracked(|c| {
+                                    set_chart_in_ecs(&current_symbol().get_untracked(), c.clone());
+                                    if c.get_candle_count() > 0 {
+                                        request_render();
+                                    }
+                                });
+                            }
+                        >
+                            {label}
+                        </button>
+                    }
+                }
             />
         </div>
     }
 }
 
-#[component]
-fn AssetSelector(set_status: WriteSignal<String>) -> impl IntoView {
-    let options = default_symbols();
-
-    view! {
-        <div style="display:flex;gap:6px;margin-top:8px;">
-            <For
-                each=move || options.clone()
-                key=|s: &Symbol| s.value().to_string()
-                children=move |sym: Symbol| {
-                    let label = sym.value().to_string();
-                    let status_cloned = set_status;
-                    view! {
-                        <button
-                            style="padding:4px 6px;border:none;border-radius:4px;background:#2a5298;color:white;"
-                            on:click=move |_| {
-                                current_symbol().set(sym.clone());
-                                let _ = spawn_local_with_current_owner(async move {
-                                    start_websocket_stream(status_cloned).await;
-                                });
-                            }
-                        >
-                            {label}
-                        </button>
-                    }
-                }
-            />
-        </div>
+#[component]
+fn LegendIndicatorToggle(name: String) -> impl IntoView {
+    let id = name.clone();
+    let label = name.to_uppercase();
+    let checked = {
+        let name = name.clone();
+        move || global_line_visibility().with(|v| v.is_visible(&name))
+    };
+    // 🎨 Line indicators carry a color; volume overlays and markers keep theirs
+    let line_color = {
+        let name = name.clone();
+        move || global_line_visibility().with(|v| v.color(&name))
+    };
+    // Created once per entry so an open color dialog survives the recoloring it triggers
+    let initial_color = untrack(line_color.clone());
+    let picker = initial_color.map(|color| {
+        let name = name.clone();
+        let line_color = line_color.clone();
+        view! {
+            <input
+                type="color"
+                class="chart-control"
+                id=format!("{name}-color")
+                title="Line color"
+                aria-label=format!("{} line color", name.to_uppercase())
+                style="width:18px;height:18px;padding:0;border:none;background:none;"
+                prop:value=move || line_color().map(color_to_hex).unwrap_or_default()
+                on:input=move |ev| {
+                    let Some(color) = color_from_hex(&event_target_value(&ev), color[3]) else {
+                        return;
+                    };
+                    with_global_renderer(|r| {
+                        if r.set_indicator_color(&name, color) {
+                            request_render();
+                        }
+                    });
+                }
+            />
+        }
+    });
+    view! {
+        <label
+            style="display:flex;align-items:center;gap:4px;"
+            style:color=move || line_color().map(color_to_hex)
+        >
+            {picker}
+            <input
+                type="checkbox"
+                class="chart-control"
+                id=id
+                aria-label=format!("Show {label}")
+                prop:checked=checked
+                on:keydown=toggle_checkbox_on_enter
+                on:change=move |_| {
+                    if with_global_renderer(|r| {
+                        r.toggle_line_visibility(&name);
+                        request_render();
+                    }).is_none() {
+                        // renderer not available
+                    }
+                }
+            />
+            {label}
+        </label>
+    }
+}
+
+/// Style shared by the groups of the settings panel
+const SETTINGS_SECTION_STYLE: &str = "display:flex;flex-wrap:wrap;align-items:center;gap:6px;margin:0;padding:4px 6px;border:1px solid #4a5d73;border-radius:4px;";
+
+/// ⚙️ Escape inside the settings panel collapses it and returns focus to its button
+fn close_settings_on_escape(ev: ev::KeyboardEvent) {
+    if ev.key() != "Escape" {
+        return;
+    }
+    ev.prevent_default();
+    settings_panel_open().set(false);
+    if let Some(button) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id("settings-toggle"))
+        .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let _ = button.focus();
+    }
+}
+
+/// ⚙️ Collapsible panel gathering the indicator, display, scale and size settings.
+///
+/// Collapsing hides the controls without unmounting them; the expanded state
+/// survives reloads through [`settings_panel_open`].
+#[component]
+fn SettingsPanel(chart: RwSignal<Chart>) -> impl IntoView {
+    view! {
+        <div style="display:flex;flex-direction:column;gap:4px;">
+            <button
+                id="settings-toggle"
+                class="chart-control"
+                aria-label="Settings"
+                aria-controls="settings-panel"
+                aria-expanded=move || settings_panel_open().get().to_string()
+                style=move || format!(
+                    "padding:2px 6px;border:none;border-radius:4px;color:white;background:{};",
+                    if settings_panel_open().get() { "#4a5d73" } else { "#444" }
+                )
+                title="Show or hide indicator and display settings; Escape closes them"
+                on:click=move |_| toggle_settings_panel()
+            >
+                "⚙ SETTINGS"
+            </button>
+            <div
+                id="settings-panel"
+                role="region"
+                aria-label="Chart settings"
+                style="flex-direction:column;gap:4px;"
+                style:display=move || if settings_panel_open().get() { "flex" } else { "none" }
+                on:keydown=close_settings_on_escape
+            >
+                <fieldset style=SETTINGS_SECTION_STYLE>
+                    <legend style="padding:0 4px;font-size:11px;color:#888;">"Indicators"</legend>
+                    <For
+                        each=move || global_line_visibility().with(|v| v.names())
+                        key=|name| name.clone()
+                        children=move |name| view! { <LegendIndicatorToggle name=name /> }
+                    />
+                    <input
+                        type="text"
+                        class="chart-control"
+                        id="ma-configs"
+                        size="24"
+                        title="Moving averages, e.g. sma20, ema12"
+                        aria-label="Moving averages"
+                        prop:value=move || {
+                            global_line_visibility().with(|v| {
+                                v.lines.iter().map(|l| l.name()).collect::<Vec<_>>().join(", ")
+                            })
+                        }
+                        on:change=move |ev| {
+                            let configs: Vec<MaConfig> = event_target_value(&ev)
+                                .split(',')
+                                .filter_map(MaConfig::parse)
+                                .collect();
+                            chart.update(|c| c.set_ma_configs(&configs));
+                            with_global_renderer(|r| {
+                                r.set_ma_configs(&configs);
+                                request_render();
+                            });
+                        }
+                    />
+                </fieldset>
+                <fieldset style=SETTINGS_SECTION_STYLE>
+                    <legend style="padding:0 4px;font-size:11px;color:#888;">"Display"</legend>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Fit the price range to candles only and clip indicator lines"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="clip-indicators"
+                            aria-label="Clip indicators to the candle range"
+                            on:keydown=toggle_checkbox_on_enter
+                            on:change=move |_| {
+                                with_global_renderer(|r| {
+                                    let enabled = !r.clip_indicators();
+                                    r.set_clip_indicators(enabled);
+                                    request_render();
+                                });
+                            }
+                        />
+                        "CLIP MA"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Draw candle wicks; the price range keeps highs and lows either way"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="show-wicks"
+                            prop:checked=move || show_wicks().get()
+                            aria-label="Candle wicks"
+                            on:keydown=toggle_checkbox_on_enter
+                            on:change=move |_| toggle_wicks()
+                        />
+                        "WICKS"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Draw a thin border around candle bodies"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="candle-outline"
+                            aria-label="Candle outlines"
+                            on:keydown=toggle_checkbox_on_enter
+                            on:change=move |_| {
+                                with_global_renderer(|r| {
+                                    let outline = match r.candle_outline() {
+                                        Some(_) => None,
+                                        None => Some(CandleOutline::default()),
+                                    };
+                                    r.set_candle_outline(outline);
+                                    request_render();
+                                });
+                            }
+                        />
+                        "OUTLINE"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Switch between the dark and light color themes"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="light-theme"
+                            aria-label="Light theme"
+                            prop:checked=move || chart_theme().get().is_light()
+                            on:keydown=toggle_checkbox_on_enter
+                            on:change=move |_| toggle_light_theme()
+                        />
+                        "LIGHT"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Chart background color; switching the theme restores its own"
+                    >
+                        <input
+                            type="color"
+                            class="chart-control"
+                            id="background-color"
+                            aria-label="Background color"
+                            style="width:18px;height:18px;padding:0;border:none;background:none;"
+                            prop:value=move || color_to_hex(chart_theme().get().background)
+                            on:input=move |ev| {
+                                let Some(color) = color_from_hex(&event_target_value(&ev), 1.0)
+                                else {
+                                    return;
+                                };
+                                with_global_renderer(|r| {
+                                    r.set_background_color(color);
+                                    request_render();
+                                });
+                            }
+                        />
+                        "BG"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Show the background grid"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="grid-visible"
+                            aria-label="Background grid"
+                            on:keydown=toggle_checkbox_on_enter
+                            prop:checked=move || grid_visible().get()
+                            on:change=move |_| toggle_grid()
+                        />
+                        "GRID"
+                    </label>
+                </fieldset>
+                <fieldset style=SETTINGS_SECTION_STYLE>
+                    <legend style="padding:0 4px;font-size:11px;color:#888;">"Price scale"</legend>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Logarithmic price scale"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="log-scale"
+                            aria-label="Logarithmic price scale"
+                            on:keydown=toggle_checkbox_on_enter
+                            prop:checked=move || {
+                                global_price_scale_mode().get() == PriceScaleMode::Logarithmic
+                            }
+                            on:change=move |_| {
+                                with_global_renderer(|r| {
+                                    let mode = match r.price_scale_mode() {
+                                        PriceScaleMode::Linear => PriceScaleMode::Logarithmic,
+                                        PriceScaleMode::Logarithmic => PriceScaleMode::Linear,
+                                    };
+                                    r.set_price_scale_mode(mode);
+                                    request_render();
+                                });
+                            }
+                        />
+                        "LOG"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Invert the price axis"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="invert-y"
+                            aria-label="Invert the price axis"
+                            on:keydown=toggle_checkbox_on_enter
+                            prop:checked=move || invert_y().get()
+                            on:change=move |_| {
+                                with_global_renderer(|r| {
+                                    r.set_invert_y(!r.invert_y());
+                                    request_render();
+                                });
+                            }
+                        />
+                        "INV"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Snap the crosshair to the hovered candle's open, high, low or close"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="crosshair-magnet"
+                            aria-label="Snap the crosshair to OHLC values"
+                            on:keydown=toggle_checkbox_on_enter
+                            prop:checked=move || crosshair_magnet().get()
+                            on:change=move |_| crosshair_magnet().update(|m| *m = !*m)
+                        />
+                        "MAGNET"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Label prices as percent change from the first visible candle"
+                    >
+                        <input
+                            type="checkbox"
+                            class="chart-control"
+                            id="percent-mode"
+                            aria-label="Percent price labels"
+                            on:keydown=toggle_checkbox_on_enter
+                            prop:checked=move || price_display_mode().get() == PriceDisplayMode::Percent
+                            on:change=move |_| {
+                                price_display_mode().update(|mode| {
+                                    *mode = match mode {
+                                        PriceDisplayMode::Price => PriceDisplayMode::Percent,
+                                        PriceDisplayMode::Percent => PriceDisplayMode::Price,
+                                    };
+                                });
+                            }
+                        />
+                        "%"
+                    </label>
+                </fieldset>
+                <fieldset style=SETTINGS_SECTION_STYLE>
+                    <legend style="padding:0 4px;font-size:11px;color:#888;">"Time"</legend>
+                    <TimeZoneSelector />
+                </fieldset>
+                <fieldset style=SETTINGS_SECTION_STYLE>
+                    <legend style="padding:0 4px;font-size:11px;color:#888;">"Candle size"</legend>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Maximum candle body width"
+                    >
+                        <input
+                            type="range"
+                            class="chart-control"
+                            id="candle-width"
+                            aria-label="Candle width"
+                            min=MIN_USER_ELEMENT_WIDTH.to_string()
+                            max=MAX_USER_ELEMENT_WIDTH.to_string()
+                            step="0.001"
+                            prop:value=move || candle_sizing().get().max_width.to_string()
+                            style="width:70px;"
+                            on:input=move |ev| {
+                                if let Ok(width) = event_target_value(&ev).parse::<f32>() {
+                                    update_candle_sizing(|s| {
+                                        CandleSizing::new(s.min_width.min(width), width, s.spacing_ratio)
+                                    });
+                                }
+                            }
+                        />
+                        "WIDTH"
+                    </label>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Gap between candles as a share of the step"
+                    >
+                        <input
+                            type="range"
+                            class="chart-control"
+                            id="candle-spacing"
+                            aria-label="Candle gap"
+                            min="0"
+                            max=MAX_USER_SPACING_RATIO.to_string()
+                            step="0.05"
+                            prop:value=move || candle_sizing().get().spacing_ratio.to_string()
+                            style="width:70px;"
+                            on:input=move |ev| {
+                                if let Ok(spacing) = event_target_value(&ev).parse::<f32>() {
+                                    update_candle_sizing(|s| {
+                                        CandleSizing::new(s.min_width, s.max_width, spacing)
+                                    });
+                                }
+                            }
+                        />
+                        "GAP"
+                    </label>
+                </fieldset>
+                <fieldset style=SETTINGS_SECTION_STYLE>
+                    <legend style="padding:0 4px;font-size:11px;color:#888;">"Zoom"</legend>
+                    <label
+                        style="display:flex;align-items:center;gap:4px;"
+                        title="Zoom step of the mouse wheel and keyboard: low is fine, high is coarse"
+                    >
+                        <input
+                            type="range"
+                            class="chart-control"
+                            id="zoom-sensitivity"
+                            aria-label="Zoom sensitivity"
+                            min=MIN_ZOOM_SENSITIVITY.to_string()
+                            max=MAX_ZOOM_SENSITIVITY.to_string()
+                            step="0.25"
+                            prop:value=move || zoom_sensitivity().get().to_string()
+                            style="width:70px;"
+                            on:input=move |ev| {
+                                if let Ok(sensitivity) = event_target_value(&ev).parse::<f64>() {
+                                    zoom_sensitivity().set(
+                                        sensitivity.clamp(MIN_ZOOM_SENSITIVITY, MAX_ZOOM_SENSITIVITY),
+                                    );
+                                }
+                            }
+                        />
+                        {move || format!("{:.2}x", zoom_sensitivity().get())}
+                    </label>
+                </fieldset>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn Legend(chart: RwSignal<Chart>) -> impl IntoView {
+    view! {
+        <div style="display:flex;flex-wrap:wrap;align-items:flex-start;gap:6px;margin-top:8px;" role="group" aria-label="Indicators and chart options">
+            <SettingsPanel chart=chart />
+            <button
+                id="fib-tool"
+                class="chart-control"
+                aria-label="Fibonacci retracement"
+                aria-pressed=move || fib_mode().get().to_string()
+                style=move || format!(
+                    "padding:2px 6px;border:none;border-radius:4px;color:white;background:{};",
+                    if fib_mode().get() { "#4a5d73" } else { "#444" }
+                )
+                title="Fibonacci retracement: click the start and end points, Escape removes it"
+                on:click=move |_| {
+                    if fib_mode().get_untracked() {
+                        // Cancel an unfinished drawing
+                        if fib_anchor().get_untracked().is_some() {
+                            clear_fib(chart);
+                        }
+                        fib_mode().set(false);
+                    } else {
+                        fib_anchor().set(None);
+                        fib_mode().set(true);
+                    }
+                }
+            >
+                "FIB"
+            </button>
+            <button
+                id="measure-tool"
+                class="chart-control"
+                aria-label="Measure"
+                aria-pressed=move || measure_mode().get().to_string()
+                style=move || format!(
+                    "padding:2px 6px;border:none;border-radius:4px;color:white;background:{};",
+                    if measure_mode().get() { "#4a5d73" } else { "#444" }
+                )
+                title="Measure: drag from A to B for price change, percent, bars and time; Escape removes it"
+                on:click=move |_| {
+                    if measure_mode().get_untracked() {
+                        measure_mode().set(false);
+                    } else {
+                        measure_overlay().set(None);
+                        measure_mode().set(true);
+                    }
+                }
+            >
+                "MEASURE"
+            </button>
+            <button
+                id="follow-live"
+                class="chart-control"
+                aria-label="Follow live"
+                aria-pressed=move || follow_live().get().to_string()
+                style=move || format!(
+                    "padding:2px 6px;border:none;border-radius:4px;color:white;background:{};",
+                    if follow_live().get() { "#74c787" } else { "#444" }
+                )
+                title="Follow live: snap to the latest candle and keep scrolling with new ones"
+                on:click=move |_| {
+                    if follow_live().get_untracked() {
+                        follow_live().set(false);
+                    } else {
+                        follow_live().set(true);
+                        chart.update(|c| c.update_viewport_for_data());
+                        render_if_current(&current_symbol().get_untracked());
+                    }
+                }
+            >
+                "LIVE"
+            </button>
+            <button
+                id="pause-live"
+                class="chart-control"
+                aria-label="Pause live updates"
+                aria-pressed=move || live_paused().get().to_string()
+                style=move || format!(
+                    "padding:2px 6px;border:none;border-radius:4px;color:white;background:{};",
+                    if live_paused().get() { "#e16c48" } else { "#444" }
+                )
+                title="Pause: hold new candles back to study the chart; resume applies them"
+                on:click=move |_| toggle_live_paused()
+            >
+                {move || if live_paused().get() { "RESUME" } else { "PAUSE" }}
+            </button>
+            <button
+                id="export-png"
+                class="chart-control"
+                aria-label="Export PNG"
+                style="padding:2px 6px;border:none;border-radius:4px;background:#444;color:white;"
+                title="Save the chart as a PNG image"
+                on:click=move |_| {
+                    spawn_local(async move {
+                        match export_chart_png().await {
+                            Ok(url) => {
+                                let file_name = format!(
+                                    "chart-{}-{}.png",
+                                    current_symbol().get_untracked().value(),
+                                    get_time_provider().current_timestamp()
+                                );
+                                let _ = download_data_url(&url, &file_name);
+                            }
+                            Err(e) => get_logger().error(
+                                LogComponent::Presentation("ExportPng"),
+                                &format!("PNG export failed: {e:?}"),
+                            ),
+                        }
+                    });
+                }
+            >
+                "PNG"
+            </button>
+            <button
+                id="export-csv"
+                class="chart-control"
+                aria-label="Export CSV"
+                style="padding:2px 6px;border:none;border-radius:4px;background:#444;color:white;"
+                title="Save the visible candles as CSV"
+                on:click=move |_| {
+                    let interval = current_interval().get_untracked();
+                    let csv = chart.with_untracked(|ch| {
+                        ch.get_series(interval).map(|series| {
+                            let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &ch.viewport);
+                            let (start, count) =
+                                visible_range(series.get_candles().len(), zoom, pan);
+                            export_csv(series, start, count)
+                        })
+                    });
+                    let Some(csv) = csv else { return };
+                    let url = format!(
+                        "data:text/csv;charset=utf-8,{}",
+                        js_sys::encode_uri_component(&csv)
+                    );
+                    let file_name = csv_file_name(
+                        &current_symbol().get_untracked(),
+                        interval,
+                        get_time_provider().current_timestamp(),
+                    );
+                    if let Err(e) = download_data_url(&url, &file_name) {
+                        get_logger().error(
+                            LogComponent::Presentation("ExportCsv"),
+                            &format!("CSV export failed: {e:?}"),
+                        );
+                    }
+                }
+            >
+                "CSV"
+            </button>
+            <button
+                id="copy-state"
+                class="chart-control"
+                aria-label="Copy chart state"
+                style="padding:2px 6px;border:none;border-radius:4px;background:#444;color:white;"
+                title="Copy the chart state as JSON for bug reports"
+                on:click=move |_| {
+                    let copied = chart_state_json()
+                        .map_err(wasm_bindgen::JsValue::from)
+                        .and_then(|json| copy_to_clipboard(&json));
+                    if let Err(e) = copied {
+                        get_logger().error(
+                            LogComponent::Presentation("ChartState"),
+                            &format!("Copying the chart state failed: {e:?}"),
+                        );
+                    }
+                }
+            >
+                "STATE"
+            </button>
+        </div>
+    }
+}
+
+/// Size of the sparkline inside each symbol button (px)
+pub const SPARKLINE_WIDTH: f64 = 48.0;
+pub const SPARKLINE_HEIGHT: f64 = 14.0;
+/// Candles a sparkline covers, one day of hourly closes
+pub const SPARKLINE_POINTS: u32 = 24;
+pub const SPARKLINE_INTERVAL: TimeInterval = TimeInterval::OneHour;
+
+/// SVG path tracing `closes` across a `width` x `height` box, highest close at the top.
+///
+/// A flat series runs through the middle; fewer than two closes give an empty path.
+pub fn sparkline_path(closes: &[f64], width: f64, height: f64) -> String {
+    if closes.len() < 2 {
+        return String::new();
+    }
+    trace_closes(closes, width, height, 1)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (x, y))| format!("{}{x:.1},{y:.1}", if i == 0 { 'M' } else { 'L' }))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 📈 Fetch the recent closes of `symbol` for its sparkline once, within the history
+/// request budget; cached ones are kept
+fn load_sparkline(symbol: &Symbol) {
+    if sparklines().with_untracked(|m| m.contains_key(symbol)) {
+        return;
+    }
+    let symbol = symbol.clone();
+    spawn_local(async move {
+        // Sparklines share the history request budget and wait for it like backfills
+        loop {
+            let now = get_time_provider().current_timestamp();
+            if with_history_rate_limiter(|limiter| limiter.try_acquire(now)) {
+                break;
+            }
+            sleep(Duration::from_millis(history_request_wait_ms().max(1))).await;
+        }
+        let client = create_market_data_client(&symbol, SPARKLINE_INTERVAL);
+        let result = client.lock().await.fetch_historical_data(SPARKLINE_POINTS).await;
+        match result {
+            Ok(candles) => {
+                let closes = candles.iter().map(|c| c.ohlcv.close.value()).collect();
+                sparklines().update(|m| {
+                    m.insert(symbol, closes);
+                });
+            }
+            Err(e) => get_logger().warn(
+                LogComponent::Presentation("Sparkline"),
+                &format!("⚠️ Recent prices unavailable for {}: {e:?}", symbol.value()),
+            ),
+        }
+    });
+}
+
+/// 🏦 Pick the exchange; the current symbol moves to the same pair there
+#[component]
+fn ExchangeSelector(set_status: WriteSignal<String>) -> impl IntoView {
+    view! {
+        <select
+            id="exchange"
+            aria-label="Exchange"
+            style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+            on:change=move |ev| {
+                let value = event_target_value(&ev);
+                let Some(exchange) = Exchange::ALL.into_iter().find(|e| e.label() == value) else {
+                    return;
+                };
+                let current = current_symbol().get_untracked();
+                let symbol = Symbol::from(exchange.format_symbol(&current).as_str());
+                if symbol != current {
+                    switch_symbol(symbol, set_status);
+                }
+            }
+        >
+            {Exchange::ALL
+                .into_iter()
+                .map(|exchange| {
+                    view! {
+                        <option
+                            value=exchange.label()
+                            selected=move || {
+                                Exchange::for_symbol(&current_symbol().get()) == exchange
+                            }
+                        >
+                            {exchange.label()}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+#[component]
+fn AssetSelector(set_status: WriteSignal<String>) -> impl IntoView {
+    view! {
+        <div style="display:flex;gap:6px;margin-top:8px;" role="toolbar" aria-label="Symbol">
+            <ExchangeSelector set_status=set_status />
+            <For
+                each=move || Exchange::for_symbol(&current_symbol().get()).symbols()
+                key=|s: &Symbol| s.value().to_string()
+                children=move |sym: Symbol| {
+                    let label = sym.value().to_string();
+                    let status_cloned = set_status;
+                    let pressed = sym.clone();
+                    load_sparkline(&sym);
+                    let spark = sym.clone();
+                    let sparkline = move || {
+                        sparklines().with(|m| m.get(&spark).cloned()).map(|closes| {
+                            let rising = closes.last() >= closes.first();
+                            view! {
+                                <svg
+                                    width=SPARKLINE_WIDTH.to_string()
+                                    height=SPARKLINE_HEIGHT.to_string()
+                                    aria-hidden="true"
+                                    style="display:block;margin-top:2px;"
+                                >
+                                    <path
+                                        d=sparkline_path(&closes, SPARKLINE_WIDTH, SPARKLINE_HEIGHT)
+                                        fill="none"
+                                        stroke=if rising { "#74c787" } else { "#e16c48" }
+                                        stroke-width="1"
+                                    />
+                                </svg>
+                            }
+                        })
+                    };
+                    view! {
+                        <button
+                            class="chart-control"
+                            style="padding:4px 6px;border:none;border-radius:4px;background:#2a5298;color:white;"
+                            aria-label=format!("Show {label}")
+                            aria-pressed=move || (current_symbol().get() == pressed).to_string()
+                            on:click=move |_| switch_symbol(sym.clone(), status_cloned)
+                        >
+                            {label}
+                            {sparkline}
+                        </button>
+                    }
+                }
+            />
+        </div>
+    }
+}
+
+/// 🆚 Pick a second symbol to draw next to the main chart
+#[component]
+fn CompareSelector(set_status: WriteSignal<String>) -> impl IntoView {
+    let options = default_symbols();
+
+    view! {
+        <select
+            style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+            on:change=move |ev| {
+                let value = event_target_value(&ev);
+                let symbol = (!value.is_empty()).then(|| Symbol::from(value.as_str()));
+                compare_symbol().set(symbol.clone());
+                if let Some(symbol) = symbol {
+                    let _ = spawn_local_with_current_owner(async move {
+                        start_symbol_stream(symbol, set_status).await;
+                    });
+                }
+            }
+        >
+            <option value="" selected=move || compare_symbol().with(|s| s.is_none())>
+                "COMPARE"
+            </option>
+            {options
+                .into_iter()
+                .map(|sym| {
+                    let value = sym.value().to_string();
+                    let label = value.clone();
+                    view! {
+                        <option
+                            value=value
+                            selected=move || compare_symbol().with(|s| s.as_ref() == Some(&sym))
+                        >
+                            {label}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// 🆚 Whether `candles` span the candles opening from `start` to `end` (ms)
+pub fn overlay_covers(candles: &[Candle], start: u64, end: u64) -> bool {
+    overlay_missing_page(candles, start, end).is_none()
+}
+
+/// 🆚 End time (ms) of the history page the overlay still needs to span the candles
+/// opening from `start` to `end`, newer ones first; `None` once they are covered
+pub fn overlay_missing_page(candles: &[Candle], start: u64, end: u64) -> Option<u64> {
+    match (candles.first(), candles.last()) {
+        (Some(first), Some(last)) => {
+            if last.timestamp.value() < end {
+                Some(end)
+            } else if first.timestamp.value() > start {
+                Some(first.timestamp.value() - 1)
+            } else {
+                None
+            }
+        }
+        _ => Some(end),
+    }
+}
+
+/// 🆚 Merge a fetched history `page` into the overlay candles and return whether they
+/// now span more time.
+///
+/// Candles already held win over the page's copies. A page that neither overlaps
+/// nor borders them replaces them, so the overlay never has a gap.
+pub fn merge_overlay_page(
+    candles: &mut Vec<Candle>,
+    page: Vec<Candle>,
+    interval: TimeInterval,
+) -> bool {
+    let span = |c: &[Candle]| {
+        c.first().zip(c.last()).map(|(f, l)| (f.timestamp.value(), l.timestamp.value()))
+    };
+    let Some((page_first, page_last)) = span(&page) else { return false };
+    let before = span(candles);
+    let step = interval.duration_ms();
+    match before {
+        Some((first, last))
+            if page_first <= last.saturating_add(step)
+                && page_last.saturating_add(step) >= first =>
+        {
+            let mut merged: Vec<Candle> =
+                page.iter().filter(|c| c.timestamp.value() < first).cloned().collect();
+            merged.append(candles);
+            merged.extend(page.into_iter().filter(|c| c.timestamp.value() > last));
+            *candles = merged;
+        }
+        _ => *candles = page,
+    }
+    span(candles) != before
+}
+
+/// 🆚 Apply a streamed candle of the overlaid symbol: it replaces the last candle
+/// or follows it; older candles and ones that would leave a gap are dropped
+pub fn apply_overlay_candle(candles: &mut Vec<Candle>, candle: Candle, interval: TimeInterval) {
+    let Some(last) = candles.last_mut() else { return };
+    let (ts, last_ts) = (candle.timestamp.value(), last.timestamp.value());
+    if ts == last_ts {
+        *last = candle;
+    } else if ts > last_ts && ts - last_ts <= interval.duration_ms() {
+        candles.push(candle);
+    }
+}
+
+/// 🆚 Fetch the overlay history the visible range still misses, one page at a time
+/// within the history request budget.
+///
+/// Each page re-reads the visible range, so a pan during a fetch extends the same
+/// run. Older pages stop at `floor`, the oldest candle the exchange returned.
+async fn fill_overlay(
+    request: Memo<Option<OverlayRequest>>,
+    floor: Rc<Cell<Option<u64>>>,
+    set_status: WriteSignal<String>,
+) {
+    loop {
+        let Some((symbol, interval, start, end)) = request.get_untracked() else { return };
+        let start = start.max(floor.get().unwrap_or(0));
+        let Some(page_end) =
+            overlay_candles().with_untracked(|c| overlay_missing_page(c, start, end))
+        else {
+            return;
+        };
+        let wait = history_request_wait_ms();
+        if wait > 0 {
+            sleep(Duration::from_millis(wait)).await;
+            continue;
+        }
+        let result = fetch_history_page(&symbol, interval, page_end).await;
+        if request
+            .with_untracked(|r| r.as_ref().map(|(s, i, ..)| (s, *i)) != Some((&symbol, interval)))
+        {
+            continue;
+        }
+        match result {
+            Ok(page) => {
+                let first =
+                    overlay_candles().with_untracked(|c| c.first().map(|c| c.timestamp.value()));
+                let older = first.is_some_and(|first| page_end < first);
+                let mut grew = false;
+                overlay_candles().update(|c| grew = merge_overlay_page(c, page, interval));
+                if !grew {
+                    if !older {
+                        return;
+                    }
+                    floor.set(first);
+                }
+            }
+            Err(e) => {
+                set_status.set(format!("❌ Failed to load the {} overlay: {e}", symbol.value()));
+                return;
+            }
+        }
+    }
+}
+
+/// Overlaid symbol, interval and visible range (ms) whose history the overlay needs
+type OverlayRequest = (Symbol, TimeInterval, u64, u64);
+
+/// 🆚 Pick a symbol to overlay on the main chart as a percent-change line.
+///
+/// Its history follows the visible range once the range settles, and its live
+/// stream keeps the last candle current.
+#[component]
+fn OverlaySelector(set_status: WriteSignal<String>) -> impl IntoView {
+    let options = default_symbols();
+
+    let request = create_memo(move |_| {
+        let symbol = overlay_symbol().get()?;
+        let interval = current_interval().get();
+        let chart = ensure_chart(&current_symbol().get());
+        let (start, end) =
+            chart.with(|c| (c.viewport.start_time as u64, c.viewport.end_time as u64));
+        Some((symbol, interval, start, end))
+    });
+    let floor = Rc::new(Cell::new(None::<u64>));
+    let debouncer = Rc::new(RefCell::new(Debouncer::new()));
+    let filling = Rc::new(Cell::new(false));
+
+    create_effect(move |loaded: Option<Option<(Symbol, TimeInterval)>>| {
+        let Some((symbol, interval, ..)) = request.get() else {
+            overlay_candles().set(Vec::new());
+            return None;
+        };
+        let key = (symbol, interval);
+        if loaded.flatten().as_ref() != Some(&key) {
+            overlay_candles().set(Vec::new());
+            floor.set(None);
+        }
+        let token = debouncer.borrow_mut().schedule(());
+        let (debouncer, floor, filling) = (debouncer.clone(), floor.clone(), filling.clone());
+        let _ = spawn_local_with_current_owner(async move {
+            sleep(Duration::from_millis(OVERLAY_FETCH_DEBOUNCE_MS)).await;
+            // A run in progress picks up the new range itself
+            if debouncer.borrow_mut().take(token).is_none() || filling.replace(true) {
+                return;
+            }
+            fill_overlay(request, floor, set_status).await;
+            filling.set(false);
+        });
+        Some(key)
+    });
+
+    create_effect(move |_| {
+        let candles = overlay_candles().get();
+        with_global_renderer(|r| r.set_compare_overlay(candles));
+        render_if_current(&current_symbol().get_untracked());
+    });
+
+    view! {
+        <select
+            id="overlay-symbol"
+            title="Overlay another symbol's percent change from the first visible candle"
+            style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+            on:change=move |ev| {
+                let value = event_target_value(&ev);
+                let symbol = (!value.is_empty()).then(|| Symbol::from(value.as_str()));
+                overlay_symbol().set(symbol.clone());
+                if let Some(symbol) = symbol {
+                    let _ = spawn_local_with_current_owner(async move {
+                        start_symbol_stream(symbol, set_status).await;
+                    });
+                }
+            }
+        >
+            <option value="" selected=move || overlay_symbol().with(|s| s.is_none())>
+                "OVERLAY"
+            </option>
+            {options
+                .into_iter()
+                .map(|sym| {
+                    let value = sym.value().to_string();
+                    let label = value.clone();
+                    view! {
+                        <option
+                            value=value
+                            selected=move || overlay_symbol().with(|s| s.as_ref() == Some(&sym))
+                        >
+                            {label}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// 🔋 Cap how often queued renders reach the GPU
+#[component]
+fn FpsCapSelector() -> impl IntoView {
+    view! {
+        <select
+            aria-label="Frame rate cap"
+            style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+            on:change=move |ev| {
+                if let Ok(fps) = event_target_value(&ev).parse::<u32>() {
+                    set_max_fps(fps);
+                }
+            }
+        >
+            {FPS_CAP_OPTIONS
+                .into_iter()
+                .map(|fps| {
+                    let label = if fps == 0 { "FPS MAX".to_string() } else { format!("{fps} FPS") };
+                    view! {
+                        <option value=fps.to_string() selected=max_fps() == fps>
+                            {label}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// 🕐 Time zone of the time axis, crosshair and tooltip
+#[component]
+fn TimeZoneSelector() -> impl IntoView {
+    view! {
+        <select
+            id="time-zone"
+            class="chart-control"
+            aria-label="Time zone"
+            title="Time zone of axis labels and tooltips"
+            style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+            on:change=move |ev| {
+                if let Ok(zone) = event_target_value(&ev).parse::<TimeZone>() {
+                    time_zone().set(zone);
+                }
+            }
+        >
+            {TimeZone::options()
+                .into_iter()
+                .map(|zone| {
+                    view! {
+                        <option value=zone.to_string() selected=move || time_zone().get() == zone>
+                            {zone.to_string()}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// 🔁 How often to poll REST for candles while the WebSocket stream is down
+#[component]
+fn RestPollSelector() -> impl IntoView {
+    view! {
+        <select
+            aria-label="REST polling interval"
+            style="padding:4px 6px;border:none;border-radius:4px;background:#444;color:white;"
+            on:change=move |ev| {
+                if let Ok(ms) = event_target_value(&ev).parse::<u64>() {
+                    rest_poll_ms().set(ms);
+                }
+            }
+        >
+            {REST_POLL_OPTIONS_MS
+                .into_iter()
+                .map(|ms| {
+                    let label = if ms == 0 {
+                        "POLL OFF".to_string()
+                    } else {
+                        format!("POLL {}s", ms / 1000)
+                    };
+                    view! {
+                        <option value=ms.to_string() selected=move || rest_poll_ms().get() == ms>
+                            {label}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// 🆚 Chart of the compared symbol on its own canvas with its own renderer.
+///
+/// The chart is bound to [`COMPARE_CANVAS_ID`] in the ECS world and redrawn
+/// whenever its candles, the legend or the canvas size change.
+#[component]
+fn CompareChart(set_status: WriteSignal<String>) -> impl IntoView {
+    let canvas_ref = create_node_ref::<Canvas>();
+    let (ready, set_ready) = create_signal(false);
+
+    create_effect(move |_| {
+        if canvas_ref.get().is_none() || ready.get_untracked() {
+            return;
+        }
+        let _ = spawn_local_with_current_owner(async move {
+            let (width, height) = canvas_size().get_untracked();
+            match WebGpuRenderer::new(COMPARE_CANVAS_ID, width, height).await {
+                Ok(renderer) => {
+                    register_renderer(Rc::new(RefCell::new(renderer)));
+                    set_ready.set(true);
+                }
+                Err(e) => {
+                    let msg = e.as_string().unwrap_or_else(|| format!("{e:?}"));
+                    get_logger().error(
+                        LogComponent::Infrastructure("WebGPU"),
+                        &format!("❌ Compare renderer failed: {msg}"),
+                    );
+                    set_status.set(format!("❌ Compare chart failed: {msg}"));
+                }
+            }
+        });
+    });
+    on_cleanup(|| {
+        remove_renderer(COMPARE_CANVAS_ID);
+    });
+
+    // Chart signals must be read outside the ECS lock, so the binding is resolved once per symbol
+    let compare_chart = create_memo(move |_| {
+        let symbol = compare_symbol().get()?;
+        ensure_chart(&symbol);
+        let mut world = ecs_world().lock().unwrap();
+        world.bind_canvas(symbol.value(), COMPARE_CANVAS_ID);
+        world.chart_for_canvas(COMPARE_CANVAS_ID)
+    });
+
+    create_effect(move |_| {
+        let (width, height) = canvas_size().get();
+        if ready.get() {
+            with_renderer(COMPARE_CANVAS_ID, |r| r.resize(width, height));
+        }
+    });
+
+    create_effect(move |_| {
+        if !ready.get() {
+            return;
+        }
+        let Some(chart) = compare_chart.get() else { return };
+        let line_visibility = global_line_visibility().get();
+        let _ = canvas_size().get();
+        chart.with(|ch| {
+            let interval = current_interval().get_untracked();
+            let Some(series) = ch.get_series(interval).filter(|s| s.count() > 0) else {
+                return;
+            };
+            let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &ch.viewport);
+            with_renderer(COMPARE_CANVAS_ID, |r| {
+                r.mirror_line_visibility(&line_visibility);
+                r.set_zoom_params(zoom, pan);
+                let _ = r.render(ch);
+            });
+        });
+    });
+
+    view! {
+        <div style="position: relative;">
+            <div style="position:absolute;top:4px;left:8px;font-size:12px;color:#ffff00;pointer-events:none;">
+                {move || compare_symbol().get().map(|s| s.value().to_string()).unwrap_or_default()}
+            </div>
+            <canvas
+                id=COMPARE_CANVAS_ID
+                node_ref=canvas_ref
+                role="img"
+                aria-label=move || {
+                    compare_symbol().get().map(|symbol| {
+                        chart_aria_label(&symbol, current_interval().get(), global_chart_type().get())
+                    })
+                }
+                style:width=move || format!("{}px", canvas_size().get().0)
+                style:height=move || format!("{}px", canvas_size().get().1)
+                style="border: 2px solid #4a5d73; border-radius: 10px; background: #253242;"
+            />
+        </div>
+    }
+}
+
+/// Show `symbol` and start its stream; the stream start guards the switch
+fn switch_symbol(symbol: Symbol, set_status: WriteSignal<String>) {
+    let interval = current_interval().get_untracked();
+    let supported = Exchange::for_symbol(&symbol).supports_interval(interval);
+    select_symbol(symbol);
+    // An interval the new exchange has no candles for falls back to one minute
+    if !supported {
+        select_interval(TimeInterval::OneMinute);
+        restart_streams(set_status);
+        return;
+    }
+    let _ = spawn_local_with_current_owner(async move {
+        start_websocket_stream(set_status).await;
+    });
+}
+
+/// Make `symbol` current; a price range pinned by zoom-to-box belongs to the old chart
+pub fn select_symbol(symbol: Symbol) {
+    with_global_renderer(|r| r.set_price_range(None));
+    current_symbol().set(symbol);
+}
+
+/// Make `interval` current, ending any timeframe preview and releasing a pinned price range
+pub fn select_interval(interval: TimeInterval) {
+    with_global_renderer(|r| {
+        r.set_preview_interval(None);
+        r.set_price_range(None);
+    });
+    current_interval().set(interval);
+}
+
+/// Symbol `step` places after `current` in `symbols`, wrapping around both ends.
+///
+/// Moving from an unlisted `current` enters the list at its first or last symbol.
+pub fn cycle_symbol(symbols: &[Symbol], current: &Symbol, step: isize) -> Option<Symbol> {
+    if symbols.is_empty() {
+        return None;
+    }
+    let len = symbols.len() as isize;
+    let next = match symbols.iter().position(|s| s == current) {
+        Some(index) => index as isize + step,
+        None if step > 0 => step - 1,
+        None => step,
+    };
+    symbols.get(next.rem_euclid(len) as usize).cloned()
+}
+
+/// Abort all active streams except the one for `symbol`.
+///
+/// Streams of other symbols keep running by default so a watchlist stays
+/// live; call this to release their connections.
+pub fn abort_other_streams(symbol: &Symbol) {
+    stream_abort_handles().update(|m| {
+        m.retain(|sym, handle| {
+            if sym != symbol {
+                handle.abort();
+                false
+            } else {
+                true
+            }
+        });
+    });
+}
+
+/// Abort every running stream and return the symbols that were streaming.
+///
+/// Streams subscribe to one interval, so a timeframe switch has to restart
+/// them all, background watchlist symbols included.
+pub fn take_running_streams() -> Vec<Symbol> {
+    let mut symbols = Vec::new();
+    stream_abort_handles().update(|m| {
+        for (symbol, handle) in m.drain() {
+            handle.abort();
+            symbols.push(symbol);
+        }
+    });
+    symbols.sort_by(|a, b| a.value().cmp(b.value()));
+    symbols
+}
+
+/// 🔄 Restart the current symbol's stream and every background stream on the
+/// current interval
+pub fn restart_streams(set_status: WriteSignal<String>) {
+    let current = current_symbol().get_untracked();
+    for symbol in take_running_streams().into_iter().filter(|s| *s != current) {
+        let _ = spawn_local_with_current_owner(async move {
+            start_symbol_stream(symbol, set_status).await;
+        });
+    }
+    let _ = spawn_local_with_current_owner(async move {
+        start_websocket_stream(set_status).await;
+    });
+}
+
+/// 🖼️ Redraw the current symbol's chart through the render queue, under the FPS cap.
+///
+/// A timeframe preview stays on screen until [`preview_timeframe`] ends it.
+pub fn request_render() {
+    enqueue_render_task(Box::new(|r| {
+        let Some(chart) = get_chart_signal(&current_symbol().get_untracked()) else { return };
+        chart.with_untracked(|ch| {
+            if ch.get_candle_count() == 0 {
+                return;
+            }
+            let _ = match r.preview_interval() {
+                Some(interval) => r.render(&ch.preview(interval)),
+                None => r.render(ch),
+            };
+        });
+    }));
+}
+
+/// Render the chart of the current symbol if `symbol` is still selected
+fn render_if_current(symbol: &Symbol) {
+    if *symbol != current_symbol().get_untracked() {
+        return;
+    }
+    let Some(chart) = get_chart_signal(symbol) else { return };
+    chart.with_untracked(|ch| {
+        if ch.get_candle_count() > 0 {
+            with_global_renderer(|r| {
+                let interval = current_interval().get_untracked();
+                let series = ch.get_series(interval).unwrap();
+                let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &ch.viewport);
+                r.set_zoom_params(zoom, pan);
+                request_render();
+            });
+        }
+    });
+}
+
+/// Apply a streamed candle to the chart of `symbol`.
+///
+/// Every streaming symbol keeps its own chart current; only the current
+/// symbol updates the global signals and is rendered. Returns whether
+/// `symbol` is the current symbol and the candle was applied; stale candles
+/// are dropped by the chart.
+pub fn route_stream_candle(symbol: &Symbol, candle: Candle) -> bool {
+    let chart = ensure_chart(symbol);
+    let interval = current_interval().get_untracked();
+    let mut order = CandleOrder::New;
+    chart.update(|ch| {
+        order = ch.add_realtime_candle(candle.clone());
+        if follow_live().get_untracked() {
+            ch.update_viewport_for_data();
+        }
+    });
+    if order == CandleOrder::Stale {
+        return false;
+    }
+    set_chart_in_ecs(symbol, chart.get_untracked());
+    crate::global_state::push_realtime_candle(symbol, candle.clone());
+    if overlay_symbol().with_untracked(|s| s.as_ref() == Some(symbol)) {
+        overlay_candles().update(|c| apply_overlay_candle(c, candle.clone(), interval));
+    }
+
+    if *symbol != current_symbol().get_untracked() {
+        return false;
+    }
+
+    global_current_price().set(candle.ohlcv.close.value());
+    domain_state().update(|ds| {
+        let mut v = (*ds.candles).clone();
+        v.push(candle.clone());
+        ds.candles = Arc::new(v);
+    });
+
+    let count = chart.with_untracked(|c| c.get_candle_count());
+    global_candle_count().set(count);
+
+    let max_vol = chart.with_untracked(|c| {
+        c.get_series(interval)
+            .unwrap()
+            .get_candles()
+            .iter()
+            .map(|c| c.ohlcv.volume.value())
+            .fold(0.0f64, |a, b| a.max(b))
+    });
+    global_max_volume().set(max_vol);
+
+    let sym_for_queue = symbol.clone();
+    enqueue_render_task(Box::new(move |r| {
+        if sym_for_queue != current_symbol().get_untracked() {
+            return;
+        }
+        let chart_signal = get_chart_signal(&sym_for_queue).unwrap();
+        chart_signal.with_untracked(|ch| {
+            if ch.get_candle_count() > 0 {
+                let interval = current_interval().get_untracked();
+                let series = ch.get_series(interval).unwrap();
+                let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &ch.viewport);
+                r.set_zoom_params(zoom, pan);
+                let _ = r.render(ch);
+            }
+        });
+    }));
+    true
+}
+
+/// ⏪ Replay the current symbol's loaded series from candle `start`.
+///
+/// Candles before `start` become the history and the rest arrive one at a
+/// time through [`route_stream_candle`]. The live stream of the symbol stops
+/// until [`stop_replay`] or any other stream start.
+pub fn start_replay(start: usize, set_status: WriteSignal<String>) {
+    let symbol = current_symbol().get_untracked();
+    let interval = current_interval().get_untracked();
+    let chart = ensure_chart(&symbol);
+    let controller = chart.with_untracked(|ch| {
+        ch.get_series(interval).map(|series| ReplayController::new(series, start))
+    });
+    let Some(controller) = controller.filter(|c| c.state() != ReplayState::Finished) else {
+        set_status.set("⚠️ Nothing to replay from this candle".to_string());
+        return;
+    };
+
+    // Taking over the connection id silences the live handlers of the symbol
+    let connection = next_connection_id(&symbol);
+    stream_abort_handles().update(|m| {
+        if let Some(handle) = m.remove(&symbol) {
+            handle.abort();
+        }
+    });
+    set_stream_last_message(&symbol, None);
+    global_is_streaming().set(false);
+
+    let history = controller.history().to_vec();
+    chart.update(|ch| {
+        ch.set_historical_data(history.clone());
+        ch.update_viewport_for_data();
+    });
+    set_chart_in_ecs(&symbol, chart.get_untracked());
+    domain_state().update(|ds| {
+        ds.candles = Arc::new(history.clone());
+        ds.indicators = Arc::new(Vec::new());
+    });
+    global_candle_count().set(history.len());
+    if let Some(last) = history.last() {
+        global_current_price().set(last.ohlcv.close.value());
+    }
+    global_max_volume()
+        .set(history.iter().map(|c| c.ohlcv.volume.value()).fold(0.0f64, |a, b| a.max(b)));
+    render_if_current(&symbol);
+
+    set_status.set(format!("⏪ Replaying from candle {}", controller.position()));
+    replay().set(Some(ReplaySession { symbol: symbol.clone(), connection, controller }));
+    spawn_local(run_replay(symbol, connection));
+}
+
+/// ⏪ Feed replayed candles on the session's timer until it ends
+async fn run_replay(symbol: Symbol, connection: u64) {
+    loop {
+        let tick_ms = replay().with_untracked(|r| {
+            r.as_ref()
+                .filter(|s| s.connection == connection && s.symbol == symbol)
+                .map(|s| s.controller.speed().tick_ms())
+        });
+        let Some(tick_ms) = tick_ms else { return };
+        sleep(Duration::from_millis(tick_ms as u64)).await;
+        if !is_current_connection(&symbol, connection) {
+            return;
+        }
+        let playing = replay().with_untracked(|r| {
+            r.as_ref().is_some_and(|s| s.controller.state() == ReplayState::Playing)
+        });
+        if playing {
+            advance_replay(|controller| controller.tick());
+        }
+    }
+}
+
+/// ⏪ Apply the candle `next` takes from the replay, if any
+fn advance_replay(next: impl FnOnce(&mut ReplayController) -> Option<Candle>) {
+    let mut taken = None;
+    replay().update(|r| {
+        if let Some(session) = r {
+            taken = next(&mut session.controller).map(|c| (session.symbol.clone(), c));
+        }
+    });
+    if let Some((symbol, candle)) = taken {
+        route_stream_candle(&symbol, candle);
     }
 }
 
-/// Abort all active streams except the one for `symbol`.
-pub fn abort_other_streams(symbol: &Symbol) {
-    stream_abort_handles().update(|m| {
-        m.retain(|sym, handle| {
-            if sym != symbol {
-                handle.abort();
-                false
-            } else {
-                true
-            }
-        });
+/// ⏪ End the replay and reload the live history and stream
+pub fn stop_replay(set_status: WriteSignal<String>) {
+    let _ = spawn_local_with_current_owner(async move {
+        start_websocket_stream(set_status).await;
     });
 }
 
-/// 🌐 Start WebSocket stream in Leptos and update global signals
-pub async fn start_websocket_stream(set_status: WriteSignal<String>) {
-    let symbol = current_symbol().get_untracked();
-    abort_other_streams(&symbol);
-    ensure_chart(&symbol);
-    let chart = get_chart_signal(&symbol).unwrap();
+/// 📶 Interval between stream health re-evaluations while no messages arrive
+const STREAM_HEALTH_REFRESH_MS: u64 = 1_000;
 
-    if let Some(handle) = stream_abort_handles().with(|m| m.get(&symbol).cloned()) {
-        handle.abort();
-        stream_abort_handles().update(|m| {
-            m.remove(&symbol);
-        });
-        set_status.set("🔄 Restarting stream".to_string());
+/// 📶 Record a message of `symbol`'s stream (`Some(now)`) or its lost connection (`None`)
+fn set_stream_last_message(symbol: &Symbol, at: Option<u64>) {
+    stream_last_message().update(|m| match at {
+        Some(at) => {
+            m.insert(symbol.clone(), at);
+        }
+        None => {
+            m.remove(symbol);
+        }
+    });
+    refresh_stream_health();
+}
+
+/// 📶 Health of `symbol`'s `interval` stream judged by the age of its latest message
+fn symbol_stream_health(symbol: &Symbol, interval: TimeInterval) -> StreamHealth {
+    let now = get_time_provider().current_timestamp();
+    let age =
+        stream_last_message().with_untracked(|m| m.get(symbol).map(|at| now.saturating_sub(*at)));
+    StreamHealth::classify(age, interval)
+}
+
+/// 📶 Reclassify the age of the current symbol's latest stream message
+fn refresh_stream_health() {
+    let health =
+        symbol_stream_health(&current_symbol().get_untracked(), current_interval().get_untracked());
+    if stream_health().get_untracked() != health {
+        stream_health().set(health);
     }
+}
 
-    let interval = current_interval().get_untracked();
-    let conn_id = connection_id().get_untracked() + 1;
-    connection_id().set(conn_id);
-    domain_state().update(|ds| {
-        ds.timeframe = Duration::from_millis(interval.duration_ms());
-        ds.candles = Arc::new(Vec::new());
-        ds.indicators = Arc::new(Vec::new());
-    });
+/// 🌐 Start the WebSocket stream of the current symbol and update global signals.
+///
+/// Streams of other symbols are left running. If the current symbol already
+/// streams, its chart is shown without reconnecting; changing the interval
+/// must remove the old handle first, see [`restart_streams`].
+pub async fn start_websocket_stream(set_status: WriteSignal<String>) {
+    // Live data replaces any replay
+    if replay().with_untracked(|r| r.is_some()) {
+        replay().set(None);
+    }
+    let symbol = current_symbol().get_untracked();
+    follow_order_book(&symbol);
+    start_symbol_stream(symbol, set_status).await;
+}
+
+/// Candles from the one opened at `end` through the one open at `now` (ms), both
+/// included so the fetched page joins the cached range
+pub fn missing_candle_count(end: u64, now: u64, interval: TimeInterval) -> u32 {
+    let open = interval.bucket_start(now);
+    (open.saturating_sub(end).div_ceil(interval.duration_ms()) + 1) as u32
+}
 
-    let rest_client_arc =
-        Arc::new(Mutex::new(BinanceWebSocketClient::new(symbol.clone(), interval)));
+/// Load the history of `symbol` and stream it unless its stream already runs.
+///
+/// Any symbol can stream, e.g. the one on the comparison canvas; only the
+/// current symbol reports status and updates the global signals.
+async fn start_symbol_stream(symbol: Symbol, set_status: WriteSignal<String>) {
+    let chart = ensure_chart(&symbol);
+    let is_current = |symbol: &Symbol| *symbol == current_symbol().get_untracked();
 
-    // Set the streaming status
-    global_is_streaming().set(false);
+    if stream_abort_handles().with_untracked(|m| m.contains_key(&symbol)) {
+        if !is_current(&symbol) {
+            return;
+        }
+        let cnt = chart.with_untracked(|c| c.get_candle_count());
+        global_candle_count().set(cnt);
+        if let Some(price) = chart.with_untracked(|c| {
+            c.get_series(current_interval().get_untracked())
+                .and_then(|s| s.get_candles().back().map(|c| c.ohlcv.close.value()))
+        }) {
+            global_current_price().set(price);
+        }
+        render_if_current(&symbol);
+        set_status.set("🌐 WebSocket LIVE • Real-time updates".to_string());
+        return;
+    }
 
-    // 📈 First load historical data
-    set_status.set("📈 Loading historical data...".to_string());
+    load_price_decimals(&symbol);
+    let interval = current_interval().get_untracked();
+    let conn_id = next_connection_id(&symbol);
+    if is_current(&symbol) {
+        domain_state().update(|ds| {
+            ds.timeframe = Duration::from_millis(interval.duration_ms());
+            ds.candles = Arc::new(Vec::new());
+            ds.indicators = Arc::new(Vec::new());
+        });
+        set_status.set("📈 Loading historical data...".to_string());
+    }
 
-    let hist_res = {
-        let client = rest_client_arc.lock().await;
-        client.fetch_historical_data(1000).await
+    // 🔌 Stream right away so live candles arrive while history loads or retries
+    spawn_live_stream(symbol.clone(), interval, conn_id, set_status);
+
+    let window = history_window().get_untracked();
+    let now = get_time_provider().current_timestamp();
+    let cached = with_history_cache(|cache| cache.latest(&symbol, interval, now));
+    let hist_res = match cached {
+        Some(candles) => Ok(candles),
+        None => {
+            // A cached range that stops before the open candle only needs the ones since
+            let limit = with_history_cache(|cache| cache.newest(&symbol, interval, now))
+                .map_or(window.fetch_limit, |end| {
+                    missing_candle_count(end, now, interval).min(window.fetch_limit)
+                });
+            let result = fetch_initial_history(&symbol, interval, conn_id, limit, set_status).await;
+            result.map(|candles| {
+                with_history_cache(|cache| {
+                    cache.insert(&symbol, interval, &candles, now);
+                    cache.ranges(&symbol, interval).last().map_or(candles, |r| r.candles.clone())
+                })
+            })
+        }
     };
-    if conn_id != connection_id().get_untracked() {
+    if !is_current_connection(&symbol, conn_id) {
         return;
     }
     match hist_res {
@@ -1249,59 +5456,231 @@ pub async fn start_websocket_stream(set_status: WriteSignal<String>) {
                 &format!("✅ Loaded {} historical candles", historical_candles.len()),
             );
 
-            chart.update(|ch| ch.set_historical_data(historical_candles.clone()));
-            chart.with_untracked(|c| set_chart_in_ecs(&symbol, c.clone()));
-            domain_state().update(|ds| {
-                ds.candles = Arc::new(historical_candles.clone());
-                ds.indicators = Arc::new(Vec::new());
-            });
-            chart.with_untracked(|c| {
-                if c.get_candle_count() > 0
-                    && with_global_renderer(|r| {
-                        let interval = current_interval().get_untracked();
-                        let series = c.get_series(interval).unwrap();
-                        let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &c.viewport);
-                        r.set_zoom_params(zoom, pan);
-                        let _ = r.render(c);
-                    })
-                    .is_none()
-                {
-                    // renderer not available
+            // A symbol viewed before keeps its place instead of jumping to the newest candles
+            let keep_view = symbol == current_symbol().get_untracked()
+                && has_symbol_view(&symbol)
+                && chart.with_untracked(|ch| ch.has_data());
+            if keep_view {
+                save_symbol_view(&symbol);
+            }
+            chart.update(|ch| {
+                // Keep live candles that arrived while the history was loading
+                let history_end = historical_candles.last().map_or(0, |c| c.timestamp.value());
+                let live: Vec<Candle> = ch.get_series(interval).map_or_else(Vec::new, |s| {
+                    s.get_candles()
+                        .iter()
+                        .filter(|c| c.timestamp.value() >= history_end)
+                        .cloned()
+                        .collect()
+                });
+                ch.set_capacity(window.buffer_size);
+                ch.set_historical_data(historical_candles.clone());
+                for candle in live {
+                    ch.add_realtime_candle(candle);
                 }
             });
+            set_chart_in_ecs(&symbol, chart.get_untracked());
+            if keep_view {
+                restore_symbol_view(&symbol);
+            }
+
+            // The user may have switched symbols while the history was loading
+            if is_current(&symbol) {
+                domain_state().update(|ds| {
+                    ds.candles = Arc::new(historical_candles.clone());
+                    ds.indicators = Arc::new(Vec::new());
+                });
+                render_if_current(&symbol);
 
-            // Update global signals using the historical data
-            let cnt = chart.with(|c| c.get_candle_count());
-            global_candle_count().set(cnt);
+                // Update global signals using the historical data
+                let cnt = chart.with(|c| c.get_candle_count());
+                global_candle_count().set(cnt);
 
-            if let Some(last_candle) = historical_candles.last() {
-                global_current_price().set(last_candle.ohlcv.close.value());
-            }
+                if let Some(last_candle) = historical_candles.last() {
+                    global_current_price().set(last_candle.ohlcv.close.value());
+                }
 
-            // Compute the maximum volume from history
-            let max_vol = historical_candles
-                .iter()
-                .map(|c| c.ohlcv.volume.value())
-                .fold(0.0f64, |a, b| a.max(b));
-            global_max_volume().set(max_vol);
+                // Compute the maximum volume from history
+                let max_vol = historical_candles
+                    .iter()
+                    .map(|c| c.ohlcv.volume.value())
+                    .fold(0.0f64, |a, b| a.max(b));
+                global_max_volume().set(max_vol);
 
-            set_status.set("✅ Historical data loaded. Starting real-time stream...".to_string());
+                set_status.set("✅ Historical data loaded".to_string());
+            }
         }
         Err(e) => {
             get_logger().error(
                 LogComponent::Presentation("WebSocketStream"),
                 &format!("❌ Failed to load historical data: {e}"),
             );
-            set_status.set("⚠️ Historical data failed. Starting real-time only...".to_string());
+            if is_current(&symbol) {
+                set_status.set(format!("⚠️ Historical data failed ({e}). Showing real-time only"));
+            }
+        }
+    }
+}
+
+/// Initial history load attempts before falling back to real-time only
+pub const HISTORY_LOAD_ATTEMPTS: u32 = 4;
+/// Delay before the first history retry, doubled for each later one
+pub const HISTORY_RETRY_BASE_MS: u64 = 1_000;
+/// Longest delay between history retries
+pub const HISTORY_RETRY_MAX_MS: u64 = 8_000;
+
+/// Whether the initial history load should try again after `attempt` failed with `error`
+pub fn should_retry_history(error: &AppError, attempt: u32) -> bool {
+    error.is_retryable() && attempt < HISTORY_LOAD_ATTEMPTS
+}
+
+/// 📈 Latest `limit` candles of `symbol`, retrying network failures with backoff
+/// while the connection `conn_id` is still the current one
+async fn fetch_initial_history(
+    symbol: &Symbol,
+    interval: TimeInterval,
+    conn_id: u64,
+    limit: u32,
+    set_status: WriteSignal<String>,
+) -> NetworkResult<Vec<Candle>> {
+    let client = create_market_data_client(symbol, interval);
+    let mut backoff = ReconnectBackoff::new(HISTORY_RETRY_BASE_MS, HISTORY_RETRY_MAX_MS);
+    loop {
+        let result = {
+            let client = client.lock().await;
+            client.fetch_historical_data(limit).await
+        };
+        let error = match result {
+            Ok(candles) => return Ok(candles),
+            Err(error) => error,
+        };
+        let attempt = backoff.attempt() + 1;
+        if !should_retry_history(&error, attempt) || !is_current_connection(symbol, conn_id) {
+            return Err(error);
+        }
+        let delay = backoff.next_delay();
+        get_logger().warn(
+            LogComponent::Presentation("WebSocketStream"),
+            &format!("⚠️ History load attempt {attempt} failed: {error}"),
+        );
+        if *symbol == current_symbol().get_untracked() {
+            set_status.set(format!(
+                "🔄 History load failed, retrying in {:.1}s (attempt {attempt})",
+                delay.as_secs_f64()
+            ));
+        }
+        sleep(delay).await;
+    }
+}
+
+/// Run `f` on the next animation frame, or right away without a window
+fn on_next_animation_frame(f: impl FnOnce() + 'static) {
+    let callback = wasm_bindgen::closure::Closure::once_into_js(move |_: f64| f());
+    let scheduled = web_sys::window()
+        .is_some_and(|w| w.request_animation_frame(callback.unchecked_ref()).is_ok());
+    if !scheduled {
+        let _ = callback
+            .unchecked_ref::<js_sys::Function>()
+            .call1(&wasm_bindgen::JsValue::NULL, &0.0.into());
+    }
+}
+
+/// Coalescer of a live stream with the flush that applies its candles
+struct LiveStreamBuffer {
+    conn_id: u64,
+    coalescer: Rc<RefCell<CandleCoalescer>>,
+    schedule_flush: Rc<dyn Fn()>,
+}
+
+thread_local! {
+    /// ⏸️ Buffers of the running live streams by symbol, paused and resumed together
+    static LIVE_STREAM_BUFFERS: RefCell<HashMap<Symbol, LiveStreamBuffer>> =
+        RefCell::new(HashMap::new());
+}
+
+/// 📡 Handler of the candles streamed for `symbol` on the connection `conn_id`.
+///
+/// Bursts collapse into one chart update per animation frame and are held back while
+/// live updates are paused. The stream's buffer stays registered for
+/// [`toggle_live_paused`] until a newer connection of the symbol replaces it or the
+/// stream ends.
+pub fn live_candle_handler(
+    symbol: Symbol,
+    conn_id: u64,
+    abort_handle: futures::future::AbortHandle,
+    set_status: WriteSignal<String>,
+) -> impl Fn(Candle) + 'static {
+    let coalescer = Rc::new(RefCell::new(CandleCoalescer::new()));
+    coalescer.borrow_mut().set_paused(live_paused().get_untracked());
+    let schedule_flush: Rc<dyn Fn()> = {
+        let coalescer = coalescer.clone();
+        let flush_handle = abort_handle.clone();
+        let flush_symbol = symbol.clone();
+        Rc::new(move || {
+            let coalescer = coalescer.clone();
+            let flush_handle = flush_handle.clone();
+            let flush_symbol = flush_symbol.clone();
+            on_next_animation_frame(move || {
+                let candles = coalescer.borrow_mut().take();
+                if flush_handle.is_aborted() || !is_current_connection(&flush_symbol, conn_id) {
+                    return;
+                }
+                let mut routed = false;
+                for candle in candles {
+                    routed |= route_stream_candle(&flush_symbol, candle);
+                }
+                if !routed {
+                    return;
+                }
+                if !global_is_streaming().get_untracked() {
+                    global_is_streaming().set(true);
+                }
+                set_status.set("🌐 WebSocket LIVE • Real-time updates".to_string());
+            });
+        })
+    };
+    LIVE_STREAM_BUFFERS.with(|buffers| {
+        let buffer = LiveStreamBuffer {
+            conn_id,
+            coalescer: coalescer.clone(),
+            schedule_flush: schedule_flush.clone(),
+        };
+        buffers.borrow_mut().insert(symbol.clone(), buffer);
+    });
+    move |candle: Candle| {
+        if abort_handle.is_aborted() || !is_current_connection(&symbol, conn_id) {
+            return;
+        }
+        let received_at = get_time_provider().current_timestamp();
+        set_stream_last_message(&symbol, Some(received_at));
+        if coalescer.borrow_mut().push(candle) {
+            schedule_flush();
         }
     }
+}
+
+/// Drop the buffer of the ended stream `conn_id` of `symbol` unless a newer one took over
+fn release_live_stream_buffer(symbol: &Symbol, conn_id: u64) {
+    LIVE_STREAM_BUFFERS.with(|buffers| {
+        let mut buffers = buffers.borrow_mut();
+        if buffers.get(symbol).is_some_and(|buffer| buffer.conn_id == conn_id) {
+            buffers.remove(symbol);
+        }
+    });
+}
 
-    // 🔌 Start the WebSocket for real-time updates
-    set_status.set("🔌 Starting WebSocket stream...".to_string());
-    global_is_streaming().set(true);
+/// 🔌 Stream real-time candles of `symbol` for the connection `conn_id`
+fn spawn_live_stream(
+    symbol: Symbol,
+    interval: TimeInterval,
+    conn_id: u64,
+    set_status: WriteSignal<String>,
+) {
+    if symbol == current_symbol().get_untracked() {
+        global_is_streaming().set(true);
+    }
 
-    let stream_client_arc =
-        Arc::new(Mutex::new(BinanceWebSocketClient::new(symbol.clone(), interval)));
+    let stream_client_arc = create_market_data_client(&symbol, interval);
     let (abort_handle, abort_reg) = futures::future::AbortHandle::new_pair();
     let (done_tx, done_rx) = oneshot::channel::<()>();
     stream_abort_handles().update(|m| {
@@ -1321,79 +5700,35 @@ pub async fn start_websocket_stream(set_status: WriteSignal<String>) {
             });
         }
     });
+    spawn_rest_poller(symbol.clone(), interval, conn_id, abort_handle.clone(), set_status);
     let handle_check = abort_handle.clone();
+    let stream_symbol = symbol.clone();
+    let handler = live_candle_handler(symbol.clone(), conn_id, abort_handle.clone(), set_status);
     let fut = futures::future::Abortable::new(
         async move {
-            let handler_handle = handle_check.clone();
-            let connection_guard = conn_id;
-            let handler = move |candle: Candle| {
-                if handler_handle.is_aborted()
-                    || connection_guard != connection_id().get_untracked()
-                {
+            let reconnect_check = handle_check.clone();
+            let reconnect_symbol = symbol.clone();
+            let on_reconnect = move |attempt: u32| {
+                if reconnect_check.is_aborted() {
                     return;
                 }
-                global_current_price().set(candle.ohlcv.close.value());
-
-                chart.update(|ch| {
-                    ch.add_realtime_candle(candle.clone());
-                    let interval = current_interval().get_untracked();
-                    let series = ch.get_series(interval).unwrap();
-                    let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &ch.viewport);
-                    let len = ch.get_candle_count();
-                    if should_auto_scroll(len, zoom, pan) {
-                        ch.update_viewport_for_data();
-                    }
-                });
-                chart.with_untracked(|c| set_chart_in_ecs(&symbol, c.clone()));
-                crate::global_state::push_realtime_candle(candle.clone());
-                domain_state().update(|ds| {
-                    let mut v = (*ds.candles).clone();
-                    v.push(candle.clone());
-                    ds.candles = Arc::new(v);
-                });
-
-                let count = chart.with(|c| c.get_candle_count());
-                global_candle_count().set(count);
-
-                let max_vol = chart.with(|c| {
-                    c.get_series(interval)
-                        .unwrap()
-                        .get_candles()
-                        .iter()
-                        .map(|c| c.ohlcv.volume.value())
-                        .fold(0.0f64, |a, b| a.max(b))
-                });
-                global_max_volume().set(max_vol);
-
-                let sym_for_queue = symbol.clone();
-                enqueue_render_task(Box::new(move |r| {
-                    let chart_signal = get_chart_signal(&sym_for_queue).unwrap();
-                    chart_signal.with_untracked(|ch| {
-                        if ch.get_candle_count() > 0 {
-                            let interval = current_interval().get_untracked();
-                            let series = ch.get_series(interval).unwrap();
-                            let (zoom, pan) = viewport_zoom_pan(series.get_candles(), &ch.viewport);
-                            r.set_zoom_params(zoom, pan);
-                            let _ = r.render(ch);
-                        }
-                    });
-                }));
-
-                if handler_handle.is_aborted() {
+                set_stream_last_message(&reconnect_symbol, None);
+                if reconnect_symbol != current_symbol().get_untracked() {
                     return;
                 }
-                set_status.set("🌐 WebSocket LIVE • Real-time updates".to_string());
+                global_is_streaming().set(false);
+                set_status.set(format!("🔄 Reconnecting (attempt {attempt})"));
             };
-
             let result = {
                 let mut client = stream_client_arc.lock().await;
-                client.start_stream(handler).await
+                client.start_stream(Box::new(handler), Box::new(on_reconnect)).await
             };
             if handle_check.is_aborted() {
                 return;
             }
             if let Err(e) = result {
-                if handle_check.is_aborted() {
+                set_stream_last_message(&symbol, None);
+                if handle_check.is_aborted() || symbol != current_symbol().get_untracked() {
                     return;
                 }
                 set_status.set(format!("❌ WebSocket error: {e}"));
@@ -1405,10 +5740,161 @@ pub async fn start_websocket_stream(set_status: WriteSignal<String>) {
 
     let _ = spawn_local_with_current_owner(async move {
         let _ = fut.await;
+        release_live_stream_buffer(&stream_symbol, conn_id);
+        // A finished stream no longer blocks a reconnect when its symbol is selected again
+        if is_current_connection(&stream_symbol, conn_id) {
+            stream_abort_handles().update(|m| {
+                m.remove(&stream_symbol);
+            });
+        }
         let _ = done_tx.send(());
     });
 }
 
+/// Candles requested per REST poll, enough to cover a few missed intervals
+pub const REST_POLL_LIMIT: u32 = 5;
+/// Default interval between REST polls while the stream is down
+pub const DEFAULT_REST_POLL_MS: u64 = 10_000;
+/// Poll intervals offered in the UI (ms), 0 meaning off
+pub const REST_POLL_OPTIONS_MS: [u64; 4] = [0, 5_000, 10_000, 30_000];
+
+/// 🔁 Merge candles polled over REST into `symbol`'s chart.
+///
+/// Candles before the chart's latest one are final and skipped, the latest is
+/// revised in place and newer ones are appended; of polled duplicates the last
+/// copy wins. Returns how many candles were merged.
+pub fn merge_polled_candles(symbol: &Symbol, mut polled: Vec<Candle>) -> usize {
+    let latest = ensure_chart(symbol).with_untracked(|c| {
+        c.get_series(TimeInterval::TwoSeconds)
+            .and_then(|s| s.latest())
+            .map(|candle| candle.timestamp.value())
+    });
+    polled.sort_by_key(|c| c.timestamp.value());
+    polled.reverse();
+    polled.dedup_by_key(|c| c.timestamp.value());
+    polled.reverse();
+    polled.retain(|c| latest.is_none_or(|latest| c.timestamp.value() >= latest));
+
+    let merged = polled.len();
+    for candle in polled {
+        route_stream_candle(symbol, candle);
+    }
+    merged
+}
+
+/// 🔁 Poll the latest candles of `symbol` over REST while its stream is not live,
+/// e.g. when a proxy blocks WebSockets, until the connection `conn_id` ends
+fn spawn_rest_poller(
+    symbol: Symbol,
+    interval: TimeInterval,
+    conn_id: u64,
+    abort: futures::future::AbortHandle,
+    set_status: WriteSignal<String>,
+) {
+    let _ = spawn_local_with_current_owner(async move {
+        let client = create_market_data_client(&symbol, interval);
+        loop {
+            let period = rest_poll_ms().get_untracked();
+            sleep(Duration::from_millis(if period == 0 { DEFAULT_REST_POLL_MS } else { period }))
+                .await;
+            if abort.is_aborted() || !is_current_connection(&symbol, conn_id) {
+                return;
+            }
+            if period == 0
+                || live_paused().get_untracked()
+                || matches!(symbol_stream_health(&symbol, interval), StreamHealth::Live(_))
+            {
+                continue;
+            }
+            let now = get_time_provider().current_timestamp();
+            if !with_history_rate_limiter(|limiter| limiter.try_acquire(now)) {
+                continue;
+            }
+
+            let result = {
+                let client = client.lock().await;
+                client.fetch_historical_data(REST_POLL_LIMIT).await
+            };
+            if abort.is_aborted() || !is_current_connection(&symbol, conn_id) {
+                return;
+            }
+            match result {
+                Ok(candles) => {
+                    if merge_polled_candles(&symbol, candles) > 0
+                        && symbol == current_symbol().get_untracked()
+                    {
+                        set_status.set("🔁 REST polling • WebSocket unavailable".to_string());
+                    }
+                }
+                Err(e) => get_logger().warn(
+                    LogComponent::Presentation("RestPoller"),
+                    &format!("⚠️ Polling {} failed: {e}", symbol.value()),
+                ),
+            }
+        }
+    });
+}
+
+thread_local! {
+    /// 📚 The one order book stream, of the current symbol, and the handle that stops it
+    static ORDER_BOOK_STREAM: RefCell<Option<(Symbol, futures::future::AbortHandle)>> =
+        const { RefCell::new(None) };
+}
+
+/// Symbol of the order book stream and its handle, aborted once the stream stops
+pub fn order_book_stream() -> Option<(Symbol, futures::future::AbortHandle)> {
+    ORDER_BOOK_STREAM.with(|s| s.borrow().clone())
+}
+
+/// 📚 Stream the order book of `symbol` for the depth overlay, stopping the stream of
+/// the previously selected symbol. Coinbase symbols have no depth stream.
+pub fn follow_order_book(symbol: &Symbol) {
+    if order_book_stream().is_some_and(|(s, handle)| s == *symbol && !handle.is_aborted()) {
+        return;
+    }
+    if let Some((_, handle)) = ORDER_BOOK_STREAM.with(|s| s.borrow_mut().take()) {
+        handle.abort();
+    }
+    if Exchange::for_symbol(symbol) != Exchange::Binance {
+        return;
+    }
+    let (handle, registration) = futures::future::AbortHandle::new_pair();
+    ORDER_BOOK_STREAM.with(|s| *s.borrow_mut() = Some((symbol.clone(), handle.clone())));
+    let interval = current_interval().get_untracked();
+    let depth = stream_order_book(symbol.clone(), interval, handle.clone());
+    let fut = futures::future::Abortable::new(depth, registration);
+    spawn_local(async move {
+        let _ = fut.await;
+        // A stream that stopped on its own is reopened by the next start
+        handle.abort();
+    });
+}
+
+/// Forward order book snapshots of `symbol` to the renderer while it is selected
+async fn stream_order_book(
+    symbol: Symbol,
+    interval: TimeInterval,
+    abort: futures::future::AbortHandle,
+) {
+    let mut client = BinanceWebSocketClient::new(symbol.clone(), interval);
+    let handler_symbol = symbol.clone();
+    let result = client
+        .start_depth_stream(move |book| {
+            if abort.is_aborted() || handler_symbol != current_symbol().get_untracked() {
+                return;
+            }
+            with_global_renderer(|r| r.set_order_book(Some(book)));
+            render_if_current(&handler_symbol);
+        })
+        .await;
+    if let Err(e) = result {
+        get_logger().error(
+            LogComponent::Presentation("DepthStream"),
+            &format!("❌ Order book stream for {} stopped: {e}", symbol.value()),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1510,7 +5996,72 @@ mod tests {
         let cb = find_checkbox(&container, "sma20").expect("sma20 checkbox not found");
         cb.click();
 
-        assert!(!renderer.borrow().line_visibility().sma_20);
+        assert!(!renderer.borrow().line_visibility().is_visible("sma20"));
+    }
+
+    #[wasm_bindgen_test]
+    fn settings_panel_controls_update_signals() {
+        use crate::infrastructure::rendering::renderer::{dummy_renderer, set_global_renderer};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let container = setup_container();
+        let chart = create_rw_signal(Chart::new("test".to_string(), ChartType::Candlestick, 10));
+        set_global_renderer(Rc::new(RefCell::new(dummy_renderer())));
+        settings_panel_open().set(false);
+        leptos::mount_to(container.clone(), move || view! { <SettingsPanel chart=chart /> });
+
+        let toggle = container.query_selector("#settings-toggle").unwrap().unwrap();
+        let toggle = toggle.dyn_into::<web_sys::HtmlElement>().unwrap();
+        assert_eq!(toggle.get_attribute("aria-expanded").as_deref(), Some("false"));
+        toggle.click();
+        assert!(settings_panel_open().get_untracked());
+        assert_eq!(toggle.get_attribute("aria-expanded").as_deref(), Some("true"));
+
+        let wicks = find_checkbox(&container, "show-wicks").unwrap();
+        assert!(wicks.checked());
+        wicks.click();
+        assert!(!show_wicks().get_untracked());
+
+        let grid = grid_visible().get_untracked();
+        find_checkbox(&container, "grid-visible").unwrap().click();
+        assert_eq!(grid_visible().get_untracked(), !grid);
+
+        let light = chart_theme().get_untracked().is_light();
+        find_checkbox(&container, "light-theme").unwrap().click();
+        assert_eq!(chart_theme().get_untracked().is_light(), !light);
+
+        let sma = find_checkbox(&container, "sma20").unwrap();
+        let visible = global_line_visibility().get_untracked().is_visible("sma20");
+        sma.click();
+        assert_eq!(global_line_visibility().get_untracked().is_visible("sma20"), !visible);
+
+        let width = find_checkbox(&container, "candle-width").unwrap();
+        width.set_value("0.05");
+        width.dispatch_event(&web_sys::Event::new("input").unwrap()).unwrap();
+        assert!((candle_sizing().get_untracked().max_width - 0.05).abs() < 1e-6);
+    }
+
+    #[wasm_bindgen_test]
+    fn escape_collapses_the_settings_panel() {
+        let container = setup_container();
+        let chart = create_rw_signal(Chart::new("test".to_string(), ChartType::Candlestick, 10));
+        settings_panel_open().set(true);
+        leptos::mount_to(container.clone(), move || view! { <SettingsPanel chart=chart /> });
+
+        let grid = find_checkbox(&container, "grid-visible").unwrap();
+        let escape = web_sys::KeyboardEvent::new("keydown").unwrap();
+        escape
+            .init_keyboard_event_with_bubbles_arg_and_cancelable_arg_and_view_arg_and_key_arg(
+                "keydown", true, true, None, "Escape",
+            )
+            .unwrap();
+        grid.dispatch_event(&escape).unwrap();
+
+        assert!(!settings_panel_open().get_untracked());
+        let panel = container.query_selector("#settings-panel").unwrap().unwrap();
+        let panel = panel.dyn_into::<web_sys::HtmlElement>().unwrap();
+        assert_eq!(panel.style().get_property_value("display").unwrap(), "none");
     }
 
     #[wasm_bindgen_test]
@@ -1560,4 +6111,95 @@ mod tests {
         btc_btn.click();
         assert_eq!(current_symbol().get(), Symbol::from("BTCUSDT"));
     }
+
+    #[wasm_bindgen_test]
+    fn selectors_label_their_buttons() {
+        let container = setup_container();
+        let chart = create_rw_signal(Chart::new("test".to_string(), ChartType::Candlestick, 10));
+        let (_, set_status) = create_signal(String::new());
+        current_interval().set(TimeInterval::OneMinute);
+        current_symbol().set(Symbol::from("BTCUSDT"));
+        leptos::mount_to(container.clone(), move || {
+            view! {
+                <TimeframeSelector chart=chart set_status=set_status />
+                <AssetSelector set_status=set_status />
+            }
+        });
+
+        let toolbars = container.query_selector_all("[role=toolbar]").unwrap();
+        assert_eq!(toolbars.length(), 2);
+
+        let one_minute = find_button(&container, "1m").unwrap();
+        assert_eq!(one_minute.get_attribute("aria-label").as_deref(), Some("1m timeframe"));
+        assert_eq!(one_minute.get_attribute("aria-pressed").as_deref(), Some("true"));
+        let one_hour = find_button(&container, "1h").unwrap();
+        assert_eq!(one_hour.get_attribute("aria-pressed").as_deref(), Some("false"));
+
+        let eth = find_button(&container, "ETHUSDT").unwrap();
+        assert_eq!(eth.get_attribute("aria-label").as_deref(), Some("Show ETHUSDT"));
+        assert_eq!(eth.get_attribute("aria-pressed").as_deref(), Some("false"));
+        assert_eq!(eth.class_name(), "chart-control");
+    }
+
+    #[wasm_bindgen_test]
+    fn legend_controls_carry_aria_labels() {
+        use crate::infrastructure::rendering::renderer::{dummy_renderer, set_global_renderer};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let container = setup_container();
+        let chart = create_rw_signal(Chart::new("test".to_string(), ChartType::Candlestick, 10));
+        set_global_renderer(Rc::new(RefCell::new(dummy_renderer())));
+        leptos::mount_to(container.clone(), move || view! { <Legend chart=chart /> });
+
+        let group = container.query_selector("[role=group]").unwrap().unwrap();
+        assert_eq!(
+            group.get_attribute("aria-label").as_deref(),
+            Some("Indicators and chart options")
+        );
+        let sma = find_checkbox(&container, "sma20").unwrap();
+        assert_eq!(sma.get_attribute("aria-label").as_deref(), Some("Show SMA20"));
+        let grid = find_checkbox(&container, "grid-visible").unwrap();
+        assert_eq!(grid.get_attribute("aria-label").as_deref(), Some("Background grid"));
+
+        let fib = container.query_selector("#fib-tool").unwrap().unwrap();
+        assert_eq!(fib.get_attribute("aria-label").as_deref(), Some("Fibonacci retracement"));
+        assert_eq!(fib.get_attribute("aria-pressed").as_deref(), Some("false"));
+    }
+
+    #[wasm_bindgen_test]
+    fn enter_toggles_a_legend_checkbox() {
+        use crate::infrastructure::rendering::renderer::{dummy_renderer, set_global_renderer};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let container = setup_container();
+        let chart = create_rw_signal(Chart::new("test".to_string(), ChartType::Candlestick, 10));
+        let renderer = Rc::new(RefCell::new(dummy_renderer()));
+        set_global_renderer(renderer.clone());
+        leptos::mount_to(container.clone(), move || view! { <Legend chart=chart /> });
+
+        let sma = find_checkbox(&container, "sma20").unwrap();
+        assert!(sma.checked());
+        let enter = web_sys::KeyboardEvent::new("keydown").unwrap();
+        enter
+            .init_keyboard_event_with_bubbles_arg_and_cancelable_arg_and_view_arg_and_key_arg(
+                "keydown", true, true, None, "Enter",
+            )
+            .unwrap();
+        sma.dispatch_event(&enter).unwrap();
+
+        assert!(!sma.checked());
+        assert!(!renderer.borrow().line_visibility().is_visible("sma20"));
+    }
+
+    #[test]
+    fn canvas_label_names_symbol_timeframe_and_type() {
+        let label = chart_aria_label(
+            &Symbol::from("ETHUSDT"),
+            TimeInterval::FiveMinutes,
+            ChartType::Candlestick,
+        );
+        assert!(label.starts_with("Candlestick chart of ETHUSDT on the 5m timeframe"));
+    }
 }
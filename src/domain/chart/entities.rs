@@ -1,10 +1,19 @@
-use super::value_objects::{ChartType, Viewport};
+use super::value_objects::{ChartType, FibRetracement, Viewport};
+use crate::domain::logging::{LogComponent, get_logger};
 use crate::domain::market_data::services::{Aggregator, IchimokuData};
-use crate::domain::market_data::{Candle, CandleSeries, MovingAverageEngine, TimeInterval, Volume};
+use crate::domain::market_data::{
+    Candle, CandleOrder, CandleSeries, MaConfig, MovingAverageEngine, TimeInterval, Volume,
+    sum_taker_buy,
+};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 /// Domain entity - Chart
-#[derive(Debug, Clone)]
+///
+/// Serialized without its derived caches: moving averages are recomputed
+/// from the stored candles when a chart is deserialized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "ChartState", from = "ChartState")]
 pub struct Chart {
     pub id: String,
     pub chart_type: ChartType,
@@ -13,9 +22,60 @@ pub struct Chart {
     pub indicators: Vec<Indicator>,
     pub ichimoku: IchimokuData,
     pub ma_engines: HashMap<TimeInterval, MovingAverageEngine>,
+    /// Prices marked by the user with a horizontal alert line
+    pub price_alerts: Vec<f64>,
+    /// Fibonacci retracement drawn by the user
+    pub fib_retracement: Option<FibRetracement>,
     open_buckets: HashSet<TimeInterval>,
 }
 
+/// Serialized form of a [`Chart`]
+#[derive(Serialize, Deserialize)]
+struct ChartState {
+    id: String,
+    chart_type: ChartType,
+    series: HashMap<TimeInterval, CandleSeries>,
+    viewport: Viewport,
+    indicators: Vec<Indicator>,
+    ma_configs: Vec<MaConfig>,
+    price_alerts: Vec<f64>,
+    fib_retracement: Option<FibRetracement>,
+    open_buckets: HashSet<TimeInterval>,
+}
+
+impl From<Chart> for ChartState {
+    fn from(chart: Chart) -> Self {
+        Self {
+            ma_configs: chart.ma_configs(),
+            id: chart.id,
+            chart_type: chart.chart_type,
+            series: chart.series,
+            viewport: chart.viewport,
+            indicators: chart.indicators,
+            price_alerts: chart.price_alerts,
+            fib_retracement: chart.fib_retracement,
+            open_buckets: chart.open_buckets,
+        }
+    }
+}
+
+impl From<ChartState> for Chart {
+    fn from(state: ChartState) -> Self {
+        let mut chart = Chart::new(state.id, state.chart_type, 0);
+        chart.series.extend(state.series);
+        chart.viewport = state.viewport;
+        chart.indicators = state.indicators;
+        chart.price_alerts = state.price_alerts;
+        chart.fib_retracement = state.fib_retracement;
+        chart.open_buckets = state.open_buckets;
+        chart.set_ma_configs(&state.ma_configs);
+        chart
+    }
+}
+
+/// Relative distance under which two alert prices count as the same alert
+const PRICE_ALERT_EPSILON: f64 = 1e-9;
+
 impl Chart {
     pub fn new(id: String, chart_type: ChartType, max_candles: usize) -> Self {
         let mut series = HashMap::new();
@@ -24,6 +84,7 @@ impl Chart {
         series.insert(TimeInterval::FiveMinutes, CandleSeries::new(max_candles));
         series.insert(TimeInterval::FifteenMinutes, CandleSeries::new(max_candles));
         series.insert(TimeInterval::OneHour, CandleSeries::new(max_candles));
+        series.insert(TimeInterval::FourHours, CandleSeries::new(max_candles));
         series.insert(TimeInterval::OneDay, CandleSeries::new(max_candles));
         series.insert(TimeInterval::OneWeek, CandleSeries::new(max_candles));
         series.insert(TimeInterval::OneMonth, CandleSeries::new(max_candles));
@@ -34,6 +95,7 @@ impl Chart {
         ma_engines.insert(TimeInterval::FiveMinutes, MovingAverageEngine::new());
         ma_engines.insert(TimeInterval::FifteenMinutes, MovingAverageEngine::new());
         ma_engines.insert(TimeInterval::OneHour, MovingAverageEngine::new());
+        ma_engines.insert(TimeInterval::FourHours, MovingAverageEngine::new());
         ma_engines.insert(TimeInterval::OneDay, MovingAverageEngine::new());
         ma_engines.insert(TimeInterval::OneWeek, MovingAverageEngine::new());
         ma_engines.insert(TimeInterval::OneMonth, MovingAverageEngine::new());
@@ -46,6 +108,8 @@ impl Chart {
             indicators: Vec::new(),
             ichimoku: IchimokuData::default(),
             ma_engines,
+            price_alerts: Vec::new(),
+            fib_retracement: None,
             open_buckets: HashSet::new(),
         }
     }
@@ -54,11 +118,12 @@ impl Chart {
         if let Some(base) = self.series.get_mut(&TimeInterval::TwoSeconds) {
             let latest_ts = base.latest().map(|c| c.timestamp.value());
             let is_new_candle = latest_ts.is_none_or(|ts| candle.timestamp.value() > ts);
-            base.add_candle(candle.clone());
-            if is_new_candle
-                && let Some(engine) = self.ma_engines.get_mut(&TimeInterval::TwoSeconds)
-            {
-                engine.update_on_close(candle.ohlcv.close.value());
+            let evicted = base.add_candle(candle.clone());
+            if let Some(engine) = self.ma_engines.get_mut(&TimeInterval::TwoSeconds) {
+                if is_new_candle {
+                    engine.update_on_close(candle.ohlcv.close.value());
+                }
+                engine.drop_oldest(evicted);
             }
         }
         self.update_aggregates(candle);
@@ -67,7 +132,7 @@ impl Chart {
     /// Add historical data, replacing existing values
     pub fn set_historical_data(&mut self, mut candles: Vec<Candle>) {
         // Sort by timestamp for stability
-        candles.sort_by(|a, b| a.timestamp.value().cmp(&b.timestamp.value()));
+        candles.sort_by_key(|c| c.timestamp.value());
 
         // Create a new series with the original limit
         let limit = self
@@ -79,15 +144,16 @@ impl Chart {
             *s = CandleSeries::new(limit);
         }
         for e in self.ma_engines.values_mut() {
-            *e = MovingAverageEngine::new();
+            *e = MovingAverageEngine::with_configs(&e.configs());
         }
         self.open_buckets.clear();
 
         for candle in candles {
             if let Some(base) = self.series.get_mut(&TimeInterval::TwoSeconds) {
-                base.add_candle(candle.clone());
+                let evicted = base.add_candle(candle.clone());
                 if let Some(engine) = self.ma_engines.get_mut(&TimeInterval::TwoSeconds) {
                     engine.update_on_close(candle.ohlcv.close.value());
+                    engine.drop_oldest(evicted);
                 }
             }
             self.update_aggregates(candle);
@@ -96,21 +162,39 @@ impl Chart {
         // Update the viewport
         self.update_viewport_for_data();
     }
-    /// Add a new candle in real time
-    pub fn add_realtime_candle(&mut self, candle: Candle) {
+    /// Add a new candle in real time.
+    ///
+    /// A candle older than the latest bucket is rejected and logged so a late
+    /// stream message cannot corrupt the series; one with the latest timestamp
+    /// updates that bucket in place.
+    pub fn add_realtime_candle(&mut self, candle: Candle) -> CandleOrder {
         let is_empty = self.get_candle_count() == 0;
 
+        let order = self
+            .series
+            .get(&TimeInterval::TwoSeconds)
+            .map_or(CandleOrder::New, |base| base.order_of(&candle));
+        if order == CandleOrder::Stale {
+            let latest = self
+                .series
+                .get(&TimeInterval::TwoSeconds)
+                .and_then(|base| base.latest())
+                .map_or(0, |c| c.timestamp.value());
+            get_logger().warn(
+                LogComponent::Domain("Chart"),
+                &format!(
+                    "⚠️ Rejected out-of-order candle at {} older than the latest at {latest}",
+                    candle.timestamp.value()
+                ),
+            );
+            return order;
+        }
+
         if let Some(base) = self.series.get_mut(&TimeInterval::TwoSeconds) {
-            let latest_ts = base.latest().map(|c| c.timestamp.value());
-            let is_update = latest_ts == Some(candle.timestamp.value());
-            let is_new_candle = latest_ts.is_none_or(|ts| candle.timestamp.value() > ts);
-            base.add_candle(candle.clone());
+            let evicted = base.add_candle(candle.clone());
             if let Some(engine) = self.ma_engines.get_mut(&TimeInterval::TwoSeconds) {
-                if is_new_candle {
-                    engine.update_on_close(candle.ohlcv.close.value());
-                } else if is_update {
-                    engine.replace_last_close(candle.ohlcv.close.value());
-                }
+                engine.update_tail(candle.ohlcv.close.value(), order == CandleOrder::New);
+                engine.drop_oldest(evicted);
             }
         }
         self.update_aggregates(candle);
@@ -118,6 +202,7 @@ impl Chart {
         if is_empty {
             self.update_viewport_for_data();
         }
+        order
     }
 
     /// Get total number of candles
@@ -172,6 +257,19 @@ impl Chart {
         }
     }
 
+    /// Show a previously saved time and price window, clamped to the stored candles.
+    ///
+    /// The canvas size of the current viewport is kept.
+    pub fn restore_viewport(&mut self, viewport: &Viewport) {
+        let (width, height) = (self.viewport.width, self.viewport.height);
+        self.viewport = Viewport { width, height, ..viewport.clone() };
+        if let Some(series) = self.series.get(&TimeInterval::TwoSeconds)
+            && let Some((first, last)) = series.time_bounds()
+        {
+            self.viewport.clamp_to_data(first, last);
+        }
+    }
+
     /// Vertical zoom by price
     pub fn zoom_price(&mut self, factor: f32, center_y: f32) {
         self.viewport.zoom_price(factor, center_y);
@@ -186,16 +284,112 @@ impl Chart {
         }
     }
 
+    /// Add a price alert; returns false for invalid prices or an existing alert
+    pub fn add_price_alert(&mut self, price: f64) -> bool {
+        if !price.is_finite() || price <= 0.0 {
+            return false;
+        }
+        let duplicate =
+            self.price_alerts.iter().any(|p| (p - price).abs() <= price * PRICE_ALERT_EPSILON);
+        if !duplicate {
+            self.price_alerts.push(price);
+        }
+        !duplicate
+    }
+
+    /// Remove alerts within `tolerance` of `price`; returns whether any was removed
+    pub fn remove_price_alert(&mut self, price: f64, tolerance: f64) -> bool {
+        let before = self.price_alerts.len();
+        let tolerance = tolerance.max(price.abs() * PRICE_ALERT_EPSILON);
+        self.price_alerts.retain(|p| (p - price).abs() > tolerance);
+        self.price_alerts.len() != before
+    }
+
+    /// Track a new set of moving averages, recomputing them from stored candles
+    pub fn set_ma_configs(&mut self, configs: &[MaConfig]) {
+        for (interval, engine) in self.ma_engines.iter_mut() {
+            *engine = MovingAverageEngine::with_configs(configs);
+            let Some(series) = self.series.get(interval) else { continue };
+            // The latest bucket of an aggregate stays out of the engine until it closes
+            let closed = if self.open_buckets.contains(interval) {
+                series.count().saturating_sub(1)
+            } else {
+                series.count()
+            };
+            for candle in series.get_candles().iter().take(closed) {
+                engine.update_on_close(candle.ohlcv.close.value());
+            }
+        }
+    }
+
+    /// Most candles kept per series before the oldest are evicted
+    pub fn max_candles(&self) -> usize {
+        self.series.get(&TimeInterval::TwoSeconds).map_or(0, |s| s.capacity())
+    }
+
+    /// Keep at most `max_candles` per series; moving averages follow the dropped candles
+    pub fn set_capacity(&mut self, max_candles: usize) {
+        let trimmed = self.series.values().any(|s| s.count() > max_candles);
+        for series in self.series.values_mut() {
+            series.set_capacity(max_candles);
+        }
+        if trimmed {
+            self.set_ma_configs(&self.ma_configs());
+        }
+    }
+
+    /// Moving averages tracked by the chart's engines
+    pub fn ma_configs(&self) -> Vec<MaConfig> {
+        self.ma_engines.get(&TimeInterval::TwoSeconds).map(|e| e.configs()).unwrap_or_default()
+    }
+
     pub fn get_series(&self, interval: TimeInterval) -> Option<&CandleSeries> {
         self.series.get(&interval)
     }
 
+    /// Aggregate the base candles into `interval` buckets without touching the chart
+    pub fn aggregated_series(&self, interval: TimeInterval) -> CandleSeries {
+        let Some(base) = self.series.get(&TimeInterval::TwoSeconds) else {
+            return CandleSeries::new(0);
+        };
+        let mut series = CandleSeries::new(base.capacity());
+        let candles: Vec<Candle> = base.get_candles().iter().cloned().collect();
+        for bucket in candles.chunk_by(|a, b| {
+            interval.bucket_start(a.timestamp.value()) == interval.bucket_start(b.timestamp.value())
+        }) {
+            if let Some(candle) = Aggregator::aggregate(bucket, interval) {
+                series.add_candle(candle);
+            }
+        }
+        series
+    }
+
+    /// Copy of the chart with its `interval` series rebuilt from the base candles,
+    /// for previewing a timeframe before switching to it
+    pub fn preview(&self, interval: TimeInterval) -> Chart {
+        let mut preview = self.clone();
+        if interval == TimeInterval::TwoSeconds {
+            return preview;
+        }
+        let series = self.aggregated_series(interval);
+        let mut engine = MovingAverageEngine::with_configs(&self.ma_configs());
+        // The latest bucket is still open, as in the live aggregates
+        for candle in series.get_candles().iter().take(series.count().saturating_sub(1)) {
+            engine.update_on_close(candle.ohlcv.close.value());
+        }
+        preview.open_buckets.insert(interval);
+        preview.series.insert(interval, series);
+        preview.ma_engines.insert(interval, engine);
+        preview
+    }
+
     fn update_aggregates(&mut self, candle: Candle) {
         let intervals = [
             TimeInterval::OneMinute,
             TimeInterval::FiveMinutes,
             TimeInterval::FifteenMinutes,
             TimeInterval::OneHour,
+            TimeInterval::FourHours,
             TimeInterval::OneDay,
             TimeInterval::OneWeek,
             TimeInterval::OneMonth,
@@ -203,8 +397,7 @@ impl Chart {
 
         for interval in intervals.iter() {
             if let Some(series) = self.series.get_mut(interval) {
-                let bucket_start =
-                    candle.timestamp.value() / interval.duration_ms() * interval.duration_ms();
+                let bucket_start = interval.bucket_start(candle.timestamp.value());
 
                 let latest_ts = series.latest().map(|c| c.timestamp.value());
                 if latest_ts == Some(bucket_start) {
@@ -219,6 +412,10 @@ impl Chart {
                         last.ohlcv.close = candle.ohlcv.close;
                         last.ohlcv.volume =
                             Volume::from(last.ohlcv.volume.value() + candle.ohlcv.volume.value());
+                        last.ohlcv.taker_buy_volume = sum_taker_buy([
+                            last.ohlcv.taker_buy_volume,
+                            candle.ohlcv.taker_buy_volume,
+                        ]);
                         new_close = Some(last.ohlcv.close.value());
                     }
                     if let Some(close) = new_close
@@ -247,7 +444,10 @@ impl Chart {
 
                 let new_candle = Aggregator::aggregate(std::slice::from_ref(&candle), *interval)
                     .unwrap_or_else(|| candle.clone());
-                series.add_candle(new_candle);
+                let evicted = series.add_candle(new_candle);
+                if let Some(engine) = self.ma_engines.get_mut(interval) {
+                    engine.drop_oldest(evicted);
+                }
                 if is_new_bucket {
                     self.open_buckets.insert(*interval);
                 }
@@ -257,7 +457,7 @@ impl Chart {
 }
 
 /// Simplified Indicator entity - only essential fields
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Indicator {
     pub id: String,
     pub indicator_type: IndicatorType,
@@ -270,7 +470,7 @@ impl Indicator {
 }
 
 /// Essential indicator types only
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum IndicatorType {
     SimpleMovingAverage,
     ExponentialMovingAverage,
@@ -1,7 +1,9 @@
 //! Chart aggregate containing entities and value objects.
 
 pub mod entities;
+pub mod replay;
 pub mod value_objects;
 
 pub use entities::*;
+pub use replay::*;
 pub use value_objects::*;
@@ -0,0 +1,142 @@
+use crate::domain::market_data::{Candle, CandleSeries};
+use derive_more::Display;
+
+/// Milliseconds between replayed candles at normal speed
+pub const REPLAY_BASE_TICK_MS: u32 = 1_000;
+
+/// Value Object - Replay playback speed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Display)]
+pub enum ReplaySpeed {
+    #[default]
+    #[display(fmt = "1x")]
+    Normal,
+    #[display(fmt = "2x")]
+    Double,
+    #[display(fmt = "5x")]
+    Fivefold,
+}
+
+impl ReplaySpeed {
+    pub const ALL: [ReplaySpeed; 3] =
+        [ReplaySpeed::Normal, ReplaySpeed::Double, ReplaySpeed::Fivefold];
+
+    pub fn multiplier(self) -> u32 {
+        match self {
+            ReplaySpeed::Normal => 1,
+            ReplaySpeed::Double => 2,
+            ReplaySpeed::Fivefold => 5,
+        }
+    }
+
+    /// Milliseconds between two replayed candles
+    pub fn tick_ms(self) -> u32 {
+        REPLAY_BASE_TICK_MS / self.multiplier()
+    }
+}
+
+/// Value Object - Replay playback state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayState {
+    Paused,
+    Playing,
+    /// Every candle has been replayed
+    Finished,
+}
+
+/// Steps through a recorded series one candle at a time.
+///
+/// Candles before the start index form the initial history; the rest are
+/// handed out by [`step`](Self::step) and [`tick`](Self::tick) as if they
+/// arrived in real time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayController {
+    candles: Vec<Candle>,
+    start: usize,
+    cursor: usize,
+    state: ReplayState,
+    speed: ReplaySpeed,
+}
+
+impl ReplayController {
+    /// Replay `series` from candle `start`, clamped to its length, paused
+    pub fn new(series: &CandleSeries, start: usize) -> Self {
+        let candles: Vec<Candle> = series.get_candles().iter().cloned().collect();
+        let start = start.min(candles.len());
+        let state =
+            if start == candles.len() { ReplayState::Finished } else { ReplayState::Paused };
+        Self { candles, start, cursor: start, state, speed: ReplaySpeed::default() }
+    }
+
+    /// Candles shown before the replay begins
+    pub fn history(&self) -> &[Candle] {
+        &self.candles[..self.start]
+    }
+
+    pub fn state(&self) -> ReplayState {
+        self.state
+    }
+
+    pub fn speed(&self) -> ReplaySpeed {
+        self.speed
+    }
+
+    pub fn set_speed(&mut self, speed: ReplaySpeed) {
+        self.speed = speed;
+    }
+
+    /// Index of the next candle to replay
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// Number of candles in the replayed series
+    pub fn len(&self) -> usize {
+        self.candles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.candles.is_empty()
+    }
+
+    pub fn play(&mut self) {
+        if self.state == ReplayState::Paused {
+            self.state = ReplayState::Playing;
+        }
+    }
+
+    pub fn pause(&mut self) {
+        if self.state == ReplayState::Playing {
+            self.state = ReplayState::Paused;
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        match self.state {
+            ReplayState::Playing => self.pause(),
+            _ => self.play(),
+        }
+    }
+
+    /// Pause playback and hand out exactly the next candle
+    pub fn step(&mut self) -> Option<Candle> {
+        self.pause();
+        self.advance()
+    }
+
+    /// Next candle when a timer tick fires during playback
+    pub fn tick(&mut self) -> Option<Candle> {
+        if self.state != ReplayState::Playing {
+            return None;
+        }
+        self.advance()
+    }
+
+    fn advance(&mut self) -> Option<Candle> {
+        let candle = self.candles.get(self.cursor).cloned()?;
+        self.cursor += 1;
+        if self.cursor == self.candles.len() {
+            self.state = ReplayState::Finished;
+        }
+        Some(candle)
+    }
+}
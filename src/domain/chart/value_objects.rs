@@ -1,8 +1,22 @@
+use crate::domain::market_data::{TimeInterval, Timestamp};
 use derive_more::Display;
+use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumIter, EnumString};
 
 /// Value Object - Chart type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter, EnumString, AsRefStr)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Display,
+    EnumIter,
+    EnumString,
+    AsRefStr,
+    Serialize,
+    Deserialize,
+)]
 pub enum ChartType {
     #[display(fmt = "Candlestick")]
     #[strum(serialize = "candlestick")]
@@ -16,9 +30,9 @@ pub enum ChartType {
     #[display(fmt = "OHLC")]
     #[strum(serialize = "ohlc")]
     OHLC,
-    #[display(fmt = "Heikin")]
-    #[strum(serialize = "heikin")]
-    Heikin,
+    #[display(fmt = "Heikin-Ashi")]
+    #[strum(serialize = "heikin-ashi")]
+    HeikinAshi,
     #[display(fmt = "Renko")]
     #[strum(serialize = "renko")]
     Renko,
@@ -28,7 +42,7 @@ pub enum ChartType {
 }
 
 /// Value Object - Viewport
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Viewport {
     pub start_time: f64,
     pub end_time: f64,
@@ -158,6 +172,61 @@ impl Viewport {
     }
 }
 
+/// Standard Fibonacci retracement ratios, from the end point (0%) back to the start (100%)
+pub const FIB_RATIOS: [f64; 6] = [0.0, 0.236, 0.382, 0.5, 0.618, 1.0];
+
+/// Value Object - Fibonacci retracement between two (time, price) anchors
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FibRetracement {
+    pub start: (Timestamp, f64),
+    pub end: (Timestamp, f64),
+}
+
+impl FibRetracement {
+    pub fn new(start: (Timestamp, f64), end: (Timestamp, f64)) -> Self {
+        Self { start, end }
+    }
+
+    /// `(ratio, price)` of every level in `FIB_RATIOS` order
+    pub fn levels(&self) -> Vec<(f64, f64)> {
+        let (from, to) = (self.start.1, self.end.1);
+        FIB_RATIOS.iter().map(|&ratio| (ratio, to - (to - from) * ratio)).collect()
+    }
+}
+
+/// Value Object - move from point A to point B, each a (candle index, price) pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceMeasurement {
+    pub start: (usize, f64),
+    pub end: (usize, f64),
+}
+
+impl PriceMeasurement {
+    pub fn new(start: (usize, f64), end: (usize, f64)) -> Self {
+        Self { start, end }
+    }
+
+    /// Absolute price change from A to B
+    pub fn price_change(&self) -> f64 {
+        self.end.1 - self.start.1
+    }
+
+    /// Price change relative to A in percent; `None` when A is at zero
+    pub fn percent_change(&self) -> Option<f64> {
+        (self.start.1 != 0.0).then(|| self.price_change() / self.start.1 * 100.0)
+    }
+
+    /// Number of bars between A and B, regardless of direction
+    pub fn bar_count(&self) -> usize {
+        self.start.0.abs_diff(self.end.0)
+    }
+
+    /// Time covered by the bars of `interval`
+    pub fn elapsed_ms(&self, interval: TimeInterval) -> u64 {
+        self.bar_count() as u64 * interval.duration_ms()
+    }
+}
+
 /// Value Object - Color
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Color {
@@ -18,6 +18,24 @@ impl std::fmt::Display for AppError {
 
 impl std::error::Error for AppError {}
 
+impl AppError {
+    /// Error for a failed HTTP response: server trouble and throttling are
+    /// transient network errors, any other status means the request is wrong
+    pub fn from_http_status(status: u16) -> Self {
+        let msg = format!("HTTP error: {status}");
+        if status >= 500 || status == 408 || status == 429 {
+            AppError::NetworkError(msg)
+        } else {
+            AppError::ValidationError(msg)
+        }
+    }
+
+    /// Whether repeating the failed operation may succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, AppError::NetworkError(_))
+    }
+}
+
 // Simple convenience type aliases
 pub type NetworkResult<T> = Result<T, AppError>;
 pub type RenderingResult<T> = Result<T, AppError>;
@@ -1,3 +1,4 @@
+use crate::domain::market_data::TimeInterval;
 use derive_more::Display;
 
 /// Log levels with automatic Display implementation
@@ -15,6 +16,35 @@ pub enum LogLevel {
     Error = 4,
 }
 
+impl LogLevel {
+    /// Level stored as `level as u8`; out-of-range values saturate to `Error`
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Trace,
+            1 => Self::Debug,
+            2 => Self::Info,
+            3 => Self::Warn,
+            _ => Self::Error,
+        }
+    }
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    /// Parse a level name such as "debug" or "WARN"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "trace" => Ok(Self::Trace),
+            "debug" => Ok(Self::Debug),
+            "info" => Ok(Self::Info),
+            "warn" | "warning" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            other => Err(format!("Unknown log level: {other}")),
+        }
+    }
+}
+
 /// Log components with automatic Display implementation
 #[derive(Debug, Clone, Display)]
 pub enum LogComponent {
@@ -42,12 +72,30 @@ pub struct LogEntry {
 pub trait TimeProvider: Send + Sync {
     fn current_timestamp(&self) -> u64;
     fn format_timestamp(&self, timestamp: u64) -> String;
+
+    /// Human-readable date and time of a candle opened at `timestamp`
+    fn format_datetime(&self, timestamp: u64, interval: TimeInterval) -> String {
+        crate::time_utils::format_full_datetime(timestamp, interval)
+    }
+
+    /// ISO 8601 UTC date and time, used by data exports
+    fn format_iso_datetime(&self, timestamp: u64) -> String {
+        crate::time_utils::format_iso_datetime(timestamp)
+    }
 }
 
 /// Domain abstraction for structured logging
 pub trait Logger: Send + Sync {
     fn log(&self, entry: LogEntry);
 
+    /// Change the minimum level at runtime; loggers without a threshold ignore it
+    fn set_min_level(&self, _level: LogLevel) {}
+
+    /// Whether entries of `level` are currently kept
+    fn is_enabled(&self, _level: LogLevel) -> bool {
+        true
+    }
+
     /// Convenience methods with default implementations
     fn trace(&self, component: LogComponent, message: &str) {
         self.log(LogEntry::new(LogLevel::Trace, component, message));
@@ -128,6 +176,11 @@ pub fn get_logger() -> &'static dyn Logger {
     GLOBAL_LOGGER.get().map(|logger| logger.as_ref()).unwrap_or(&NoOpLogger)
 }
 
+/// Change the minimum level of the global logger
+pub fn set_log_level(level: LogLevel) {
+    get_logger().set_min_level(level);
+}
+
 /// Get global time provider reference
 pub fn get_time_provider() -> &'static dyn TimeProvider {
     GLOBAL_TIME_PROVIDER.get().map(|provider| provider.as_ref()).unwrap_or(&BasicTimeProvider)
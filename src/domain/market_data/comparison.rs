@@ -0,0 +1,47 @@
+//! Percent-change comparison of two symbols.
+
+use super::Candle;
+
+/// Closes of two series at one shared timestamp, in percent from the first shared one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RebasedPoint {
+    /// Index of the candle in the primary series
+    pub index: usize,
+    pub timestamp: u64,
+    /// Change of the primary close in percent
+    pub primary: f64,
+    /// Change of the secondary close in percent
+    pub secondary: f64,
+}
+
+/// Align `secondary` with `primary` on candle timestamps and rebase both to the
+/// percent change from the first timestamp they share.
+///
+/// Timestamps present in only one series are skipped, so series with missing bars
+/// or different lengths still line up. Both slices must be in time order.
+pub fn rebase_on_timestamps(primary: &[Candle], secondary: &[Candle]) -> Vec<RebasedPoint> {
+    let mut points = Vec::new();
+    let mut base: Option<(f64, f64)> = None;
+    let mut j = 0;
+    for (index, candle) in primary.iter().enumerate() {
+        let timestamp = candle.timestamp.value();
+        while j < secondary.len() && secondary[j].timestamp.value() < timestamp {
+            j += 1;
+        }
+        let Some(other) = secondary.get(j).filter(|c| c.timestamp.value() == timestamp) else {
+            continue;
+        };
+        let (close, other_close) = (candle.ohlcv.close.value(), other.ohlcv.close.value());
+        let (primary_base, secondary_base) = *base.get_or_insert((close, other_close));
+        if primary_base <= 0.0 || secondary_base <= 0.0 {
+            return Vec::new();
+        }
+        points.push(RebasedPoint {
+            index,
+            timestamp,
+            primary: (close / primary_base - 1.0) * 100.0,
+            secondary: (other_close / secondary_base - 1.0) * 100.0,
+        });
+    }
+    points
+}
@@ -1,6 +1,11 @@
+use super::value_objects::TimeInterval;
 pub use super::value_objects::{OHLCV, Price, Timestamp, Volume};
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
+use std::ops::Range;
+
+/// Default gap threshold as a multiple of the interval duration
+pub const GAP_THRESHOLD: f64 = 1.5;
 
 /// Domain entity - Candle
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -35,8 +40,28 @@ impl Candle {
     }
 }
 
+/// Place of an incoming candle relative to the latest candle of a series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleOrder {
+    /// Opens a bucket after the latest one
+    New,
+    /// Revises the latest bucket
+    Update,
+    /// Belongs to a bucket before the latest one
+    Stale,
+}
+
+/// Candles with the largest close-to-close change from the previous candle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LargestMoves {
+    /// Index of the candle with the largest absolute change
+    pub absolute: Option<usize>,
+    /// Index of the candle with the largest percent change
+    pub percent: Option<usize>,
+}
+
 /// Domain entity - Candle series
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CandleSeries {
     candles: VecDeque<Candle>,
     max_size: usize,
@@ -47,32 +72,47 @@ impl CandleSeries {
         Self { candles: VecDeque::new(), max_size }
     }
 
-    pub fn add_candle(&mut self, candle: Candle) {
+    /// Order of `candle` relative to the latest candle of the series
+    pub fn order_of(&self, candle: &Candle) -> CandleOrder {
+        match self.candles.back().map(|c| c.timestamp.value()) {
+            Some(latest) if candle.timestamp.value() < latest => CandleOrder::Stale,
+            Some(latest) if candle.timestamp.value() == latest => CandleOrder::Update,
+            _ => CandleOrder::New,
+        }
+    }
+
+    /// Add or update a candle, returning how many of the oldest candles were
+    /// evicted to stay within the capacity
+    pub fn add_candle(&mut self, candle: Candle) -> usize {
         // Check whether to update the existing candle or add a new one
         if let Some(last_candle) = self.candles.back_mut() {
             if last_candle.timestamp == candle.timestamp {
                 *last_candle = candle;
-                return;
+                return 0;
             }
 
             // Ensure chronological order
             if candle.timestamp.value() < last_candle.timestamp.value() {
                 // If the new candle is older than the last, insert it sorted
-                self.insert_candle_sorted(candle);
-                return;
+                return self.insert_candle_sorted(candle);
             }
         }
 
         self.candles.push_back(candle);
 
         // Limit size for performance
-        if self.candles.len() > self.max_size {
-            self.candles.pop_front();
-        }
+        self.evict_overflow()
+    }
+
+    /// Drop the oldest candles beyond the capacity, returning how many were dropped
+    fn evict_overflow(&mut self) -> usize {
+        let evicted = self.candles.len().saturating_sub(self.max_size);
+        self.candles.drain(..evicted);
+        evicted
     }
 
     /// Insert a candle while keeping time order
-    fn insert_candle_sorted(&mut self, candle: Candle) {
+    fn insert_candle_sorted(&mut self, candle: Candle) -> usize {
         // Find the correct insertion position
         let insert_pos = self
             .candles
@@ -89,9 +129,7 @@ impl CandleSeries {
         }
 
         // Limit the size
-        if self.candles.len() > self.max_size {
-            self.candles.pop_front();
-        }
+        self.evict_overflow()
     }
 
     pub fn get_candles(&self) -> &VecDeque<Candle> {
@@ -120,6 +158,12 @@ impl CandleSeries {
         self.max_size
     }
 
+    /// Change the maximum candle count, dropping the oldest candles beyond it
+    pub fn set_capacity(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        self.evict_overflow();
+    }
+
     /// Get the last closing price
     pub fn get_latest_price(&self) -> Option<&Price> {
         self.candles.back().map(|candle| &candle.ohlcv.close)
@@ -155,4 +199,153 @@ impl CandleSeries {
         let last = self.candles.back().unwrap().timestamp.value();
         Some((first, last))
     }
+
+    /// Index of the candle opening closest to `timestamp` (ms); ties go to the earlier one
+    pub fn nearest_index(&self, timestamp: u64) -> Option<usize> {
+        if self.candles.is_empty() {
+            return None;
+        }
+        let after = self.candles.partition_point(|c| c.timestamp.value() < timestamp);
+        if after == 0 {
+            return Some(0);
+        }
+        if after == self.candles.len() {
+            return Some(after - 1);
+        }
+        let before_gap = timestamp - self.candles[after - 1].timestamp.value();
+        let after_gap = self.candles[after].timestamp.value() - timestamp;
+        Some(if after_gap < before_gap { after } else { after - 1 })
+    }
+
+    /// Find missing bars: `i - 1..i` for each pair of neighbouring candles whose
+    /// timestamps are more than `threshold` interval durations apart
+    pub fn detect_gaps(&self, interval: TimeInterval, threshold: f64) -> Vec<Range<usize>> {
+        let max_delta = interval.duration_ms() as f64 * threshold;
+        self.candles
+            .iter()
+            .zip(self.candles.iter().skip(1))
+            .enumerate()
+            .filter(|(_, (prev, next))| {
+                next.timestamp.value().saturating_sub(prev.timestamp.value()) as f64 > max_delta
+            })
+            .map(|(i, _)| i..i + 1)
+            .collect()
+    }
+
+    /// Find the candles whose close moved the most from the previous close, both in
+    /// price and in percent; ties go to the earlier candle
+    pub fn largest_moves(&self) -> LargestMoves {
+        let mut moves = LargestMoves::default();
+        let (mut max_abs, mut max_pct) = (0.0, 0.0);
+        for (i, (prev, next)) in self.candles.iter().zip(self.candles.iter().skip(1)).enumerate() {
+            let prev_close = prev.ohlcv.close.value();
+            let change = (next.ohlcv.close.value() - prev_close).abs();
+            if change > max_abs {
+                max_abs = change;
+                moves.absolute = Some(i + 1);
+            }
+            if prev_close > 0.0 && change / prev_close > max_pct {
+                max_pct = change / prev_close;
+                moves.percent = Some(i + 1);
+            }
+        }
+        moves
+    }
+}
+
+/// One price level of an order book
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderBookLevel {
+    pub price: f64,
+    pub quantity: f64,
+}
+
+impl OrderBookLevel {
+    pub fn new(price: f64, quantity: f64) -> Self {
+        Self { price, quantity }
+    }
+}
+
+/// Bid and ask quantity summed over the prices `price..price + bucket size`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthBucket {
+    pub price: f64,
+    pub bid_quantity: f64,
+    pub ask_quantity: f64,
+}
+
+/// Domain entity - order book snapshot with the best levels first on each side
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OrderBook {
+    last_update_id: u64,
+    bids: Vec<OrderBookLevel>,
+    asks: Vec<OrderBookLevel>,
+}
+
+impl OrderBook {
+    /// Build a book, dropping empty or invalid levels and sorting bids
+    /// descending and asks ascending by price
+    pub fn new(
+        last_update_id: u64,
+        mut bids: Vec<OrderBookLevel>,
+        mut asks: Vec<OrderBookLevel>,
+    ) -> Self {
+        let valid = |l: &OrderBookLevel| l.price.is_finite() && l.price > 0.0 && l.quantity > 0.0;
+        bids.retain(valid);
+        asks.retain(valid);
+        bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+        asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+        Self { last_update_id, bids, asks }
+    }
+
+    pub fn last_update_id(&self) -> u64 {
+        self.last_update_id
+    }
+
+    pub fn bids(&self) -> &[OrderBookLevel] {
+        &self.bids
+    }
+
+    pub fn asks(&self) -> &[OrderBookLevel] {
+        &self.asks
+    }
+
+    pub fn best_bid(&self) -> Option<f64> {
+        self.bids.first().map(|l| l.price)
+    }
+
+    pub fn best_ask(&self) -> Option<f64> {
+        self.asks.first().map(|l| l.price)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    /// Sum quantities into buckets `bucket_size` wide, lowest price first.
+    ///
+    /// Bucket `k` covers `k * bucket_size..(k + 1) * bucket_size`; a
+    /// non-positive size yields no buckets.
+    pub fn aggregate(&self, bucket_size: f64) -> Vec<DepthBucket> {
+        if !bucket_size.is_finite() || bucket_size <= 0.0 {
+            return Vec::new();
+        }
+        let mut buckets: BTreeMap<i64, (f64, f64)> = BTreeMap::new();
+        for level in &self.bids {
+            let key = (level.price / bucket_size).floor() as i64;
+            buckets.entry(key).or_default().0 += level.quantity;
+        }
+        for level in &self.asks {
+            let key = (level.price / bucket_size).floor() as i64;
+            buckets.entry(key).or_default().1 += level.quantity;
+        }
+        buckets
+            .into_iter()
+            .map(|(key, (bid_quantity, ask_quantity))| DepthBucket {
+                price: key as f64 * bucket_size,
+                bid_quantity,
+                ask_quantity,
+            })
+            .collect()
+    }
 }
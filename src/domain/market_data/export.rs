@@ -0,0 +1,33 @@
+//! CSV export of candle data.
+
+use super::{CandleSeries, Symbol, TimeInterval};
+use crate::domain::logging::get_time_provider;
+
+/// Header row of exported CSV files
+pub const CSV_HEADER: &str = "timestamp,open,high,low,close,volume";
+
+/// CSV with one row per candle in `start..start + count`, timestamps formatted
+/// as ISO dates by the configured time provider
+pub fn export_csv(series: &CandleSeries, start: usize, count: usize) -> String {
+    let time = get_time_provider();
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for candle in series.get_candles().iter().skip(start).take(count) {
+        let o = &candle.ohlcv;
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            time.format_iso_datetime(candle.timestamp.value()),
+            o.open.value(),
+            o.high.value(),
+            o.low.value(),
+            o.close.value(),
+            o.volume.value()
+        ));
+    }
+    csv
+}
+
+/// File name of an export, e.g. `BTCUSDT-1m-1700000000000.csv`
+pub fn csv_file_name(symbol: &Symbol, interval: TimeInterval, timestamp: u64) -> String {
+    format!("{}-{}-{timestamp}.csv", symbol.value(), interval.to_binance_str())
+}
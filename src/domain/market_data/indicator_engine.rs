@@ -1,48 +1,206 @@
 use super::{Candle, Price};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+/// Kind of moving average
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MaKind {
+    Sma,
+    Ema,
+}
+
+impl MaKind {
+    /// Short lowercase name used in indicator identifiers (`sma20`, `ema12`)
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            MaKind::Sma => "sma",
+            MaKind::Ema => "ema",
+        }
+    }
+}
+
+/// Compute a moving average of arbitrary period over close prices.
+///
+/// Matches [`MovingAverageEngine`] semantics: SMA values start once the window
+/// is full, EMA values start at the first close.
+pub fn moving_average(kind: MaKind, period: usize, closes: &[f64]) -> Vec<Price> {
+    if period == 0 {
+        return Vec::new();
+    }
+    match kind {
+        MaKind::Sma => {
+            if closes.len() < period {
+                return Vec::new();
+            }
+            let mut sum: f64 = closes[..period].iter().sum();
+            let mut out = Vec::with_capacity(closes.len() - period + 1);
+            out.push(Price::from(sum / period as f64));
+            for i in period..closes.len() {
+                sum += closes[i] - closes[i - period];
+                out.push(Price::from(sum / period as f64));
+            }
+            out
+        }
+        MaKind::Ema => {
+            let alpha = 2.0 / (period as f64 + 1.0);
+            let mut last: Option<f64> = None;
+            closes
+                .iter()
+                .map(|&close| {
+                    let val = last.map_or(close, |prev| alpha * close + (1.0 - alpha) * prev);
+                    last = Some(val);
+                    Price::from(val)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Moving average to maintain: its kind and window length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MaConfig {
+    pub kind: MaKind,
+    pub period: usize,
+}
+
+impl MaConfig {
+    pub const fn new(kind: MaKind, period: usize) -> Self {
+        Self { kind, period }
+    }
+
+    /// Identifier used by the legend and toggles, e.g. `sma20`
+    pub fn name(&self) -> String {
+        format!("{}{}", self.kind.prefix(), self.period)
+    }
+
+    /// Parse an identifier like `sma20` or `EMA12`; zero periods are rejected
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        let (kind, period) = if let Some(period) = name.strip_prefix("sma") {
+            (MaKind::Sma, period)
+        } else {
+            (MaKind::Ema, name.strip_prefix("ema")?)
+        };
+        let period = period.parse().ok().filter(|p| *p > 0)?;
+        Some(Self::new(kind, period))
+    }
+}
+
+/// Moving averages tracked when nothing else is configured
+pub const DEFAULT_MA_CONFIGS: [MaConfig; 5] = [
+    MaConfig::new(MaKind::Sma, 20),
+    MaConfig::new(MaKind::Sma, 50),
+    MaConfig::new(MaKind::Sma, 200),
+    MaConfig::new(MaKind::Ema, 12),
+    MaConfig::new(MaKind::Ema, 26),
+];
+
+/// Values of several configured moving averages
 #[derive(Debug, Clone, Default)]
 pub struct MovingAveragesData {
-    pub sma_20: Vec<Price>,
-    pub sma_50: Vec<Price>,
-    pub sma_200: Vec<Price>,
-    pub ema_12: Vec<Price>,
-    pub ema_26: Vec<Price>,
+    lines: Vec<(MaConfig, Vec<Price>)>,
+    /// Values at the front of each line that belong to candles evicted from the
+    /// series; they are dropped in bulk so eviction stays amortized O(1)
+    heads: Vec<usize>,
 }
 
-/// Engine maintaining SMA/EMA incrementally
-#[derive(Debug, Clone, Default)]
+impl MovingAveragesData {
+    pub fn new(lines: Vec<(MaConfig, Vec<Price>)>) -> Self {
+        let heads = vec![0; lines.len()];
+        Self { lines, heads }
+    }
+
+    /// Values of the given moving average, if it is part of the set
+    pub fn get(&self, kind: MaKind, period: usize) -> Option<&[Price]> {
+        self.lines()
+            .find(|(config, _)| config.kind == kind && config.period == period)
+            .map(|(_, values)| values)
+    }
+
+    /// Every moving average with its values, oldest first
+    pub fn lines(&self) -> impl Iterator<Item = (MaConfig, &[Price])> {
+        self.lines
+            .iter()
+            .zip(&self.heads)
+            .map(|((config, values), &head)| (*config, &values[head..]))
+    }
+
+    /// Drop up to `count` of the oldest values of every line
+    fn drop_front(&mut self, count: usize) {
+        for ((_, values), head) in self.lines.iter_mut().zip(&mut self.heads) {
+            *head += count.min(values.len() - *head);
+            // Compact once the dropped values outnumber the kept ones
+            if *head * 2 > values.len() {
+                values.drain(..*head);
+                *head = 0;
+            }
+        }
+    }
+}
+
+impl PartialEq for MovingAveragesData {
+    fn eq(&self, other: &Self) -> bool {
+        self.lines().eq(other.lines())
+    }
+}
+
+/// Incremental state of one moving average
+#[derive(Debug, Clone)]
+struct MaState {
+    /// SMA window of recent closes
+    window: VecDeque<f64>,
+    sum: f64,
+    ema_last: Option<f64>,
+    alpha: f64,
+}
+
+impl MaState {
+    fn new(config: MaConfig) -> Self {
+        let capacity = if config.kind == MaKind::Sma { config.period } else { 0 };
+        Self {
+            window: VecDeque::with_capacity(capacity),
+            sum: 0.0,
+            ema_last: None,
+            alpha: 2.0 / (config.period as f64 + 1.0),
+        }
+    }
+}
+
+/// Engine maintaining configured SMA/EMA sequences incrementally
+#[derive(Debug, Clone)]
 pub struct MovingAverageEngine {
     pub data: MovingAveragesData,
-    sma20_win: VecDeque<f64>,
-    sma20_sum: f64,
-    sma50_win: VecDeque<f64>,
-    sma50_sum: f64,
-    sma200_win: VecDeque<f64>,
-    sma200_sum: f64,
-    ema12_last: Option<f64>,
-    ema26_last: Option<f64>,
-    alpha12: f64,
-    alpha26: f64,
+    states: Vec<MaState>,
+    closes_seen: usize,
+}
+
+impl Default for MovingAverageEngine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MovingAverageEngine {
+    /// Engine tracking [`DEFAULT_MA_CONFIGS`]
     pub fn new() -> Self {
+        Self::with_configs(&DEFAULT_MA_CONFIGS)
+    }
+
+    /// Engine tracking the given moving averages; zero periods are skipped
+    pub fn with_configs(configs: &[MaConfig]) -> Self {
+        let configs: Vec<MaConfig> = configs.iter().copied().filter(|c| c.period > 0).collect();
         Self {
-            data: MovingAveragesData::default(),
-            sma20_win: VecDeque::with_capacity(20),
-            sma20_sum: 0.0,
-            sma50_win: VecDeque::with_capacity(50),
-            sma50_sum: 0.0,
-            sma200_win: VecDeque::with_capacity(200),
-            sma200_sum: 0.0,
-            ema12_last: None,
-            ema26_last: None,
-            alpha12: 2.0 / (12.0 + 1.0),
-            alpha26: 2.0 / (26.0 + 1.0),
+            states: configs.iter().copied().map(MaState::new).collect(),
+            data: MovingAveragesData::new(configs.into_iter().map(|c| (c, Vec::new())).collect()),
+            closes_seen: 0,
         }
     }
 
+    /// Moving averages tracked by this engine
+    pub fn configs(&self) -> Vec<MaConfig> {
+        self.data.lines().map(|(config, _)| config).collect()
+    }
+
     pub fn compute_historical(&mut self, candles: &[Candle]) {
         for c in candles {
             self.update_on_close(c.ohlcv.close.value());
@@ -125,79 +283,71 @@ impl MovingAverageEngine {
 
     /// Update indicators when a candle closes
     pub fn update_on_close(&mut self, close: f64) {
-        Self::update_sma(
-            &mut self.sma20_win,
-            &mut self.sma20_sum,
-            20,
-            close,
-            &mut self.data.sma_20,
-        );
-        Self::update_sma(
-            &mut self.sma50_win,
-            &mut self.sma50_sum,
-            50,
-            close,
-            &mut self.data.sma_50,
-        );
-        Self::update_sma(
-            &mut self.sma200_win,
-            &mut self.sma200_sum,
-            200,
-            close,
-            &mut self.data.sma_200,
-        );
-        Self::update_ema(&mut self.ema12_last, self.alpha12, close, &mut self.data.ema_12);
-        Self::update_ema(&mut self.ema26_last, self.alpha26, close, &mut self.data.ema_26);
+        self.closes_seen += 1;
+        for (state, (config, out)) in self.states.iter_mut().zip(&mut self.data.lines) {
+            match config.kind {
+                MaKind::Sma => {
+                    Self::update_sma(&mut state.window, &mut state.sum, config.period, close, out)
+                }
+                MaKind::Ema => Self::update_ema(&mut state.ema_last, state.alpha, close, out),
+            }
+        }
     }
 
     /// Preview SMA for an in-progress candle
     pub fn preview_sma_value(&self, period: usize, close: f64) -> Option<Price> {
-        match period {
-            20 => Self::preview_sma(&self.sma20_win, self.sma20_sum, 20, close),
-            50 => Self::preview_sma(&self.sma50_win, self.sma50_sum, 50, close),
-            200 => Self::preview_sma(&self.sma200_win, self.sma200_sum, 200, close),
-            _ => None,
-        }
+        self.states
+            .iter()
+            .zip(&self.data.lines)
+            .find(|(_, (config, _))| config.kind == MaKind::Sma && config.period == period)
+            .and_then(|(state, _)| Self::preview_sma(&state.window, state.sum, period, close))
     }
 
     pub fn data(&self) -> &MovingAveragesData {
         &self.data
     }
 
+    /// Values maintained by the engine for the given kind and period, if tracked
+    pub fn values(&self, kind: MaKind, period: usize) -> Option<&[Price]> {
+        self.data.get(kind, period)
+    }
+
+    /// Apply a realtime close, recomputing only the tail of each sequence.
+    ///
+    /// A new candle appends one value per indicator; an update of the last
+    /// candle replaces the latest value and adjusts the SMA window sums.
+    pub fn update_tail(&mut self, close: f64, is_new_candle: bool) {
+        if is_new_candle || self.closes_seen == 0 {
+            self.update_on_close(close);
+        } else {
+            self.replace_last_close(close);
+        }
+    }
+
+    /// Drop the values of the `count` oldest candles once they leave the series.
+    ///
+    /// The rolling windows keep their closes, so the next values stay exact and
+    /// the remaining ones stay aligned with the remaining candles; a line still
+    /// in its lead-in simply has nothing to drop.
+    pub fn drop_oldest(&mut self, count: usize) {
+        self.data.drop_front(count);
+    }
+
     /// Replace the latest close value, adjusting SMA/EMA sequences
     pub fn replace_last_close(&mut self, close: f64) {
-        Self::replace_sma(
-            &mut self.sma20_win,
-            &mut self.sma20_sum,
-            20,
-            close,
-            self.data.sma_20.as_mut_slice(),
-        );
-        Self::replace_sma(
-            &mut self.sma50_win,
-            &mut self.sma50_sum,
-            50,
-            close,
-            self.data.sma_50.as_mut_slice(),
-        );
-        Self::replace_sma(
-            &mut self.sma200_win,
-            &mut self.sma200_sum,
-            200,
-            close,
-            self.data.sma_200.as_mut_slice(),
-        );
-        Self::replace_ema(
-            &mut self.ema12_last,
-            self.alpha12,
-            close,
-            self.data.ema_12.as_mut_slice(),
-        );
-        Self::replace_ema(
-            &mut self.ema26_last,
-            self.alpha26,
-            close,
-            self.data.ema_26.as_mut_slice(),
-        );
+        for (state, (config, out)) in self.states.iter_mut().zip(&mut self.data.lines) {
+            match config.kind {
+                MaKind::Sma => Self::replace_sma(
+                    &mut state.window,
+                    &mut state.sum,
+                    config.period,
+                    close,
+                    out.as_mut_slice(),
+                ),
+                MaKind::Ema => {
+                    Self::replace_ema(&mut state.ema_last, state.alpha, close, out.as_mut_slice())
+                }
+            }
+        }
     }
 }
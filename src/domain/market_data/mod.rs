@@ -1,10 +1,14 @@
 //! Market data aggregate containing entities, services and value objects.
 
+pub mod comparison;
 pub mod entities;
+pub mod export;
 pub mod indicator_engine;
 pub mod services;
 pub mod value_objects;
 
+pub use comparison::*;
 pub use entities::*;
+pub use export::*;
 pub use indicator_engine::*;
 pub use value_objects::*;
@@ -1,5 +1,7 @@
 use crate::domain::market_data::{
-    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume, indicator_engine::MovingAveragesData,
+    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume,
+    indicator_engine::{MaConfig, MaKind, MovingAveragesData, moving_average},
+    value_objects::sum_taker_buy,
 };
 
 /// Ichimoku indicator components
@@ -12,6 +14,131 @@ pub struct IchimokuData {
     pub chikou_span: Vec<Price>,
 }
 
+/// Bollinger Bands: SMA middle line with bands at a multiple of the standard deviation
+#[derive(Debug, Clone, Default)]
+pub struct BollingerBands {
+    pub upper: Vec<Price>,
+    pub middle: Vec<Price>,
+    pub lower: Vec<Price>,
+}
+
+/// Donchian Channels: highest high and lowest low of a window with their midpoint
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DonchianChannels {
+    pub upper: Vec<f64>,
+    pub middle: Vec<f64>,
+    pub lower: Vec<f64>,
+}
+
+/// Keltner Channels: EMA middle line with bands at a multiple of the ATR
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeltnerChannels {
+    pub upper: Vec<f64>,
+    pub middle: Vec<f64>,
+    pub lower: Vec<f64>,
+}
+
+/// MACD line, its signal line and the histogram between them
+#[derive(Debug, Clone, Default)]
+pub struct MacdData {
+    pub macd: Vec<f64>,
+    pub signal: Vec<f64>,
+    pub histogram: Vec<f64>,
+}
+
+/// Stochastic oscillator: %K and its %D moving average
+#[derive(Debug, Clone, Default)]
+pub struct StochasticData {
+    pub k: Vec<f64>,
+    pub d: Vec<f64>,
+}
+
+/// Parabolic SAR with the direction of the trend it trails, per candle
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PsarData {
+    pub sar: Vec<f64>,
+    /// True while the trend is up and the SAR sits below the candles
+    pub rising: Vec<bool>,
+}
+
+/// Volume traded per price bin over a range of candles
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VolumeProfile {
+    /// Lower edge of the first bin
+    pub min_price: f64,
+    pub bin_size: f64,
+    /// Volume per bin from the lowest price up
+    pub volumes: Vec<f64>,
+}
+
+impl VolumeProfile {
+    /// Bin with the most volume, the Point of Control; ties keep the lowest bin
+    pub fn point_of_control(&self) -> Option<usize> {
+        self.volumes
+            .iter()
+            .enumerate()
+            .filter(|(_, volume)| **volume > 0.0)
+            .fold(None, |best: Option<(usize, f64)>, (index, &volume)| match best {
+                Some((_, max)) if max >= volume => best,
+                _ => Some((index, volume)),
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Price range `(low, high)` of bin `index`
+    pub fn bin_range(&self, index: usize) -> (f64, f64) {
+        let low = self.min_price + index as f64 * self.bin_size;
+        (low, low + self.bin_size)
+    }
+}
+
+/// Length of a trading session; sessions start at UTC midnight
+pub const SESSION_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Index of the UTC session (day) containing `timestamp` (ms)
+pub fn utc_session(timestamp: u64) -> u64 {
+    timestamp / SESSION_MS
+}
+
+/// Reference level of the current session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SessionLevelKind {
+    Open,
+    High,
+    Low,
+}
+
+impl SessionLevelKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            SessionLevelKind::Open => "Open",
+            SessionLevelKind::High => "High",
+            SessionLevelKind::Low => "Low",
+        }
+    }
+}
+
+/// Open of a UTC session with its high and low so far
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionLevels {
+    /// Session start (ms)
+    pub start: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+}
+
+impl SessionLevels {
+    /// Each level with its price, open first
+    pub fn levels(&self) -> [(SessionLevelKind, f64); 3] {
+        [
+            (SessionLevelKind::Open, self.open),
+            (SessionLevelKind::High, self.high),
+            (SessionLevelKind::Low, self.low),
+        ]
+    }
+}
+
 /// Domain service for market analysis
 pub struct MarketAnalysisService;
 
@@ -47,7 +174,7 @@ impl MarketAnalysisService {
                                     candle.ohlcv.high.value() < 1_000_000.0; // Maximum $1M
 
         // 4. Validate timestamp (not more than 1 minute in the future)
-        let now = js_sys::Date::now() as u64;
+        let now = crate::domain::logging::get_time_provider().current_timestamp();
         let timestamp_valid = candle.timestamp.value() <= now + 60_000; // +1 minute buffer
 
         ohlc_valid && positive_values && reasonable_price_range && timestamp_valid
@@ -63,7 +190,7 @@ impl MarketAnalysisService {
 
         for i in (period - 1)..candles.len() {
             let sum: f64 =
-                candles[i - period + 1..=i].iter().map(|candle| candle.ohlcv.close.value()).sum();
+                candles[i + 1 - period..=i].iter().map(|candle| candle.ohlcv.close.value()).sum();
 
             sma_values.push(Price::from(sum / period as f64));
         }
@@ -71,6 +198,88 @@ impl MarketAnalysisService {
         sma_values
     }
 
+    /// Volume profile of `candles` over `bins` equal bins between `min_price` and `max_price`.
+    ///
+    /// Each candle's volume is spread over its high-low range in proportion to
+    /// the overlap with every bin; candles without a range count at their
+    /// typical price. Volume outside the price range is dropped.
+    pub fn calculate_volume_profile(
+        &self,
+        candles: &[Candle],
+        min_price: f64,
+        max_price: f64,
+        bins: usize,
+    ) -> VolumeProfile {
+        if bins == 0 || max_price <= min_price {
+            return VolumeProfile::default();
+        }
+        let bin_size = (max_price - min_price) / bins as f64;
+        let mut volumes = vec![0.0; bins];
+        let bin_of = |price: f64| ((price - min_price) / bin_size).floor() as usize;
+        for candle in candles {
+            let ohlcv = &candle.ohlcv;
+            let (low, high) = (ohlcv.low.value(), ohlcv.high.value());
+            let volume = ohlcv.volume.value();
+            if high - low <= f64::EPSILON {
+                let typical = (high + low + ohlcv.close.value()) / 3.0;
+                if (min_price..=max_price).contains(&typical) {
+                    volumes[bin_of(typical).min(bins - 1)] += volume;
+                }
+                continue;
+            }
+            let (from, to) = (low.max(min_price), high.min(max_price));
+            if from >= to {
+                continue;
+            }
+            for (index, bin_volume) in
+                volumes.iter_mut().enumerate().take(bin_of(to).min(bins - 1) + 1).skip(bin_of(from))
+            {
+                let bin_low = min_price + index as f64 * bin_size;
+                let overlap = to.min(bin_low + bin_size) - from.max(bin_low);
+                if overlap > 0.0 {
+                    *bin_volume += volume * overlap / (high - low);
+                }
+            }
+        }
+        VolumeProfile { min_price, bin_size, volumes }
+    }
+
+    /// Simple moving average of candle volumes, one value per complete window
+    pub fn calculate_volume_sma(&self, candles: &[Candle], period: usize) -> Vec<Volume> {
+        let volumes: Vec<f64> = candles.iter().map(|c| c.ohlcv.volume.value()).collect();
+        moving_average(MaKind::Sma, period, &volumes)
+            .into_iter()
+            .map(|avg| Volume::from(avg.value()))
+            .collect()
+    }
+
+    /// Calculate the Volume-Weighted Moving Average (VWMA).
+    ///
+    /// Each close is weighted by its candle volume; windows without any volume
+    /// fall back to the plain average of the closes.
+    pub fn calculate_vwma(&self, candles: &[Candle], period: usize) -> Vec<Price> {
+        if period == 0 || candles.len() < period {
+            return Vec::new();
+        }
+
+        candles
+            .windows(period)
+            .map(|window| {
+                let (price_volume, volume, closes) =
+                    window.iter().fold((0.0, 0.0, 0.0), |(pv, v, c), candle| {
+                        let close = candle.ohlcv.close.value();
+                        let volume = candle.ohlcv.volume.value();
+                        (pv + close * volume, v + volume, c + close)
+                    });
+                Price::from(if volume > 0.0 {
+                    price_volume / volume
+                } else {
+                    closes / period as f64
+                })
+            })
+            .collect()
+    }
+
     /// Calculate the Exponential Moving Average (EMA)
     pub fn calculate_ema(&self, candles: &[Candle], period: usize) -> Vec<Price> {
         if candles.len() < period {
@@ -99,15 +308,347 @@ impl MarketAnalysisService {
         ema_values
     }
 
-    /// Calculate multiple moving averages at once
-    pub fn calculate_multiple_mas(&self, candles: &[Candle]) -> MovingAveragesData {
-        MovingAveragesData {
-            sma_20: self.calculate_sma(candles, 20),
-            sma_50: self.calculate_sma(candles, 50),
-            sma_200: self.calculate_sma(candles, 200),
-            ema_12: self.calculate_ema(candles, 12),
-            ema_26: self.calculate_ema(candles, 26),
+    /// Calculate the configured moving averages at once
+    pub fn calculate_multiple_mas(
+        &self,
+        candles: &[Candle],
+        configs: &[MaConfig],
+    ) -> MovingAveragesData {
+        let lines = configs
+            .iter()
+            .filter(|config| config.period > 0)
+            .map(|config| {
+                let values = match config.kind {
+                    MaKind::Sma => self.calculate_sma(candles, config.period),
+                    MaKind::Ema => self.calculate_ema(candles, config.period),
+                };
+                (*config, values)
+            })
+            .collect();
+        MovingAveragesData::new(lines)
+    }
+
+    /// Calculate Bollinger Bands over close prices.
+    ///
+    /// Values are aligned like [`Self::calculate_sma`]: the first value belongs
+    /// to the candle at index `period - 1`.
+    pub fn calculate_bollinger_bands(
+        &self,
+        candles: &[Candle],
+        period: usize,
+        std_dev_mult: f64,
+    ) -> BollingerBands {
+        if period == 0 || candles.len() < period {
+            return BollingerBands::default();
+        }
+
+        let closes: Vec<f64> = candles.iter().map(|c| c.ohlcv.close.value()).collect();
+        let mut bands = BollingerBands::default();
+        let mut sum: f64 = closes[..period].iter().sum();
+        let mut sum_sq: f64 = closes[..period].iter().map(|c| c * c).sum();
+
+        for i in (period - 1)..closes.len() {
+            if i >= period {
+                let (added, removed) = (closes[i], closes[i - period]);
+                sum += added - removed;
+                sum_sq += added * added - removed * removed;
+            }
+            let mean = sum / period as f64;
+            // Population variance; clamp rounding noise below zero
+            let std_dev = (sum_sq / period as f64 - mean * mean).max(0.0).sqrt();
+            bands.middle.push(Price::from(mean));
+            bands.upper.push(Price::from(mean + std_dev_mult * std_dev));
+            bands.lower.push(Price::from(mean - std_dev_mult * std_dev));
         }
+
+        bands
+    }
+
+    /// Calculate the volume-weighted average of the typical price `(H + L + C) / 3`.
+    ///
+    /// The running sums restart at every UTC day boundary, so each value covers
+    /// the session of its candle up to and including it. Values are aligned 1:1
+    /// with `candles`; a session without volume so far yields the typical price.
+    pub fn calculate_vwap(&self, candles: &[Candle]) -> Vec<Price> {
+        let mut session = None;
+        let mut price_volume = 0.0;
+        let mut volume = 0.0;
+
+        candles
+            .iter()
+            .map(|candle| {
+                let day = utc_session(candle.timestamp.value());
+                if session != Some(day) {
+                    session = Some(day);
+                    price_volume = 0.0;
+                    volume = 0.0;
+                }
+                let ohlcv = &candle.ohlcv;
+                let typical = (ohlcv.high.value() + ohlcv.low.value() + ohlcv.close.value()) / 3.0;
+                price_volume += typical * ohlcv.volume.value();
+                volume += ohlcv.volume.value();
+                Price::from(if volume > 0.0 { price_volume / volume } else { typical })
+            })
+            .collect()
+    }
+
+    /// Open, high and low of the UTC session of the latest candle.
+    ///
+    /// The open comes from the first candle of that session; `candles` must be
+    /// sorted by time. `None` without candles.
+    pub fn calculate_session_levels(&self, candles: &[Candle]) -> Option<SessionLevels> {
+        let session = utc_session(candles.last()?.timestamp.value());
+        let first = candles.partition_point(|c| utc_session(c.timestamp.value()) < session);
+        let current = &candles[first..];
+        let (high, low) = current.iter().fold((f64::MIN, f64::MAX), |(high, low), c| {
+            (high.max(c.ohlcv.high.value()), low.min(c.ohlcv.low.value()))
+        });
+        Some(SessionLevels {
+            start: session * SESSION_MS,
+            open: current[0].ohlcv.open.value(),
+            high,
+            low,
+        })
+    }
+
+    /// Calculate the Relative Strength Index with Wilder smoothing.
+    ///
+    /// Values lie in `0..=100`; the first one belongs to the candle at index
+    /// `period`, since each step needs the previous close.
+    pub fn calculate_rsi(&self, candles: &[Candle], period: usize) -> Vec<f64> {
+        if period == 0 || candles.len() <= period {
+            return Vec::new();
+        }
+
+        let rsi = |gain: f64, loss: f64| {
+            if loss <= 0.0 {
+                if gain <= 0.0 { 50.0 } else { 100.0 }
+            } else {
+                100.0 - 100.0 / (1.0 + gain / loss)
+            }
+        };
+
+        let changes: Vec<f64> = candles
+            .windows(2)
+            .map(|w| w[1].ohlcv.close.value() - w[0].ohlcv.close.value())
+            .collect();
+        let mut avg_gain =
+            changes[..period].iter().map(|c| c.max(0.0)).sum::<f64>() / period as f64;
+        let mut avg_loss =
+            changes[..period].iter().map(|c| (-c).max(0.0)).sum::<f64>() / period as f64;
+
+        let mut values = Vec::with_capacity(changes.len() - period + 1);
+        values.push(rsi(avg_gain, avg_loss));
+        for change in &changes[period..] {
+            avg_gain = (avg_gain * (period - 1) as f64 + change.max(0.0)) / period as f64;
+            avg_loss = (avg_loss * (period - 1) as f64 + (-change).max(0.0)) / period as f64;
+            values.push(rsi(avg_gain, avg_loss));
+        }
+        values
+    }
+
+    /// Calculate MACD (fast EMA - slow EMA), its signal EMA and the histogram.
+    ///
+    /// All three series have the same length; the first value belongs to the
+    /// candle at index `slow + signal - 2`, where both EMAs and the signal exist.
+    pub fn calculate_macd(
+        &self,
+        candles: &[Candle],
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    ) -> MacdData {
+        if fast == 0 || signal == 0 || fast > slow {
+            return MacdData::default();
+        }
+        let fast_ema = self.calculate_ema(candles, fast);
+        let slow_ema = self.calculate_ema(candles, slow);
+        if slow_ema.is_empty() {
+            return MacdData::default();
+        }
+
+        // Fast EMA starts `slow - fast` candles earlier than the slow one
+        let macd: Vec<f64> = slow_ema
+            .iter()
+            .zip(&fast_ema[slow - fast..])
+            .map(|(s, f)| f.value() - s.value())
+            .collect();
+        if macd.len() < signal {
+            return MacdData::default();
+        }
+
+        let alpha = 2.0 / (signal as f64 + 1.0);
+        let mut signal_line = Vec::with_capacity(macd.len() - signal + 1);
+        signal_line.push(macd[..signal].iter().sum::<f64>() / signal as f64);
+        for value in &macd[signal..] {
+            let prev = signal_line[signal_line.len() - 1];
+            signal_line.push(alpha * value + (1.0 - alpha) * prev);
+        }
+
+        let macd = macd[signal - 1..].to_vec();
+        let histogram = macd.iter().zip(&signal_line).map(|(m, s)| m - s).collect();
+        MacdData { macd, signal: signal_line, histogram }
+    }
+
+    /// Calculate the Average True Range with Wilder smoothing.
+    ///
+    /// The true range is the largest of high - low, |high - previous close| and
+    /// |low - previous close|, so gaps between candles count as volatility.
+    /// Values are aligned with `candles`; the first one is the first candle's
+    /// high - low range.
+    pub fn calculate_atr(&self, candles: &[Candle], period: usize) -> Vec<f64> {
+        if period == 0 || candles.is_empty() {
+            return Vec::new();
+        }
+
+        let first = &candles[0].ohlcv;
+        let mut atr = first.high.value() - first.low.value();
+        let mut values = Vec::with_capacity(candles.len());
+        values.push(atr);
+        for pair in candles.windows(2) {
+            let prev_close = pair[0].ohlcv.close.value();
+            let high = pair[1].ohlcv.high.value();
+            let low = pair[1].ohlcv.low.value();
+            let true_range =
+                (high - low).max((high - prev_close).abs()).max((low - prev_close).abs());
+            atr = (atr * (period - 1) as f64 + true_range) / period as f64;
+            values.push(atr);
+        }
+        values
+    }
+
+    /// Calculate the Stochastic oscillator.
+    ///
+    /// %K places the close within the high-low range of the last `k_period`
+    /// candles, in `0..=100`; a flat range yields 50. %D is the simple average
+    /// of the last `d_period` %K values. The first %K belongs to the candle at
+    /// index `k_period - 1`, the first %D to `k_period + d_period - 2`.
+    pub fn calculate_stochastic(
+        &self,
+        candles: &[Candle],
+        k_period: usize,
+        d_period: usize,
+    ) -> StochasticData {
+        if k_period == 0 || d_period == 0 || candles.len() < k_period {
+            return StochasticData::default();
+        }
+
+        let k: Vec<f64> = candles
+            .windows(k_period)
+            .map(|window| {
+                let (low, high) =
+                    window.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |acc, c| {
+                        (acc.0.min(c.ohlcv.low.value()), acc.1.max(c.ohlcv.high.value()))
+                    });
+                let close = window[k_period - 1].ohlcv.close.value();
+                if high > low { (close - low) / (high - low) * 100.0 } else { 50.0 }
+            })
+            .collect();
+        let d = k.windows(d_period).map(|w| w.iter().sum::<f64>() / d_period as f64).collect();
+        StochasticData { k, d }
+    }
+
+    /// Calculate Wilder's Parabolic SAR.
+    ///
+    /// The SAR moves towards the extreme point of the trend by an acceleration
+    /// factor that starts at `step` and grows by `step`, up to `max_step`, each
+    /// time a new extreme is made. It never enters the previous two candles'
+    /// range; when price crosses it the trend flips and the SAR restarts at the
+    /// old extreme. The first trend follows the first two closes. Values are
+    /// aligned with `candles`.
+    pub fn calculate_psar(&self, candles: &[Candle], step: f64, max_step: f64) -> PsarData {
+        if candles.len() < 2 || step <= 0.0 || max_step < step {
+            return PsarData::default();
+        }
+
+        let high = |i: usize| candles[i].ohlcv.high.value();
+        let low = |i: usize| candles[i].ohlcv.low.value();
+        let mut rising = candles[1].ohlcv.close >= candles[0].ohlcv.close;
+        let mut sar = if rising { low(0) } else { high(0) };
+        let mut extreme = if rising { high(0) } else { low(0) };
+        let mut factor = step;
+
+        let mut data = PsarData {
+            sar: Vec::with_capacity(candles.len()),
+            rising: Vec::with_capacity(candles.len()),
+        };
+        data.sar.push(sar);
+        data.rising.push(rising);
+        for i in 1..candles.len() {
+            sar += factor * (extreme - sar);
+            let prev = i.saturating_sub(2)..i;
+            if rising {
+                sar = prev.fold(sar, |s, j| s.min(low(j)));
+                if low(i) < sar {
+                    (rising, sar, extreme, factor) = (false, extreme, low(i), step);
+                } else if high(i) > extreme {
+                    extreme = high(i);
+                    factor = (factor + step).min(max_step);
+                }
+            } else {
+                sar = prev.fold(sar, |s, j| s.max(high(j)));
+                if high(i) > sar {
+                    (rising, sar, extreme, factor) = (true, extreme, high(i), step);
+                } else if low(i) < extreme {
+                    extreme = low(i);
+                    factor = (factor + step).min(max_step);
+                }
+            }
+            data.sar.push(sar);
+            data.rising.push(rising);
+        }
+        data
+    }
+
+    /// Calculate Donchian Channels over the highs and lows of `period` candles.
+    ///
+    /// Values are aligned like [`Self::calculate_sma`]: the first value belongs
+    /// to the candle at index `period - 1`.
+    pub fn calculate_donchian(&self, candles: &[Candle], period: usize) -> DonchianChannels {
+        if period == 0 || candles.len() < period {
+            return DonchianChannels::default();
+        }
+
+        let mut channels = DonchianChannels::default();
+        for window in candles.windows(period) {
+            let high =
+                window.iter().map(|c| c.ohlcv.high.value()).fold(f64::NEG_INFINITY, f64::max);
+            let low = window.iter().map(|c| c.ohlcv.low.value()).fold(f64::INFINITY, f64::min);
+            channels.upper.push(high);
+            channels.middle.push((high + low) / 2.0);
+            channels.lower.push(low);
+        }
+
+        channels
+    }
+
+    /// Calculate Keltner Channels: an EMA of the closes over `ema_period` with bands
+    /// `mult` ATRs over `atr_period` above and below it.
+    ///
+    /// Values are aligned like [`Self::calculate_ema`]: the first value belongs
+    /// to the candle at index `ema_period - 1`.
+    pub fn calculate_keltner(
+        &self,
+        candles: &[Candle],
+        ema_period: usize,
+        atr_period: usize,
+        mult: f64,
+    ) -> KeltnerChannels {
+        if ema_period == 0 || atr_period == 0 {
+            return KeltnerChannels::default();
+        }
+
+        let ema = self.calculate_ema(candles, ema_period);
+        let atr = self.calculate_atr(candles, atr_period);
+        let mut channels = KeltnerChannels::default();
+        for (middle, atr) in ema.iter().zip(atr.iter().skip(ema_period - 1)) {
+            let middle = middle.value();
+            channels.upper.push(middle + mult * atr);
+            channels.middle.push(middle);
+            channels.lower.push(middle - mult * atr);
+        }
+
+        channels
     }
 
     /// Find local highs and lows
@@ -226,13 +767,46 @@ impl MarketAnalysisService {
         self.calculate_tenkan_sen(candles, period)
     }
 
-    /// Calculate the Chikou Span (closing prices shifted back)
+    /// Calculate the Chikou Span (closing prices shifted back).
+    ///
+    /// Value `i` belongs to candle `i` and repeats the close `shift` candles later.
     pub fn calculate_chikou_span(&self, candles: &[Candle], shift: usize) -> Vec<Price> {
         if candles.len() <= shift {
             return Vec::new();
         }
 
-        candles[..candles.len() - shift].iter().map(|c| c.ohlcv.close).collect()
+        candles[shift..].iter().map(|c| c.ohlcv.close).collect()
+    }
+
+    /// Convert candles to Heikin-Ashi bars.
+    ///
+    /// HA close is the OHLC average and HA open is the midpoint of the previous
+    /// HA bar; the first bar keeps its raw OHLC.
+    pub fn calculate_heikin_ashi(&self, candles: &[Candle]) -> Vec<Candle> {
+        let mut result: Vec<Candle> = Vec::with_capacity(candles.len());
+        for candle in candles {
+            let Some(prev) = result.last() else {
+                result.push(candle.clone());
+                continue;
+            };
+            let o = candle.ohlcv;
+            let ha_close =
+                (o.open.value() + o.high.value() + o.low.value() + o.close.value()) / 4.0;
+            let ha_open = (prev.ohlcv.open.value() + prev.ohlcv.close.value()) / 2.0;
+            let ha_high = o.high.value().max(ha_open).max(ha_close);
+            let ha_low = o.low.value().min(ha_open).min(ha_close);
+            result.push(Candle::new(
+                candle.timestamp,
+                OHLCV::new(
+                    Price::from(ha_open),
+                    Price::from(ha_high),
+                    Price::from(ha_low),
+                    Price::from(ha_close),
+                    o.volume,
+                ),
+            ));
+        }
+        result
     }
 
     /// Calculate all Ichimoku components with default periods
@@ -263,12 +837,11 @@ impl Aggregator {
         let low = candles.iter().map(|c| c.ohlcv.low.value()).fold(open.value(), f64::min);
         let volume_sum: f64 = candles.iter().map(|c| c.ohlcv.volume.value()).sum();
 
-        let start =
-            candles.first()?.timestamp.value() / interval.duration_ms() * interval.duration_ms();
-        Some(Candle::new(
-            Timestamp::from(start),
-            OHLCV::new(open, Price::from(high), Price::from(low), close, Volume::from(volume_sum)),
-        ))
+        let start = interval.bucket_start(candles.first()?.timestamp.value());
+        let mut ohlcv =
+            OHLCV::new(open, Price::from(high), Price::from(low), close, Volume::from(volume_sum));
+        ohlcv.taker_buy_volume = sum_taker_buy(candles.iter().map(|c| c.ohlcv.taker_buy_volume));
+        Some(Candle::new(Timestamp::from(start), ohlcv))
     }
 }
 
@@ -86,16 +86,34 @@ impl Timestamp {
 }
 
 /// Value Object - OHLCV data with generated helpers
-#[derive(Debug, Clone, Copy, PartialEq, Constructor, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct OHLCV {
     pub open: Price,
     pub high: Price,
     pub low: Price,
     pub close: Price,
     pub volume: Volume,
+    /// Part of `volume` bought by takers, when the exchange reports it
+    #[serde(default)]
+    pub taker_buy_volume: Option<Volume>,
 }
 
 impl OHLCV {
+    pub fn new(open: Price, high: Price, low: Price, close: Price, volume: Volume) -> Self {
+        Self { open, high, low, close, volume, taker_buy_volume: None }
+    }
+
+    /// The same candle with `volume` of it bought by takers
+    pub fn with_taker_buy_volume(self, volume: Volume) -> Self {
+        Self { taker_buy_volume: Some(volume), ..self }
+    }
+
+    /// Taker buy minus taker sell volume; `None` without taker data
+    pub fn delta_volume(&self) -> Option<f64> {
+        let buy = self.taker_buy_volume?.value();
+        Some(buy - (self.volume.value() - buy))
+    }
+
     /// Check the validity of OHLCV data
     pub fn is_valid(&self) -> bool {
         self.high >= self.open
@@ -107,6 +125,11 @@ impl OHLCV {
     }
 }
 
+/// Total taker buy volume of merged candles; `None` when none of them report it
+pub fn sum_taker_buy(volumes: impl IntoIterator<Item = Option<Volume>>) -> Option<Volume> {
+    volumes.into_iter().flatten().map(|v| v.value()).reduce(|a, b| a + b).map(Volume::from)
+}
+
 /// Value Object - Trading symbol with generated helpers
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deref, DerefMut, Display, Serialize, Deserialize)]
 #[display(fmt = "Symbol({})", _0)]
@@ -131,6 +154,25 @@ impl From<&str> for Symbol {
     }
 }
 
+/// Decimals shown for prices of a symbol whose tick size is unknown
+pub const DEFAULT_PRICE_DECIMALS: u32 = 2;
+/// Most decimals a price is shown with
+pub const MAX_PRICE_DECIMALS: u32 = 8;
+
+/// Decimals needed to show prices moving in steps of `tick_size`, e.g. 2 for 0.01
+pub fn decimals_for_tick_size(tick_size: f64) -> u32 {
+    if !tick_size.is_finite() || tick_size <= 0.0 {
+        return DEFAULT_PRICE_DECIMALS;
+    }
+    // The small margin keeps 0.001 from rounding up to 4 decimals
+    (-tick_size.log10() - 1e-9).ceil().clamp(0.0, MAX_PRICE_DECIMALS as f64) as u32
+}
+
+/// `price` with `decimals` digits after the point
+pub fn format_price(price: f64, decimals: u32) -> String {
+    format!("{price:.*}", decimals.min(MAX_PRICE_DECIMALS) as usize)
+}
+
 /// List of supported trading symbols
 pub fn default_symbols() -> Vec<Symbol> {
     vec![Symbol::from("BTCUSDT"), Symbol::from("ETHUSDT"), Symbol::from("SOLUSDT")]
@@ -207,4 +249,34 @@ impl TimeInterval {
             Self::OneMonth => 30 * 24 * 60 * 60 * 1000,
         }
     }
+
+    /// Start of the bucket containing `timestamp`, in milliseconds.
+    ///
+    /// Buckets are aligned to the Unix epoch, except weeks which start on
+    /// Monday 00:00 UTC like Binance weekly klines.
+    pub fn bucket_start(&self, timestamp: u64) -> u64 {
+        let duration = self.duration_ms();
+        match self {
+            Self::OneWeek => {
+                // 1970-01-01 was a Thursday; the first Monday is 4 days later
+                const MONDAY_OFFSET_MS: u64 = 4 * 24 * 60 * 60 * 1000;
+                let shift = duration - MONDAY_OFFSET_MS;
+                ((timestamp + shift) / duration * duration).saturating_sub(shift)
+            }
+            _ => timestamp / duration * duration,
+        }
+    }
+
+    /// Time left until the live candle closes, in milliseconds.
+    ///
+    /// `candle_open` is the open time of the latest candle; once its bucket has
+    /// ended, the countdown follows the bucket containing `now` until the next
+    /// candle arrives.
+    pub fn remaining_ms(&self, candle_open: u64, now: u64) -> u64 {
+        let close = self.bucket_start(candle_open) + self.duration_ms();
+        if close > now {
+            return close - now;
+        }
+        self.bucket_start(now) + self.duration_ms() - now
+    }
 }
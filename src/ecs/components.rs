@@ -1,6 +1,10 @@
 use crate::domain::chart::{Chart, value_objects::Viewport};
-use crate::domain::market_data::Candle;
+use crate::domain::market_data::{
+    Candle, CandleSeries, MaConfig, MaKind, MovingAverageEngine, Price, Symbol, TimeInterval,
+};
 use leptos::RwSignal;
+use std::collections::VecDeque;
+use std::sync::Arc;
 
 /// ECS component containing a reactive trading chart.
 #[derive(Debug, Clone, Copy)]
@@ -10,6 +14,114 @@ pub struct ChartComponent(pub RwSignal<Chart>);
 #[derive(Debug, Clone)]
 pub struct CandleComponent(pub Candle);
 
+/// Symbol a candle entity belongs to; candles without it apply to every chart.
+#[derive(Debug, Clone)]
+pub struct SymbolComponent(pub Symbol);
+
+/// ECS component naming the canvas a chart is drawn on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanvasComponent(pub String);
+
 /// ECS component for viewport state.
 #[derive(Debug, Clone)]
 pub struct ViewportComponent(pub Viewport);
+
+/// ECS component caching moving averages of a chart's series.
+///
+/// The values follow the series incrementally: updates of the latest candle
+/// replace the last value, new candles append one and candles evicted from
+/// the front of a full series drop theirs. When older candles change, the
+/// cache is rebuilt. Clones share the engine until one of them is synced.
+#[derive(Debug, Clone)]
+pub struct IndicatorComponent {
+    chart_id: String,
+    interval: TimeInterval,
+    engine: Arc<MovingAverageEngine>,
+    /// Candles fed to the engine
+    len: usize,
+    first_timestamp: Option<u64>,
+    last_timestamp: Option<u64>,
+}
+
+impl IndicatorComponent {
+    /// Empty cache for `configs` over the `interval` series of `chart_id`
+    pub fn new(chart_id: &str, interval: TimeInterval, configs: &[MaConfig]) -> Self {
+        Self {
+            chart_id: chart_id.to_string(),
+            interval,
+            engine: Arc::new(MovingAverageEngine::with_configs(configs)),
+            len: 0,
+            first_timestamp: None,
+            last_timestamp: None,
+        }
+    }
+
+    /// Candles evicted from the front of `candles` since the last sync, if the
+    /// synced candles are otherwise still in place
+    fn evicted(
+        &self,
+        chart_id: &str,
+        interval: TimeInterval,
+        candles: &VecDeque<Candle>,
+    ) -> Option<usize> {
+        if self.chart_id != chart_id || self.interval != interval || self.len == 0 {
+            return None;
+        }
+        let last = self.last_timestamp?;
+        // A full series shifts, moving the last synced candle towards the front
+        let kept = candles.binary_search_by_key(&last, |c| c.timestamp.value()).ok()? + 1;
+        let evicted = self.len.checked_sub(kept)?;
+        let first = candles.front().map(|c| c.timestamp.value());
+        (evicted > 0 || first == self.first_timestamp).then_some(evicted)
+    }
+
+    /// Bring the cache up to date with `series`
+    pub fn sync(&mut self, chart_id: &str, interval: TimeInterval, series: &CandleSeries) {
+        let candles = series.get_candles();
+        let fed = match self.evicted(chart_id, interval, candles) {
+            Some(evicted) => {
+                let kept = self.len - evicted;
+                let engine = Arc::make_mut(&mut self.engine);
+                engine.drop_oldest(evicted);
+                engine.replace_last_close(candles[kept - 1].ohlcv.close.value());
+                kept
+            }
+            None => {
+                *self = Self::new(chart_id, interval, &self.engine.configs());
+                0
+            }
+        };
+        let engine = Arc::make_mut(&mut self.engine);
+        for candle in candles.iter().skip(fed) {
+            engine.update_on_close(candle.ohlcv.close.value());
+        }
+        self.len = candles.len();
+        self.first_timestamp = candles.front().map(|c| c.timestamp.value());
+        self.last_timestamp = candles.back().map(|c| c.timestamp.value());
+    }
+
+    /// Whether the cache reflects `series` of `chart_id` as last synced
+    pub fn is_current(
+        &self,
+        chart_id: &str,
+        interval: TimeInterval,
+        series: &CandleSeries,
+    ) -> bool {
+        let candles = series.get_candles();
+        self.chart_id == chart_id
+            && self.interval == interval
+            && self.len == candles.len()
+            && self.first_timestamp == candles.front().map(|c| c.timestamp.value())
+            && self.last_timestamp == candles.back().map(|c| c.timestamp.value())
+    }
+
+    /// Moving averages kept in the cache
+    pub fn configs(&self) -> Vec<MaConfig> {
+        self.engine.configs()
+    }
+
+    /// Cached values of the given moving average, if tracked
+    pub fn values(&self, kind: MaKind, period: usize) -> Option<&[Price]> {
+        self.engine.values(kind, period)
+    }
+}
@@ -26,6 +26,49 @@ impl EcsWorld {
         self.world.spawn((ChartComponent(signal), ViewportComponent(viewport)))
     }
 
+    /// Draw the chart with `chart_id` on `canvas_id`, unbinding any chart drawn there before.
+    ///
+    /// Returns false if no chart has that id.
+    pub fn bind_canvas(&mut self, chart_id: &str, canvas_id: &str) -> bool {
+        use crate::ecs::components::{CanvasComponent, ChartComponent};
+        use leptos::SignalWithUntracked;
+
+        let Some(entity) = self
+            .world
+            .query::<&ChartComponent>()
+            .iter()
+            .find(|(_, chart)| chart.0.with_untracked(|c| c.id == chart_id))
+            .map(|(entity, _)| entity)
+        else {
+            return false;
+        };
+        let bound: Vec<hecs::Entity> = self
+            .world
+            .query::<&CanvasComponent>()
+            .iter()
+            .filter(|(_, canvas)| canvas.0 == canvas_id)
+            .map(|(entity, _)| entity)
+            .collect();
+        for other in bound {
+            let _ = self.world.remove_one::<CanvasComponent>(other);
+        }
+        self.world.insert_one(entity, CanvasComponent(canvas_id.to_string())).is_ok()
+    }
+
+    /// Chart drawn on `canvas_id`, if one is bound.
+    pub fn chart_for_canvas(
+        &self,
+        canvas_id: &str,
+    ) -> Option<leptos::RwSignal<crate::domain::chart::Chart>> {
+        use crate::ecs::components::{CanvasComponent, ChartComponent};
+
+        self.world
+            .query::<(&ChartComponent, &CanvasComponent)>()
+            .iter()
+            .find(|(_, (_, canvas))| canvas.0 == canvas_id)
+            .map(|(_, (chart, _))| chart.0)
+    }
+
     /// Apply all pending candle components to charts.
     pub fn run_candle_system(&mut self) {
         crate::ecs::systems::apply_candles(&mut self.world);
@@ -36,6 +79,27 @@ impl EcsWorld {
         crate::ecs::systems::apply_candles_parallel(&mut self.world);
     }
 
+    /// Update cached moving averages of every chart for `interval`.
+    pub fn run_indicator_system(
+        &mut self,
+        interval: crate::domain::market_data::TimeInterval,
+        configs: &[crate::domain::market_data::MaConfig],
+    ) {
+        crate::ecs::systems::update_indicators(&mut self.world, interval, configs);
+    }
+
+    /// Cached moving averages of the chart with `chart_id`, if computed.
+    pub fn indicators(&self, chart_id: &str) -> Option<crate::ecs::components::IndicatorComponent> {
+        use crate::ecs::components::{ChartComponent, IndicatorComponent};
+        use leptos::SignalWithUntracked;
+
+        self.world
+            .query::<(&ChartComponent, &IndicatorComponent)>()
+            .iter()
+            .find(|(_, (chart, _))| chart.0.with_untracked(|c| c.id == chart_id))
+            .map(|(_, (_, indicators))| indicators.clone())
+    }
+
     /// Sync viewport components with the chart state.
     pub fn run_viewport_system(&mut self) {
         crate::ecs::systems::sync_viewports(&mut self.world);
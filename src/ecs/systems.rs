@@ -1,26 +1,46 @@
 use hecs::World;
 
 use super::components::ViewportComponent;
-use super::components::{CandleComponent, ChartComponent};
+use super::components::{CandleComponent, ChartComponent, IndicatorComponent, SymbolComponent};
+use crate::domain::market_data::{MaConfig, TimeInterval};
 use leptos::{SignalUpdate, SignalWithUntracked};
 
-/// Apply new candles to all charts and remove processed candle entities.
+/// Candle entities with the chart id they target, `None` for every chart.
+type PendingCandle = (hecs::Entity, CandleComponent, Option<String>);
+
+fn pending_candles(world: &World) -> Vec<PendingCandle> {
+    world
+        .query::<(&CandleComponent, Option<&SymbolComponent>)>()
+        .iter()
+        .map(|(e, (c, s))| (e, c.clone(), s.map(|s| s.0.value().to_string())))
+        .collect()
+}
+
+fn targets(chart_id: &str, target: &Option<String>) -> bool {
+    target.as_deref().is_none_or(|id| id == chart_id)
+}
+
+/// Apply new candles to their symbol's chart (or to all charts when the
+/// candle has no symbol) and remove processed candle entities.
 pub fn apply_candles(world: &mut World) {
     let mut candle_entities = Vec::new();
-    let candles: Vec<(hecs::Entity, CandleComponent)> =
-        world.query::<&CandleComponent>().iter().map(|(e, c)| (e, c.clone())).collect();
+    let candles = pending_candles(world);
 
     if candles.is_empty() {
         return;
     }
 
-    for (_, candle) in &candles {
+    for (_, candle, target) in &candles {
         for (_, chart) in world.query::<&mut ChartComponent>().iter() {
-            chart.0.update(|c| c.add_realtime_candle(candle.0.clone()));
+            if chart.0.with_untracked(|c| targets(&c.id, target)) {
+                chart.0.update(|c| {
+                    c.add_realtime_candle(candle.0.clone());
+                });
+            }
         }
     }
 
-    candle_entities.extend(candles.into_iter().map(|(e, _)| e));
+    candle_entities.extend(candles.into_iter().map(|(e, _, _)| e));
     for e in candle_entities {
         let _ = world.despawn(e);
     }
@@ -31,8 +51,7 @@ pub fn apply_candles(world: &mut World) {
 pub fn apply_candles_parallel(world: &mut World) {
     use rayon::prelude::*;
 
-    let candles: Vec<CandleComponent> =
-        world.query::<&CandleComponent>().iter().map(|(_, c)| c.clone()).collect();
+    let candles = pending_candles(world);
 
     if candles.is_empty() {
         return;
@@ -46,8 +65,10 @@ pub fn apply_candles_parallel(world: &mut World) {
         .collect();
 
     charts.par_iter_mut().for_each(|comp| {
-        for candle in &candles {
-            comp.0.add_realtime_candle(candle.0.clone());
+        for (_, candle, target) in &candles {
+            if targets(&comp.0.id, target) {
+                comp.0.add_realtime_candle(candle.0.clone());
+            }
         }
     });
 
@@ -77,3 +98,36 @@ pub fn sync_viewports(world: &mut World) {
         viewport.0 = vp;
     }
 }
+
+/// Keep each chart's `IndicatorComponent` in sync with its `interval` series,
+/// attaching one tracking `configs` to charts that lack it.
+pub fn update_indicators(world: &mut World, interval: TimeInterval, configs: &[MaConfig]) {
+    let missing: Vec<hecs::Entity> = world
+        .query::<&ChartComponent>()
+        .without::<&IndicatorComponent>()
+        .iter()
+        .map(|(e, _)| e)
+        .collect();
+    for entity in missing {
+        let _ = world.insert_one(entity, IndicatorComponent::new("", interval, configs));
+    }
+
+    for (_, (chart, indicators)) in
+        world.query::<(&ChartComponent, &mut IndicatorComponent)>().iter()
+    {
+        if indicators.configs() != configs {
+            *indicators = IndicatorComponent::new("", interval, configs);
+        }
+        chart.0.with_untracked(|c| {
+            // Same fallback as the renderer when the interval has no series
+            let (interval, series) = match c.get_series(interval) {
+                Some(series) => (interval, series),
+                None => match c.get_series(TimeInterval::TwoSeconds) {
+                    Some(series) => (TimeInterval::TwoSeconds, series),
+                    None => return,
+                },
+            };
+            indicators.sync(&c.id, interval, series);
+        });
+    }
+}
@@ -66,3 +66,67 @@ where
 use leptos::{HtmlElement, html::AnyElement};
 
 pub fn wheel_event_options(_el: HtmlElement<AnyElement>, _opts: &EventOptions) {}
+
+/// Handle of a [`ResizeObserver`](web_sys::ResizeObserver) that stops observing on removal
+pub struct ResizeObserverHandle {
+    observer: web_sys::ResizeObserver,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl ResizeObserverHandle {
+    pub fn remove(self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Call `cb` with the content box size of `element` whenever it changes
+pub fn observe_resize(
+    element: &web_sys::Element,
+    mut cb: impl FnMut(f64, f64) + 'static,
+) -> Option<ResizeObserverHandle> {
+    let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+        let last = entries.iter().last();
+        if let Some(entry) = last.and_then(|e| e.dyn_into::<web_sys::ResizeObserverEntry>().ok()) {
+            let rect = entry.content_rect();
+            cb(rect.width(), rect.height());
+        }
+    }) as Box<dyn FnMut(js_sys::Array)>);
+    let observer = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+    observer.observe(element);
+    Some(ResizeObserverHandle { observer, _callback: callback })
+}
+
+/// Keeps only the latest of rapidly repeated values.
+///
+/// Every [`schedule`](Self::schedule) returns a token; after the debounce delay
+/// [`take`](Self::take) yields the value only for the most recent token, so
+/// bursts of events collapse into one.
+#[derive(Debug)]
+pub struct Debouncer<T> {
+    generation: u64,
+    pending: Option<T>,
+}
+
+impl<T> Default for Debouncer<T> {
+    fn default() -> Self {
+        Self { generation: 0, pending: None }
+    }
+}
+
+impl<T> Debouncer<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the pending value and return the token that may take it
+    pub fn schedule(&mut self, value: T) -> u64 {
+        self.generation += 1;
+        self.pending = Some(value);
+        self.generation
+    }
+
+    /// The pending value if nothing was scheduled after `token`
+    pub fn take(&mut self, token: u64) -> Option<T> {
+        if token == self.generation { self.pending.take() } else { None }
+    }
+}
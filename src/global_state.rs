@@ -4,11 +4,16 @@
 //! UI flags. `OnceCell` is used to ensure the globals are created only on first
 //! access.
 
-use crate::app::TooltipData;
+use crate::app::{
+    ContextMenu, CrosshairLabels, MeasureOverlay, ReplaySession, TooltipData, ZoomBox,
+};
 use crate::domain::{
     DomainState,
-    chart::{Chart, value_objects::ChartType},
-    market_data::{Candle, Symbol, TimeInterval},
+    chart::{
+        Chart,
+        value_objects::{ChartType, Viewport},
+    },
+    market_data::{Candle, Symbol, TimeInterval, Timestamp},
 };
 use crate::ecs::{EcsWorld, components::ChartComponent};
 use crate::view_state::ViewState;
@@ -19,6 +24,13 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+/// Where the user left a symbol: its time/price window and zoom state
+#[derive(Clone, Debug)]
+pub struct SymbolView {
+    pub viewport: Viewport,
+    pub view_state: ViewState,
+}
+
 pub struct Globals {
     pub current_price: RwSignal<f64>,
     pub candle_count: RwSignal<usize>,
@@ -27,15 +39,90 @@ pub struct Globals {
     pub loading_more: RwSignal<bool>,
     pub tooltip_data: RwSignal<Option<TooltipData>>,
     pub tooltip_visible: RwSignal<bool>,
+    pub crosshair_labels: RwSignal<Option<CrosshairLabels>>,
     pub is_dragging: RwSignal<bool>,
     pub last_mouse_x: RwSignal<f64>,
     pub current_interval: RwSignal<TimeInterval>,
     pub current_symbol: RwSignal<Symbol>,
+    pub price_decimals: RwSignal<HashMap<Symbol, u32>>,
     pub stream_abort_handles: RwSignal<HashMap<Symbol, AbortHandle>>,
     pub line_visibility: RwSignal<crate::infrastructure::rendering::renderer::LineVisibility>,
+    pub price_scale_mode: RwSignal<crate::infrastructure::rendering::renderer::PriceScaleMode>,
+    pub price_display_mode: RwSignal<crate::infrastructure::rendering::renderer::PriceDisplayMode>,
+    /// Close of the first visible candle, the 0% of percent mode
+    pub percent_base: RwSignal<Option<f64>>,
+    pub chart_type: RwSignal<ChartType>,
     pub domain_state: RwSignal<DomainState>,
     pub view_state: RwSignal<ViewState>,
-    pub connection_id: RwSignal<u64>,
+    /// Views of symbols the user switched away from
+    pub symbol_views: RwSignal<HashMap<Symbol, SymbolView>>,
+    /// Latest stream connection id per symbol; older connections ignore their results
+    pub connection_ids: RwSignal<HashMap<Symbol, u64>>,
+    /// Fibonacci tool armed: the next canvas clicks place its anchors
+    pub fib_mode: RwSignal<bool>,
+    /// First anchor of the Fibonacci retracement being drawn
+    pub fib_anchor: RwSignal<Option<(Timestamp, f64)>>,
+    pub fib_labels: RwSignal<Vec<crate::infrastructure::rendering::renderer::FibLabel>>,
+    pub session_labels: RwSignal<Vec<crate::infrastructure::rendering::renderer::SessionLabel>>,
+    pub extreme_labels: RwSignal<Vec<crate::infrastructure::rendering::renderer::ExtremeLabel>>,
+    /// Price window of the last rendered frame, for HTML overlays on the canvas
+    pub price_axis_range:
+        RwSignal<Option<crate::infrastructure::rendering::renderer::PriceAxisRange>>,
+    /// Right-click menu, `None` while closed
+    pub context_menu: RwSignal<Option<ContextMenu>>,
+    pub grid_visible: RwSignal<bool>,
+    /// Scroll to every new realtime candle; cleared when the user moves off the edge
+    pub follow_live: RwSignal<bool>,
+    pub replay: RwSignal<Option<ReplaySession>>,
+    /// Symbol drawn on the comparison canvas next to the main chart
+    pub compare_symbol: RwSignal<Option<Symbol>>,
+    /// Symbol overlaid on the main chart as a percent-change line
+    pub overlay_symbol: RwSignal<Option<Symbol>>,
+    /// History of the overlaid symbol covering the visible range
+    pub overlay_candles: RwSignal<Vec<Candle>>,
+    /// History requested per load and kept per series
+    pub history_window: RwSignal<crate::app::HistoryWindow>,
+    /// Performance HUD shown over the canvas, toggled with F
+    pub perf_hud_visible: RwSignal<bool>,
+    pub perf_stats: RwSignal<crate::infrastructure::rendering::renderer::PerfStats>,
+    /// Measure tool armed: the next drag on the canvas draws a measurement box
+    pub measure_mode: RwSignal<bool>,
+    pub measure_overlay: RwSignal<Option<MeasureOverlay>>,
+    /// Box being dragged with Ctrl held to zoom into a region
+    pub zoom_box: RwSignal<Option<ZoomBox>>,
+    /// Canvas size in CSS pixels, following its container
+    pub canvas_size: RwSignal<(u32, u32)>,
+    /// When each symbol's stream last delivered a candle (ms)
+    pub stream_last_message: RwSignal<HashMap<Symbol, u64>>,
+    pub stream_health: RwSignal<crate::infrastructure::websocket::StreamHealth>,
+    /// Why WebGPU could not start, shown in a dismissible banner
+    pub webgpu_error: RwSignal<Option<String>>,
+    /// Chart filled with generated sample data because WebGPU is unavailable
+    pub demo_mode: RwSignal<bool>,
+    /// Interval between REST polls while a stream is down (ms), 0 disables polling
+    pub rest_poll_ms: RwSignal<u64>,
+    /// Keyboard and mouse controls overlay, toggled with ?
+    pub shortcut_help_visible: RwSignal<bool>,
+    /// Price axis drawn upside down, mirroring the renderer setting
+    pub invert_y: RwSignal<bool>,
+    /// Crosshair price snaps to the hovered candle's nearest OHLC value
+    pub crosshair_magnet: RwSignal<bool>,
+    /// Chart color palette, mirroring the renderer setting
+    pub chart_theme: RwSignal<crate::infrastructure::rendering::renderer::Theme>,
+    /// Candle wicks drawn, mirroring the renderer setting
+    pub show_wicks: RwSignal<bool>,
+    /// Candle width limits and spacing, mirroring the renderer setting
+    pub candle_sizing: RwSignal<crate::infrastructure::rendering::renderer::CandleSizing>,
+    /// Settings panel expanded below the chart
+    pub settings_panel_open: RwSignal<bool>,
+    /// Recent closes drawn as sparklines in the symbol buttons, fetched once per symbol
+    pub sparklines: RwSignal<HashMap<Symbol, Vec<f64>>>,
+    /// Time zone of axis labels and tooltips
+    pub time_zone: RwSignal<crate::time_utils::TimeZone>,
+    /// Scale of the wheel and keyboard zoom steps
+    pub zoom_sensitivity: RwSignal<f64>,
+    /// Hold streamed candles back from the chart until live updates resume
+    pub live_paused: RwSignal<bool>,
 }
 
 // The `OnceCell` ensures this state is created at most once on demand.
@@ -51,20 +138,72 @@ pub fn globals() -> &'static Globals {
         loading_more: create_rw_signal(false),
         tooltip_data: create_rw_signal(None),
         tooltip_visible: create_rw_signal(false),
+        crosshair_labels: create_rw_signal(None),
         is_dragging: create_rw_signal(false),
         last_mouse_x: create_rw_signal(0.0),
         current_interval: create_rw_signal(TimeInterval::OneMinute),
         current_symbol: create_rw_signal(Symbol::from("BTCUSDT")),
+        price_decimals: create_rw_signal(HashMap::new()),
         stream_abort_handles: create_rw_signal(HashMap::new()),
         line_visibility: create_rw_signal(
             crate::infrastructure::rendering::renderer::LineVisibility::default(),
         ),
+        price_scale_mode: create_rw_signal(
+            crate::infrastructure::rendering::renderer::PriceScaleMode::default(),
+        ),
+        price_display_mode: create_rw_signal(
+            crate::infrastructure::rendering::renderer::PriceDisplayMode::default(),
+        ),
+        percent_base: create_rw_signal(None),
+        chart_type: create_rw_signal(ChartType::Candlestick),
         domain_state: create_rw_signal(DomainState::new(
             Duration::from_secs(1),
             Arc::new(Vec::new()),
         )),
-        view_state: create_rw_signal(ViewState::new(5.0, 1.0, 20.0)),
-        connection_id: create_rw_signal(0),
+        view_state: create_rw_signal(ViewState::default()),
+        symbol_views: create_rw_signal(HashMap::new()),
+        connection_ids: create_rw_signal(HashMap::new()),
+        fib_mode: create_rw_signal(false),
+        fib_anchor: create_rw_signal(None),
+        fib_labels: create_rw_signal(Vec::new()),
+        session_labels: create_rw_signal(Vec::new()),
+        extreme_labels: create_rw_signal(Vec::new()),
+        price_axis_range: create_rw_signal(None),
+        context_menu: create_rw_signal(None),
+        grid_visible: create_rw_signal(true),
+        follow_live: create_rw_signal(true),
+        replay: create_rw_signal(None),
+        compare_symbol: create_rw_signal(None),
+        overlay_symbol: create_rw_signal(None),
+        overlay_candles: create_rw_signal(Vec::new()),
+        history_window: create_rw_signal(Default::default()),
+        perf_hud_visible: create_rw_signal(false),
+        perf_stats: create_rw_signal(Default::default()),
+        measure_mode: create_rw_signal(false),
+        measure_overlay: create_rw_signal(None),
+        zoom_box: create_rw_signal(None),
+        canvas_size: create_rw_signal((
+            crate::app::DEFAULT_CANVAS_WIDTH,
+            crate::app::DEFAULT_CANVAS_HEIGHT,
+        )),
+        stream_last_message: create_rw_signal(HashMap::new()),
+        stream_health: create_rw_signal(
+            crate::infrastructure::websocket::StreamHealth::Disconnected,
+        ),
+        webgpu_error: create_rw_signal(None),
+        demo_mode: create_rw_signal(false),
+        rest_poll_ms: create_rw_signal(crate::app::DEFAULT_REST_POLL_MS),
+        shortcut_help_visible: create_rw_signal(false),
+        invert_y: create_rw_signal(false),
+        crosshair_magnet: create_rw_signal(false),
+        chart_theme: create_rw_signal(Default::default()),
+        show_wicks: create_rw_signal(true),
+        candle_sizing: create_rw_signal(Default::default()),
+        settings_panel_open: create_rw_signal(false),
+        sparklines: create_rw_signal(HashMap::new()),
+        time_zone: create_rw_signal(Default::default()),
+        zoom_sensitivity: create_rw_signal(crate::app::DEFAULT_ZOOM_SENSITIVITY),
+        live_paused: create_rw_signal(false),
     })
 }
 
@@ -88,7 +227,11 @@ pub fn ensure_chart(symbol: &Symbol) -> RwSignal<Chart> {
         return sig;
     }
     let mut world = ecs_world().lock().unwrap();
-    let chart = Chart::new(symbol.value().to_string(), ChartType::Candlestick, 1000);
+    let chart_type = globals().chart_type.get_untracked();
+    let buffer_size = globals().history_window.get_untracked().buffer_size;
+    let mut chart = Chart::new(symbol.value().to_string(), chart_type, buffer_size);
+    chart.price_alerts = crate::infrastructure::storage::load_price_alerts(symbol);
+    (chart.viewport.width, chart.viewport.height) = globals().canvas_size.get_untracked();
     let entity = world.spawn_chart(chart);
     world.world.get::<&ChartComponent>(entity).map(|c| c.0).expect("chart just spawned")
 }
@@ -105,19 +248,88 @@ pub fn view_state() -> RwSignal<ViewState> {
     globals().view_state
 }
 
-pub fn connection_id() -> RwSignal<u64> {
-    globals().connection_id
+/// Remember the viewport of `symbol` and, if it is current, the zoom state
+pub fn save_symbol_view(symbol: &Symbol) {
+    let Some(chart) = get_chart_signal(symbol) else { return };
+    let viewport = chart.with_untracked(|ch| ch.viewport.clone());
+    let view_state = globals().view_state.get_untracked();
+    globals().symbol_views.update(|views| {
+        views.insert(symbol.clone(), SymbolView { viewport, view_state });
+    });
+}
+
+/// Whether a view was saved for `symbol`
+pub fn has_symbol_view(symbol: &Symbol) -> bool {
+    globals().symbol_views.with_untracked(|views| views.contains_key(symbol))
+}
+
+/// Bring back the saved view of `symbol`; symbols never viewed fit their data instead.
+///
+/// The zoom state is global, so it only follows the current symbol. Returns
+/// whether a saved view was applied.
+pub fn restore_symbol_view(symbol: &Symbol) -> bool {
+    let chart = ensure_chart(symbol);
+    let saved = globals().symbol_views.with_untracked(|views| views.get(symbol).cloned());
+    let is_current = globals().current_symbol.get_untracked() == *symbol;
+    match &saved {
+        Some(saved) => chart.update(|ch| ch.restore_viewport(&saved.viewport)),
+        None => chart.update(|ch| ch.update_viewport_for_data()),
+    }
+    if is_current {
+        globals().view_state.set(saved.as_ref().map(|s| s.view_state.clone()).unwrap_or_default());
+    }
+    set_chart_in_ecs(symbol, chart.get_untracked());
+    saved.is_some()
+}
+
+/// Start a new stream connection for `symbol`, invalidating its previous one
+pub fn next_connection_id(symbol: &Symbol) -> u64 {
+    let mut id = 0;
+    globals().connection_ids.update(|ids| {
+        let entry = ids.entry(symbol.clone()).or_insert(0);
+        *entry += 1;
+        id = *entry;
+    });
+    id
 }
 
-/// Add a candle to the ECS world and process systems.
-pub fn push_realtime_candle(candle: Candle) {
-    use crate::ecs::components::CandleComponent;
+/// Whether `id` is still the latest connection of `symbol`
+pub fn is_current_connection(symbol: &Symbol, id: u64) -> bool {
+    globals().connection_ids.with_untracked(|ids| ids.get(symbol) == Some(&id))
+}
+
+/// Add a candle for `symbol` to the ECS world and process systems.
+pub fn push_realtime_candle(symbol: &Symbol, candle: Candle) {
+    use crate::ecs::components::{CandleComponent, SymbolComponent};
     {
         let mut world = ecs_world().lock().unwrap();
-        world.world.spawn((CandleComponent(candle),));
+        world.world.spawn((CandleComponent(candle), SymbolComponent(symbol.clone())));
         world.run_candle_system_parallel();
         world.run_viewport_system();
     }
+    refresh_indicator_cache();
+}
+
+/// Run the indicator system for the current interval and visible lines and
+/// hand the current symbol's cached moving averages to the renderer.
+pub fn refresh_indicator_cache() {
+    let interval = globals().current_interval.get_untracked();
+    let configs: Vec<_> = globals().line_visibility.with_untracked(|v| {
+        v.lines.iter().filter(|l| l.visible && l.period > 0).map(|l| l.config()).collect()
+    });
+    let symbol = globals().current_symbol.get_untracked();
+    // Releasing the renderer's copy first lets the system update the shared engine in place
+    crate::infrastructure::rendering::renderer::with_global_renderer(|r| {
+        r.set_indicator_cache(None)
+    });
+    let cache = {
+        let mut world = ecs_world().lock().unwrap();
+        world.run_indicator_system(interval, &configs);
+        world.indicators(symbol.value())
+    };
+    crate::infrastructure::rendering::renderer::with_global_renderer(|r| {
+        r.set_indicator_cache(cache)
+    });
 }
 
 /// Replace or spawn a chart entity in the ECS world.
@@ -138,4 +350,5 @@ pub fn set_chart_in_ecs(symbol: &Symbol, chart: Chart) {
         }
         world.run_viewport_system();
     }
+    refresh_indicator_cache();
 }
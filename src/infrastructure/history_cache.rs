@@ -0,0 +1,178 @@
+//! In-memory cache of fetched candle history.
+//!
+//! Candles are kept per `(Symbol, TimeInterval)` as sorted, non-overlapping
+//! ranges so switching back to a symbol or scrolling over already loaded
+//! history does not hit the network again. Whole symbols are evicted in least
+//! recently used order, and ranges older than the TTL are dropped.
+
+use crate::domain::market_data::{Candle, Symbol, TimeInterval};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+/// Symbols kept before the least recently used one is evicted
+pub const HISTORY_CACHE_CAPACITY: usize = 8;
+/// Age after which cached candles are refetched (1 minute)
+pub const HISTORY_CACHE_TTL_MS: u64 = 60 * 1000;
+
+thread_local! {
+    static HISTORY_CACHE: RefCell<HistoryCache> =
+        RefCell::new(HistoryCache::new(HISTORY_CACHE_CAPACITY, HISTORY_CACHE_TTL_MS));
+}
+
+/// Run `f` with the application-wide history cache
+pub fn with_history_cache<R>(f: impl FnOnce(&mut HistoryCache) -> R) -> R {
+    HISTORY_CACHE.with(|cache| f(&mut cache.borrow_mut()))
+}
+
+/// Contiguous run of candles sorted by timestamp
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandleRange {
+    pub candles: Vec<Candle>,
+    /// When the oldest part of the range was fetched (ms)
+    pub fetched_at: u64,
+}
+
+impl CandleRange {
+    pub fn start(&self) -> u64 {
+        self.candles.first().map_or(0, |c| c.timestamp.value())
+    }
+
+    pub fn end(&self) -> u64 {
+        self.candles.last().map_or(0, |c| c.timestamp.value())
+    }
+}
+
+/// LRU cache of candle ranges per symbol and interval
+#[derive(Debug)]
+pub struct HistoryCache {
+    capacity: usize,
+    ttl_ms: u64,
+    entries: HashMap<(Symbol, TimeInterval), Vec<CandleRange>>,
+    /// Cached symbols from least to most recently used
+    lru: VecDeque<Symbol>,
+}
+
+impl HistoryCache {
+    pub fn new(capacity: usize, ttl_ms: u64) -> Self {
+        Self { capacity: capacity.max(1), ttl_ms, entries: HashMap::new(), lru: VecDeque::new() }
+    }
+
+    /// Store fetched candles, merging them with overlapping or adjacent ranges
+    pub fn insert(
+        &mut self,
+        symbol: &Symbol,
+        interval: TimeInterval,
+        candles: &[Candle],
+        now: u64,
+    ) {
+        if candles.is_empty() {
+            return;
+        }
+        self.purge_expired(symbol, interval, now);
+
+        let mut merged = CandleRange { candles: candles.to_vec(), fetched_at: now };
+        merged.candles.sort_by_key(|c| c.timestamp.value());
+        merged.candles.dedup_by_key(|c| c.timestamp.value());
+
+        let step = interval.duration_ms();
+        let ranges = self.entries.entry((symbol.clone(), interval)).or_default();
+        let (touching, mut rest): (Vec<_>, Vec<_>) = ranges.drain(..).partition(|r| {
+            r.start() <= merged.end().saturating_add(step)
+                && merged.start() <= r.end().saturating_add(step)
+        });
+        for range in touching {
+            merged.fetched_at = merged.fetched_at.min(range.fetched_at);
+            // Freshly fetched candles win over cached ones with the same timestamp
+            let mut candles = merged.candles;
+            candles.extend(range.candles);
+            candles.sort_by_key(|c| c.timestamp.value());
+            candles.dedup_by_key(|c| c.timestamp.value());
+            merged.candles = candles;
+        }
+        rest.push(merged);
+        rest.sort_by_key(|r| r.start());
+        *ranges = rest;
+
+        self.touch(symbol);
+    }
+
+    /// The most recent range, if it has not expired and reaches the candle open at `now`.
+    ///
+    /// A range ending in a closed candle misses candles the live stream never sends;
+    /// [`HistoryCache::newest`] tells where to fetch them from.
+    pub fn latest(
+        &mut self,
+        symbol: &Symbol,
+        interval: TimeInterval,
+        now: u64,
+    ) -> Option<Vec<Candle>> {
+        self.purge_expired(symbol, interval, now);
+        let open = interval.bucket_start(now);
+        let candles = self
+            .entries
+            .get(&(symbol.clone(), interval))?
+            .last()
+            .filter(|r| r.end() >= open)
+            .map(|r| r.candles.clone())?;
+        self.touch(symbol);
+        Some(candles)
+    }
+
+    /// Timestamp of the newest cached candle that has not expired
+    pub fn newest(&mut self, symbol: &Symbol, interval: TimeInterval, now: u64) -> Option<u64> {
+        self.purge_expired(symbol, interval, now);
+        self.entries.get(&(symbol.clone(), interval))?.last().map(CandleRange::end)
+    }
+
+    /// Up to `limit` cached candles at or before `end_time`, taken from the range containing it
+    pub fn before(
+        &mut self,
+        symbol: &Symbol,
+        interval: TimeInterval,
+        end_time: u64,
+        limit: usize,
+        now: u64,
+    ) -> Option<Vec<Candle>> {
+        self.purge_expired(symbol, interval, now);
+        let range = self
+            .entries
+            .get(&(symbol.clone(), interval))?
+            .iter()
+            .find(|r| r.start() <= end_time && end_time <= r.end())?;
+        let count = range.candles.partition_point(|c| c.timestamp.value() <= end_time);
+        if count == 0 {
+            return None;
+        }
+        let candles = range.candles[count.saturating_sub(limit)..count].to_vec();
+        self.touch(symbol);
+        Some(candles)
+    }
+
+    /// Cached ranges of a symbol and interval, oldest first
+    pub fn ranges(&self, symbol: &Symbol, interval: TimeInterval) -> &[CandleRange] {
+        self.entries.get(&(symbol.clone(), interval)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Cached symbols from least to most recently used
+    pub fn symbols(&self) -> Vec<Symbol> {
+        self.lru.iter().cloned().collect()
+    }
+
+    fn purge_expired(&mut self, symbol: &Symbol, interval: TimeInterval, now: u64) {
+        let ttl = self.ttl_ms;
+        if let Some(ranges) = self.entries.get_mut(&(symbol.clone(), interval)) {
+            ranges.retain(|r| now.saturating_sub(r.fetched_at) < ttl);
+        }
+    }
+
+    /// Mark `symbol` as most recently used and evict beyond the capacity
+    fn touch(&mut self, symbol: &Symbol) {
+        self.lru.retain(|s| s != symbol);
+        self.lru.push_back(symbol.clone());
+        while self.lru.len() > self.capacity {
+            if let Some(evicted) = self.lru.pop_front() {
+                self.entries.retain(|(s, _), _| *s != evicted);
+            }
+        }
+    }
+}
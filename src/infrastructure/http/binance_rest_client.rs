@@ -3,8 +3,15 @@ use crate::domain::market_data::{
     Candle, TimeInterval,
     value_objects::{OHLCV, Price, Symbol, Timestamp, Volume},
 };
+use crate::infrastructure::websocket::dto::with_taker_buy;
 use gloo_net::http::Request;
 
+#[derive(Debug, serde::Deserialize)]
+struct BinanceServerTime {
+    #[serde(rename = "serverTime")]
+    server_time: u64,
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct BinanceHistoricalKline(
     u64,
@@ -16,11 +23,69 @@ struct BinanceHistoricalKline(
     serde::de::IgnoredAny,
     serde::de::IgnoredAny,
     serde::de::IgnoredAny,
-    serde::de::IgnoredAny,
+    String, // Taker buy base asset volume
     serde::de::IgnoredAny,
     serde::de::IgnoredAny,
 );
 
+#[derive(Debug, serde::Deserialize)]
+struct BinanceExchangeInfo {
+    symbols: Vec<BinanceSymbolInfo>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct BinanceSymbolInfo {
+    filters: Vec<BinanceSymbolFilter>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct BinanceSymbolFilter {
+    #[serde(rename = "filterType")]
+    filter_type: String,
+    #[serde(rename = "tickSize")]
+    tick_size: Option<String>,
+}
+
+/// Candles of a `klines` or `uiKlines` response, with the taker buy volume of each
+pub fn parse_klines(json: &str) -> Result<Vec<Candle>, String> {
+    let klines: Vec<BinanceHistoricalKline> =
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse JSON: {e}"))?;
+
+    let mut candles = Vec::with_capacity(klines.len());
+    for kline in klines {
+        let open = kline.1.parse::<f64>().map_err(|_| "Invalid open price")?;
+        let high = kline.2.parse::<f64>().map_err(|_| "Invalid high price")?;
+        let low = kline.3.parse::<f64>().map_err(|_| "Invalid low price")?;
+        let close = kline.4.parse::<f64>().map_err(|_| "Invalid close price")?;
+        let volume = kline.5.parse::<f64>().map_err(|_| "Invalid volume")?;
+
+        let ohlcv = OHLCV::new(
+            Price::new(open),
+            Price::new(high),
+            Price::new(low),
+            Price::new(close),
+            Volume::new(volume),
+        );
+        let ohlcv = with_taker_buy(ohlcv, Some(&kline.9));
+        candles.push(Candle::new(Timestamp::new(kline.0), ohlcv));
+    }
+    Ok(candles)
+}
+
+/// Tick size from the `PRICE_FILTER` of an `exchangeInfo` response
+pub fn parse_tick_size(json: &str) -> Option<f64> {
+    let info: BinanceExchangeInfo = serde_json::from_str(json).ok()?;
+    info.symbols
+        .first()?
+        .filters
+        .iter()
+        .find(|f| f.filter_type == "PRICE_FILTER")?
+        .tick_size
+        .as_ref()?
+        .parse()
+        .ok()
+}
+
 /// Simple REST client for Binance API
 pub struct BinanceRestClient {
     symbol: Symbol,
@@ -58,6 +123,45 @@ impl BinanceRestClient {
         )
     }
 
+    pub fn time_url(&self) -> String {
+        format!("{}/time", self.base_url())
+    }
+
+    pub fn exchange_info_url(&self) -> String {
+        format!("{}/exchangeInfo?symbol={}", self.base_url(), self.symbol.value().to_uppercase())
+    }
+
+    /// Fetch the price step of the symbol from `exchangeInfo`
+    pub async fn fetch_tick_size(&self) -> Result<f64, String> {
+        let response = Request::get(&self.exchange_info_url())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch exchange info: {e:?}"))?;
+
+        if !response.ok() {
+            return Err(format!("HTTP error: {}", response.status()));
+        }
+
+        let body = response.text().await.map_err(|e| format!("Failed to read body: {e:?}"))?;
+        parse_tick_size(&body).ok_or_else(|| "No PRICE_FILTER tick size".to_string())
+    }
+
+    /// Fetch the current Binance server time in milliseconds
+    pub async fn fetch_server_time(&self) -> Result<u64, String> {
+        let response = Request::get(&self.time_url())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch server time: {e:?}"))?;
+
+        if !response.ok() {
+            return Err(format!("HTTP error: {}", response.status()));
+        }
+
+        let time: BinanceServerTime =
+            response.json().await.map_err(|e| format!("Failed to parse JSON: {e:?}"))?;
+        Ok(time.server_time)
+    }
+
     /// Fetch candles before the specified time, using uiKlines then falling back to klines
     pub async fn fetch_historical_before(
         &self,
@@ -85,28 +189,8 @@ impl BinanceRestClient {
             return Err(format!("HTTP error: {}", response.status()));
         }
 
-        let klines: Vec<BinanceHistoricalKline> =
-            response.json().await.map_err(|e| format!("Failed to parse JSON: {e:?}"))?;
-
-        let mut candles = Vec::new();
-        for kline in klines {
-            let open = kline.1.parse::<f64>().map_err(|_| "Invalid open price")?;
-            let high = kline.2.parse::<f64>().map_err(|_| "Invalid high price")?;
-            let low = kline.3.parse::<f64>().map_err(|_| "Invalid low price")?;
-            let close = kline.4.parse::<f64>().map_err(|_| "Invalid close price")?;
-            let volume = kline.5.parse::<f64>().map_err(|_| "Invalid volume")?;
-
-            let ohlcv = OHLCV::new(
-                Price::new(open),
-                Price::new(high),
-                Price::new(low),
-                Price::new(close),
-                Volume::new(volume),
-            );
-
-            let candle = Candle::new(Timestamp::new(kline.0), ohlcv);
-            candles.push(candle);
-        }
+        let body = response.text().await.map_err(|e| format!("Failed to read body: {e:?}"))?;
+        let candles = parse_klines(&body)?;
 
         get_logger().info(
             LogComponent::Infrastructure("BinanceAPI"),
@@ -131,4 +215,29 @@ mod tests {
             "https://api.binance.com/api/v3/uiKlines?symbol=BTCUSDT&interval=1m&endTime=12345&limit=1000"
         );
     }
+
+    #[test]
+    fn test_exchange_info_url() {
+        let client = BinanceRestClient::new(Symbol::from("pepeusdt"), TimeInterval::OneMinute);
+        assert_eq!(
+            client.exchange_info_url(),
+            "https://api.binance.com/api/v3/exchangeInfo?symbol=PEPEUSDT"
+        );
+    }
+
+    #[test]
+    fn test_parse_tick_size() {
+        let json = r#"{"symbols":[{"symbol":"PEPEUSDT","filters":[
+            {"filterType":"LOT_SIZE","stepSize":"1.00"},
+            {"filterType":"PRICE_FILTER","minPrice":"0.00000001","tickSize":"0.00000001"}
+        ]}]}"#;
+        assert_eq!(parse_tick_size(json), Some(0.00000001));
+        assert_eq!(parse_tick_size(r#"{"symbols":[]}"#), None);
+    }
+
+    #[test]
+    fn test_time_url() {
+        let client = BinanceRestClient::new(Symbol::from("BTCUSDT"), TimeInterval::OneMinute);
+        assert_eq!(client.time_url(), "https://api.binance.com/api/v3/time");
+    }
 }
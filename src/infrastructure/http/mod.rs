@@ -1,2 +1,3 @@
 //! REST clients for external APIs.
 pub mod binance_rest_client;
+pub mod server_time;
@@ -0,0 +1,70 @@
+//! Binance server time synchronization.
+//!
+//! The local clock may drift from the exchange clock. The offset between them
+//! is measured via the REST `/time` endpoint and applied to every "now"-based
+//! computation through [`server_now`].
+
+use super::binance_rest_client::BinanceRestClient;
+use crate::domain::logging::{LogComponent, get_logger};
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// How often the server time offset is refreshed (10 minutes)
+pub const SERVER_TIME_REFRESH_MS: u32 = 10 * 60 * 1000;
+
+static SERVER_TIME_OFFSET: AtomicI64 = AtomicI64::new(0);
+
+/// Current offset (server - local) in milliseconds
+pub fn server_time_offset() -> i64 {
+    SERVER_TIME_OFFSET.load(Ordering::Relaxed)
+}
+
+/// Store a new offset (server - local) in milliseconds
+pub fn set_server_time_offset(offset_ms: i64) {
+    SERVER_TIME_OFFSET.store(offset_ms, Ordering::Relaxed);
+}
+
+/// Compute the offset assuming the server replied halfway through the request
+pub fn compute_offset(server_ms: u64, request_start_ms: u64, request_end_ms: u64) -> i64 {
+    let local_mid = request_start_ms + request_end_ms.saturating_sub(request_start_ms) / 2;
+    server_ms as i64 - local_mid as i64
+}
+
+/// Shift a local timestamp by the given offset
+pub fn apply_offset(local_ms: u64, offset_ms: i64) -> u64 {
+    local_ms.saturating_add_signed(offset_ms)
+}
+
+/// Current time corrected to the Binance server clock
+pub fn server_now() -> u64 {
+    apply_offset(js_sys::Date::now() as u64, server_time_offset())
+}
+
+/// Measure and store the server time offset once
+pub async fn sync_server_time(client: &BinanceRestClient) -> Result<i64, String> {
+    let start = js_sys::Date::now() as u64;
+    let server_ms = client.fetch_server_time().await?;
+    let end = js_sys::Date::now() as u64;
+
+    let offset = compute_offset(server_ms, start, end);
+    set_server_time_offset(offset);
+    get_logger().info(
+        LogComponent::Infrastructure("ServerTime"),
+        &format!("🕒 Server time offset: {offset} ms"),
+    );
+    Ok(offset)
+}
+
+/// Periodically refresh the server time offset in the background
+pub fn start_server_time_sync(client: BinanceRestClient, refresh_ms: u32) {
+    leptos::spawn_local(async move {
+        loop {
+            if let Err(e) = sync_server_time(&client).await {
+                get_logger().warn(
+                    LogComponent::Infrastructure("ServerTime"),
+                    &format!("⚠️ Server time sync failed, keeping previous offset: {e}"),
+                );
+            }
+            gloo_timers::future::TimeoutFuture::new(refresh_ms).await;
+        }
+    });
+}
@@ -1,25 +1,36 @@
 //! Concrete implementations of services used by the application.
 //!
-//! This module houses rendering and WebSocket communication layers along with
-//! helper utilities such as logging and time providers.
+//! This module houses rendering and WebSocket communication layers, settings
+//! storage, shareable URL parameters, the history cache, the history request
+//! rate limiter, and helper utilities such as logging and time providers.
 
+pub mod history_cache;
 pub mod http;
+pub mod rate_limiter;
 pub mod rendering;
+pub mod storage;
+pub mod url_params;
 pub mod websocket;
 
 /// Infrastructure services
 pub mod services {
     use crate::domain::logging::{LogComponent, LogEntry, LogLevel, Logger, TimeProvider};
     use gloo::console;
+    use std::sync::atomic::{AtomicU8, Ordering};
 
     /// Console logger implementation using gloo
     pub struct ConsoleLogger {
-        min_level: LogLevel,
+        /// `LogLevel` as `u8`, changeable at runtime
+        min_level: AtomicU8,
     }
 
     impl ConsoleLogger {
         pub fn new(min_level: LogLevel) -> Self {
-            Self { min_level }
+            Self { min_level: AtomicU8::new(min_level as u8) }
+        }
+
+        pub fn min_level(&self) -> LogLevel {
+            LogLevel::from_u8(self.min_level.load(Ordering::Relaxed))
         }
 
         pub fn new_production() -> Self {
@@ -51,7 +62,7 @@ pub mod services {
 
     impl Logger for ConsoleLogger {
         fn log(&self, entry: LogEntry) {
-            if entry.level >= self.min_level {
+            if self.is_enabled(entry.level) {
                 use crate::domain::logging::get_time_provider;
                 let formatted = self.format_log_entry(&entry, get_time_provider());
 
@@ -64,6 +75,14 @@ pub mod services {
                 }
             }
         }
+
+        fn set_min_level(&self, level: LogLevel) {
+            self.min_level.store(level as u8, Ordering::Relaxed);
+        }
+
+        fn is_enabled(&self, level: LogLevel) -> bool {
+            level >= self.min_level()
+        }
     }
 
     /// Browser-based time provider using JS Date API
@@ -83,7 +102,7 @@ pub mod services {
 
     impl TimeProvider for BrowserTimeProvider {
         fn current_timestamp(&self) -> u64 {
-            js_sys::Date::now() as u64
+            crate::infrastructure::http::server_time::server_now()
         }
 
         fn format_timestamp(&self, timestamp: u64) -> String {
@@ -0,0 +1,99 @@
+//! Token bucket limiting how fast REST history is requested.
+//!
+//! Each request takes one token; tokens refill continuously up to the bucket
+//! capacity. Rapid panning therefore gets a short burst of pages and is then
+//! throttled to the refill rate instead of tripping the exchange's limit.
+
+use std::cell::RefCell;
+
+/// History requests allowed in a burst
+pub const HISTORY_RATE_CAPACITY: u32 = 5;
+/// History requests regained per second
+pub const HISTORY_RATE_REFILL_PER_SEC: f64 = 1.0;
+
+thread_local! {
+    static HISTORY_RATE_LIMITER: RefCell<TokenBucket> = RefCell::new(TokenBucket::new(
+        RateLimit::new(HISTORY_RATE_CAPACITY, HISTORY_RATE_REFILL_PER_SEC),
+        0,
+    ));
+}
+
+/// Run `f` with the application-wide limiter of REST history requests
+pub fn with_history_rate_limiter<R>(f: impl FnOnce(&mut TokenBucket) -> R) -> R {
+    HISTORY_RATE_LIMITER.with(|limiter| f(&mut limiter.borrow_mut()))
+}
+
+/// Burst size and sustained rate of a [`TokenBucket`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub capacity: u32,
+    pub refill_per_sec: f64,
+}
+
+impl RateLimit {
+    /// Limit of at least one token that refills at a positive rate
+    pub fn new(capacity: u32, refill_per_sec: f64) -> Self {
+        let refill_per_sec =
+            if refill_per_sec.is_finite() && refill_per_sec > 0.0 { refill_per_sec } else { 1.0 };
+        Self { capacity: capacity.max(1), refill_per_sec }
+    }
+}
+
+/// Token bucket over millisecond timestamps
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenBucket {
+    limit: RateLimit,
+    tokens: f64,
+    /// When tokens were last refilled (ms)
+    refilled_at: u64,
+}
+
+impl TokenBucket {
+    /// Full bucket as of `now` (ms)
+    pub fn new(limit: RateLimit, now: u64) -> Self {
+        Self { limit, tokens: limit.capacity as f64, refilled_at: now }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Change the limit, keeping the tokens left up to the new capacity
+    pub fn set_limit(&mut self, limit: RateLimit) {
+        self.limit = limit;
+        self.tokens = self.tokens.min(limit.capacity as f64);
+    }
+
+    /// Whole tokens available at `now`
+    pub fn available(&mut self, now: u64) -> u32 {
+        self.refill(now);
+        self.tokens.floor() as u32
+    }
+
+    /// Take a token for a request at `now`; false if the request must wait
+    pub fn try_acquire(&mut self, now: u64) -> bool {
+        self.refill(now);
+        if self.tokens < 1.0 {
+            return false;
+        }
+        self.tokens -= 1.0;
+        true
+    }
+
+    /// Milliseconds until a token is available, 0 if one is available now
+    pub fn retry_after_ms(&mut self, now: u64) -> u64 {
+        self.refill(now);
+        let missing = 1.0 - self.tokens;
+        if missing <= 0.0 {
+            return 0;
+        }
+        (missing * 1000.0 / self.limit.refill_per_sec).ceil() as u64
+    }
+
+    fn refill(&mut self, now: u64) {
+        let elapsed = now.saturating_sub(self.refilled_at) as f64 / 1000.0;
+        self.tokens =
+            (self.tokens + elapsed * self.limit.refill_per_sec).min(self.limit.capacity as f64);
+        self.refilled_at = self.refilled_at.max(now);
+    }
+}
@@ -1,5 +1,44 @@
+use crate::domain::market_data::services::SessionLevelKind;
 use bytemuck::{Pod, Zeroable};
 
+/// Maximum number of configurable indicator lines (one uniform color slot each)
+pub const MAX_INDICATOR_LINES: usize = 8;
+
+/// Color type of the first built-in line colored through [`ChartUniforms::line_colors`]
+pub const FIRST_LINE_COLOR_TYPE: usize = 10;
+
+/// Number of built-in line color types (`10..=34`)
+pub const LINE_COLOR_SLOTS: usize = 25;
+
+/// Default colors of the built-in lines, indexed by color type minus [`FIRST_LINE_COLOR_TYPE`]
+pub const DEFAULT_LINE_COLORS: [[f32; 4]; LINE_COLOR_SLOTS] = [
+    [0.2, 0.6, 1.0, 0.9],    // 10 Ichimoku Tenkan-sen - blue
+    [0.9, 0.3, 0.4, 0.9],    // 11 Ichimoku Kijun-sen - crimson
+    [1.0, 1.0, 1.0, 1.0],    // 12 unused
+    [1.0, 1.0, 1.0, 1.0],    // 13 unused
+    [0.5, 0.8, 0.4, 0.9],    // 14 Ichimoku Chikou span - green
+    [0.4, 0.6, 1.0, 0.9],    // 15 Bollinger upper - light blue
+    [0.7, 0.5, 1.0, 0.9],    // 16 Bollinger middle - violet
+    [0.4, 0.6, 1.0, 0.9],    // 17 Bollinger lower - light blue
+    [1.0, 1.0, 0.0, 0.9],    // 18 RSI - yellow
+    [0.5, 0.5, 0.5, 0.6],    // 19 panel guides - gray
+    [0.3, 0.8, 1.0, 0.9],    // 20 MACD line - sky blue
+    [1.0, 0.6, 0.2, 0.9],    // 21 MACD signal - orange
+    [1.0, 0.45, 0.8, 0.9],   // 22 VWAP - pink
+    [0.6, 0.9, 0.6, 0.9],    // 23 ATR - pale green
+    [0.3, 0.9, 0.8, 0.9],    // 24 VWMA - teal
+    [0.3, 0.8, 1.0, 0.9],    // 25 Stochastic %K - sky blue
+    [1.0, 0.6, 0.2, 0.9],    // 26 Stochastic %D - orange
+    [1.0, 1.0, 1.0, 1.0],    // 27 close line, drawn in the bullish color
+    [0.95, 0.75, 0.3, 0.9],  // 28 Donchian upper - amber
+    [0.8, 0.8, 0.8, 0.7],    // 29 Donchian middle - light gray
+    [0.95, 0.75, 0.3, 0.9],  // 30 Donchian lower - amber
+    [0.3, 0.8, 0.8, 0.9],    // 31 Keltner upper - teal
+    [0.8, 0.8, 0.8, 0.7],    // 32 Keltner middle - light gray
+    [0.3, 0.8, 0.8, 0.9],    // 33 Keltner lower - teal
+    [0.85, 0.55, 1.0, 0.95], // 34 compare overlay - violet
+];
+
 /// Indicator types for GPU rendering
 #[derive(Debug, Clone, Copy)]
 pub enum IndicatorType {
@@ -8,11 +47,36 @@ pub enum IndicatorType {
     SMA200,
     EMA12,
     EMA26,
+    /// Configured indicator line using the given color slot (`0..MAX_INDICATOR_LINES`)
+    Slot(u8),
     Tenkan,
     Kijun,
     SenkouA,
     SenkouB,
     Chikou,
+    BollingerUpper,
+    BollingerMiddle,
+    BollingerLower,
+    Rsi,
+    /// Guide lines of oscillator panels (RSI 30/70, MACD zero, Stochastic 20/80)
+    PanelGuide,
+    MacdLine,
+    MacdSignal,
+    VWAP,
+    Atr,
+    VWMA,
+    StochasticK,
+    StochasticD,
+    /// Close-price line of the line and area chart types
+    Close,
+    DonchianUpper,
+    DonchianMiddle,
+    DonchianLower,
+    KeltnerUpper,
+    KeltnerMiddle,
+    KeltnerLower,
+    /// Percent-change line of the overlaid symbol
+    CompareOverlay,
 }
 
 /// GPU representation of a candle for the vertex buffer
@@ -23,9 +87,21 @@ pub struct CandleVertex {
     pub position_x: f32,
     /// Y position (price in normalized coordinates)
     pub position_y: f32,
-    /// Element type: 0 = body, 1 = wick, 2 = indicator line, 3 = grid, 4 = current price line
+    /// Element type: 0 = body, 1 = wick, 2 = indicator line, 3 = grid, 4 = current price line,
+    /// 5 = volume, 6 = ichimoku cloud, 7 = debug outline, 8 = candle outline, 9 = crosshair,
+    /// 10 = price alert, 11 = Fibonacci level, 12 = gap separator, 13 = order book depth,
+    /// 14 = area fill, 15 = extreme marker, 16 = volume average, 17 = volume profile,
+    /// 18 = session level, 19 = delta volume bar, 20 = Parabolic SAR dot,
+    /// 21 = largest move highlight
     pub element_type: f32,
-    /// Color/indicator: for candles 0/1, for indicators: 2=SMA20, 3=SMA50, 4=SMA200, 5=EMA12, 6=EMA26, 7 = current price
+    /// Color/indicator: for candles 0/1, for indicators: 2..=9 = color slot of a configured line
+    /// (defaults 2=SMA20, 3=SMA50, 4=SMA200, 5=EMA12, 6=EMA26), 7 = current price,
+    /// 10..=14 = Ichimoku, 15..=17 = Bollinger upper/middle/lower, 18 = RSI, 19 = panel guides,
+    /// 20 = MACD line, 21 = MACD signal, 22 = VWAP, 23 = ATR,
+    /// 24 = VWMA, 25 = Stochastic %K, 26 = Stochastic %D, 27 = close line,
+    /// 28..=30 = Donchian upper/middle/lower, 31..=33 = Keltner upper/middle/lower,
+    /// 34 = compare overlay;
+    /// for the area fill its opacity from 0 (bottom) to 1 (line)
     pub color_type: f32,
 }
 
@@ -53,11 +129,32 @@ impl CandleVertex {
             IndicatorType::SMA200 => 4.0,
             IndicatorType::EMA12 => 5.0,
             IndicatorType::EMA26 => 6.0,
+            IndicatorType::Slot(slot) => 2.0 + slot as f32,
             IndicatorType::Tenkan => 10.0,
             IndicatorType::Kijun => 11.0,
             IndicatorType::SenkouA => 12.0,
             IndicatorType::SenkouB => 13.0,
             IndicatorType::Chikou => 14.0,
+            IndicatorType::BollingerUpper => 15.0,
+            IndicatorType::BollingerMiddle => 16.0,
+            IndicatorType::BollingerLower => 17.0,
+            IndicatorType::Rsi => 18.0,
+            IndicatorType::PanelGuide => 19.0,
+            IndicatorType::MacdLine => 20.0,
+            IndicatorType::MacdSignal => 21.0,
+            IndicatorType::VWAP => 22.0,
+            IndicatorType::Atr => 23.0,
+            IndicatorType::VWMA => 24.0,
+            IndicatorType::StochasticK => 25.0,
+            IndicatorType::StochasticD => 26.0,
+            IndicatorType::Close => 27.0,
+            IndicatorType::DonchianUpper => 28.0,
+            IndicatorType::DonchianMiddle => 29.0,
+            IndicatorType::DonchianLower => 30.0,
+            IndicatorType::KeltnerUpper => 31.0,
+            IndicatorType::KeltnerMiddle => 32.0,
+            IndicatorType::KeltnerLower => 33.0,
+            IndicatorType::CompareOverlay => 34.0,
         };
 
         Self {
@@ -108,6 +205,112 @@ impl CandleVertex {
         }
     }
 
+    /// 🐞 Create vertex for a debug outline
+    /// (0 = price pane, 1 = volume pane, 2 = visible candles, 3 = sub-panel)
+    pub fn debug_outline_vertex(x: f32, y: f32, region: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 7.0, color_type: region }
+    }
+
+    /// Create vertex for the mouse crosshair
+    pub fn crosshair_vertex(x: f32, y: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 9.0, color_type: 0.0 }
+    }
+
+    /// Create vertex for a user-placed price alert line
+    pub fn price_alert_vertex(x: f32, y: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 10.0, color_type: 0.0 }
+    }
+
+    /// Create vertex for a Fibonacci retracement level
+    pub fn fib_level_vertex(x: f32, y: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 11.0, color_type: 0.0 }
+    }
+
+    /// Create vertex for the separator drawn at a gap in the data
+    pub fn gap_separator_vertex(x: f32, y: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 12.0, color_type: 0.0 }
+    }
+
+    /// Create vertex for an order book depth bar
+    pub fn depth_vertex(x: f32, y: f32, is_bid: bool) -> Self {
+        Self {
+            position_x: x,
+            position_y: y,
+            element_type: 13.0,
+            color_type: if is_bid { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// Create vertex for a volume profile bar; the Point of Control is highlighted
+    pub fn volume_profile_vertex(x: f32, y: f32, is_poc: bool) -> Self {
+        Self {
+            position_x: x,
+            position_y: y,
+            element_type: 17.0,
+            color_type: if is_poc { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// Create vertex for the area below the close line; `fade` is 1 at the line, 0 at the bottom
+    pub fn area_vertex(x: f32, y: f32, fade: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 14.0, color_type: fade }
+    }
+
+    /// Create vertex for the marker of the visible high (buy color) or low (sell color)
+    pub fn extreme_marker_vertex(x: f32, y: f32, is_high: bool) -> Self {
+        Self {
+            position_x: x,
+            position_y: y,
+            element_type: 15.0,
+            color_type: if is_high { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// Create vertex for the volume average line drawn over the volume bars
+    pub fn volume_ma_vertex(x: f32, y: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 16.0, color_type: 0.0 }
+    }
+
+    /// Create vertex for a session reference line: open (0), high (1) or low (2)
+    pub fn session_level_vertex(x: f32, y: f32, kind: SessionLevelKind) -> Self {
+        let color_type = match kind {
+            SessionLevelKind::Open => 0.0,
+            SessionLevelKind::High => 1.0,
+            SessionLevelKind::Low => 2.0,
+        };
+        Self { position_x: x, position_y: y, element_type: 18.0, color_type }
+    }
+
+    /// Create vertex for a delta volume bar: net taker buying (buy color) or selling
+    pub fn delta_volume_vertex(x: f32, y: f32, is_buy: bool) -> Self {
+        Self {
+            position_x: x,
+            position_y: y,
+            element_type: 19.0,
+            color_type: if is_buy { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// Create vertex for a Parabolic SAR dot: uptrend (buy color) or downtrend
+    pub fn psar_vertex(x: f32, y: f32, rising: bool) -> Self {
+        Self {
+            position_x: x,
+            position_y: y,
+            element_type: 20.0,
+            color_type: if rising { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// Create vertex for the band behind a candle with one of the largest moves
+    pub fn move_highlight_vertex(x: f32, y: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 21.0, color_type: 0.0 }
+    }
+
+    /// Create vertex for the outline around a candle body
+    pub fn candle_outline_vertex(x: f32, y: f32) -> Self {
+        Self { position_x: x, position_y: y, element_type: 8.0, color_type: 0.0 }
+    }
+
     /// Vertex buffer descriptor for wgpu
     pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
         wgpu::VertexBufferLayout {
@@ -219,7 +422,7 @@ pub struct ChartUniforms {
     pub view_proj_matrix: [[f32; 4]; 4],
     /// Viewport dimensions (width, height, min_price, max_price)
     pub viewport: [f32; 4],
-    /// Time range (start_time, end_time, time_range, _padding)
+    /// Time range (start_time, end_time, time_range, first visible close)
     pub time_range: [f32; 4],
     /// Colors (bullish_r, bullish_g, bullish_b, bullish_a)
     pub bullish_color: [f32; 4],
@@ -227,18 +430,16 @@ pub struct ChartUniforms {
     pub bearish_color: [f32; 4],
     /// Wick color (wick_r, wick_g, wick_b, wick_a)
     pub wick_color: [f32; 4],
-    /// SMA 20 color (sma20_r, sma20_g, sma20_b, sma20_a)
-    pub sma20_color: [f32; 4],
-    /// SMA 50 color (sma50_r, sma50_g, sma50_b, sma50_a)
-    pub sma50_color: [f32; 4],
-    /// SMA 200 color (sma200_r, sma200_g, sma200_b, sma200_a)
-    pub sma200_color: [f32; 4],
-    /// EMA 12 color (ema12_r, ema12_g, ema12_b, ema12_a)
-    pub ema12_color: [f32; 4],
-    /// EMA 26 color (ema26_r, ema26_g, ema26_b, ema26_a)
-    pub ema26_color: [f32; 4],
+    /// Indicator line colors indexed by color slot (r, g, b, a)
+    pub indicator_colors: [[f32; 4]; MAX_INDICATOR_LINES],
+    /// Built-in line colors indexed by color type minus [`FIRST_LINE_COLOR_TYPE`]
+    pub line_colors: [[f32; 4]; LINE_COLOR_SLOTS],
     /// 💰 Current price color (current_price_r, current_price_g, current_price_b, current_price_a)
     pub current_price_color: [f32; 4],
+    /// Candle outline color (outline_r, outline_g, outline_b, outline_a)
+    pub outline_color: [f32; 4],
+    /// Grid line color (r, g, b, a)
+    pub grid_color: [f32; 4],
     /// Rendering parameters (candle_width, spacing, line_width, _padding)
     pub render_params: [f32; 4],
 }
@@ -263,12 +464,11 @@ impl ChartUniforms {
             bullish_color: [0.455, 0.780, 0.529, 1.0], // #74c787 - buy
             bearish_color: [0.882, 0.424, 0.282, 1.0], // #e16c48 - sell
             wick_color: [0.6, 0.6, 0.6, 1.0],          // gray
-            sma20_color: [1.0, 1.0, 0.0, 1.0],         // yellow
-            sma50_color: [1.0, 1.0, 0.0, 1.0],         // yellow
-            sma200_color: [1.0, 1.0, 0.0, 1.0],        // yellow
-            ema12_color: [1.0, 1.0, 0.0, 1.0],         // yellow
-            ema26_color: [1.0, 1.0, 0.0, 1.0],         // yellow
+            indicator_colors: [[1.0, 1.0, 0.0, 1.0]; MAX_INDICATOR_LINES], // yellow
+            line_colors: DEFAULT_LINE_COLORS,
             current_price_color: [1.0, 1.0, 0.0, 0.8], // 💰 bright yellow with transparency
+            outline_color: [0.0, 0.0, 0.0, 1.0],       // black
+            grid_color: [0.3, 0.3, 0.3, 0.3],          // semi-transparent gray
             render_params: [8.0, 2.0, 1.0, 0.0],       // width, spacing, line_width, padding
         }
     }
@@ -289,6 +489,43 @@ impl CandleGeometry {
     fn corner_segments(width: f32) -> usize {
         if width >= 0.04 { 12 } else { Self::BASE_CORNER_SEGMENTS }
     }
+
+    /// Wick width as a fraction of the candle width
+    pub const WICK_WIDTH_RATIO: f32 = 0.1;
+    /// Number of vertices in the instanced candle template
+    pub const TEMPLATE_VERTEX_COUNT: u32 = 18;
+    /// First body vertex of the template; the wicks come before it
+    pub const TEMPLATE_BODY_START: u32 = 12;
+
+    /// Template vertices to draw per candle, skipping both wicks when hidden
+    pub fn template_draw_range(show_wicks: bool) -> std::ops::Range<u32> {
+        let start = if show_wicks { 0 } else { Self::TEMPLATE_BODY_START };
+        start..Self::TEMPLATE_VERTEX_COUNT
+    }
+
+    /// Unit template expanded by `vs_candle` for every `CandleInstance`.
+    ///
+    /// `position_x` is a fraction of the instance width centered on `x` and
+    /// `position_y` interpolates within the part selected by `element_type`:
+    /// body (0), upper wick (1) or lower wick (2).
+    pub fn candle_template() -> Vec<CandleVertex> {
+        let quad = |half: f32, element_type: f32| {
+            let v = |x: f32, y: f32| CandleVertex {
+                position_x: x,
+                position_y: y,
+                element_type,
+                color_type: 0.0,
+            };
+            [v(-half, 0.0), v(half, 0.0), v(-half, 1.0), v(half, 0.0), v(half, 1.0), v(-half, 1.0)]
+        };
+        let wick_half = Self::WICK_WIDTH_RATIO * 0.5;
+        let mut vertices = Vec::with_capacity(Self::TEMPLATE_VERTEX_COUNT as usize);
+        vertices.extend_from_slice(&quad(wick_half, 1.0));
+        vertices.extend_from_slice(&quad(wick_half, 2.0));
+        vertices.extend_from_slice(&quad(0.5, 0.0));
+        vertices
+    }
+
     /// Create vertices for a single candle
     #[allow(clippy::too_many_arguments)]
     pub fn create_candle_vertices(
@@ -409,7 +646,7 @@ impl CandleGeometry {
         }
 
         // Create lines for the upper and lower wicks
-        let wick_width = width * 0.1; // wick is thinner than the body
+        let wick_width = width * Self::WICK_WIDTH_RATIO; // wick is thinner than the body
         let wick_half = wick_width * 0.5;
 
         // Upper wick (if present)
@@ -462,12 +699,30 @@ impl CandleGeometry {
         width: f32,
         volume_ratio: f32,
         is_bullish: bool,
+    ) -> Vec<CandleVertex> {
+        Self::create_volume_vertices_in(
+            x_normalized,
+            width,
+            volume_ratio,
+            is_bullish,
+            -1.0,
+            Self::VOLUME_HEIGHT,
+        )
+    }
+
+    /// Create vertices for a volume bar standing on `bottom` with the given maximum height
+    pub fn create_volume_vertices_in(
+        x_normalized: f32,
+        width: f32,
+        volume_ratio: f32,
+        is_bullish: bool,
+        bottom: f32,
+        max_height: f32,
     ) -> Vec<CandleVertex> {
         let half_width = width * 0.5;
         let left = x_normalized - half_width;
         let right = x_normalized + half_width;
-        let bottom = -1.0;
-        let top = bottom + volume_ratio.clamp(0.0, 1.0) * Self::VOLUME_HEIGHT;
+        let top = bottom + volume_ratio.clamp(0.0, 1.0) * max_height;
         vec![
             CandleVertex::volume_vertex(left, bottom, is_bullish),
             CandleVertex::volume_vertex(right, bottom, is_bullish),
@@ -478,11 +733,50 @@ impl CandleGeometry {
         ]
     }
 
+    /// Create a thin delta volume bar standing on `bottom`, `delta_ratio` of `max_height` tall
+    pub fn create_delta_bar(
+        x: f32,
+        width: f32,
+        delta_ratio: f32,
+        is_buy: bool,
+        bottom: f32,
+        max_height: f32,
+    ) -> Vec<CandleVertex> {
+        let half_width = width * 0.5;
+        let (left, right) = (x - half_width, x + half_width);
+        let top = bottom + delta_ratio.clamp(0.0, 1.0) * max_height;
+        let v = |x: f32, y: f32| CandleVertex::delta_volume_vertex(x, y, is_buy);
+        vec![
+            v(left, bottom),
+            v(right, bottom),
+            v(left, top),
+            v(right, bottom),
+            v(right, top),
+            v(left, top),
+        ]
+    }
+
     /// Create vertices for an indicator line - improved algorithm for solid lines
     pub fn create_indicator_line_vertices(
         points: &[(f32, f32)], // (x_normalized, y_normalized) points
         indicator_type: IndicatorType,
         line_width: f32,
+    ) -> Vec<CandleVertex> {
+        Self::create_line(points, line_width, |x, y| {
+            CandleVertex::indicator_vertex(x, y, indicator_type)
+        })
+    }
+
+    /// Create the translucent volume average line over the volume bars
+    pub fn create_volume_ma_line(points: &[(f32, f32)], line_width: f32) -> Vec<CandleVertex> {
+        Self::create_line(points, line_width, CandleVertex::volume_ma_vertex)
+    }
+
+    /// Create a polyline through `points` from vertices made by `vertex`
+    fn create_line(
+        points: &[(f32, f32)],
+        line_width: f32,
+        vertex: impl Fn(f32, f32) -> CandleVertex,
     ) -> Vec<CandleVertex> {
         if points.len() < 2 {
             return Vec::new();
@@ -511,13 +805,13 @@ impl CandleGeometry {
             // Create a rectangle as two triangles without gaps
             let segment_vertices = [
                 // First triangle (CCW)
-                CandleVertex::indicator_vertex(x1 - perp_x, y1 - perp_y, indicator_type),
-                CandleVertex::indicator_vertex(x2 - perp_x, y2 - perp_y, indicator_type),
-                CandleVertex::indicator_vertex(x1 + perp_x, y1 + perp_y, indicator_type),
+                vertex(x1 - perp_x, y1 - perp_y),
+                vertex(x2 - perp_x, y2 - perp_y),
+                vertex(x1 + perp_x, y1 + perp_y),
                 // Second triangle (CCW)
-                CandleVertex::indicator_vertex(x1 + perp_x, y1 + perp_y, indicator_type),
-                CandleVertex::indicator_vertex(x2 - perp_x, y2 - perp_y, indicator_type),
-                CandleVertex::indicator_vertex(x2 + perp_x, y2 + perp_y, indicator_type),
+                vertex(x1 + perp_x, y1 + perp_y),
+                vertex(x2 - perp_x, y2 - perp_y),
+                vertex(x2 + perp_x, y2 + perp_y),
             ];
 
             vertices.extend_from_slice(&segment_vertices);
@@ -616,6 +910,337 @@ impl CandleGeometry {
         vertices
     }
 
+    /// 🐞 Create a rectangular outline drawn inside the given bounds
+    pub fn create_debug_outline(
+        left: f32,
+        bottom: f32,
+        right: f32,
+        top: f32,
+        thickness: f32,
+        region: f32,
+    ) -> Vec<CandleVertex> {
+        Self::rect_outline(left, bottom, right, top, thickness, |x, y| {
+            CandleVertex::debug_outline_vertex(x, y, region)
+        })
+    }
+
+    /// Create a thin border around a candle body; the border is drawn inside the body bounds
+    /// and never takes more than a quarter of the candle width
+    pub fn create_candle_outline(
+        x: f32,
+        width: f32,
+        body_top: f32,
+        body_bottom: f32,
+        thickness: f32,
+    ) -> Vec<CandleVertex> {
+        let half_width = width * 0.5;
+        let thickness = thickness.min(width * 0.25);
+        Self::rect_outline(
+            x - half_width,
+            body_bottom,
+            x + half_width,
+            body_top,
+            thickness,
+            CandleVertex::candle_outline_vertex,
+        )
+    }
+
+    /// Four strips (two triangles each) along the inner edges of a rectangle
+    fn rect_outline(
+        left: f32,
+        bottom: f32,
+        right: f32,
+        top: f32,
+        thickness: f32,
+        vertex: impl Fn(f32, f32) -> CandleVertex,
+    ) -> Vec<CandleVertex> {
+        let edges = [
+            (left, bottom, right, bottom + thickness), // bottom
+            (left, top - thickness, right, top),       // top
+            (left, bottom, left + thickness, top),     // left
+            (right - thickness, bottom, right, top),   // right
+        ];
+
+        let mut vertices = Vec::with_capacity(edges.len() * 6);
+        for (x0, y0, x1, y1) in edges {
+            vertices.extend_from_slice(&[
+                vertex(x0, y0),
+                vertex(x1, y0),
+                vertex(x0, y1),
+                vertex(x1, y0),
+                vertex(x1, y1),
+                vertex(x0, y1),
+            ]);
+        }
+        vertices
+    }
+
+    /// Create a histogram bar between the zero line and the value, colored by its sign
+    pub fn create_histogram_bar(
+        x: f32,
+        width: f32,
+        zero_y: f32,
+        value_y: f32,
+    ) -> Vec<CandleVertex> {
+        let half_width = width * 0.5;
+        let (left, right) = (x - half_width, x + half_width);
+        let positive = value_y >= zero_y;
+        let (bottom, top) = (zero_y.min(value_y), zero_y.max(value_y));
+        vec![
+            CandleVertex::volume_vertex(left, bottom, positive),
+            CandleVertex::volume_vertex(right, bottom, positive),
+            CandleVertex::volume_vertex(left, top, positive),
+            CandleVertex::volume_vertex(right, bottom, positive),
+            CandleVertex::volume_vertex(right, top, positive),
+            CandleVertex::volume_vertex(left, top, positive),
+        ]
+    }
+
+    /// Create the fill between a polyline and `bottom`, fading out towards the bottom
+    pub fn create_area_fill(points: &[(f32, f32)], bottom: f32) -> Vec<CandleVertex> {
+        let mut vertices = Vec::with_capacity(points.len().saturating_sub(1) * 6);
+        for pair in points.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            vertices.extend_from_slice(&[
+                CandleVertex::area_vertex(x1, bottom, 0.0),
+                CandleVertex::area_vertex(x2, bottom, 0.0),
+                CandleVertex::area_vertex(x1, y1, 1.0),
+                CandleVertex::area_vertex(x2, bottom, 0.0),
+                CandleVertex::area_vertex(x2, y2, 1.0),
+                CandleVertex::area_vertex(x1, y1, 1.0),
+            ]);
+        }
+        vertices
+    }
+
+    /// Create a depth bar growing left from `right` between `bottom` and `top`
+    pub fn create_depth_bar(
+        right: f32,
+        width: f32,
+        bottom: f32,
+        top: f32,
+        is_bid: bool,
+    ) -> Vec<CandleVertex> {
+        Self::create_right_bar(right, width, bottom, top, |x, y| {
+            CandleVertex::depth_vertex(x, y, is_bid)
+        })
+    }
+
+    /// Create a horizontal volume profile bar extending left from `right`
+    pub fn create_volume_profile_bar(
+        right: f32,
+        width: f32,
+        bottom: f32,
+        top: f32,
+        is_poc: bool,
+    ) -> Vec<CandleVertex> {
+        Self::create_right_bar(right, width, bottom, top, |x, y| {
+            CandleVertex::volume_profile_vertex(x, y, is_poc)
+        })
+    }
+
+    /// Rectangle from `right - width` to `right` made of vertices from `vertex`
+    fn create_right_bar(
+        right: f32,
+        width: f32,
+        bottom: f32,
+        top: f32,
+        vertex: impl Fn(f32, f32) -> CandleVertex,
+    ) -> Vec<CandleVertex> {
+        let left = right - width;
+        vec![
+            vertex(left, bottom),
+            vertex(right, bottom),
+            vertex(left, top),
+            vertex(right, bottom),
+            vertex(right, top),
+            vertex(left, top),
+        ]
+    }
+
+    /// Create a full-width dashed horizontal line; `dash` and `gap` are NDC lengths
+    pub fn create_dashed_line(y: f32, thickness: f32, dash: f32, gap: f32) -> Vec<CandleVertex> {
+        let half = thickness * 0.5;
+        let step = (dash + gap).max(f32::EPSILON);
+        let segments = (2.0 / step).ceil() as usize;
+        let mut vertices = Vec::with_capacity(segments * 6);
+        for i in 0..segments {
+            let left = -1.0 + i as f32 * step;
+            let right = (left + dash).min(1.0);
+            vertices.extend_from_slice(&[
+                CandleVertex::price_alert_vertex(left, y - half),
+                CandleVertex::price_alert_vertex(right, y - half),
+                CandleVertex::price_alert_vertex(left, y + half),
+                CandleVertex::price_alert_vertex(right, y - half),
+                CandleVertex::price_alert_vertex(right, y + half),
+                CandleVertex::price_alert_vertex(left, y + half),
+            ]);
+        }
+        vertices
+    }
+
+    /// Create grid lines: vertical at each `xs` from `bottom` to the top, horizontal at each `ys`
+    pub fn create_grid_lines(
+        xs: &[f32],
+        ys: &[f32],
+        bottom: f32,
+        thickness_x: f32,
+        thickness_y: f32,
+    ) -> Vec<CandleVertex> {
+        let hx = thickness_x * 0.5;
+        let hy = thickness_y * 0.5;
+        let mut vertices = Vec::with_capacity((xs.len() + ys.len()) * 6);
+        for &x in xs {
+            vertices.extend_from_slice(&[
+                CandleVertex::grid_vertex(x - hx, bottom),
+                CandleVertex::grid_vertex(x + hx, bottom),
+                CandleVertex::grid_vertex(x - hx, 1.0),
+                CandleVertex::grid_vertex(x + hx, bottom),
+                CandleVertex::grid_vertex(x + hx, 1.0),
+                CandleVertex::grid_vertex(x - hx, 1.0),
+            ]);
+        }
+        for &y in ys {
+            vertices.extend_from_slice(&[
+                CandleVertex::grid_vertex(-1.0, y - hy),
+                CandleVertex::grid_vertex(1.0, y - hy),
+                CandleVertex::grid_vertex(-1.0, y + hy),
+                CandleVertex::grid_vertex(1.0, y - hy),
+                CandleVertex::grid_vertex(1.0, y + hy),
+                CandleVertex::grid_vertex(-1.0, y + hy),
+            ]);
+        }
+        vertices
+    }
+
+    /// Create a vertical gap separator at `x` from `bottom` to the top of the chart
+    pub fn create_gap_separator(x: f32, bottom: f32, thickness: f32) -> Vec<CandleVertex> {
+        let half = thickness * 0.5;
+        vec![
+            CandleVertex::gap_separator_vertex(x - half, bottom),
+            CandleVertex::gap_separator_vertex(x + half, bottom),
+            CandleVertex::gap_separator_vertex(x - half, 1.0),
+            CandleVertex::gap_separator_vertex(x + half, bottom),
+            CandleVertex::gap_separator_vertex(x + half, 1.0),
+            CandleVertex::gap_separator_vertex(x - half, 1.0),
+        ]
+    }
+
+    /// Create a band of `width` centered on `x` from `bottom` to the top of the chart
+    pub fn create_move_highlight(x: f32, width: f32, bottom: f32) -> Vec<CandleVertex> {
+        let half = width * 0.5;
+        vec![
+            CandleVertex::move_highlight_vertex(x - half, bottom),
+            CandleVertex::move_highlight_vertex(x + half, bottom),
+            CandleVertex::move_highlight_vertex(x - half, 1.0),
+            CandleVertex::move_highlight_vertex(x + half, bottom),
+            CandleVertex::move_highlight_vertex(x + half, 1.0),
+            CandleVertex::move_highlight_vertex(x - half, 1.0),
+        ]
+    }
+
+    /// Create a triangle with its tip at (`x`, `y`) pointing down at a high or up at a low
+    pub fn create_extreme_marker(
+        x: f32,
+        y: f32,
+        half_width: f32,
+        height: f32,
+        is_high: bool,
+    ) -> Vec<CandleVertex> {
+        let base = if is_high { y + height } else { y - height };
+        vec![
+            CandleVertex::extreme_marker_vertex(x, y, is_high),
+            CandleVertex::extreme_marker_vertex(x - half_width, base, is_high),
+            CandleVertex::extreme_marker_vertex(x + half_width, base, is_high),
+        ]
+    }
+
+    /// Create a diamond point glyph centered on (`x`, `y`)
+    pub fn create_point_glyph(
+        x: f32,
+        y: f32,
+        half_width: f32,
+        half_height: f32,
+        rising: bool,
+    ) -> Vec<CandleVertex> {
+        let v = |x: f32, y: f32| CandleVertex::psar_vertex(x, y, rising);
+        vec![
+            v(x - half_width, y),
+            v(x + half_width, y),
+            v(x, y + half_height),
+            v(x - half_width, y),
+            v(x + half_width, y),
+            v(x, y - half_height),
+        ]
+    }
+
+    /// Create horizontal Fibonacci level lines at each `ys` between `left` and `right`
+    pub fn create_fib_levels(
+        left: f32,
+        right: f32,
+        ys: &[f32],
+        thickness: f32,
+    ) -> Vec<CandleVertex> {
+        let half = thickness * 0.5;
+        let mut vertices = Vec::with_capacity(ys.len() * 6);
+        for &y in ys {
+            vertices.extend_from_slice(&[
+                CandleVertex::fib_level_vertex(left, y - half),
+                CandleVertex::fib_level_vertex(right, y - half),
+                CandleVertex::fib_level_vertex(left, y + half),
+                CandleVertex::fib_level_vertex(right, y - half),
+                CandleVertex::fib_level_vertex(right, y + half),
+                CandleVertex::fib_level_vertex(left, y + half),
+            ]);
+        }
+        vertices
+    }
+
+    /// Create a full-width session reference line at `y`
+    pub fn create_session_level(
+        y: f32,
+        thickness: f32,
+        kind: SessionLevelKind,
+    ) -> Vec<CandleVertex> {
+        let half = thickness * 0.5;
+        let v = |x: f32, y: f32| CandleVertex::session_level_vertex(x, y, kind);
+        vec![
+            v(-1.0, y - half),
+            v(1.0, y - half),
+            v(-1.0, y + half),
+            v(1.0, y - half),
+            v(1.0, y + half),
+            v(-1.0, y + half),
+        ]
+    }
+
+    /// Create a full-width horizontal and a full-height vertical line through the cursor
+    pub fn create_crosshair(
+        x: f32,
+        y: f32,
+        thickness_x: f32,
+        thickness_y: f32,
+    ) -> Vec<CandleVertex> {
+        let hx = thickness_x * 0.5;
+        let hy = thickness_y * 0.5;
+        vec![
+            // Horizontal line
+            CandleVertex::crosshair_vertex(-1.0, y - hy),
+            CandleVertex::crosshair_vertex(1.0, y - hy),
+            CandleVertex::crosshair_vertex(-1.0, y + hy),
+            CandleVertex::crosshair_vertex(1.0, y - hy),
+            CandleVertex::crosshair_vertex(1.0, y + hy),
+            CandleVertex::crosshair_vertex(-1.0, y + hy),
+            // Vertical line
+            CandleVertex::crosshair_vertex(x - hx, -1.0),
+            CandleVertex::crosshair_vertex(x + hx, -1.0),
+            CandleVertex::crosshair_vertex(x - hx, 1.0),
+            CandleVertex::crosshair_vertex(x + hx, -1.0),
+            CandleVertex::crosshair_vertex(x + hx, 1.0),
+            CandleVertex::crosshair_vertex(x - hx, 1.0),
+        ]
+    }
+
     /// Create a smart price grid with nice levels
     pub fn create_price_grid(
         min_price: f32,
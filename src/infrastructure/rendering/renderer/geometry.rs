@@ -1,11 +1,16 @@
 use super::*;
+use crate::domain::chart::value_objects::ChartType;
 use crate::domain::logging::{LogComponent, get_logger};
-use crate::domain::market_data::{Price, TimeInterval};
+use crate::domain::market_data::{
+    CandleSeries, OHLCV, Price, TimeInterval, Volume, moving_average, rebase_on_timestamps,
+    services::MarketAnalysisService, sum_taker_buy,
+};
 use crate::infrastructure::rendering::gpu_structures::{
-    CandleGeometry, CandleInstance, IndicatorType,
+    CandleGeometry, CandleInstance, IndicatorType, MAX_INDICATOR_LINES,
 };
 use crate::{log_info, log_warn};
 use leptos::SignalGetUntracked;
+use std::borrow::Cow;
 
 /// Minimum element width (candle or volume bar)
 pub const MIN_ELEMENT_WIDTH: f32 = 0.002;
@@ -15,49 +20,980 @@ pub const MAX_ELEMENT_WIDTH: f32 = 0.1;
 pub const SPACING_RATIO: f32 = 0.2;
 /// Gap between the right edge and the last element
 pub const EDGE_GAP: f32 = 0.003;
+/// RSI lookback period
+pub const RSI_PERIOD: usize = 14;
+/// RSI level drawn as the lower guide line
+pub const RSI_OVERSOLD: f64 = 30.0;
+/// RSI level drawn as the upper guide line
+pub const RSI_OVERBOUGHT: f64 = 70.0;
+/// MACD fast EMA period
+pub const MACD_FAST: usize = 12;
+/// MACD slow EMA period
+pub const MACD_SLOW: usize = 26;
+/// MACD signal line EMA period
+pub const MACD_SIGNAL: usize = 9;
+/// ATR smoothing period
+pub const ATR_PERIOD: usize = 14;
+/// Stochastic %K lookback period
+pub const STOCHASTIC_K_PERIOD: usize = 14;
+/// Stochastic %D smoothing period
+pub const STOCHASTIC_D_PERIOD: usize = 3;
+/// Stochastic level drawn as the lower guide line
+pub const STOCHASTIC_OVERSOLD: f64 = 20.0;
+/// Stochastic level drawn as the upper guide line
+pub const STOCHASTIC_OVERBOUGHT: f64 = 80.0;
+/// Bollinger Bands window length
+pub const BOLLINGER_PERIOD: usize = 20;
+/// Donchian Channels window length
+pub const DONCHIAN_PERIOD: usize = 20;
+/// Keltner Channels EMA window length
+pub const KELTNER_EMA_PERIOD: usize = 20;
+/// Keltner Channels ATR window length
+pub const KELTNER_ATR_PERIOD: usize = 10;
+/// Keltner Channels band distance in ATRs
+pub const KELTNER_MULTIPLIER: f64 = 2.0;
+/// VWMA window length
+pub const VWMA_PERIOD: usize = 20;
+/// Default window of the volume average
+pub const VOLUME_MA_PERIOD: usize = 20;
+/// Ichimoku Tenkan-sen (conversion line) window length
+pub const TENKAN_PERIOD: usize = 9;
+/// Ichimoku Kijun-sen (base line) window length
+pub const KIJUN_PERIOD: usize = 26;
+/// Candles the Ichimoku Chikou span is drawn back by
+pub const CHIKOU_SHIFT: usize = 26;
+/// Bollinger Bands width in standard deviations
+pub const BOLLINGER_STD_DEV: f64 = 2.0;
+/// Parabolic SAR starting acceleration factor and its increment
+pub const PSAR_STEP: f64 = 0.02;
+/// Parabolic SAR acceleration factor cap
+pub const PSAR_MAX_STEP: f64 = 0.2;
+
+/// Price buckets the visible range is split into for the depth overlay
+pub const DEPTH_BUCKETS: usize = 60;
+/// Width of the longest depth bar (NDC)
+pub const DEPTH_MAX_WIDTH: f32 = 0.15;
+/// Price bins of the volume profile
+pub const VOLUME_PROFILE_BINS: usize = 40;
+/// Width of the Point of Control bar of the volume profile (NDC)
+pub const VOLUME_PROFILE_MAX_WIDTH: f32 = 0.25;
+
+/// Width of a delta volume bar as a fraction of the candle width
+pub const DELTA_BAR_WIDTH_RATIO: f32 = 0.3;
+
+/// Smallest element width a user can pick
+pub const MIN_USER_ELEMENT_WIDTH: f32 = 0.0005;
+/// Largest element width a user can pick
+pub const MAX_USER_ELEMENT_WIDTH: f32 = 0.5;
+/// Largest spacing ratio a user can pick
+pub const MAX_USER_SPACING_RATIO: f32 = 0.9;
+
+/// Width limits and spacing of candles and volume bars, in NDC units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandleSizing {
+    pub min_width: f32,
+    pub max_width: f32,
+    pub spacing_ratio: f32,
+}
+
+impl Default for CandleSizing {
+    fn default() -> Self {
+        Self {
+            min_width: MIN_ELEMENT_WIDTH,
+            max_width: MAX_ELEMENT_WIDTH,
+            spacing_ratio: SPACING_RATIO,
+        }
+    }
+}
+
+impl CandleSizing {
+    /// Sizing from user values, clamped to the supported ranges.
+    ///
+    /// Non-finite values fall back to the defaults and `min_width` never
+    /// exceeds `max_width`.
+    pub fn new(min_width: f32, max_width: f32, spacing_ratio: f32) -> Self {
+        let defaults = Self::default();
+        let valid = |value: f32, default: f32| if value.is_finite() { value } else { default };
+        let max_width = valid(max_width, defaults.max_width)
+            .clamp(MIN_USER_ELEMENT_WIDTH, MAX_USER_ELEMENT_WIDTH);
+        let min_width =
+            valid(min_width, defaults.min_width).clamp(MIN_USER_ELEMENT_WIDTH, max_width);
+        let spacing_ratio =
+            valid(spacing_ratio, defaults.spacing_ratio).clamp(0.0, MAX_USER_SPACING_RATIO);
+        Self { min_width, max_width, spacing_ratio }
+    }
+
+    /// Dynamic spacing based on number of visible candles
+    pub fn spacing_ratio_for(&self, visible_len: usize) -> f32 {
+        assert!(visible_len > 0, "visible_len must be > 0");
+        let factor = (visible_len as f32 / 100.0).min(1.0);
+        self.spacing_ratio * factor
+    }
+
+    /// Width of an element spanning `step` with the given spacing.
+    ///
+    /// The width never exceeds the spacing-adjusted share of the step, so
+    /// neighbors keep their gap even when `min_width` is larger than that share.
+    pub fn width_for_step(&self, step: f32, spacing: f32) -> f32 {
+        let fitted = step * (1.0 - spacing);
+        fitted.clamp(self.min_width, self.max_width).min(fitted)
+    }
 
-/// Dynamic spacing based on number of visible candles
+    /// Width of one of `visible_len` elements
+    pub fn element_width(&self, visible_len: usize) -> f32 {
+        let spacing = self.spacing_ratio_for(visible_len);
+        self.width_for_step(2.0 / visible_len as f32, spacing)
+    }
+
+    /// Candle/bar position taking right edge into account
+    pub fn x_position(&self, index: usize, visible_len: usize) -> f32 {
+        let width = self.element_width(visible_len);
+        let step_size = 2.0 / visible_len as f32;
+        let base_x = 1.0 - (visible_len as f32 - index as f32 - 1.0) * step_size;
+        base_x - width / 2.0 - EDGE_GAP
+    }
+}
+
+/// Dynamic spacing based on number of visible candles, with the default sizing
 pub fn spacing_ratio_for(visible_len: usize) -> f32 {
-    assert!(visible_len > 0, "visible_len must be > 0");
-    let factor = (visible_len as f32 / 100.0).min(1.0);
-    SPACING_RATIO * factor
+    CandleSizing::default().spacing_ratio_for(visible_len)
 }
 
-/// Candle/bar position taking right edge into account
+/// Candle/bar position taking right edge into account, with the default sizing
 pub fn candle_x_position(index: usize, visible_len: usize) -> f32 {
-    assert!(visible_len > 0, "visible_len must be > 0");
-    let step_size = 2.0 / visible_len as f32;
-    let spacing = spacing_ratio_for(visible_len);
-    let width = (step_size * (1.0 - spacing)).clamp(MIN_ELEMENT_WIDTH, MAX_ELEMENT_WIDTH);
-    let base_x = 1.0 - (visible_len as f32 - index as f32 - 1.0) * step_size;
-    base_x - width / 2.0 - EDGE_GAP
+    CandleSizing::default().x_position(index, visible_len)
+}
+
+/// Candles merged into one drawn element so `visible_len` candles fit in `max_elements`.
+///
+/// Merging keeps one element spare for the partial run at the left edge.
+pub fn candles_per_element(visible_len: usize, max_elements: usize) -> usize {
+    let per_element = visible_len.div_ceil(max_elements.max(1)).max(1);
+    if per_element == 1 {
+        return 1;
+    }
+    visible_len.div_ceil(max_elements.saturating_sub(1).max(1))
+}
+
+/// Index ranges of the runs [`downsample_candles`] merges from `len` candles starting
+/// at series index `start_index`.
+///
+/// Runs are aligned to series indices, so panning or a new candle leaves the
+/// runs in between unchanged; only the first and last may be shorter.
+pub fn downsample_runs(
+    len: usize,
+    start_index: usize,
+    per_element: usize,
+) -> Vec<std::ops::Range<usize>> {
+    let per_element = per_element.max(1);
+    let mut runs = Vec::with_capacity(len / per_element + 2);
+    let mut first = 0;
+    while first < len {
+        let end = (first + per_element - (start_index + first) % per_element).min(len);
+        runs.push(first..end);
+        first = end;
+    }
+    runs
+}
+
+/// Merge runs of `per_element` consecutive candles into OHLC summaries, with the
+/// first of `candles` at series index `start_index`.
+///
+/// A summary opens with the first candle of its run, closes with the last,
+/// keeps the extreme high and low for accurate wicks and sums the volumes.
+pub fn downsample_candles(
+    candles: &[Candle],
+    start_index: usize,
+    per_element: usize,
+) -> Vec<Candle> {
+    downsample_runs(candles.len(), start_index, per_element)
+        .into_iter()
+        .map(|run| {
+            let run = &candles[run];
+            let first = &run[0];
+            let last = &run[run.len() - 1];
+            let (low, high, volume) = run.iter().fold(
+                (f64::INFINITY, f64::NEG_INFINITY, 0.0),
+                |(low, high, volume), c| {
+                    (
+                        low.min(c.ohlcv.low.value()),
+                        high.max(c.ohlcv.high.value()),
+                        volume + c.ohlcv.volume.value(),
+                    )
+                },
+            );
+            let mut ohlcv = OHLCV::new(
+                first.ohlcv.open,
+                Price::from(high),
+                Price::from(low),
+                last.ohlcv.close,
+                Volume::from(volume),
+            );
+            ohlcv.taker_buy_volume = sum_taker_buy(run.iter().map(|c| c.ohlcv.taker_buy_volume));
+            Candle::new(first.timestamp, ohlcv)
+        })
+        .collect()
+}
+
+/// 🔺 Highest and lowest prices of a run of candles with the indices reaching them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceExtremes {
+    pub high_index: usize,
+    pub high: f64,
+    pub low_index: usize,
+    pub low: f64,
+}
+
+/// Extremes of `candles`, from closes only when `close_only`; ties keep the earliest candle
+pub fn price_extremes(candles: &[Candle], close_only: bool) -> Option<PriceExtremes> {
+    let first = candles.first()?;
+    let range = |c: &Candle| {
+        if close_only {
+            (c.ohlcv.close.value(), c.ohlcv.close.value())
+        } else {
+            (c.ohlcv.low.value(), c.ohlcv.high.value())
+        }
+    };
+    let (low, high) = range(first);
+    let mut extremes = PriceExtremes { high_index: 0, high, low_index: 0, low };
+    for (i, candle) in candles.iter().enumerate().skip(1) {
+        let (low, high) = range(candle);
+        if high > extremes.high {
+            extremes.high_index = i;
+            extremes.high = high;
+        }
+        if low < extremes.low {
+            extremes.low_index = i;
+            extremes.low = low;
+        }
+    }
+    Some(extremes)
+}
+
+/// NDC thickness of a line `logical_px` CSS pixels wide across `extent_px` device pixels.
+///
+/// The width is rounded to whole device pixels so lines stay crisp on HiDPI
+/// screens, and never drops below one device pixel.
+pub fn line_thickness_ndc(logical_px: f32, extent_px: u32, device_pixel_ratio: f64) -> f32 {
+    let device_px = (logical_px * device_pixel_ratio as f32).round().max(1.0);
+    device_px / extent_px.max(1) as f32 * 2.0
+}
+
+/// Visible moving average: its slot, settings and values aligned to the series
+type MaLine<'a> = (usize, &'a IndicatorLine, Cow<'a, [Price]>);
+
+/// Geometry of a chart with the tail a live tick on its last candle can patch
+type Geometry = (Vec<CandleInstance>, Vec<CandleVertex>, ChartUniforms, Option<TailLayout>);
+
+/// Raw price range of `candles` and of the moving average values drawn over them
+fn raw_price_range(
+    candles: &[Candle],
+    close_line: bool,
+    lines: &[MaLine],
+    start_index: usize,
+) -> (f32, f32) {
+    let mut min_price = f32::INFINITY;
+    let mut max_price = f32::NEG_INFINITY;
+    for candle in candles {
+        let (low, high) = if close_line {
+            (candle.ohlcv.close, candle.ohlcv.close)
+        } else {
+            (candle.ohlcv.low, candle.ohlcv.high)
+        };
+        min_price = min_price.min(low.value() as f32);
+        max_price = max_price.max(high.value() as f32);
+    }
+    for (_, line, values) in lines {
+        for (idx, val) in values.iter().enumerate() {
+            let candle_idx = idx + line.period - 1;
+            if candle_idx < start_index || candle_idx >= start_index + candles.len() {
+                continue;
+            }
+            min_price = min_price.min(val.value() as f32);
+            max_price = max_price.max(val.value() as f32);
+        }
+    }
+    (min_price, max_price)
+}
+
+/// Price window of the candle area: the drawn bounds and the padded scaled range
+/// prices are mapped through
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct PriceWindow {
+    min_price: f32,
+    max_price: f32,
+    scaled_min: f32,
+    scaled_max: f32,
+}
+
+/// ⚡ Where a full build put the geometry that follows the last candle, so a live tick
+/// on that candle regenerates just these vertices and its instance
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct TailLayout {
+    viewport_start: f64,
+    start_index: usize,
+    visible_len: usize,
+    candle_width: f32,
+    line_width: f32,
+    window: PriceWindow,
+    /// Raw price range of the visible candles and lines before the last candle
+    prefix_range: (f32, f32),
+    prefix_max_volume: f32,
+    max_volume: f32,
+    prefix_extremes: Option<PriceExtremes>,
+    move_highlights: usize,
+    last_element: std::ops::Range<usize>,
+    /// First vertex of the last segment of each moving average, `None` for lines with
+    /// less than two visible points, which draw nothing
+    ma_segments: Vec<Option<usize>>,
+    extreme_markers: std::ops::Range<usize>,
+    price_line: usize,
+}
+
+/// Regenerated last candle instance and the vertex runs to overwrite, by first vertex
+pub(super) struct TailPatch {
+    pub(super) instance: CandleInstance,
+    pub(super) vertices: Vec<(usize, Vec<CandleVertex>)>,
 }
 
 impl WebGpuRenderer {
-    /// Convert pixel size to normalized device coordinates
+    /// Convert a vertical size in CSS pixels to normalized device coordinates
     fn px_to_ndc(&self, px: f32) -> f32 {
-        (px / self.height as f32) * 2.0
+        line_thickness_ndc(px, self.height, self.device_pixel_ratio)
+    }
+
+    /// Convert a horizontal size in CSS pixels to normalized device coordinates
+    fn px_to_ndc_x(&self, px: f32) -> f32 {
+        line_thickness_ndc(px, self.width, self.device_pixel_ratio)
+    }
+
+    /// Outline the price pane, the volume pane, the visible candle region and sub-panels
+    fn create_debug_outlines(
+        &self,
+        instances: &[CandleInstance],
+        candle_width: f32,
+        layout: &PanelLayout,
+    ) -> Vec<CandleVertex> {
+        let thickness = self.px_to_ndc(1.0);
+        let main_bottom = layout.main_bottom;
+        let mut vertices =
+            CandleGeometry::create_debug_outline(-1.0, main_bottom, 1.0, 1.0, thickness, 0.0);
+        vertices.extend(CandleGeometry::create_debug_outline(
+            -1.0,
+            main_bottom,
+            1.0,
+            main_bottom + CandleGeometry::VOLUME_HEIGHT * layout.main_height() / 2.0,
+            thickness,
+            1.0,
+        ));
+        for panel in &layout.panels {
+            vertices.extend(CandleGeometry::create_debug_outline(
+                -1.0,
+                panel.bottom,
+                1.0,
+                panel.top,
+                thickness,
+                3.0,
+            ));
+        }
+
+        if let (Some(first), Some(last)) = (instances.first(), instances.last()) {
+            let (low, high) =
+                instances.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), i| {
+                    (lo.min(i.low), hi.max(i.high))
+                });
+            vertices.extend(CandleGeometry::create_debug_outline(
+                first.x - candle_width / 2.0,
+                low,
+                last.x + candle_width / 2.0,
+                high,
+                thickness,
+                2.0,
+            ));
+        }
+
+        vertices
+    }
+
+    /// Oscillator lines, guides and histogram bars for one sub-panel
+    fn create_sub_panel(
+        &self,
+        panel: &SubPanel,
+        candles: &[Candle],
+        start_index: usize,
+        visible_len: usize,
+        candle_width: f32,
+    ) -> Vec<CandleVertex> {
+        let service = MarketAnalysisService::new();
+        let line_width = self.px_to_ndc(2.0);
+        let guide_width = self.px_to_ndc(1.0);
+        let sizing = self.candle_sizing;
+        let mut vertices = Vec::new();
+
+        // Values whose first element belongs to candle `first_candle`, as (x, value) pairs
+        let visible = |values: &[f64], first_candle: usize| -> Vec<(f32, f64)> {
+            values
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, &value)| {
+                    let candle_idx = idx + first_candle;
+                    if candle_idx < start_index || candle_idx >= start_index + visible_len {
+                        return None;
+                    }
+                    Some((sizing.x_position(candle_idx - start_index, visible_len), value))
+                })
+                .collect()
+        };
+        let line = |points: Vec<(f32, f32)>, indicator: IndicatorType, width: f32| {
+            if points.len() < 2 {
+                return Vec::new();
+            }
+            CandleGeometry::create_indicator_line_vertices(&points, indicator, width)
+        };
+
+        match panel.kind {
+            SubPanelKind::Rsi => {
+                let to_y = |v: f64| panel.value_to_y(v, 0.0, 100.0);
+                for level in [RSI_OVERSOLD, RSI_OVERBOUGHT] {
+                    let y = to_y(level);
+                    vertices.extend(line(
+                        vec![(-1.0, y), (1.0, y)],
+                        IndicatorType::PanelGuide,
+                        guide_width,
+                    ));
+                }
+                let rsi = service.calculate_rsi(candles, RSI_PERIOD);
+                let points = visible(&rsi, RSI_PERIOD).into_iter().map(|(x, v)| (x, to_y(v)));
+                vertices.extend(line(points.collect(), IndicatorType::Rsi, line_width));
+            }
+            SubPanelKind::Macd => {
+                let macd = service.calculate_macd(candles, MACD_FAST, MACD_SLOW, MACD_SIGNAL);
+                let first = MACD_SLOW + MACD_SIGNAL - 2;
+                let macd_pts = visible(&macd.macd, first);
+                let signal_pts = visible(&macd.signal, first);
+                let hist_pts = visible(&macd.histogram, first);
+
+                // Symmetric range keeps the zero line in the middle of the panel
+                let max_abs = macd_pts
+                    .iter()
+                    .chain(&signal_pts)
+                    .chain(&hist_pts)
+                    .fold(0.0f64, |acc, (_, v)| acc.max(v.abs()))
+                    .max(f64::EPSILON);
+                let to_y = |v: f64| panel.value_to_y(v, -max_abs, max_abs);
+                let zero_y = to_y(0.0);
+
+                vertices.extend(line(
+                    vec![(-1.0, zero_y), (1.0, zero_y)],
+                    IndicatorType::PanelGuide,
+                    guide_width,
+                ));
+                for (x, value) in &hist_pts {
+                    vertices.extend(CandleGeometry::create_histogram_bar(
+                        *x,
+                        candle_width,
+                        zero_y,
+                        to_y(*value),
+                    ));
+                }
+                let to_points =
+                    |pts: Vec<(f32, f64)>| pts.into_iter().map(|(x, v)| (x, to_y(v))).collect();
+                vertices.extend(line(to_points(macd_pts), IndicatorType::MacdLine, line_width));
+                vertices.extend(line(to_points(signal_pts), IndicatorType::MacdSignal, line_width));
+            }
+            SubPanelKind::Atr => {
+                let atr = visible(&service.calculate_atr(candles, ATR_PERIOD), 0);
+                // Only the visible values scale the panel; the price range ignores ATR
+                let max = atr.iter().fold(0.0f64, |acc, (_, v)| acc.max(*v)).max(f64::EPSILON);
+                let points = atr.into_iter().map(|(x, v)| (x, panel.value_to_y(v, 0.0, max)));
+                vertices.extend(line(points.collect(), IndicatorType::Atr, line_width));
+            }
+            SubPanelKind::Stochastic => {
+                let to_y = |v: f64| panel.value_to_y(v, 0.0, 100.0);
+                for level in [STOCHASTIC_OVERSOLD, STOCHASTIC_OVERBOUGHT] {
+                    let y = to_y(level);
+                    vertices.extend(line(
+                        vec![(-1.0, y), (1.0, y)],
+                        IndicatorType::PanelGuide,
+                        guide_width,
+                    ));
+                }
+                let stoch =
+                    service.calculate_stochastic(candles, STOCHASTIC_K_PERIOD, STOCHASTIC_D_PERIOD);
+                let to_points = |values: &[f64], first: usize| -> Vec<(f32, f32)> {
+                    visible(values, first).into_iter().map(|(x, v)| (x, to_y(v))).collect()
+                };
+                let k_first = STOCHASTIC_K_PERIOD - 1;
+                let d_first = k_first + STOCHASTIC_D_PERIOD - 1;
+                vertices.extend(line(
+                    to_points(&stoch.k, k_first),
+                    IndicatorType::StochasticK,
+                    line_width,
+                ));
+                vertices.extend(line(
+                    to_points(&stoch.d, d_first),
+                    IndicatorType::StochasticD,
+                    line_width,
+                ));
+            }
+        }
+
+        vertices
+    }
+
+    /// Series index of the first visible bar and the bars the chart draws from there
+    pub(super) fn visible_bars(&self, chart: &Chart) -> Option<(usize, Vec<Candle>)> {
+        let series = chart
+            .get_series(self.series_interval())
+            .or_else(|| chart.get_series(TimeInterval::TwoSeconds))?;
+        let candles: Vec<Candle> = series.get_candles().iter().cloned().collect();
+        let (start, count) =
+            crate::app::visible_range_by_time(&candles, &chart.viewport, self.zoom_level);
+        let bars = if chart.chart_type == ChartType::HeikinAshi {
+            MarketAnalysisService::new().calculate_heikin_ashi(&candles)
+        } else {
+            candles
+        };
+        Some((start, bars.into_iter().skip(start).take(count).collect()))
     }
+
+    /// 🕘 Open, high and low of the current session of `chart` while their lines are shown
+    pub fn session_levels(&self, chart: &Chart) -> Option<SessionLevels> {
+        if !self.line_visibility.session_levels {
+            return None;
+        }
+        let series = chart
+            .get_series(self.series_interval())
+            .or_else(|| chart.get_series(TimeInterval::TwoSeconds))?;
+        let candles: Vec<Candle> = series.get_candles().iter().cloned().collect();
+        MarketAnalysisService::new().calculate_session_levels(&candles)
+    }
+
+    /// 🔺 Visible high and low of `chart` with the series indices of their candles
+    pub fn visible_extremes(&self, chart: &Chart) -> Option<PriceExtremes> {
+        let (start, bars) = self.visible_bars(chart)?;
+        let close_only = matches!(chart.chart_type, ChartType::Line | ChartType::Area);
+        price_extremes(&bars, close_only).map(|e| PriceExtremes {
+            high_index: start + e.high_index,
+            low_index: start + e.low_index,
+            ..e
+        })
+    }
+
+    /// Series the chart is drawn from and its interval, the base series when the selected
+    /// one is missing
+    fn drawn_series<'a>(&self, chart: &'a Chart) -> (TimeInterval, &'a CandleSeries) {
+        let interval = self.series_interval();
+        match chart.get_series(interval) {
+            Some(series) => (interval, series),
+            None => (
+                TimeInterval::TwoSeconds,
+                chart.get_series(TimeInterval::TwoSeconds).expect("base series not found"),
+            ),
+        }
+    }
+
+    /// Moving averages: cached by the ECS indicator system or the chart's engine when
+    /// tracked, computed from closes otherwise
+    fn ma_lines<'a>(
+        &'a self,
+        chart: &'a Chart,
+        series_interval: TimeInterval,
+        series: &CandleSeries,
+        closes: &[f64],
+    ) -> Vec<MaLine<'a>> {
+        let cache = self
+            .indicator_cache
+            .as_ref()
+            .filter(|cache| cache.is_current(&chart.id, series_interval, series));
+        let interval = self.series_interval();
+        let engine = chart
+            .ma_engines
+            .get(&interval)
+            .or_else(|| chart.ma_engines.get(&TimeInterval::TwoSeconds))
+            .expect("engine not found");
+        self.line_visibility
+            .lines
+            .iter()
+            .enumerate()
+            .take(MAX_INDICATOR_LINES)
+            .filter(|(_, line)| line.visible && line.period > 0)
+            .map(|(slot, line)| {
+                let cached = cache.and_then(|cache| cache.values(line.kind, line.period));
+                let values = match cached.or_else(|| engine.values(line.kind, line.period)) {
+                    Some(values) => Cow::Borrowed(values),
+                    None => Cow::Owned(moving_average(line.kind, line.period, closes)),
+                };
+                (slot, line, values)
+            })
+            .collect()
+    }
+
+    /// Padded window around the raw range `min_price..max_price`; a box zoom overrides
+    /// the drawn bounds
+    fn price_window(&self, min_price: f32, max_price: f32) -> PriceWindow {
+        // Pad in scaled space so log mode keeps symmetric margins
+        let scale = self.price_scale_mode;
+        let (scaled_min, scaled_max) = self.price_padding.apply(
+            scale.to_scale(min_price as f64) as f32,
+            scale.to_scale(max_price as f64) as f32,
+        );
+        let (min_price, max_price) = match self.price_range {
+            // 🔲 A box zoom overrides the fitted window
+            Some((min, max)) => (min as f32, max as f32),
+            None => (
+                scale.from_scale(scaled_min as f64) as f32,
+                scale.from_scale(scaled_max as f64) as f32,
+            ),
+        };
+        PriceWindow { min_price, max_price, scaled_min, scaled_max }
+    }
+
+    /// Height of `price` in the candle area drawn in `window`
+    fn price_normalizer<'a>(
+        &'a self,
+        window: PriceWindow,
+        layout: &'a PanelLayout,
+    ) -> impl Fn(f64) -> f32 + 'a {
+        let scaled_range = window.scaled_max - window.scaled_min;
+        move |price: f64| -> f32 {
+            let normalized =
+                (self.price_scale_mode.to_scale(price) as f32 - window.scaled_min) / scaled_range;
+            layout.main_y(if self.invert_y { 1.0 - normalized } else { normalized })
+        }
+    }
+
+    /// Volume, delta and outline vertices of one drawn element, with its candle instance
+    /// unless the chart traces closes
+    #[allow(clippy::too_many_arguments)]
+    fn element_geometry(
+        &self,
+        candle: &Candle,
+        x: f32,
+        candle_width: f32,
+        max_volume: f32,
+        layout: &PanelLayout,
+        price_norm: &impl Fn(f64) -> f32,
+        close_line: bool,
+    ) -> (Vec<CandleVertex>, Option<CandleInstance>) {
+        let volume_height = CandleGeometry::VOLUME_HEIGHT * layout.main_height() / 2.0;
+        let open_y = price_norm(candle.ohlcv.open.value());
+        let high_y = price_norm(candle.ohlcv.high.value());
+        let low_y = price_norm(candle.ohlcv.low.value());
+        let close_y = price_norm(candle.ohlcv.close.value());
+
+        let body_top = open_y.max(close_y);
+        let body_bottom = open_y.min(close_y);
+
+        // Minimum height for visibility
+        let min_height = 0.005;
+        let actual_body_top = if (body_top - body_bottom).abs() < min_height {
+            body_bottom + min_height
+        } else {
+            body_top
+        };
+
+        let is_bullish = candle.ohlcv.close.value() >= candle.ohlcv.open.value();
+
+        let vol_ratio = (candle.ohlcv.volume.value() as f32) / max_volume;
+        let mut vertices = CandleGeometry::create_volume_vertices_in(
+            x,
+            candle_width,
+            vol_ratio,
+            is_bullish,
+            layout.main_bottom,
+            volume_height,
+        );
+
+        // ⚖️ Taker buy/sell imbalance as a thin bar over the volume bar
+        if self.line_visibility.delta_volume
+            && let Some(delta) = candle.ohlcv.delta_volume()
+        {
+            vertices.extend(CandleGeometry::create_delta_bar(
+                x,
+                candle_width * DELTA_BAR_WIDTH_RATIO,
+                delta.abs() as f32 / max_volume,
+                delta >= 0.0,
+                layout.main_bottom,
+                volume_height,
+            ));
+        }
+
+        if close_line {
+            return (vertices, None);
+        }
+
+        if let Some(outline) = &self.candle_outline {
+            vertices.extend(CandleGeometry::create_candle_outline(
+                x,
+                candle_width,
+                actual_body_top,
+                body_bottom,
+                self.px_to_ndc(outline.width_px),
+            ));
+        }
+
+        let instance = CandleInstance {
+            x,
+            width: candle_width,
+            body_top: actual_body_top,
+            body_bottom,
+            // Wicks run to the upper and lower edges, whichever way the axis points
+            high: high_y.max(low_y),
+            low: low_y.min(high_y),
+            bullish: if is_bullish { 1.0 } else { 0.0 },
+            _padding: 0.0,
+        };
+        (vertices, Some(instance))
+    }
+
+    /// ⚡ Faint bands behind the candles with the largest close-to-close moves. Both are
+    /// always emitted, collapsed when hidden or shared, so a live tick that moves them
+    /// keeps the vertex layout and the partial update path
+    fn move_highlights(
+        &self,
+        series: &CandleSeries,
+        start_index: usize,
+        visible_len: usize,
+        candle_width: f32,
+        bottom: f32,
+    ) -> Vec<CandleVertex> {
+        let moves = series.largest_moves();
+        let percent = moves.percent.filter(|&index| moves.absolute != Some(index));
+        [moves.absolute, percent]
+            .into_iter()
+            .flat_map(|index| {
+                let visible = index
+                    .and_then(|index| index.checked_sub(start_index))
+                    .filter(|&i| i < visible_len);
+                let (x, width) = match visible {
+                    Some(i) => (self.candle_sizing.x_position(i, visible_len), candle_width),
+                    None => (0.0, 0.0),
+                };
+                CandleGeometry::create_move_highlight(x, width, bottom)
+            })
+            .collect()
+    }
+
+    /// Solid line across the chart at the current price
+    fn current_price_line(
+        &self,
+        last_close: f64,
+        price_norm: &impl Fn(f64) -> f32,
+    ) -> Vec<CandleVertex> {
+        // Additional canvases show their own symbol's last close
+        let current_price = if self.primary {
+            crate::app::global_current_price().get_untracked()
+        } else {
+            last_close
+        };
+        let price_y = price_norm(current_price); // same area as candles
+
+        // Keep the line width constant regardless of zoom level
+        let line_thickness = self.px_to_ndc(1.0);
+
+        vec![
+            CandleVertex::current_price_vertex(-1.0, price_y - line_thickness),
+            CandleVertex::current_price_vertex(1.0, price_y - line_thickness),
+            CandleVertex::current_price_vertex(-1.0, price_y + line_thickness),
+            CandleVertex::current_price_vertex(1.0, price_y - line_thickness),
+            CandleVertex::current_price_vertex(1.0, price_y + line_thickness),
+            CandleVertex::current_price_vertex(-1.0, price_y + line_thickness),
+        ]
+    }
+
+    /// 🔺 Markers pointing at the visible high from above and the visible low from below
+    fn extreme_markers(
+        &self,
+        extremes: PriceExtremes,
+        visible_len: usize,
+        candle_width: f32,
+        price_norm: &impl Fn(f64) -> f32,
+    ) -> Vec<CandleVertex> {
+        let half_width = (candle_width * 0.5).max(self.px_to_ndc_x(4.0));
+        // On an inverted axis the high sits at the bottom, so the markers flip too
+        let side = if self.invert_y { -1.0 } else { 1.0 };
+        let (gap, height) = (self.px_to_ndc(4.0) * side, self.px_to_ndc(6.0) * side);
+        let mut vertices = CandleGeometry::create_extreme_marker(
+            self.candle_sizing.x_position(extremes.high_index, visible_len),
+            price_norm(extremes.high) + gap,
+            half_width,
+            height,
+            true,
+        );
+        vertices.extend(CandleGeometry::create_extreme_marker(
+            self.candle_sizing.x_position(extremes.low_index, visible_len),
+            price_norm(extremes.low) - gap,
+            half_width,
+            height,
+            false,
+        ));
+        vertices
+    }
+
+    /// Whether everything drawn from the last candle has a fixed place in the vertex
+    /// list: candles with moving averages only, no panels, overlays or debug outlines
+    fn tail_patchable(&self, chart: &Chart) -> bool {
+        let v = &self.line_visibility;
+        let extra_lines = v.bb
+            || v.donchian
+            || v.keltner
+            || v.vwap
+            || v.vwma
+            || v.psar
+            || v.volume_ma
+            || v.volume_profile
+            || v.session_levels
+            || v.tenkan
+            || v.kijun
+            || v.chikou;
+        chart.chart_type == ChartType::Candlestick
+            && !extra_lines
+            && v.sub_panels().is_empty()
+            && self.compare_overlay.is_empty()
+            && chart.ichimoku.senkou_span_a.is_empty()
+            && !self.debug_mode
+    }
+
+    /// ⚡ Regenerate the geometry `tail` recorded after a tick on the last candle of
+    /// `chart`. `None` when the tick moves the price window or the volume scale, or the
+    /// layout no longer fits, so the chart needs a full build instead.
+    pub(super) fn patch_tail(&self, chart: &Chart, tail: &TailLayout) -> Option<TailPatch> {
+        let (series_interval, series) = self.drawn_series(chart);
+        let candles = series.get_candles();
+        let last = candles.back()?;
+        let len = tail.visible_len;
+        if candles.len() != tail.start_index + len
+            || chart.viewport.start_time.to_bits() != tail.viewport_start.to_bits()
+        {
+            return None;
+        }
+
+        let closes: Vec<f64> = candles.iter().map(|c| c.ohlcv.close.value()).collect();
+        let ma_lines = self.ma_lines(chart, series_interval, series, &closes);
+        if ma_lines.len() != tail.ma_segments.len() {
+            return None;
+        }
+        // Each line's value on the last candle and the one before it; lines whose window
+        // is not complete yet have none
+        let mut ma_tails = Vec::with_capacity(ma_lines.len());
+        for ((slot, line, values), &segment) in ma_lines.iter().zip(&tail.ma_segments) {
+            let Some(last_value) = values.last() else { continue };
+            if values.len() + line.period - 1 != candles.len() {
+                return None;
+            }
+            let prev = values.len().checked_sub(2).map(|i| values[i].value());
+            ma_tails.push((*slot, segment, prev, last_value.value()));
+        }
+
+        // The tick may only stay inside the window and under the volume peak
+        let (mut min_price, mut max_price) = tail.prefix_range;
+        min_price = min_price.min(last.ohlcv.low.value() as f32);
+        max_price = max_price.max(last.ohlcv.high.value() as f32);
+        if !self.clip_indicators {
+            for &(_, _, _, value) in &ma_tails {
+                min_price = min_price.min(value as f32);
+                max_price = max_price.max(value as f32);
+            }
+        }
+        let mut max_volume = tail.prefix_max_volume.max(last.ohlcv.volume.value() as f32);
+        if max_volume <= 0.0 {
+            max_volume = 1.0;
+        }
+        if self.price_window(min_price, max_price) != tail.window || max_volume != tail.max_volume {
+            return None;
+        }
+
+        let layout = self.panel_layout();
+        let price_norm = self.price_normalizer(tail.window, &layout);
+        let sizing = self.candle_sizing;
+        let x = sizing.x_position(len - 1, len);
+        let (element, instance) = self.element_geometry(
+            last,
+            x,
+            tail.candle_width,
+            max_volume,
+            &layout,
+            &price_norm,
+            false,
+        );
+        if element.len() != tail.last_element.len() {
+            return None;
+        }
+
+        let mut vertices = vec![
+            (
+                tail.move_highlights,
+                self.move_highlights(
+                    series,
+                    tail.start_index,
+                    len,
+                    tail.candle_width,
+                    layout.main_bottom,
+                ),
+            ),
+            (tail.last_element.start, element),
+        ];
+        let segment_x = (sizing.x_position(len - 2, len), x);
+        for (slot, segment, prev, last_value) in ma_tails {
+            let (Some(at), Some(prev)) = (segment, prev) else { continue };
+            let points = [(segment_x.0, price_norm(prev)), (segment_x.1, price_norm(last_value))];
+            vertices.push((
+                at,
+                CandleGeometry::create_indicator_line_vertices(
+                    &points,
+                    IndicatorType::Slot(slot as u8),
+                    tail.line_width,
+                ),
+            ));
+        }
+
+        let (low, high) = (last.ohlcv.low.value(), last.ohlcv.high.value());
+        let extremes = tail.prefix_extremes.map(|mut extremes| {
+            if high > extremes.high {
+                (extremes.high_index, extremes.high) = (len - 1, high);
+            }
+            if low < extremes.low {
+                (extremes.low_index, extremes.low) = (len - 1, low);
+            }
+            extremes
+        })?;
+        vertices.push((
+            tail.extreme_markers.start,
+            self.extreme_markers(extremes, len, tail.candle_width, &price_norm),
+        ));
+        vertices.push((
+            tail.price_line,
+            self.current_price_line(last.ohlcv.close.value(), &price_norm),
+        ));
+
+        Some(TailPatch { instance: instance?, vertices })
+    }
+
     pub(super) fn create_geometry(
         &self,
         chart: &Chart,
     ) -> (Vec<CandleInstance>, Vec<CandleVertex>, ChartUniforms) {
-        use crate::app::current_interval;
+        let (instances, vertices, uniforms, _) = self.build_geometry(chart);
+        (instances, vertices, uniforms)
+    }
 
-        let interval = current_interval().get_untracked();
-        let candles = chart.get_series(interval).map(|s| s.get_candles()).unwrap_or_else(|| {
-            chart.get_series(TimeInterval::TwoSeconds).expect("base series not found").get_candles()
-        });
+    /// Full chart geometry, with the layout of its tail when a live tick on the last
+    /// candle can patch it
+    pub(super) fn build_geometry(&self, chart: &Chart) -> Geometry {
+        let sizing = self.candle_sizing;
+        let (series_interval, series) = self.drawn_series(chart);
+        let candles = series.get_candles();
 
         if candles.is_empty() {
             get_logger()
                 .error(LogComponent::Infrastructure("WebGpuRenderer"), "⚠️ No candles to render");
 
-            return (Vec::new(), Vec::new(), ChartUniforms::new());
+            return (Vec::new(), Vec::new(), ChartUniforms::new(), None);
         }
 
         // ⚡ Performance: log less frequently
-        if candles.len() % 100 == 0 {
+        if candles.len().is_multiple_of(100) {
             get_logger().info(
                 LogComponent::Infrastructure("WebGpuRenderer"),
                 &format!("🔧 Creating optimized geometry for {} candles", candles.len()),
@@ -70,62 +1006,204 @@ impl WebGpuRenderer {
         let candle_vec: Vec<Candle> = candles.iter().cloned().collect();
         let (start_index, visible_count) =
             crate::app::visible_range_by_time(&candle_vec, &chart.viewport, self.zoom_level);
+
+        // 🕯️ Heikin-Ashi bars are derived from the whole series so the first visible bar is
+        // seeded correctly; indicators keep using the raw closes
+        let bar_candles: Cow<[Candle]> = if chart.chart_type == ChartType::HeikinAshi {
+            Cow::Owned(MarketAnalysisService::new().calculate_heikin_ashi(&candle_vec))
+        } else {
+            Cow::Borrowed(&candle_vec)
+        };
         let visible_candles: Vec<Candle> =
-            candle_vec.iter().skip(start_index).take(visible_count).cloned().collect();
+            bar_candles.iter().skip(start_index).take(visible_count).cloned().collect();
 
         let mut vertices = Vec::with_capacity(visible_candles.len() * 24);
 
-        // Moving averages precomputed in chart's indicator engines
-        let engine = chart
-            .ma_engines
-            .get(&interval)
-            .or_else(|| chart.ma_engines.get(&TimeInterval::TwoSeconds))
-            .expect("engine not found");
-        let mas = engine.data();
+        let closes: Vec<f64> = candle_vec.iter().map(|c| c.ohlcv.close.value()).collect();
+        let ma_lines = self.ma_lines(chart, series_interval, series, &closes);
 
-        // Scale candles based on currently visible data and indicator values
-        let mut min_price = f32::INFINITY;
-        let mut max_price = f32::NEG_INFINITY;
-        for candle in &visible_candles {
-            min_price = min_price.min(candle.ohlcv.low.value() as f32);
-            max_price = max_price.max(candle.ohlcv.high.value() as f32);
-        }
+        let bollinger = self.line_visibility.bb.then(|| {
+            MarketAnalysisService::new().calculate_bollinger_bands(
+                &candle_vec,
+                BOLLINGER_PERIOD,
+                BOLLINGER_STD_DEV,
+            )
+        });
 
-        let mut consider_ma = |values: &[Price], period: usize| {
-            for (idx, val) in values.iter().enumerate() {
-                let candle_idx = idx + period - 1;
-                if candle_idx < start_index || candle_idx >= start_index + visible_candles.len() {
-                    continue;
-                }
+        // Donchian lines as prices so they share the SMA-aligned point mapping
+        let donchian: Vec<(IndicatorType, Vec<Price>)> = self
+            .line_visibility
+            .donchian
+            .then(|| MarketAnalysisService::new().calculate_donchian(&candle_vec, DONCHIAN_PERIOD))
+            .map(|channels| {
+                [
+                    (IndicatorType::DonchianUpper, channels.upper),
+                    (IndicatorType::DonchianMiddle, channels.middle),
+                    (IndicatorType::DonchianLower, channels.lower),
+                ]
+                .into_iter()
+                .map(|(kind, values)| (kind, values.into_iter().map(Price::from).collect()))
+                .collect()
+            })
+            .unwrap_or_default();
+
+        let keltner: Vec<(IndicatorType, Vec<Price>)> = self
+            .line_visibility
+            .keltner
+            .then(|| {
+                MarketAnalysisService::new().calculate_keltner(
+                    &candle_vec,
+                    KELTNER_EMA_PERIOD,
+                    KELTNER_ATR_PERIOD,
+                    KELTNER_MULTIPLIER,
+                )
+            })
+            .map(|channels| {
+                [
+                    (IndicatorType::KeltnerUpper, channels.upper),
+                    (IndicatorType::KeltnerMiddle, channels.middle),
+                    (IndicatorType::KeltnerLower, channels.lower),
+                ]
+                .into_iter()
+                .map(|(kind, values)| (kind, values.into_iter().map(Price::from).collect()))
+                .collect()
+            })
+            .unwrap_or_default();
+
+        // 🆚 Overlaid symbol rebased at the first visible shared candle and drawn on the
+        // price axis at the matching percent change from that candle's close
+        let raw_visible = &candle_vec[start_index..start_index + visible_candles.len()];
+        let overlay_points = rebase_on_timestamps(raw_visible, &self.compare_overlay);
+        let overlay: Vec<(usize, f64)> = overlay_points
+            .first()
+            .map(|first| raw_visible[first.index].ohlcv.close.value())
+            .map(|base| {
+                overlay_points
+                    .iter()
+                    .map(|p| (p.index, base * (1.0 + p.secondary / 100.0)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let vwap = self
+            .line_visibility
+            .vwap
+            .then(|| MarketAnalysisService::new().calculate_vwap(&candle_vec));
+        let vwma = self
+            .line_visibility
+            .vwma
+            .then(|| MarketAnalysisService::new().calculate_vwma(&candle_vec, VWMA_PERIOD));
+        let psar = self.line_visibility.psar.then(|| {
+            MarketAnalysisService::new().calculate_psar(&candle_vec, PSAR_STEP, PSAR_MAX_STEP)
+        });
+        let volume_ma = self.line_visibility.volume_ma.then(|| {
+            MarketAnalysisService::new().calculate_volume_sma(&candle_vec, self.volume_ma_period)
+        });
+
+        // ☁️ Ichimoku lines with the candle offset of their first value; Chikou values
+        // belong `CHIKOU_SHIFT` candles before the close they repeat
+        let visibility = &self.line_visibility;
+        let service = MarketAnalysisService::new();
+        let ichimoku_lines: Vec<(IndicatorType, Vec<Price>, usize)> = [
+            (visibility.tenkan, IndicatorType::Tenkan, TENKAN_PERIOD),
+            (visibility.kijun, IndicatorType::Kijun, KIJUN_PERIOD),
+        ]
+        .into_iter()
+        .filter(|(visible, _, _)| *visible)
+        .map(|(_, kind, period)| {
+            (kind, service.calculate_tenkan_sen(&candle_vec, period), period - 1)
+        })
+        .chain(visibility.chikou.then(|| {
+            (IndicatorType::Chikou, service.calculate_chikou_span(&candle_vec, CHIKOU_SHIFT), 0)
+        }))
+        .collect();
+
+        // 📈 Line and area charts trace the closes instead of drawing candles
+        let close_line = matches!(chart.chart_type, ChartType::Line | ChartType::Area);
+
+        // ⚡ Only the last candle of a fully visible series can be patched by a live tick
+        let patchable = self.tail_patchable(chart)
+            && visible_candles.len() >= 2
+            && start_index + visible_candles.len() == candles.len();
+
+        // Scale candles based on currently visible data and indicator values.
+        // When clipping, lines leaving the range are cut off by the pane edge instead.
+        let fit_lines: &[_] = if self.clip_indicators { &[] } else { &ma_lines };
+        let (mut min_price, mut max_price) =
+            raw_price_range(&visible_candles, close_line, fit_lines, start_index);
+        let prefix_range = patchable.then(|| {
+            let prefix = &visible_candles[..visible_candles.len() - 1];
+            raw_price_range(prefix, close_line, fit_lines, start_index)
+        });
+        let fit_ichimoku: &[_] = if self.clip_indicators { &[] } else { &ichimoku_lines };
+        for (_, values, first) in fit_ichimoku {
+            let last_visible = (start_index + visible_candles.len()).saturating_sub(*first);
+            for val in values.iter().take(last_visible).skip(start_index.saturating_sub(*first)) {
                 min_price = min_price.min(val.value() as f32);
                 max_price = max_price.max(val.value() as f32);
             }
-        };
-
-        if self.line_visibility.sma_20 {
-            consider_ma(&mas.sma_20, 20);
         }
-        if self.line_visibility.sma_50 {
-            consider_ma(&mas.sma_50, 50);
+        if let Some(vwap) = vwap.as_ref().filter(|_| !self.clip_indicators) {
+            for val in vwap.iter().skip(start_index).take(visible_candles.len()) {
+                min_price = min_price.min(val.value() as f32);
+                max_price = max_price.max(val.value() as f32);
+            }
         }
-        if self.line_visibility.sma_200 {
-            consider_ma(&mas.sma_200, 200);
+        if let Some(psar) = psar.as_ref().filter(|_| !self.clip_indicators) {
+            for &val in psar.sar.iter().skip(start_index).take(visible_candles.len()) {
+                min_price = min_price.min(val as f32);
+                max_price = max_price.max(val as f32);
+            }
         }
-        if self.line_visibility.ema_12 {
-            consider_ma(&mas.ema_12, 12);
+        if let Some(vwma) = vwma.as_ref().filter(|_| !self.clip_indicators) {
+            let first_visible = start_index.saturating_sub(VWMA_PERIOD - 1);
+            let last_visible =
+                (start_index + visible_candles.len()).saturating_sub(VWMA_PERIOD - 1);
+            for val in vwma.iter().take(last_visible).skip(first_visible) {
+                min_price = min_price.min(val.value() as f32);
+                max_price = max_price.max(val.value() as f32);
+            }
         }
-        if self.line_visibility.ema_26 {
-            consider_ma(&mas.ema_26, 26);
+        if let Some(bands) = bollinger.as_ref().filter(|_| !self.clip_indicators) {
+            let first_visible = start_index.saturating_sub(BOLLINGER_PERIOD - 1);
+            let last_visible =
+                (start_index + visible_candles.len()).saturating_sub(BOLLINGER_PERIOD - 1);
+            for band in [&bands.upper, &bands.lower] {
+                for val in band.iter().take(last_visible).skip(first_visible) {
+                    min_price = min_price.min(val.value() as f32);
+                    max_price = max_price.max(val.value() as f32);
+                }
+            }
+        }
+        if !self.clip_indicators {
+            for &(_, price) in &overlay {
+                min_price = min_price.min(price as f32);
+                max_price = max_price.max(price as f32);
+            }
+        }
+        let fit_channels: &[_] = if self.clip_indicators {
+            &[]
+        } else {
+            &[(&donchian, DONCHIAN_PERIOD), (&keltner, KELTNER_EMA_PERIOD)]
+        };
+        for &(lines, period) in fit_channels {
+            let first_visible = start_index.saturating_sub(period - 1);
+            let last_visible = (start_index + visible_candles.len()).saturating_sub(period - 1);
+            for (_, values) in lines {
+                for val in values.iter().take(last_visible).skip(first_visible) {
+                    min_price = min_price.min(val.value() as f32);
+                    max_price = max_price.max(val.value() as f32);
+                }
+            }
         }
 
-        let price_range = (max_price - min_price).abs().max(1e-6);
-        min_price -= price_range * 0.05;
-        max_price += price_range * 0.05;
+        let scale = self.price_scale_mode;
+        let window = self.price_window(min_price, max_price);
+        let PriceWindow { min_price, max_price, .. } = window;
 
         // Log estimated candle width using the number of visible candles
         let step_size = chart_width / visible_candles.len() as f64;
-        let candle_width_estimate =
-            step_size * (1.0 - spacing_ratio_for(visible_candles.len()) as f64);
+        let candle_width_estimate = sizing.element_width(visible_candles.len()) as f64;
 
         get_logger().info(
             LogComponent::Infrastructure("WebGpuRenderer"),
@@ -139,11 +1217,11 @@ impl WebGpuRenderer {
         if (max_price - min_price).abs() < 0.01 {
             get_logger()
                 .error(LogComponent::Infrastructure("WebGpuRenderer"), "❌ Invalid price range!");
-            return (Vec::new(), Vec::new(), ChartUniforms::new());
+            return (Vec::new(), Vec::new(), ChartUniforms::new(), None);
         }
 
         // Log less often for performance
-        if visible_candles.len() % 50 == 0 {
+        if visible_candles.len().is_multiple_of(50) {
             get_logger().info(
                 LogComponent::Infrastructure("WebGpuRenderer"),
                 &format!(
@@ -156,37 +1234,82 @@ impl WebGpuRenderer {
             );
         }
 
-        // Create instance data for each visible candle
+        // 🗜️ With more candles than pixel columns, draw one summary per run of candles
+        let per_element = candles_per_element(visible_candles.len(), self.width as usize);
+        let runs = downsample_runs(visible_candles.len(), start_index, per_element);
+        let elements: Cow<[Candle]> = if per_element > 1 {
+            Cow::Owned(downsample_candles(&visible_candles, start_index, per_element))
+        } else {
+            Cow::Borrowed(&visible_candles)
+        };
+
+        // Create instance data for each drawn element
         let step_size = 2.0 / visible_candles.len() as f32;
-        let spacing = spacing_ratio_for(visible_candles.len());
-        let candle_width =
-            (step_size * (1.0 - spacing)).clamp(MIN_ELEMENT_WIDTH, MAX_ELEMENT_WIDTH);
-        let mut instances = Vec::with_capacity(visible_candles.len());
+        let spacing = sizing.spacing_ratio_for(visible_candles.len());
+        let candle_width = sizing.width_for_step(step_size * per_element as f32, spacing);
+        // Oscillator histograms keep one bar per candle
+        let bar_width = sizing.width_for_step(step_size, spacing);
+        let mut instances = Vec::with_capacity(elements.len());
 
-        let price_range = max_price - min_price;
-        let price_norm = |price: f64| -> f32 {
-            let normalized = (price as f32 - min_price) / price_range;
-            normalized * 2.0 - 1.0
-        };
+        // Candles and volume share the area above the oscillator panels
+        let layout = self.panel_layout();
+        let price_norm = self.price_normalizer(window, &layout);
+        let volume_height = CandleGeometry::VOLUME_HEIGHT * layout.main_height() / 2.0;
 
         let mut max_volume = 0.0f32;
-        for c in &visible_candles {
+        for c in elements.iter().take(elements.len() - 1) {
             max_volume = max_volume.max(c.ohlcv.volume.value() as f32);
         }
+        let prefix_max_volume = max_volume;
+        if let Some(last) = elements.last() {
+            max_volume = max_volume.max(last.ohlcv.volume.value() as f32);
+        }
         if max_volume <= 0.0 {
             max_volume = 1.0;
         }
 
-        for (i, candle) in visible_candles.iter().enumerate() {
-            let x = candle_x_position(i, visible_candles.len());
+        // #️⃣ Grid leads the vertex list so it is drawn behind the candles; its price rows
+        // use the same levels as the price axis labels
+        if self.grid_visible {
+            let (columns, rows) = self.grid_divisions;
+            let xs: Vec<f32> =
+                (0..=columns).map(|i| i as f32 / columns as f32 * 2.0 - 1.0).collect();
+            let ys: Vec<f32> =
+                crate::app::price_levels_between(min_price as f64, max_price as f64, scale, rows)
+                    .into_iter()
+                    .map(&price_norm)
+                    .collect();
+            vertices.extend(CandleGeometry::create_grid_lines(
+                &xs,
+                &ys,
+                layout.main_bottom,
+                self.px_to_ndc_x(1.0),
+                self.px_to_ndc(1.0),
+            ));
+        }
 
-            let open_y = price_norm(candle.ohlcv.open.value());
-            let high_y = price_norm(candle.ohlcv.high.value());
-            let low_y = price_norm(candle.ohlcv.low.value());
-            let close_y = price_norm(candle.ohlcv.close.value());
+        let move_highlights_at = vertices.len();
+        vertices.extend(self.move_highlights(
+            series,
+            start_index,
+            visible_candles.len(),
+            candle_width,
+            layout.main_bottom,
+        ));
+
+        let mut last_element = 0..0;
+        for (i, (candle, run)) in elements.iter().zip(&runs).enumerate() {
+            // Centered on the candles it covers, so indicators stay aligned
+            let (first, last) = (run.start, run.end - 1);
+            let x = (sizing.x_position(first, visible_candles.len())
+                + sizing.x_position(last, visible_candles.len()))
+                / 2.0;
 
             // Log only the first 3 and last 3 candles
-            if i < 3 || i >= visible_candles.len() - 3 {
+            if i < 3 || i >= elements.len() - 3 {
+                let [open_y, high_y, low_y, close_y] =
+                    [candle.ohlcv.open, candle.ohlcv.high, candle.ohlcv.low, candle.ohlcv.close]
+                        .map(|price| price_norm(price.value()));
                 get_logger().info(
                     LogComponent::Infrastructure("WebGpuRenderer"),
                     &format!(
@@ -196,49 +1319,80 @@ impl WebGpuRenderer {
                 );
             }
 
-            let body_top = open_y.max(close_y);
-            let body_bottom = open_y.min(close_y);
+            let (element, instance) = self.element_geometry(
+                candle,
+                x,
+                candle_width,
+                max_volume,
+                &layout,
+                &price_norm,
+                close_line,
+            );
+            last_element = vertices.len()..vertices.len() + element.len();
+            vertices.extend(element);
+            instances.extend(instance);
+        }
 
-            // Minimum height for visibility
-            let min_height = 0.005;
-            let actual_body_top = if (body_top - body_bottom).abs() < min_height {
-                body_bottom + min_height
-            } else {
-                body_top
-            };
+        let line_width = self.px_to_ndc(2.0);
 
-            let is_bullish = close_y >= open_y;
+        // 📊 Volume average on the bars' scale; it starts once its first window is complete.
+        // Downsampled bars sum their candles, so the average is scaled to match.
+        if let Some(volume_ma) = &volume_ma {
+            let period = self.volume_ma_period;
+            let points: Vec<(f32, f32)> = volume_ma
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, avg)| {
+                    let visible = (idx + period - 1)
+                        .checked_sub(start_index)
+                        .filter(|&i| i < visible_candles.len())?;
+                    let ratio = (avg.value() * per_element as f64) as f32 / max_volume;
+                    Some((
+                        sizing.x_position(visible, visible_candles.len()),
+                        layout.main_bottom + ratio.clamp(0.0, 1.0) * volume_height,
+                    ))
+                })
+                .collect();
+            vertices.extend(CandleGeometry::create_volume_ma_line(&points, line_width));
+        }
 
-            instances.push(CandleInstance {
-                x,
-                width: candle_width,
-                body_top: actual_body_top,
-                body_bottom,
-                high: high_y,
-                low: low_y,
-                bullish: if is_bullish { 1.0 } else { 0.0 },
-                _padding: 0.0,
-            });
+        if close_line {
+            let points: Vec<(f32, f32)> = visible_candles
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    (sizing.x_position(i, visible_candles.len()), price_norm(c.ohlcv.close.value()))
+                })
+                .collect();
+            if chart.chart_type == ChartType::Area {
+                vertices.extend(CandleGeometry::create_area_fill(&points, layout.main_bottom));
+            }
+            vertices.extend(CandleGeometry::create_indicator_line_vertices(
+                &points,
+                IndicatorType::Close,
+                line_width,
+            ));
+        }
 
-            let candle_vertices = CandleGeometry::create_candle_vertices(
-                candle.timestamp.as_f64(),
-                candle.ohlcv.open.value() as f32,
-                candle.ohlcv.high.value() as f32,
-                candle.ohlcv.low.value() as f32,
-                candle.ohlcv.close.value() as f32,
+        // ⏸️ Separators between visible candles around missing bars
+        let separator_width = self.px_to_ndc_x(1.0);
+        for gap in series.detect_gaps(series_interval, self.gap_threshold) {
+            let (Some(prev), Some(next)) =
+                (gap.start.checked_sub(start_index), gap.end.checked_sub(start_index))
+            else {
+                continue;
+            };
+            if next >= visible_candles.len() {
+                continue;
+            }
+            let x = (sizing.x_position(prev, visible_candles.len())
+                + sizing.x_position(next, visible_candles.len()))
+                / 2.0;
+            vertices.extend(CandleGeometry::create_gap_separator(
                 x,
-                open_y,
-                high_y,
-                low_y,
-                close_y,
-                candle_width,
-            );
-            vertices.extend_from_slice(&candle_vertices);
-
-            let vol_ratio = (candle.ohlcv.volume.value() as f32) / max_volume;
-            let volume_vertices =
-                CandleGeometry::create_volume_vertices(x, candle_width, vol_ratio, is_bullish);
-            vertices.extend_from_slice(&volume_vertices);
+                layout.main_bottom,
+                separator_width,
+            ));
         }
 
         let to_points = |values: &[Price], period: usize| -> Vec<(f32, f32)> {
@@ -251,134 +1405,218 @@ impl WebGpuRenderer {
                     {
                         return None;
                     }
-                    let x = candle_x_position(candle_idx - start_index, visible_candles.len());
+                    let x = sizing.x_position(candle_idx - start_index, visible_candles.len());
                     let y = price_norm(val.value());
                     Some((x, y))
                 })
                 .collect()
         };
 
-        let line_width = self.px_to_ndc(2.0);
-
-        if self.line_visibility.sma_20 {
-            let points = to_points(&mas.sma_20, 20);
+        let mut ma_segments = Vec::with_capacity(ma_lines.len());
+        for (slot, line, values) in &ma_lines {
+            let points = to_points(values, line.period);
             log_info!(
                 LogComponent::Infrastructure("WebGpuRenderer"),
-                "SMA20 points: {}",
+                "{} points: {}",
+                line.name().to_uppercase(),
                 points.len()
             );
             if points.len() < 2 {
                 log_warn!(
                     LogComponent::Infrastructure("WebGpuRenderer"),
-                    "Not enough points for SMA20"
+                    "Not enough points for {}",
+                    line.name().to_uppercase()
                 );
             }
+            // Every segment is a quad of its own, so the last one can be rewritten alone
+            ma_segments.push(points.len().checked_sub(2).map(|last| vertices.len() + last * 6));
             vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
                 &points,
-                IndicatorType::SMA20,
+                IndicatorType::Slot(*slot as u8),
                 line_width,
             ));
         }
 
-        if self.line_visibility.sma_50 {
-            let points = to_points(&mas.sma_50, 50);
-            log_info!(
-                LogComponent::Infrastructure("WebGpuRenderer"),
-                "SMA50 points: {}",
-                points.len()
-            );
-            if points.len() < 2 {
-                log_warn!(
-                    LogComponent::Infrastructure("WebGpuRenderer"),
-                    "Not enough points for SMA50"
-                );
-            }
-            vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
-                &points,
-                IndicatorType::SMA50,
-                line_width,
+        // 📉 Oscillators in their own panels below the candles
+        for panel in &layout.panels {
+            vertices.extend(self.create_sub_panel(
+                panel,
+                &candle_vec,
+                start_index,
+                visible_candles.len(),
+                bar_width,
             ));
         }
 
-        if self.line_visibility.sma_200 {
-            let points = to_points(&mas.sma_200, 200);
-            log_info!(
-                LogComponent::Infrastructure("WebGpuRenderer"),
-                "SMA200 points: {}",
-                points.len()
-            );
-            if points.len() < 2 {
-                log_warn!(
-                    LogComponent::Infrastructure("WebGpuRenderer"),
-                    "Not enough points for SMA200"
-                );
+        // Bollinger Bands share the SMA alignment of their window
+        if let Some(bands) = &bollinger {
+            for (values, band) in [
+                (&bands.upper, IndicatorType::BollingerUpper),
+                (&bands.middle, IndicatorType::BollingerMiddle),
+                (&bands.lower, IndicatorType::BollingerLower),
+            ] {
+                let points = to_points(values, BOLLINGER_PERIOD);
+                if points.len() >= 2 {
+                    vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
+                        &points, band, line_width,
+                    ));
+                }
             }
-            vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
-                &points,
-                IndicatorType::SMA200,
-                line_width,
-            ));
         }
 
-        if self.line_visibility.ema_12 {
-            let points = to_points(&mas.ema_12, 12);
-            log_info!(
-                LogComponent::Infrastructure("WebGpuRenderer"),
-                "EMA12 points: {}",
-                points.len()
-            );
-            if points.len() < 2 {
-                log_warn!(
-                    LogComponent::Infrastructure("WebGpuRenderer"),
-                    "Not enough points for EMA12"
-                );
+        // Donchian and Keltner Channels start once their first window is complete
+        let channels = donchian
+            .iter()
+            .map(|line| (line, DONCHIAN_PERIOD))
+            .chain(keltner.iter().map(|line| (line, KELTNER_EMA_PERIOD)));
+        for ((kind, values), period) in channels {
+            let points = to_points(values, period);
+            if points.len() >= 2 {
+                vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
+                    &points, *kind, line_width,
+                ));
             }
-            vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
+        }
+
+        if overlay.len() >= 2 {
+            let points: Vec<(f32, f32)> = overlay
+                .iter()
+                .map(|&(i, price)| (sizing.x_position(i, visible_candles.len()), price_norm(price)))
+                .collect();
+            vertices.extend(CandleGeometry::create_indicator_line_vertices(
                 &points,
-                IndicatorType::EMA12,
+                IndicatorType::CompareOverlay,
                 line_width,
             ));
         }
 
-        if self.line_visibility.ema_26 {
-            let points = to_points(&mas.ema_26, 26);
-            log_info!(
-                LogComponent::Infrastructure("WebGpuRenderer"),
-                "EMA26 points: {}",
-                points.len()
-            );
-            if points.len() < 2 {
-                log_warn!(
-                    LogComponent::Infrastructure("WebGpuRenderer"),
-                    "Not enough points for EMA26"
-                );
+        // VWAP has a value for every candle
+        if let Some(vwap) = &vwap {
+            let points = to_points(vwap, 1);
+            if points.len() >= 2 {
+                vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
+                    &points,
+                    IndicatorType::VWAP,
+                    line_width,
+                ));
             }
-            vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
-                &points,
-                IndicatorType::EMA26,
-                line_width,
-            ));
         }
 
-        // Add a solid line for the current price
-        if !visible_candles.is_empty() {
-            let current_price = crate::app::global_current_price().get_untracked() as f32;
-            let price_y = ((current_price - min_price) / price_range) * 2.0 - 1.0; // same area as candles
+        // 🔵 Parabolic SAR dots trailing each visible candle
+        if let Some(psar) = &psar {
+            let (half_width, half_height) = (self.px_to_ndc_x(2.0), self.px_to_ndc(2.0));
+            let len = visible_candles.len();
+            for (i, (&sar, &rising)) in
+                psar.sar.iter().zip(&psar.rising).skip(start_index).take(len).enumerate()
+            {
+                vertices.extend(CandleGeometry::create_point_glyph(
+                    sizing.x_position(i, len),
+                    price_norm(sar),
+                    half_width,
+                    half_height,
+                    rising,
+                ));
+            }
+        }
 
-            // Keep the line width constant regardless of zoom level
-            let line_thickness = 2.0 / self.height as f32;
+        for (kind, values, first) in &ichimoku_lines {
+            let points = to_points(values, first + 1);
+            if points.len() >= 2 {
+                vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
+                    &points, *kind, line_width,
+                ));
+            }
+        }
 
-            let price_line = vec![
-                CandleVertex::current_price_vertex(-1.0, price_y - line_thickness),
-                CandleVertex::current_price_vertex(1.0, price_y - line_thickness),
-                CandleVertex::current_price_vertex(-1.0, price_y + line_thickness),
-                CandleVertex::current_price_vertex(1.0, price_y - line_thickness),
-                CandleVertex::current_price_vertex(1.0, price_y + line_thickness),
-                CandleVertex::current_price_vertex(-1.0, price_y + line_thickness),
-            ];
-            vertices.extend_from_slice(&price_line);
+        // VWMA starts once its first window is complete
+        if let Some(vwma) = &vwma {
+            let points = to_points(vwma, VWMA_PERIOD);
+            if points.len() >= 2 {
+                vertices.extend_from_slice(&CandleGeometry::create_indicator_line_vertices(
+                    &points,
+                    IndicatorType::VWMA,
+                    line_width,
+                ));
+            }
         }
 
+        // 📚 Order book depth beside the price axis, bucketed like the visible price range
+        if let Some(book) = &self.order_book {
+            let bucket_size = (max_price - min_price) as f64 / DEPTH_BUCKETS as f64;
+            let buckets: Vec<_> = book
+                .aggregate(bucket_size)
+                .into_iter()
+                .filter(|b| b.price + bucket_size > min_price as f64 && b.price < max_price as f64)
+                .collect();
+            let max_quantity =
+                buckets.iter().map(|b| b.bid_quantity.max(b.ask_quantity)).fold(0.0f64, f64::max);
+            let row_gap = self.px_to_ndc(1.0);
+            for bucket in buckets.iter().filter(|_| max_quantity > 0.0) {
+                let (a, b) = (
+                    price_norm(bucket.price.max(min_price as f64)),
+                    price_norm((bucket.price + bucket_size).min(max_price as f64)),
+                );
+                let (bottom, top) = (a.min(b), a.max(b) - row_gap);
+                for (quantity, is_bid) in
+                    [(bucket.bid_quantity, true), (bucket.ask_quantity, false)]
+                {
+                    if quantity > 0.0 && top > bottom {
+                        let width = (quantity / max_quantity) as f32 * DEPTH_MAX_WIDTH;
+                        vertices.extend(CandleGeometry::create_depth_bar(
+                            1.0, width, bottom, top, is_bid,
+                        ));
+                    }
+                }
+            }
+        }
+
+        // 📊 Volume by price from the right edge, the Point of Control highlighted
+        if self.line_visibility.volume_profile {
+            let profile = MarketAnalysisService::new().calculate_volume_profile(
+                &visible_candles,
+                min_price as f64,
+                max_price as f64,
+                VOLUME_PROFILE_BINS,
+            );
+            let max_volume = profile.volumes.iter().cloned().fold(0.0f64, f64::max);
+            let point_of_control = profile.point_of_control();
+            let row_gap = self.px_to_ndc(1.0);
+            for (index, &volume) in profile.volumes.iter().enumerate() {
+                let (low, high) = profile.bin_range(index);
+                let (a, b) = (price_norm(low), price_norm(high));
+                let (bottom, top) = (a.min(b), a.max(b) - row_gap);
+                if volume > 0.0 && top > bottom {
+                    let width = (volume / max_volume) as f32 * VOLUME_PROFILE_MAX_WIDTH;
+                    vertices.extend(CandleGeometry::create_volume_profile_bar(
+                        1.0,
+                        width,
+                        bottom,
+                        top,
+                        point_of_control == Some(index),
+                    ));
+                }
+            }
+        }
+
+        // 🕘 Session open, high and low across the candle area
+        if let Some(session) = self.session_levels(chart) {
+            for (kind, price) in session.levels() {
+                let y = price_norm(price);
+                if (layout.main_bottom..=1.0).contains(&y) {
+                    vertices.extend(CandleGeometry::create_session_level(
+                        y,
+                        self.px_to_ndc(1.0),
+                        kind,
+                    ));
+                }
+            }
+        }
+
+        // Add a solid line for the current price
+        let price_line_at = vertices.len();
+        let last_close = candle_vec.last().map_or(0.0, |c| c.ohlcv.close.value());
+        vertices.extend(self.current_price_line(last_close, &price_norm));
+
         // Ichimoku cloud
         let ichimoku = &chart.ichimoku;
         if !ichimoku.senkou_span_a.is_empty() && !ichimoku.senkou_span_b.is_empty() {
@@ -386,13 +1624,9 @@ impl WebGpuRenderer {
             let mut span_a_pts = Vec::new();
             let mut span_b_pts = Vec::new();
             for i in 0..span_len {
-                let x = candle_x_position(i, visible_count);
-                let y_a = ((ichimoku.senkou_span_a[i].value() as f32 - min_price) / price_range)
-                    * 2.0
-                    - 1.0;
-                let y_b = ((ichimoku.senkou_span_b[i].value() as f32 - min_price) / price_range)
-                    * 2.0
-                    - 1.0;
+                let x = sizing.x_position(i, visible_count);
+                let y_a = price_norm(ichimoku.senkou_span_a[i].value());
+                let y_b = price_norm(ichimoku.senkou_span_b[i].value());
                 span_a_pts.push((x, y_a));
                 span_b_pts.push((x, y_b));
             }
@@ -404,6 +1638,63 @@ impl WebGpuRenderer {
             ));
         }
 
+        // 🔔 Dashed lines for user price alerts inside the candle area
+        for &alert in &chart.price_alerts {
+            let y = price_norm(alert);
+            if (layout.main_bottom..=1.0).contains(&y) {
+                vertices.extend(CandleGeometry::create_dashed_line(
+                    y,
+                    self.px_to_ndc(1.0),
+                    self.px_to_ndc(6.0),
+                    self.px_to_ndc(4.0),
+                ));
+            }
+        }
+
+        // 📐 Fibonacci levels from the earlier anchor to the right edge
+        if let Some(fib) = &chart.fib_retracement {
+            let first = fib.start.0.value().min(fib.end.0.value());
+            let idx = visible_candles.partition_point(|c| c.timestamp.value() < first);
+            let left = if idx == 0 {
+                -1.0
+            } else if idx >= visible_candles.len() {
+                1.0
+            } else {
+                sizing.x_position(idx, visible_candles.len())
+            };
+            let ys: Vec<f32> =
+                fib.levels().into_iter().map(|(_, price)| price_norm(price)).collect();
+            vertices.extend(CandleGeometry::create_fib_levels(left, 1.0, &ys, self.px_to_ndc(1.0)));
+        }
+
+        let extremes = price_extremes(&visible_candles, close_line);
+        let extreme_markers_at = vertices.len();
+        if let Some(extremes) = extremes {
+            vertices.extend(self.extreme_markers(
+                extremes,
+                visible_candles.len(),
+                candle_width,
+                &price_norm,
+            ));
+        }
+        let extreme_markers = extreme_markers_at..vertices.len();
+
+        // ➕ Crosshair following the mouse
+        if let Some((x, y)) = self.crosshair {
+            let thickness_x = self.px_to_ndc_x(1.0);
+            vertices.extend(CandleGeometry::create_crosshair(
+                x,
+                y,
+                thickness_x,
+                self.px_to_ndc(1.0),
+            ));
+        }
+
+        // 🐞 Debug outlines for panes and the visible candle region
+        if self.debug_mode {
+            vertices.extend(self.create_debug_outlines(&instances, candle_width, &layout));
+        }
+
         // Identity matrix - vertices are already in NDC coordinates [-1, 1]
         let view_proj_matrix = [
             [1.0, 0.0, 0.0, 0.0],
@@ -412,24 +1703,64 @@ impl WebGpuRenderer {
             [0.0, 0.0, 0.0, 1.0],
         ];
 
+        // 📊 Percent mode rebases the axis to the first visible close
+        let base_close = candle_vec.get(start_index).map_or(0.0, |c| c.ohlcv.close.value() as f32);
+
+        let theme = &self.theme;
+        let mut indicator_colors = theme.indicator_colors;
+        for (slot, line) in self.line_visibility.lines.iter().take(MAX_INDICATOR_LINES).enumerate()
+        {
+            indicator_colors[slot] = line.color;
+        }
+
         // Create uniforms with corrected parameters
         let uniforms = ChartUniforms {
             view_proj_matrix,
             viewport: [self.width as f32, self.height as f32, min_price, max_price],
-            time_range: [0.0, visible_candles.len() as f32, visible_candles.len() as f32, 0.0],
-            bullish_color: [0.455, 0.780, 0.529, 1.0], // #74c787 - green
-            bearish_color: [0.882, 0.424, 0.282, 1.0], // #e16c48 - red
-            wick_color: [0.6, 0.6, 0.6, 0.9],          // light gray
-            sma20_color: [1.0, 1.0, 0.0, 0.9],         // yellow
-            sma50_color: [1.0, 1.0, 0.0, 0.9],         // yellow
-            sma200_color: [1.0, 1.0, 0.0, 0.9],        // yellow
-            ema12_color: [1.0, 1.0, 0.0, 0.9],         // yellow
-            ema26_color: [1.0, 1.0, 0.0, 0.9],         // yellow
+            time_range: [
+                0.0,
+                visible_candles.len() as f32,
+                visible_candles.len() as f32,
+                base_close,
+            ],
+            bullish_color: theme.bullish,
+            bearish_color: theme.bearish,
+            wick_color: theme.wick,
+            indicator_colors,
+            line_colors: self.line_visibility.builtin_colors,
             current_price_color: [1.0, 1.0, 0.0, 0.8], // 💰 bright yellow
+            outline_color: self
+                .candle_outline
+                .map_or(ChartUniforms::new().outline_color, |outline| outline.color),
+            grid_color: theme.grid,
             render_params: [candle_width, spacing, line_width, 0.0],
         };
 
-        (instances, vertices, uniforms)
+        let tail =
+            prefix_range.filter(|_| per_element == 1 && extremes.is_some()).map(|prefix_range| {
+                TailLayout {
+                    viewport_start: chart.viewport.start_time,
+                    start_index,
+                    visible_len: visible_candles.len(),
+                    candle_width,
+                    line_width,
+                    window,
+                    prefix_range,
+                    prefix_max_volume,
+                    max_volume,
+                    prefix_extremes: price_extremes(
+                        &visible_candles[..visible_candles.len() - 1],
+                        false,
+                    ),
+                    move_highlights: move_highlights_at,
+                    last_element,
+                    ma_segments,
+                    extreme_markers,
+                    price_line: price_line_at,
+                }
+            });
+
+        (instances, vertices, uniforms, tail)
     }
 }
 
@@ -441,41 +1772,6 @@ mod tests {
         market_data::{Candle, OHLCV, Price, Timestamp, Volume},
     };
     use leptos::SignalSet;
-    use std::collections::VecDeque;
-
-    #[allow(invalid_value)]
-    fn dummy_renderer() -> WebGpuRenderer {
-        unsafe {
-            WebGpuRenderer {
-                _canvas_id: String::new(),
-                width: 800,
-                height: 600,
-                surface: std::mem::MaybeUninit::zeroed().assume_init(),
-                device: std::mem::MaybeUninit::zeroed().assume_init(),
-                queue: std::mem::MaybeUninit::zeroed().assume_init(),
-                config: std::mem::MaybeUninit::zeroed().assume_init(),
-                render_pipeline: std::mem::MaybeUninit::zeroed().assume_init(),
-                vertex_buffer: std::mem::MaybeUninit::zeroed().assume_init(),
-                uniform_buffer: std::mem::MaybeUninit::zeroed().assume_init(),
-                uniform_bind_group: std::mem::MaybeUninit::zeroed().assume_init(),
-                msaa_texture: std::mem::MaybeUninit::zeroed().assume_init(),
-                msaa_view: std::mem::MaybeUninit::zeroed().assume_init(),
-                template_vertices: 0,
-                cached_vertices: Vec::new(),
-                cached_uniforms: ChartUniforms::new(),
-                cached_candle_count: 0,
-                cached_zoom_level: 1.0,
-                cached_hash: 0,
-                cached_data_hash: 0,
-                cached_line_visibility: LineVisibility::default(),
-                zoom_level: 1.0,
-                pan_offset: 0.0,
-                last_frame_time: 0.0,
-                fps_log: VecDeque::new(),
-                line_visibility: LineVisibility::default(),
-            }
-        }
-    }
 
     fn make_candle(i: u64) -> Candle {
         let base = 100.0 + i as f64;
@@ -600,11 +1896,11 @@ mod tests {
 
         let line_width = renderer.px_to_ndc(2.0);
         let checks = [
-            (&mas.sma_20, IndicatorType::SMA20, 2.0, 20usize),
-            (&mas.sma_50, IndicatorType::SMA50, 3.0, 50usize),
-            (&mas.sma_200, IndicatorType::SMA200, 4.0, 200usize),
-            (&mas.ema_12, IndicatorType::EMA12, 5.0, 12usize),
-            (&mas.ema_26, IndicatorType::EMA26, 6.0, 26usize),
+            (mas.get(MaKind::Sma, 20).unwrap(), IndicatorType::SMA20, 2.0, 20usize),
+            (mas.get(MaKind::Sma, 50).unwrap(), IndicatorType::SMA50, 3.0, 50usize),
+            (mas.get(MaKind::Sma, 200).unwrap(), IndicatorType::SMA200, 4.0, 200usize),
+            (mas.get(MaKind::Ema, 12).unwrap(), IndicatorType::EMA12, 5.0, 12usize),
+            (mas.get(MaKind::Ema, 26).unwrap(), IndicatorType::EMA26, 6.0, 26usize),
         ];
 
         for (values, t, color, period) in checks {
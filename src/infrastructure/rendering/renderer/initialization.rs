@@ -3,6 +3,17 @@ use crate::domain::market_data::TimeInterval;
 use leptos::SignalGetUntracked;
 use std::collections::VecDeque;
 
+/// Device pixels per CSS pixel of the current window
+fn window_device_pixel_ratio() -> f64 {
+    web_sys::window().map(|w| w.device_pixel_ratio()).filter(|r| *r > 0.0).unwrap_or(1.0)
+}
+
+/// Backing store size in device pixels for a canvas `width`x`height` CSS pixels large
+fn device_size(width: u32, height: u32, device_pixel_ratio: f64) -> (u32, u32) {
+    let scale = |px: u32| ((px as f64 * device_pixel_ratio).round() as u32).max(1);
+    (scale(width), scale(height))
+}
+
 impl WebGpuRenderer {
     pub async fn is_webgpu_supported() -> bool {
         if let Some(window) = web_sys::window() {
@@ -26,21 +37,38 @@ impl WebGpuRenderer {
         }
     }
 
+    /// Create a renderer for a canvas `width`x`height` CSS pixels large.
+    ///
+    /// The surface is scaled by `devicePixelRatio` so the chart stays sharp on HiDPI screens.
     pub async fn new(canvas_id: &str, width: u32, height: u32) -> Result<Self, JsValue> {
+        Self::with_sample_count(canvas_id, width, height, MSAA_SAMPLE_COUNT).await
+    }
+
+    /// Create a renderer drawing with `sample_count` MSAA samples; 1 disables antialiasing
+    pub async fn with_sample_count(
+        canvas_id: &str,
+        width: u32,
+        height: u32,
+        sample_count: u32,
+    ) -> Result<Self, JsValue> {
+        let sample_count = normalized_sample_count(sample_count);
         let canvas = document()
             .get_element_by_id(canvas_id)
             .ok_or_else(|| JsValue::from_str(&format!("Canvas with id '{}' not found", canvas_id)))?
             .dyn_into::<HtmlCanvasElement>()
             .map_err(|_| JsValue::from_str("Element is not a canvas"))?;
 
+        let device_pixel_ratio = window_device_pixel_ratio();
+        let (width, height) = device_size(width, height, device_pixel_ratio);
         get_logger().info(
             LogComponent::Infrastructure("WebGpuRenderer"),
             &format!(
-                "🎯 Canvas found: {}x{} -> setting to {}x{}",
+                "🎯 Canvas found: {}x{} -> setting to {}x{} (device pixel ratio {})",
                 canvas.width(),
                 canvas.height(),
                 width,
-                height
+                height,
+                device_pixel_ratio
             ),
         );
 
@@ -130,16 +158,12 @@ impl WebGpuRenderer {
             "🎯 Surface configured successfully",
         );
 
-        let msaa_texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("MSAA Texture"),
-            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
-            mip_level_count: 1,
-            sample_count: MSAA_SAMPLE_COUNT,
-            dimension: wgpu::TextureDimension::D2,
-            format: config.format,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            view_formats: &[],
-        });
+        let msaa_texture = device.create_texture(&msaa_texture_descriptor(
+            width,
+            height,
+            config.format,
+            sample_count,
+        ));
         let msaa_view = msaa_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
         let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -184,43 +208,51 @@ impl WebGpuRenderer {
                 push_constant_ranges: &[],
             });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Render Pipeline"),
-            layout: Some(&render_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                buffers: &[CandleVertex::desc()],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: MSAA_SAMPLE_COUNT,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
-            cache: None,
-        });
+        let create_pipeline =
+            |label: &str, entry_point: &str, buffers: &[wgpu::VertexBufferLayout]| {
+                device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: Some(label),
+                    layout: Some(&render_pipeline_layout),
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point: Some(entry_point),
+                        buffers,
+                        compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    },
+                    fragment: Some(wgpu::FragmentState {
+                        module: &shader,
+                        entry_point: Some("fs_main"),
+                        targets: &[Some(wgpu::ColorTargetState {
+                            format: config.format,
+                            blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                            write_mask: wgpu::ColorWrites::ALL,
+                        })],
+                        compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    }),
+                    primitive: wgpu::PrimitiveState {
+                        topology: wgpu::PrimitiveTopology::TriangleList,
+                        strip_index_format: None,
+                        front_face: wgpu::FrontFace::Ccw,
+                        cull_mode: Some(wgpu::Face::Back),
+                        polygon_mode: wgpu::PolygonMode::Fill,
+                        unclipped_depth: false,
+                        conservative: false,
+                    },
+                    depth_stencil: None,
+                    multisample: multisample_state(sample_count),
+                    multiview: None,
+                    cache: None,
+                })
+            };
+
+        let render_pipeline =
+            create_pipeline("Render Pipeline", "vs_main", &[CandleVertex::desc()]);
+        // Candles are drawn from a shared template expanded per instance
+        let candle_pipeline = create_pipeline(
+            "Candle Pipeline",
+            "vs_candle",
+            &[CandleVertex::desc(), CandleInstance::desc()],
+        );
 
         let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Vertex Buffer"),
@@ -229,38 +261,80 @@ impl WebGpuRenderer {
             mapped_at_creation: false,
         });
 
+        let instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Instance Buffer"),
+            size: (std::mem::size_of::<CandleInstance>() * MAX_CANDLE_INSTANCES) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        queue.write_buffer(
+            &vertex_buffer,
+            0,
+            bytemuck::cast_slice(&CandleGeometry::candle_template()),
+        );
+
         get_logger().info(
             LogComponent::Infrastructure("WebGpuRenderer"),
             "✅ Full WebGPU renderer initialized successfully.",
         );
 
         let renderer = Self {
-            _canvas_id: canvas.id(),
+            canvas_id: canvas.id(),
+            primary: true,
             width,
             height,
+            device_pixel_ratio,
             surface,
             device,
             queue,
             config,
             render_pipeline,
+            candle_pipeline,
             vertex_buffer,
+            instance_buffer,
             uniform_buffer,
             uniform_bind_group,
             msaa_texture,
             msaa_view,
-            template_vertices: 0,
+            sample_count,
+            template_vertices: CandleGeometry::TEMPLATE_VERTEX_COUNT,
             cached_vertices: Vec::new(),
+            cached_instances: Vec::new(),
             cached_uniforms: ChartUniforms::new(),
             cached_candle_count: 0,
             cached_zoom_level: 1.0,
             cached_hash: 0,
             cached_data_hash: 0,
+            cached_prefix_hash: 0,
+            cached_tail: None,
+            last_geometry_update: GeometryUpdate::Unchanged,
             cached_line_visibility: LineVisibility::default(),
             zoom_level: 1.0,
             pan_offset: 0.0,
             last_frame_time: 0.0,
             fps_log: VecDeque::new(),
-            line_visibility: LineVisibility::default(),
+            last_render_ms: 0.0,
+            line_visibility: crate::app::global_line_visibility().get_untracked(),
+            debug_mode: false,
+            clip_indicators: false,
+            show_wicks: true,
+            price_range: None,
+            preview_interval: None,
+            candle_outline: None,
+            candle_sizing: CandleSizing::default(),
+            order_book: None,
+            compare_overlay: Vec::new(),
+            indicator_cache: None,
+            price_scale_mode: PriceScaleMode::Linear,
+            sub_panel_height: SUB_PANEL_HEIGHT,
+            crosshair: None,
+            theme: Theme::default(),
+            grid_visible: true,
+            grid_divisions: GRID_DIVISIONS,
+            gap_threshold: GAP_THRESHOLD,
+            volume_ma_period: VOLUME_MA_PERIOD,
+            price_padding: PricePadding::default(),
+            invert_y: false,
         };
 
         renderer.log_gpu_memory_usage();
@@ -268,28 +342,29 @@ impl WebGpuRenderer {
         Ok(renderer)
     }
 
+    /// Resize to `new_width`x`new_height` CSS pixels, rereading the device pixel ratio
     pub fn resize(&mut self, new_width: u32, new_height: u32) {
         if new_width > 0 && new_height > 0 {
+            self.device_pixel_ratio = window_device_pixel_ratio();
+            let (new_width, new_height) =
+                device_size(new_width, new_height, self.device_pixel_ratio);
+            if let Some(canvas) = document()
+                .get_element_by_id(&self.canvas_id)
+                .and_then(|el| el.dyn_into::<HtmlCanvasElement>().ok())
+            {
+                canvas.set_width(new_width);
+                canvas.set_height(new_height);
+            }
             self.width = new_width;
             self.height = new_height;
             self.config.width = new_width;
             self.config.height = new_height;
             self.surface.configure(&self.device, &self.config);
-            self.msaa_texture = self.device.create_texture(&wgpu::TextureDescriptor {
-                label: Some("MSAA Texture"),
-                size: wgpu::Extent3d {
-                    width: new_width,
-                    height: new_height,
-                    depth_or_array_layers: 1,
-                },
-                mip_level_count: 1,
-                sample_count: MSAA_SAMPLE_COUNT,
-                dimension: wgpu::TextureDimension::D2,
-                format: self.config.format,
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                view_formats: &[],
-            });
+            // The multisampled target must match the new surface size
+            self.msaa_texture = self.device.create_texture(&self.msaa_target_descriptor());
             self.msaa_view = self.msaa_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            // Force geometry refresh on next render
+            self.cached_zoom_level = f64::MAX;
         }
     }
 
@@ -313,4 +388,9 @@ impl WebGpuRenderer {
         // Force geometry refresh on next render
         self.cached_zoom_level = f64::MAX;
     }
+
+    /// 🔍 Current zoom level and pan offset
+    pub fn zoom_params(&self) -> (f64, f64) {
+        (self.zoom_level, self.pan_offset)
+    }
 }
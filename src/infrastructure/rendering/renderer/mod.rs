@@ -1,65 +1,150 @@
 //! WebGPU renderer responsible for drawing the chart.
 //!
-//! This module manages GPU buffers and performs the render loop. The renderer
-//! is kept behind a global handle to simplify access from the UI layer.
+//! This module manages GPU buffers and performs the render loop. Renderers are
+//! kept in a registry keyed by canvas id to simplify access from the UI layer;
+//! the main chart's renderer is also reachable through the global handle.
 
-use crate::domain::market_data::Candle;
+use crate::domain::market_data::{
+    Candle, GAP_THRESHOLD, MaConfig, MaKind, OrderBook, TimeInterval, format_price,
+    services::{SessionLevelKind, SessionLevels},
+};
 use crate::domain::{
     chart::Chart,
     logging::{LogComponent, get_logger},
 };
+use crate::ecs::components::IndicatorComponent;
 use crate::infrastructure::rendering::gpu_structures::{
-    CandleInstance, CandleVertex, ChartUniforms,
+    CandleGeometry, CandleInstance, CandleVertex, ChartUniforms, DEFAULT_LINE_COLORS,
+    FIRST_LINE_COLOR_TYPE, LINE_COLOR_SLOTS, MAX_INDICATOR_LINES,
 };
 use gloo::utils::document;
 use js_sys;
 use leptos::SignalSet;
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
 use web_sys::HtmlCanvasElement;
 use wgpu::util::DeviceExt;
+type RendererHandle = Rc<RefCell<WebGpuRenderer>>;
+
 thread_local! {
-    static GLOBAL_RENDERER: RefCell<Option<Rc<RefCell<WebGpuRenderer>>>> = const { RefCell::new(None) };
+    static RENDERERS: RefCell<HashMap<String, RendererHandle>> = RefCell::new(HashMap::new());
+    static PRIMARY_CANVAS: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
 /// Number of samples for MSAA
 pub const MSAA_SAMPLE_COUNT: u32 = 4;
 
-/// Store the global renderer instance
+/// Sample count supported by WebGPU closest to `requested`: 1 disables MSAA, anything
+/// higher uses [`MSAA_SAMPLE_COUNT`]
+pub fn normalized_sample_count(requested: u32) -> u32 {
+    if requested <= 1 { 1 } else { MSAA_SAMPLE_COUNT }
+}
+
+/// Multisample state shared by every render pipeline
+pub fn multisample_state(sample_count: u32) -> wgpu::MultisampleState {
+    wgpu::MultisampleState { count: sample_count, mask: !0, alpha_to_coverage_enabled: false }
+}
+
+/// Multisampled render target resolved into the surface each frame
+pub fn msaa_texture_descriptor(
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> wgpu::TextureDescriptor<'static> {
+    wgpu::TextureDescriptor {
+        label: Some("MSAA Texture"),
+        size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+        mip_level_count: 1,
+        sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    }
+}
+
+/// Capacity of the instance buffer in candles
+pub const MAX_CANDLE_INSTANCES: usize = 10_000;
+
+/// Store the main chart's renderer, replacing the previous one.
+///
+/// The main renderer publishes labels, price axis and performance stats to the
+/// global signals; renderers added with [`register_renderer`] draw only.
 pub fn set_global_renderer(renderer: Rc<RefCell<WebGpuRenderer>>) {
-    GLOBAL_RENDERER.with(|cell| {
-        *cell.borrow_mut() = Some(renderer);
+    renderer.borrow_mut().primary = true;
+    let canvas_id = renderer.borrow().canvas_id.clone();
+    let line_visibility = renderer.borrow().line_visibility.clone();
+    let previous = PRIMARY_CANVAS.with(|cell| cell.borrow_mut().replace(canvas_id.clone()));
+    RENDERERS.with(|cell| {
+        let mut renderers = cell.borrow_mut();
+        if let Some(previous) = previous.filter(|p| *p != canvas_id) {
+            renderers.remove(&previous);
+        }
+        renderers.insert(canvas_id, renderer);
+    });
+    crate::app::global_line_visibility().set(line_visibility);
+}
+
+/// Store the renderer of an additional canvas under its canvas id
+pub fn register_renderer(renderer: Rc<RefCell<WebGpuRenderer>>) {
+    renderer.borrow_mut().primary = false;
+    let canvas_id = renderer.borrow().canvas_id.clone();
+    RENDERERS.with(|cell| {
+        cell.borrow_mut().insert(canvas_id, renderer);
     });
-    GLOBAL_RENDERER.with(|cell| {
-        if let Some(rc) = &*cell.borrow() {
-            crate::app::global_line_visibility().set(rc.borrow().line_visibility.clone());
+}
+
+/// Drop the renderer of `canvas_id`; returns whether one was stored
+pub fn remove_renderer(canvas_id: &str) -> bool {
+    PRIMARY_CANVAS.with(|cell| {
+        let mut primary = cell.borrow_mut();
+        if primary.as_deref() == Some(canvas_id) {
+            *primary = None;
         }
     });
+    RENDERERS.with(|cell| cell.borrow_mut().remove(canvas_id).is_some())
+}
+
+/// Canvas ids of all stored renderers, sorted
+pub fn renderer_canvas_ids() -> Vec<String> {
+    let mut ids: Vec<String> = RENDERERS.with(|cell| cell.borrow().keys().cloned().collect());
+    ids.sort();
+    ids
+}
+
+/// Obtain a mutable reference to the renderer drawing on `canvas_id`
+pub fn with_renderer<F, R>(canvas_id: &str, f: F) -> Option<R>
+where
+    F: FnOnce(&mut WebGpuRenderer) -> R,
+{
+    let renderer = RENDERERS.with(|cell| cell.borrow().get(canvas_id).cloned())?;
+    renderer.try_borrow_mut().ok().map(|mut r| f(&mut r))
 }
 
-/// Obtain a mutable reference to the global renderer
+/// Obtain a mutable reference to the main chart's renderer
 pub fn with_global_renderer<F, R>(f: F) -> Option<R>
 where
     F: FnOnce(&mut WebGpuRenderer) -> R,
 {
-    GLOBAL_RENDERER.with(|cell| {
-        let opt = cell.borrow();
-        if let Some(rc) = opt.as_ref() {
-            rc.try_borrow_mut().ok().map(|mut r| f(&mut r))
-        } else {
-            None
-        }
-    })
+    let canvas_id = PRIMARY_CANVAS.with(|cell| cell.borrow().clone())?;
+    with_renderer(&canvas_id, f)
 }
 
 /// Actual WebGPU renderer for candles
 pub struct WebGpuRenderer {
-    _canvas_id: String,
+    canvas_id: String,
+    /// Whether this renders the main chart and publishes its overlays
+    primary: bool,
+    /// Surface size in device pixels
     width: u32,
     height: u32,
+    /// Device pixels per CSS pixel of the canvas
+    device_pixel_ratio: f64,
 
     // WGPU state
     surface: wgpu::Surface<'static>,
@@ -69,20 +154,31 @@ pub struct WebGpuRenderer {
 
     // Rendering pipeline
     render_pipeline: wgpu::RenderPipeline,
+    candle_pipeline: wgpu::RenderPipeline,
+    /// Candle template followed by the remaining chart geometry
     vertex_buffer: wgpu::Buffer,
+    /// Per-candle attributes for the instanced draw
+    instance_buffer: wgpu::Buffer,
     uniform_buffer: wgpu::Buffer,
     uniform_bind_group: wgpu::BindGroup,
     msaa_texture: wgpu::Texture,
     msaa_view: wgpu::TextureView,
+    sample_count: u32,
     template_vertices: u32,
 
     // 🗄️ Cached data
     cached_vertices: Vec<CandleVertex>,
+    cached_instances: Vec<CandleInstance>,
     cached_uniforms: ChartUniforms,
     cached_candle_count: usize,
     cached_zoom_level: f64,
     cached_hash: u64,
     cached_data_hash: u64,
+    /// Data hash without the last candle, to spot realtime ticks on it alone
+    cached_prefix_hash: u64,
+    /// Where the last full build put the geometry a tick on the last candle changes
+    cached_tail: Option<TailLayout>,
+    last_geometry_update: GeometryUpdate,
     cached_line_visibility: LineVisibility,
 
     // 🔍 Zoom and pan parameters
@@ -92,70 +188,842 @@ pub struct WebGpuRenderer {
     // ⏱️ Performance metrics
     last_frame_time: f64,
     fps_log: VecDeque<f64>,
+    last_render_ms: f64,
 
     // 📊 Indicator line visibility
     line_visibility: LineVisibility,
+
+    // 🐞 Debug outlines around panes and regions
+    debug_mode: bool,
+
+    // ✂️ Keep indicators out of the price range and clip them at the pane edge
+    clip_indicators: bool,
+
+    // 🕯️ Draw candle wicks; bodies only when false
+    show_wicks: bool,
+
+    // 🔲 Fixed price window set by box zoom; `None` fits the visible data
+    price_range: Option<(f64, f64)>,
+
+    // 👀 Timeframe drawn instead of the current one while it is previewed
+    preview_interval: Option<TimeInterval>,
+
+    // 🔲 Optional border around candle bodies
+    candle_outline: Option<CandleOutline>,
+
+    // 🕯️ Candle width limits and spacing
+    candle_sizing: CandleSizing,
+
+    // 📚 Latest order book drawn as depth bars at the right edge
+    order_book: Option<OrderBook>,
+
+    // 🆚 Candles of another symbol drawn as a percent-change line over the chart
+    compare_overlay: Vec<Candle>,
+
+    // 📈 Moving averages cached by the ECS indicator system
+    indicator_cache: Option<IndicatorComponent>,
+
+    // 📐 Linear or logarithmic price axis
+    price_scale_mode: PriceScaleMode,
+
+    // 📉 Height of each oscillator panel below the candles (NDC)
+    sub_panel_height: f32,
+
+    // ➕ Crosshair position in NDC, hidden when `None`
+    crosshair: Option<(f32, f32)>,
+
+    // 🎨 Active color palette
+    theme: Theme,
+
+    // #️⃣ Background grid and its (time, price) divisions
+    grid_visible: bool,
+    grid_divisions: (usize, usize),
+
+    // ⏸️ Separator at candle pairs further apart than this many intervals
+    gap_threshold: f64,
+
+    // 📊 Window of the volume average drawn over the volume bars
+    volume_ma_period: usize,
+
+    // ↕️ Headroom above and below the fitted price window
+    price_padding: PricePadding,
+
+    // 🙃 Higher prices drawn further down
+    invert_y: bool,
 }
 
-/// State of indicator line visibility
+/// 📐 Label of a Fibonacci level at its vertical NDC position in the last rendered frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FibLabel {
+    pub ratio: f64,
+    pub price: f64,
+    pub y: f32,
+}
+
+/// 🔺 Label of the visible high or low at its NDC position in the last rendered frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtremeLabel {
+    /// Series index of the candle reaching the extreme
+    pub index: usize,
+    pub price: f64,
+    pub high: bool,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// 🕘 Label of a session open, high or low line at its vertical NDC position in the last
+/// rendered frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionLabel {
+    pub kind: SessionLevelKind,
+    pub price: f64,
+    pub y: f32,
+}
+
+/// Default grid divisions: (time columns, price rows between axis labels)
+pub const GRID_DIVISIONS: (usize, usize) = (10, 8);
+
+/// Default height of an oscillator panel in NDC units (15% of the canvas)
+pub const SUB_PANEL_HEIGHT: f32 = 0.3;
+
+/// Oscillator drawn in its own band below the candles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubPanelKind {
+    Rsi,
+    Macd,
+    Atr,
+    Stochastic,
+}
+
+/// Vertical NDC band reserved for an oscillator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubPanel {
+    pub kind: SubPanelKind,
+    pub bottom: f32,
+    pub top: f32,
+}
+
+impl SubPanel {
+    /// Map a value from `min..=max` into the panel band
+    pub fn value_to_y(&self, value: f64, min: f64, max: f64) -> f32 {
+        let t = ((value - min) / (max - min)).clamp(0.0, 1.0) as f32;
+        self.bottom + t * (self.top - self.bottom)
+    }
+}
+
+/// Split of the canvas between the main chart and stacked sub-panels.
+///
+/// Panels are stacked upwards from the bottom edge; the candles and volume
+/// bars are compressed into the remaining area.
 #[derive(Debug, Clone, PartialEq)]
+pub struct PanelLayout {
+    /// Lower edge of the main chart area
+    pub main_bottom: f32,
+    pub panels: Vec<SubPanel>,
+}
+
+impl PanelLayout {
+    pub fn new(kinds: &[SubPanelKind], panel_height: f32) -> Self {
+        // Always leave at least half of the canvas to the candles
+        let panel_height = panel_height.clamp(0.0, 1.0 / kinds.len().max(1) as f32);
+        let panels: Vec<SubPanel> = kinds
+            .iter()
+            .enumerate()
+            .map(|(i, &kind)| {
+                let bottom = -1.0 + i as f32 * panel_height;
+                SubPanel { kind, bottom, top: bottom + panel_height }
+            })
+            .collect();
+        let main_bottom = panels.last().map_or(-1.0, |p| p.top);
+        Self { main_bottom, panels }
+    }
+
+    /// Height of the main chart area in NDC
+    pub fn main_height(&self) -> f32 {
+        1.0 - self.main_bottom
+    }
+
+    /// Map a `0..=1` position into the main chart area
+    pub fn main_y(&self, normalized: f32) -> f32 {
+        self.main_bottom + normalized * self.main_height()
+    }
+
+    pub fn panel(&self, kind: SubPanelKind) -> Option<&SubPanel> {
+        self.panels.iter().find(|p| p.kind == kind)
+    }
+}
+
+/// Largest padding above or below the fitted prices, in percent of their range
+pub const MAX_PRICE_PADDING_PCT: f64 = 100.0;
+
+/// ↕️ Headroom added above and below the fitted price window, in percent of its range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PricePadding {
+    pub top_pct: f64,
+    pub bottom_pct: f64,
+}
+
+impl PricePadding {
+    /// Padding of `top_pct` and `bottom_pct`, each clamped to `0..=MAX_PRICE_PADDING_PCT`
+    pub fn new(top_pct: f64, bottom_pct: f64) -> Self {
+        let clamp =
+            |pct: f64| if pct.is_finite() { pct.clamp(0.0, MAX_PRICE_PADDING_PCT) } else { 0.0 };
+        Self { top_pct: clamp(top_pct), bottom_pct: clamp(bottom_pct) }
+    }
+
+    /// Widen `min..max` by the padding; a flat range is treated as a tiny one
+    pub fn apply(&self, min: f32, max: f32) -> (f32, f32) {
+        let range = (max - min).abs().max(1e-6);
+        (
+            min - range * (self.bottom_pct / 100.0) as f32,
+            max + range * (self.top_pct / 100.0) as f32,
+        )
+    }
+}
+
+impl Default for PricePadding {
+    fn default() -> Self {
+        Self::new(5.0, 5.0)
+    }
+}
+
+/// 🔁 How the last geometry refresh reached the GPU buffers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeometryUpdate {
+    /// Nothing changed since the previous frame
+    #[default]
+    Unchanged,
+    /// Every buffer was rewritten
+    Full,
+    /// Only the ranges touched by the last candle were rewritten
+    Partial,
+}
+
+/// Price window of the candle area as drawn in the last frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceAxisRange {
+    pub min_price: f64,
+    pub max_price: f64,
+    pub scale: PriceScaleMode,
+    /// Lower edge of the candle area (NDC)
+    pub main_bottom: f32,
+    /// Higher prices are drawn further down
+    pub inverted: bool,
+}
+
+impl PriceAxisRange {
+    /// Position of `price` between the bottom (0) and top (1) of the candle area
+    fn fraction(&self, price: f64) -> Option<f64> {
+        let (lo, hi) = (self.scale.to_scale(self.min_price), self.scale.to_scale(self.max_price));
+        (hi > lo).then(|| {
+            let t = (self.scale.to_scale(price) - lo) / (hi - lo);
+            if self.inverted { 1.0 - t } else { t }
+        })
+    }
+
+    /// Vertical NDC position of `price`; `None` outside the candle area
+    pub fn ndc_y(&self, price: f64) -> Option<f32> {
+        let t = self.fraction(price)? as f32;
+        (0.0..=1.0).contains(&t).then_some(self.main_bottom + t * (1.0 - self.main_bottom))
+    }
+
+    /// Price under a vertical NDC position; `None` below the candle area
+    pub fn price_at_ndc_y(&self, y: f32) -> Option<f64> {
+        let (lo, hi) = (self.scale.to_scale(self.min_price), self.scale.to_scale(self.max_price));
+        if y < self.main_bottom || hi <= lo {
+            return None;
+        }
+        let t = ((y - self.main_bottom) / (1.0 - self.main_bottom)) as f64;
+        let t = if self.inverted { 1.0 - t } else { t };
+        Some(self.scale.from_scale(lo + t * (hi - lo)))
+    }
+
+    /// Distance of `price` from the top of the canvas in percent, pinned to the
+    /// candle area edges when the price is outside it
+    pub fn percent_from_top(&self, price: f64) -> Option<f64> {
+        let t = self.fraction(price)?.clamp(0.0, 1.0);
+        let y = self.main_bottom as f64 + t * (1.0 - self.main_bottom as f64);
+        Some((1.0 - y) / 2.0 * 100.0)
+    }
+}
+
+/// Mapping between prices and the vertical axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceScaleMode {
+    #[default]
+    Linear,
+    /// Equal distances represent equal percentage moves
+    Logarithmic,
+}
+
+impl PriceScaleMode {
+    /// Project a price into the space where the axis is linear
+    pub fn to_scale(self, price: f64) -> f64 {
+        match self {
+            PriceScaleMode::Linear => price,
+            PriceScaleMode::Logarithmic => price.max(f64::MIN_POSITIVE).ln(),
+        }
+    }
+
+    /// Inverse of [`PriceScaleMode::to_scale`]
+    pub fn from_scale(self, value: f64) -> f64 {
+        match self {
+            PriceScaleMode::Linear => value,
+            PriceScaleMode::Logarithmic => value.exp(),
+        }
+    }
+}
+
+/// How prices are written on the axis and in readouts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceDisplayMode {
+    #[default]
+    Price,
+    /// Percent change from the close of the first visible candle
+    Percent,
+}
+
+/// Percent change of `price` from `base`
+pub fn percent_from_base(price: f64, base: f64) -> f64 {
+    (price / base - 1.0) * 100.0
+}
+
+impl PriceDisplayMode {
+    /// Label of `price` with `decimals` digits; percent mode falls back to the price
+    /// until `base` is known.
+    ///
+    /// Rebasing is affine, so candles and indicators keep their positions on the
+    /// linear axis and only the labels change.
+    pub fn label(self, price: f64, base: Option<f64>, decimals: u32) -> String {
+        match (self, base) {
+            (PriceDisplayMode::Percent, Some(base)) if base > 0.0 => {
+                format!("{:+.2}%", percent_from_base(price, base))
+            }
+            _ => format_price(price, decimals),
+        }
+    }
+}
+
+/// Border drawn around each candle body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandleOutline {
+    pub color: [f32; 4],
+    /// Border thickness in pixels, capped relative to the candle width
+    pub width_px: f32,
+}
+
+impl Default for CandleOutline {
+    fn default() -> Self {
+        Self { color: [0.0, 0.0, 0.0, 1.0], width_px: 1.0 }
+    }
+}
+
+/// Color palette of the chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Clear color of the render pass
+    pub background: [f32; 4],
+    pub bullish: [f32; 4],
+    pub bearish: [f32; 4],
+    pub wick: [f32; 4],
+    pub grid: [f32; 4],
+    /// Colors of indicator slots without a configured line color
+    pub indicator_colors: [[f32; 4]; MAX_INDICATOR_LINES],
+}
+
+impl Theme {
+    /// Palette for the dark `#253242` background
+    pub fn dark() -> Self {
+        Self {
+            background: [0.145, 0.196, 0.259, 1.0], // #253242
+            bullish: [0.455, 0.780, 0.529, 1.0],    // #74c787 - buy
+            bearish: [0.882, 0.424, 0.282, 1.0],    // #e16c48 - sell
+            wick: [0.6, 0.6, 0.6, 0.9],             // light gray
+            grid: [0.3, 0.3, 0.3, 0.3],             // semi-transparent gray
+            indicator_colors: [[1.0, 1.0, 0.0, 1.0]; MAX_INDICATOR_LINES], // yellow
+        }
+    }
+
+    /// Palette for a light background
+    pub fn light() -> Self {
+        Self {
+            background: [0.97, 0.97, 0.96, 1.0],
+            bullish: [0.18, 0.62, 0.33, 1.0],
+            bearish: [0.84, 0.31, 0.18, 1.0],
+            wick: [0.35, 0.35, 0.35, 0.9],
+            grid: [0.0, 0.0, 0.0, 0.12],
+            indicator_colors: [[0.8, 0.6, 0.0, 1.0]; MAX_INDICATOR_LINES], // dark yellow
+        }
+    }
+
+    /// Whether this is the light palette, with its own or a custom background
+    pub fn is_light(&self) -> bool {
+        let light = Self::light();
+        Self { background: light.background, ..*self } == light
+    }
+
+    /// Render pass clear color
+    pub fn clear_color(&self) -> wgpu::Color {
+        let [r, g, b, a] = self.background.map(f64::from);
+        wgpu::Color { r, g, b, a }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Configured moving average line drawn over the candles
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndicatorLine {
+    pub kind: MaKind,
+    pub period: usize,
+    pub color: [f32; 4],
+    pub visible: bool,
+}
+
+impl IndicatorLine {
+    pub fn new(kind: MaKind, period: usize, color: [f32; 4]) -> Self {
+        Self { kind, period, color, visible: true }
+    }
+
+    pub fn config(&self) -> MaConfig {
+        MaConfig::new(self.kind, self.period)
+    }
+
+    /// Identifier used by the legend and toggles, e.g. `sma20`
+    pub fn name(&self) -> String {
+        self.config().name()
+    }
+}
+
+/// Colors assigned cyclically to configured moving averages
+pub const INDICATOR_PALETTE: [[f32; 4]; 6] = [
+    [1.0, 1.0, 0.0, 0.9],    // yellow
+    [0.0, 0.9, 1.0, 0.9],    // cyan
+    [1.0, 0.4, 1.0, 0.9],    // magenta
+    [1.0, 0.6, 0.2, 0.9],    // orange
+    [0.6, 1.0, 0.4, 0.9],    // lime
+    [0.95, 0.95, 0.95, 0.9], // white
+];
+
+/// `#rrggbb` notation of an RGBA color, as used by color inputs
+pub fn color_to_hex(color: [f32; 4]) -> String {
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(color[0]), channel(color[1]), channel(color[2]))
+}
+
+/// RGBA color of a `#rrggbb` string with the given alpha
+pub fn color_from_hex(hex: &str, alpha: f32) -> Option<[f32; 4]> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel =
+        |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok().map(|c| c as f32 / 255.0);
+    Some([channel(0)?, channel(2)?, channel(4)?, alpha])
+}
+
+/// Legend name of the Bollinger Bands toggle
+pub const BOLLINGER_BANDS_NAME: &str = "bb";
+/// Legend name of the Donchian Channels toggle
+pub const DONCHIAN_NAME: &str = "donchian";
+/// Legend name of the Keltner Channels toggle
+pub const KELTNER_NAME: &str = "keltner";
+/// Legend name of the RSI panel toggle
+pub const RSI_NAME: &str = "rsi";
+/// Legend name of the MACD panel toggle
+pub const MACD_NAME: &str = "macd";
+/// Legend name of the VWAP toggle
+pub const VWAP_NAME: &str = "vwap";
+/// Legend name of the VWMA toggle
+pub const VWMA_NAME: &str = "vwma";
+/// Legend name of the volume average toggle
+pub const VOLUME_MA_NAME: &str = "volma";
+/// Legend name of the volume profile toggle
+pub const VOLUME_PROFILE_NAME: &str = "vprofile";
+/// Legend name of the session open/high/low lines toggle
+pub const SESSION_LEVELS_NAME: &str = "session";
+/// Legend name of the delta volume bars toggle
+pub const DELTA_VOLUME_NAME: &str = "delta";
+/// Legend name of the Parabolic SAR dots toggle
+pub const PSAR_NAME: &str = "psar";
+/// Legend name of the ATR panel toggle
+pub const ATR_NAME: &str = "atr";
+/// Legend name of the Stochastic panel toggle
+pub const STOCHASTIC_NAME: &str = "stoch";
+/// Legend name of the Ichimoku Tenkan-sen toggle
+pub const TENKAN_NAME: &str = "tenkan";
+/// Legend name of the Ichimoku Kijun-sen toggle
+pub const KIJUN_NAME: &str = "kijun";
+/// Legend name of the Ichimoku Chikou span toggle
+pub const CHIKOU_NAME: &str = "chikou";
+
+/// State of indicator line visibility
+///
+/// Missing fields deserialize to their defaults so settings saved by older
+/// versions still load.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct LineVisibility {
-    pub sma_20: bool,
-    pub sma_50: bool,
-    pub sma_200: bool,
-    pub ema_12: bool,
-    pub ema_26: bool,
+    /// Configured moving averages, drawn in order; the index is the color slot
+    pub lines: Vec<IndicatorLine>,
+    /// Bollinger Bands (upper, middle and lower lines)
+    pub bb: bool,
+    /// Donchian Channels (highest high, lowest low and their midpoint)
+    pub donchian: bool,
+    /// Keltner Channels (EMA with bands at a multiple of the ATR)
+    pub keltner: bool,
+    /// RSI oscillator panel below the candles
+    pub rsi: bool,
+    /// MACD panel with histogram below the candles
+    pub macd: bool,
+    /// Volume-weighted average price, reset each UTC day
+    pub vwap: bool,
+    /// Volume-weighted moving average of the closes over 20 candles
+    pub vwma: bool,
+    /// Simple moving average of volumes over the volume bars
+    pub volume_ma: bool,
+    /// Volume traded per price level along the right edge
+    pub volume_profile: bool,
+    /// Open, high and low of the current UTC session
+    pub session_levels: bool,
+    /// Taker buy minus sell volume as thin bars over the volume bars
+    pub delta_volume: bool,
+    /// Parabolic SAR dots below the candles in an uptrend, above in a downtrend
+    pub psar: bool,
+    /// Average True Range panel below the candles
+    pub atr: bool,
+    /// Stochastic oscillator panel (%K and %D) below the candles
+    pub stoch: bool,
+    /// Ichimoku conversion line (9-candle midpoint)
+    pub tenkan: bool,
+    /// Ichimoku base line (26-candle midpoint)
+    pub kijun: bool,
+    /// Ichimoku lagging line: closes drawn 26 candles back
+    pub chikou: bool,
+    /// Colors of the built-in lines, indexed by color type minus [`FIRST_LINE_COLOR_TYPE`]
+    pub builtin_colors: [[f32; 4]; LINE_COLOR_SLOTS],
+}
+
+/// Color types recolored by the legend entry `name`
+///
+/// Bands recolor their upper and lower lines and keep the middle one; entries
+/// drawn without lines (volume overlays, markers) have none.
+pub fn builtin_color_types(name: &str) -> &'static [usize] {
+    match name {
+        TENKAN_NAME => &[10],
+        KIJUN_NAME => &[11],
+        CHIKOU_NAME => &[14],
+        BOLLINGER_BANDS_NAME => &[15, 17],
+        RSI_NAME => &[18],
+        MACD_NAME => &[20],
+        VWAP_NAME => &[22],
+        ATR_NAME => &[23],
+        VWMA_NAME => &[24],
+        STOCHASTIC_NAME => &[25],
+        DONCHIAN_NAME => &[28, 30],
+        KELTNER_NAME => &[31, 33],
+        _ => &[],
+    }
+}
+
+impl LineVisibility {
+    /// Whether the line with the given name is configured and visible
+    pub fn is_visible(&self, name: &str) -> bool {
+        match name {
+            BOLLINGER_BANDS_NAME => self.bb,
+            DONCHIAN_NAME => self.donchian,
+            KELTNER_NAME => self.keltner,
+            RSI_NAME => self.rsi,
+            MACD_NAME => self.macd,
+            VWAP_NAME => self.vwap,
+            VWMA_NAME => self.vwma,
+            VOLUME_MA_NAME => self.volume_ma,
+            VOLUME_PROFILE_NAME => self.volume_profile,
+            SESSION_LEVELS_NAME => self.session_levels,
+            DELTA_VOLUME_NAME => self.delta_volume,
+            PSAR_NAME => self.psar,
+            ATR_NAME => self.atr,
+            STOCHASTIC_NAME => self.stoch,
+            TENKAN_NAME => self.tenkan,
+            KIJUN_NAME => self.kijun,
+            CHIKOU_NAME => self.chikou,
+            _ => self.lines.iter().any(|l| l.visible && l.name() == name),
+        }
+    }
+
+    /// Flip visibility of the named line, returning the new state
+    pub fn toggle(&mut self, name: &str) -> Option<bool> {
+        let flag = match name {
+            BOLLINGER_BANDS_NAME => Some(&mut self.bb),
+            DONCHIAN_NAME => Some(&mut self.donchian),
+            KELTNER_NAME => Some(&mut self.keltner),
+            RSI_NAME => Some(&mut self.rsi),
+            MACD_NAME => Some(&mut self.macd),
+            VWAP_NAME => Some(&mut self.vwap),
+            VWMA_NAME => Some(&mut self.vwma),
+            VOLUME_MA_NAME => Some(&mut self.volume_ma),
+            VOLUME_PROFILE_NAME => Some(&mut self.volume_profile),
+            SESSION_LEVELS_NAME => Some(&mut self.session_levels),
+            DELTA_VOLUME_NAME => Some(&mut self.delta_volume),
+            PSAR_NAME => Some(&mut self.psar),
+            ATR_NAME => Some(&mut self.atr),
+            STOCHASTIC_NAME => Some(&mut self.stoch),
+            TENKAN_NAME => Some(&mut self.tenkan),
+            KIJUN_NAME => Some(&mut self.kijun),
+            CHIKOU_NAME => Some(&mut self.chikou),
+            _ => None,
+        };
+        if let Some(flag) = flag {
+            *flag = !*flag;
+            return Some(*flag);
+        }
+        let line = self.lines.iter_mut().find(|l| l.name() == name)?;
+        line.visible = !line.visible;
+        Some(line.visible)
+    }
+
+    /// Add a new line unless one with the same name exists or all slots are used
+    pub fn add_line(&mut self, line: IndicatorLine) -> bool {
+        if self.lines.len() >= MAX_INDICATOR_LINES
+            || self.lines.iter().any(|l| l.name() == line.name())
+        {
+            return false;
+        }
+        self.lines.push(line);
+        true
+    }
+
+    /// Color of the named line, if it is drawn as one
+    pub fn color(&self, name: &str) -> Option<[f32; 4]> {
+        match builtin_color_types(name).first() {
+            Some(&color_type) => Some(self.builtin_colors[color_type - FIRST_LINE_COLOR_TYPE]),
+            None => self.lines.iter().find(|l| l.name() == name).map(|l| l.color),
+        }
+    }
+
+    /// Set the color of the named line; false if no such line exists
+    pub fn set_color(&mut self, name: &str, color: [f32; 4]) -> bool {
+        let color_types = builtin_color_types(name);
+        if !color_types.is_empty() {
+            for &color_type in color_types {
+                self.builtin_colors[color_type - FIRST_LINE_COLOR_TYPE] = color;
+            }
+            return true;
+        }
+        let Some(line) = self.lines.iter_mut().find(|l| l.name() == name) else {
+            return false;
+        };
+        line.color = color;
+        true
+    }
+
+    /// Replace the configured lines, coloring them cyclically from [`INDICATOR_PALETTE`].
+    ///
+    /// Lines that were configured before keep their visibility and color;
+    /// duplicates and entries beyond the available color slots are dropped.
+    pub fn set_configs(&mut self, configs: &[MaConfig]) {
+        let mut lines: Vec<IndicatorLine> = Vec::with_capacity(configs.len());
+        for config in configs.iter().filter(|c| c.period > 0) {
+            if lines.len() >= MAX_INDICATOR_LINES || lines.iter().any(|l| l.config() == *config) {
+                continue;
+            }
+            let color = INDICATOR_PALETTE[lines.len() % INDICATOR_PALETTE.len()];
+            let mut line = IndicatorLine::new(config.kind, config.period, color);
+            if let Some(old) = self.lines.iter().find(|l| l.config() == *config) {
+                line.visible = old.visible;
+                line.color = old.color;
+            }
+            lines.push(line);
+        }
+        self.lines = lines;
+    }
+
+    /// Remove the named line
+    pub fn remove_line(&mut self, name: &str) {
+        self.lines.retain(|l| l.name() != name);
+    }
+
+    /// Legend entries: configured lines followed by the built-in indicators
+    pub fn names(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .map(IndicatorLine::name)
+            .chain(
+                [
+                    BOLLINGER_BANDS_NAME,
+                    DONCHIAN_NAME,
+                    KELTNER_NAME,
+                    VWAP_NAME,
+                    VWMA_NAME,
+                    VOLUME_MA_NAME,
+                    VOLUME_PROFILE_NAME,
+                    DELTA_VOLUME_NAME,
+                    SESSION_LEVELS_NAME,
+                    PSAR_NAME,
+                    TENKAN_NAME,
+                    KIJUN_NAME,
+                    CHIKOU_NAME,
+                    RSI_NAME,
+                    MACD_NAME,
+                    ATR_NAME,
+                    STOCHASTIC_NAME,
+                ]
+                .map(String::from),
+            )
+            .collect()
+    }
+
+    /// Oscillator panels to stack below the candles, bottom first
+    pub fn sub_panels(&self) -> Vec<SubPanelKind> {
+        let mut panels = Vec::new();
+        if self.rsi {
+            panels.push(SubPanelKind::Rsi);
+        }
+        if self.macd {
+            panels.push(SubPanelKind::Macd);
+        }
+        if self.atr {
+            panels.push(SubPanelKind::Atr);
+        }
+        if self.stoch {
+            panels.push(SubPanelKind::Stochastic);
+        }
+        panels
+    }
 }
 
 impl Default for LineVisibility {
     fn default() -> Self {
-        Self { sma_20: true, sma_50: true, sma_200: true, ema_12: true, ema_26: true }
+        let configs = [
+            (MaKind::Sma, 20),
+            (MaKind::Sma, 50),
+            (MaKind::Sma, 200),
+            (MaKind::Ema, 12),
+            (MaKind::Ema, 26),
+        ];
+        Self {
+            lines: configs
+                .into_iter()
+                .zip(INDICATOR_PALETTE.iter().cycle())
+                .map(|((kind, period), &color)| IndicatorLine::new(kind, period, color))
+                .collect(),
+            bb: false,
+            donchian: false,
+            keltner: false,
+            rsi: false,
+            macd: false,
+            vwap: false,
+            vwma: false,
+            volume_ma: false,
+            volume_profile: false,
+            session_levels: false,
+            delta_volume: false,
+            psar: false,
+            atr: false,
+            stoch: false,
+            tenkan: false,
+            kijun: false,
+            chikou: false,
+            builtin_colors: DEFAULT_LINE_COLORS,
+        }
     }
 }
 
 mod geometry;
 pub use geometry::{
-    EDGE_GAP, MAX_ELEMENT_WIDTH, MIN_ELEMENT_WIDTH, SPACING_RATIO, candle_x_position,
-    spacing_ratio_for,
+    CHIKOU_SHIFT, CandleSizing, DELTA_BAR_WIDTH_RATIO, DEPTH_BUCKETS, DEPTH_MAX_WIDTH,
+    DONCHIAN_PERIOD, EDGE_GAP, KELTNER_ATR_PERIOD, KELTNER_EMA_PERIOD, KELTNER_MULTIPLIER,
+    KIJUN_PERIOD, MAX_ELEMENT_WIDTH, MAX_USER_ELEMENT_WIDTH, MAX_USER_SPACING_RATIO,
+    MIN_ELEMENT_WIDTH, MIN_USER_ELEMENT_WIDTH, PSAR_MAX_STEP, PSAR_STEP, PriceExtremes,
+    SPACING_RATIO, TENKAN_PERIOD, VOLUME_MA_PERIOD, VOLUME_PROFILE_BINS, VOLUME_PROFILE_MAX_WIDTH,
+    candle_x_position, candles_per_element, downsample_candles, downsample_runs,
+    line_thickness_ndc, price_extremes, spacing_ratio_for,
 };
+use geometry::{TailLayout, TailPatch};
 mod initialization;
 mod performance;
+pub use performance::{PerfStats, average_fps};
 mod render_loop;
 mod render_queue;
+mod screenshot;
 
-pub use render_queue::{enqueue_render_task, init_render_queue};
+pub use render_queue::{
+    DEFAULT_MAX_FPS, FPS_CAP_OPTIONS, RenderThrottle, enqueue_render_task, init_render_queue,
+    max_fps, min_frame_interval_ms, set_max_fps,
+};
+pub use screenshot::{download_data_url, export_chart_png, padded_bytes_per_row, unpad_rows};
 
 #[allow(invalid_value)]
 pub fn dummy_renderer() -> WebGpuRenderer {
+    dummy_renderer_for("")
+}
+
+/// [`dummy_renderer`] bound to `canvas_id`
+#[allow(invalid_value)]
+pub fn dummy_renderer_for(canvas_id: &str) -> WebGpuRenderer {
     use std::collections::VecDeque;
     unsafe {
         WebGpuRenderer {
-            _canvas_id: String::new(),
+            canvas_id: canvas_id.to_string(),
+            primary: true,
             width: 800,
             height: 600,
+            device_pixel_ratio: 1.0,
             surface: std::mem::MaybeUninit::zeroed().assume_init(),
             device: std::mem::MaybeUninit::zeroed().assume_init(),
             queue: std::mem::MaybeUninit::zeroed().assume_init(),
             config: std::mem::MaybeUninit::zeroed().assume_init(),
             render_pipeline: std::mem::MaybeUninit::zeroed().assume_init(),
+            candle_pipeline: std::mem::MaybeUninit::zeroed().assume_init(),
             vertex_buffer: std::mem::MaybeUninit::zeroed().assume_init(),
+            instance_buffer: std::mem::MaybeUninit::zeroed().assume_init(),
             uniform_buffer: std::mem::MaybeUninit::zeroed().assume_init(),
             uniform_bind_group: std::mem::MaybeUninit::zeroed().assume_init(),
             msaa_texture: std::mem::MaybeUninit::zeroed().assume_init(),
             msaa_view: std::mem::MaybeUninit::zeroed().assume_init(),
-            template_vertices: 0,
+            sample_count: MSAA_SAMPLE_COUNT,
+            template_vertices: CandleGeometry::TEMPLATE_VERTEX_COUNT,
             cached_vertices: Vec::new(),
+            cached_instances: Vec::new(),
             cached_uniforms: ChartUniforms::new(),
             cached_candle_count: 0,
             cached_zoom_level: 1.0,
             cached_hash: 0,
             cached_data_hash: 0,
+            cached_prefix_hash: 0,
+            cached_tail: None,
+            last_geometry_update: GeometryUpdate::Unchanged,
             cached_line_visibility: LineVisibility::default(),
             zoom_level: 1.0,
             pan_offset: 0.0,
             last_frame_time: 0.0,
             fps_log: VecDeque::new(),
+            last_render_ms: 0.0,
             line_visibility: LineVisibility::default(),
+            debug_mode: false,
+            clip_indicators: false,
+            show_wicks: true,
+            price_range: None,
+            preview_interval: None,
+            candle_outline: None,
+            candle_sizing: CandleSizing::default(),
+            order_book: None,
+            compare_overlay: Vec::new(),
+            indicator_cache: None,
+            price_scale_mode: PriceScaleMode::Linear,
+            sub_panel_height: SUB_PANEL_HEIGHT,
+            crosshair: None,
+            theme: Theme::default(),
+            grid_visible: true,
+            grid_divisions: GRID_DIVISIONS,
+            gap_threshold: GAP_THRESHOLD,
+            volume_ma_period: VOLUME_MA_PERIOD,
+            price_padding: PricePadding::default(),
+            invert_y: false,
         }
     }
 }
@@ -1,5 +1,19 @@
 use super::*;
 
+/// Frame statistics shown by the performance HUD
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PerfStats {
+    pub avg_fps: f64,
+    pub candle_count: usize,
+    /// Time spent encoding and submitting the last render pass
+    pub render_ms: f64,
+}
+
+/// Mean of the recorded FPS samples, 0 when nothing was recorded yet
+pub fn average_fps(samples: &VecDeque<f64>) -> f64 {
+    if samples.is_empty() { 0.0 } else { samples.iter().sum::<f64>() / samples.len() as f64 }
+}
+
 impl WebGpuRenderer {
     /// Measure average FPS for the given number of frames
     pub fn measure_fps(&mut self, chart: &Chart, num_frames: u32) -> f64 {
@@ -13,4 +27,22 @@ impl WebGpuRenderer {
         let elapsed = (end - start) / 1000.0;
         if elapsed > 0.0 { num_frames as f64 / elapsed } else { 0.0 }
     }
+
+    /// Record a frame rate sample, keeping the last 60
+    pub fn record_fps(&mut self, fps: f64) {
+        self.fps_log.push_back(fps);
+        if self.fps_log.len() > 60 {
+            self.fps_log.pop_front();
+        }
+    }
+
+    /// Average FPS over the recorded frames
+    pub fn average_fps(&self) -> f64 {
+        average_fps(&self.fps_log)
+    }
+
+    /// Statistics of the last frame for the performance HUD
+    pub fn perf_stats(&self, candle_count: usize) -> PerfStats {
+        PerfStats { avg_fps: self.average_fps(), candle_count, render_ms: self.last_render_ms }
+    }
 }
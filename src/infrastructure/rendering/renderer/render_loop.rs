@@ -2,7 +2,7 @@ use super::*;
 use crate::domain::logging::LogComponent;
 use crate::domain::market_data::TimeInterval;
 use crate::log_info;
-use leptos::{SignalGetUntracked, SignalSet};
+use leptos::{SignalGetUntracked, SignalSet, SignalWithUntracked};
 use serde_json;
 use std::hash::{Hash, Hasher};
 
@@ -20,22 +20,44 @@ impl WebGpuRenderer {
     }
 
     pub fn data_hash(chart: &Chart, _zoom: f64) -> u64 {
+        Self::data_hashes(chart).1
+    }
+
+    /// Hashes of the chart data without and with its last candle
+    fn data_hashes(chart: &Chart) -> (u64, u64) {
         let candles = chart
             .get_series(crate::app::current_interval().get_untracked())
             .or_else(|| chart.get_series(TimeInterval::TwoSeconds))
             .expect("base series not found")
             .get_candles();
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        chart.chart_type.as_ref().hash(&mut hasher);
+        for alert in &chart.price_alerts {
+            alert.to_bits().hash(&mut hasher);
+        }
+        if let Some(fib) = &chart.fib_retracement {
+            for (ts, price) in [fib.start, fib.end] {
+                ts.value().hash(&mut hasher);
+                price.to_bits().hash(&mut hasher);
+            }
+        }
         candles.len().hash(&mut hasher);
-        for c in candles {
-            c.timestamp.value().hash(&mut hasher);
-            c.ohlcv.open.value().to_bits().hash(&mut hasher);
-            c.ohlcv.high.value().to_bits().hash(&mut hasher);
-            c.ohlcv.low.value().to_bits().hash(&mut hasher);
-            c.ohlcv.close.value().to_bits().hash(&mut hasher);
-            c.ohlcv.volume.value().to_bits().hash(&mut hasher);
+        let hash_candle = |c: &Candle, hasher: &mut std::collections::hash_map::DefaultHasher| {
+            c.timestamp.value().hash(hasher);
+            c.ohlcv.open.value().to_bits().hash(hasher);
+            c.ohlcv.high.value().to_bits().hash(hasher);
+            c.ohlcv.low.value().to_bits().hash(hasher);
+            c.ohlcv.close.value().to_bits().hash(hasher);
+            c.ohlcv.volume.value().to_bits().hash(hasher);
+        };
+        for c in candles.iter().take(candles.len().saturating_sub(1)) {
+            hash_candle(c, &mut hasher);
         }
-        hasher.finish()
+        let prefix_hash = hasher.finish();
+        if let Some(c) = candles.back() {
+            hash_candle(c, &mut hasher);
+        }
+        (prefix_hash, hasher.finish())
     }
 
     fn update_cached_geometry(
@@ -50,10 +72,10 @@ impl WebGpuRenderer {
         }
 
         self.cached_vertices = vertices;
+        self.cached_instances = instances;
         self.cached_uniforms = uniforms;
         self.cached_hash = new_hash;
         self.cached_line_visibility = self.line_visibility.clone();
-        self.template_vertices = self.cached_vertices.len() as u32;
 
         #[cfg(all(not(test), not(target_arch = "wasm32")))]
         self.write_buffers();
@@ -61,15 +83,134 @@ impl WebGpuRenderer {
         true
     }
 
+    /// ⚡ Cache geometry that differs from the cached one only around the last candle,
+    /// rewriting just the changed instance and vertex ranges; anything else falls back to
+    /// a full update. Covers the charts whose tail the fast path cannot patch.
+    fn patch_cached_geometry(
+        &mut self,
+        vertices: Vec<CandleVertex>,
+        instances: Vec<CandleInstance>,
+        uniforms: ChartUniforms,
+    ) -> GeometryUpdate {
+        let bytes = bytemuck::bytes_of::<CandleVertex>;
+        let same_layout = vertices.len() == self.cached_vertices.len()
+            && instances.len() == self.cached_instances.len()
+            && bytemuck::bytes_of(&uniforms) == bytemuck::bytes_of(&self.cached_uniforms);
+        let tail = instances.len().saturating_sub(1);
+        if !same_layout
+            || bytemuck::cast_slice::<CandleInstance, u8>(&instances[..tail])
+                != bytemuck::cast_slice::<CandleInstance, u8>(&self.cached_instances[..tail])
+        {
+            return if self.update_cached_geometry(vertices, instances, uniforms) {
+                GeometryUpdate::Full
+            } else {
+                GeometryUpdate::Unchanged
+            };
+        }
+
+        let changed = |(new, old): (&CandleVertex, &CandleVertex)| bytes(new) != bytes(old);
+        let pairs = || vertices.iter().zip(&self.cached_vertices);
+        let vertex_range = match (pairs().position(changed), pairs().rposition(changed)) {
+            (Some(first), Some(last)) => first..last + 1,
+            _ => 0..0,
+        };
+        let instance_range = match (instances.last(), self.cached_instances.last()) {
+            (Some(new), Some(old)) if bytemuck::bytes_of(new) != bytemuck::bytes_of(old) => {
+                tail..tail + 1
+            }
+            _ => 0..0,
+        };
+        if vertex_range.is_empty() && instance_range.is_empty() {
+            return GeometryUpdate::Unchanged;
+        }
+
+        self.cached_hash = Self::geometry_hash(&vertices, &instances, &uniforms);
+        self.cached_vertices = vertices;
+        self.cached_instances = instances;
+
+        #[cfg(all(not(test), not(target_arch = "wasm32")))]
+        self.write_buffer_ranges(instance_range, &[vertex_range]);
+        #[cfg(any(test, target_arch = "wasm32"))]
+        let _ = (instance_range, vertex_range);
+
+        GeometryUpdate::Partial
+    }
+
+    /// ⚡ Write the regenerated last candle instance and the vertex runs following it
+    /// over the cached geometry and upload just those ranges
+    fn apply_tail_patch(&mut self, patch: TailPatch) -> GeometryUpdate {
+        let last = self.cached_instances.len().saturating_sub(1);
+        if let Some(instance) = self.cached_instances.get_mut(last) {
+            *instance = patch.instance;
+        }
+        let instance_range = last..self.cached_instances.len();
+        let mut vertex_ranges = Vec::with_capacity(patch.vertices.len());
+        for (at, vertices) in patch.vertices {
+            let range = at..at + vertices.len();
+            self.cached_vertices[range.clone()].copy_from_slice(&vertices);
+            vertex_ranges.push(range);
+        }
+        // Rehashing would walk the whole geometry again, so the next build never matches
+        self.cached_hash = 0;
+
+        #[cfg(all(not(test), not(target_arch = "wasm32")))]
+        self.write_buffer_ranges(instance_range, &vertex_ranges);
+        #[cfg(any(test, target_arch = "wasm32"))]
+        let _ = (instance_range, vertex_ranges);
+
+        GeometryUpdate::Partial
+    }
+
+    #[cfg(all(not(test), not(target_arch = "wasm32")))]
+    fn write_buffer_ranges(
+        &self,
+        instances: std::ops::Range<usize>,
+        vertices: &[std::ops::Range<usize>],
+    ) {
+        let instance_size = std::mem::size_of::<CandleInstance>();
+        let vertex_size = std::mem::size_of::<CandleVertex>();
+        let instance_end = instances.end.min(self.instance_count() as usize);
+        if instances.start < instance_end {
+            let bytes = bytemuck::cast_slice(&self.cached_instances[instances.start..instance_end]);
+            let offset = instances.start * instance_size;
+            self.queue.write_buffer(&self.instance_buffer, offset as u64, bytes);
+        }
+        for range in vertices.iter().filter(|range| !range.is_empty()) {
+            let offset = (self.template_vertices as usize + range.start) * vertex_size;
+            let bytes = bytemuck::cast_slice(&self.cached_vertices[range.clone()]);
+            self.queue.write_buffer(&self.vertex_buffer, offset as u64, bytes);
+        }
+    }
+
     #[cfg(all(not(test), not(target_arch = "wasm32")))]
     fn write_buffers(&self) {
         let vertex_bytes = bytemuck::cast_slice(&self.cached_vertices);
+        let instances = &self.cached_instances[..self.instance_count() as usize];
+        let instance_bytes = bytemuck::cast_slice(instances);
         let uniform_copy = self.cached_uniforms;
         let uniform_bytes = bytemuck::bytes_of(&uniform_copy);
-        self.queue.write_buffer(&self.vertex_buffer, 0, vertex_bytes);
+        // The candle template stays at the start of the vertex buffer
+        let template_bytes = self.template_vertices as usize * std::mem::size_of::<CandleVertex>();
+        self.queue.write_buffer(&self.vertex_buffer, template_bytes as u64, vertex_bytes);
+        self.queue.write_buffer(&self.instance_buffer, 0, instance_bytes);
         self.queue.write_buffer(&self.uniform_buffer, 0, uniform_bytes);
     }
 
+    /// Number of candle instances drawn in the current frame
+    pub fn instance_count(&self) -> u32 {
+        self.cached_instances.len().min(MAX_CANDLE_INSTANCES) as u32
+    }
+
+    /// Bytes uploaded to the instance buffer for the current frame
+    pub fn instance_buffer_bytes(&self) -> usize {
+        self.instance_count() as usize * std::mem::size_of::<CandleInstance>()
+    }
+
+    /// Vertices shared by every candle instance
+    pub fn template_vertex_count(&self) -> u32 {
+        self.template_vertices
+    }
+
     pub fn cache_geometry_for_test(&mut self, chart: &Chart) {
         let (inst, verts, uni) = self.create_geometry(chart);
         self.update_cached_geometry(verts, inst, uni);
@@ -77,7 +218,7 @@ impl WebGpuRenderer {
     }
 
     pub fn cached_hash_for_test(&self) -> u64 {
-        self.cached_hash
+        Self::geometry_hash(&self.cached_vertices, &self.cached_instances, &self.cached_uniforms)
     }
 
     pub fn create_geometry_for_test(
@@ -87,6 +228,77 @@ impl WebGpuRenderer {
         self.create_geometry(chart)
     }
 
+    /// How the last geometry refresh reached the GPU buffers
+    pub fn last_geometry_update(&self) -> GeometryUpdate {
+        self.last_geometry_update
+    }
+
+    fn candle_count(&self, chart: &Chart) -> usize {
+        chart.get_series(self.series_interval()).map(|s| s.get_candles().len()).unwrap_or_else(
+            || {
+                chart
+                    .get_series(TimeInterval::TwoSeconds)
+                    .expect("base series not found")
+                    .get_candles()
+                    .len()
+            },
+        )
+    }
+
+    /// 🔄 Rebuild the cached geometry if the chart or view changed since the last frame.
+    /// A tick that only touched the last candle regenerates and uploads just the geometry
+    /// it draws when the chart allows, and otherwise rewrites just the changed ranges.
+    /// Returns `None` when the chart yields nothing to draw.
+    pub fn refresh_geometry(&mut self, chart: &Chart) -> Option<GeometryUpdate> {
+        let candle_count = self.candle_count(chart);
+        let (prefix_hash, data_hash) = Self::data_hashes(chart);
+        let data_changed = data_hash != self.cached_data_hash;
+        let visibility_changed = self.line_visibility != self.cached_line_visibility;
+
+        let geometry_needs_update = candle_count != self.cached_candle_count
+            || (self.zoom_level - self.cached_zoom_level).abs() > f64::EPSILON;
+
+        if !(geometry_needs_update || data_changed || visibility_changed) {
+            self.last_geometry_update = GeometryUpdate::Unchanged;
+            return Some(GeometryUpdate::Unchanged);
+        }
+
+        let last_candle_only =
+            !geometry_needs_update && !visibility_changed && prefix_hash == self.cached_prefix_hash;
+        // ⚡ A tick on the last candle regenerates only the geometry it draws
+        let patch = self
+            .cached_tail
+            .as_ref()
+            .filter(|_| last_candle_only)
+            .and_then(|tail| self.patch_tail(chart, tail));
+        if let Some(patch) = patch {
+            self.cached_data_hash = data_hash;
+            let update = self.apply_tail_patch(patch);
+            self.last_geometry_update = update;
+            return Some(update);
+        }
+
+        let (instances, vertices, uniforms, tail) = self.build_geometry(chart);
+        // Line and area charts draw no candle instances
+        if instances.is_empty() && vertices.is_empty() {
+            return None;
+        }
+        self.cached_candle_count = candle_count;
+        self.cached_zoom_level = self.zoom_level;
+        self.cached_data_hash = data_hash;
+        self.cached_prefix_hash = prefix_hash;
+        let update = if last_candle_only {
+            self.patch_cached_geometry(vertices, instances, uniforms)
+        } else if self.update_cached_geometry(vertices, instances, uniforms) {
+            GeometryUpdate::Full
+        } else {
+            GeometryUpdate::Unchanged
+        };
+        self.cached_tail = tail;
+        self.last_geometry_update = update;
+        Some(update)
+    }
+
     pub fn render(&mut self, chart: &Chart) -> Result<(), JsValue> {
         // ⏱️ Measure frame time
         if let Some(window) = web_sys::window()
@@ -96,29 +308,16 @@ impl WebGpuRenderer {
             if self.last_frame_time > 0.0 {
                 let delta = now - self.last_frame_time;
                 if delta > 0.0 {
-                    let fps = 1000.0 / delta;
-                    self.fps_log.push_back(fps);
-                    if self.fps_log.len() > 60 {
-                        self.fps_log.pop_front();
-                    }
+                    self.record_fps(1000.0 / delta);
                 }
             }
             self.last_frame_time = now;
         }
 
-        use crate::app::current_interval;
-        let interval = current_interval().get_untracked();
-        let candle_count =
-            chart.get_series(interval).map(|s| s.get_candles().len()).unwrap_or_else(|| {
-                chart
-                    .get_series(TimeInterval::TwoSeconds)
-                    .expect("base series not found")
-                    .get_candles()
-                    .len()
-            });
+        let candle_count = self.candle_count(chart);
 
         // Log only every 100 frames for performance
-        if candle_count % 100 == 0 {
+        if candle_count.is_multiple_of(100) {
             log_info!(
                 LogComponent::Infrastructure("WebGpuRenderer"),
                 "📊 Chart has {} candles to render",
@@ -130,31 +329,37 @@ impl WebGpuRenderer {
             return Ok(());
         }
 
-        let data_hash = Self::data_hash(chart, self.zoom_level);
-        let data_changed = data_hash != self.cached_data_hash;
-        let visibility_changed = self.line_visibility != self.cached_line_visibility;
-
-        let geometry_needs_update = candle_count != self.cached_candle_count
-            || (self.zoom_level - self.cached_zoom_level).abs() > f64::EPSILON;
-
-        if geometry_needs_update || data_changed || visibility_changed {
-            let (instances, vertices, uniforms) = self.create_geometry(chart);
-            if instances.is_empty() {
-                return Ok(());
+        let Some(update) = self.refresh_geometry(chart) else { return Ok(()) };
+        if update != GeometryUpdate::Unchanged {
+            // Overlays and the axis follow the main chart only
+            if self.primary {
+                let labels = self.fib_labels(chart);
+                if crate::app::fib_labels().with_untracked(|l| *l != labels) {
+                    crate::app::fib_labels().set(labels);
+                }
+                let sessions = self.session_labels(chart);
+                if crate::app::session_labels().with_untracked(|l| *l != sessions) {
+                    crate::app::session_labels().set(sessions);
+                }
+                let extremes = self.extreme_labels(chart);
+                if crate::app::extreme_labels().with_untracked(|l| *l != extremes) {
+                    crate::app::extreme_labels().set(extremes);
+                }
+                let range = self.price_axis_range();
+                if crate::app::price_axis_range().with_untracked(|r| *r != range) {
+                    crate::app::price_axis_range().set(range);
+                }
+                let base = self.percent_base();
+                if crate::app::percent_base().get_untracked() != base {
+                    crate::app::percent_base().set(base);
+                }
             }
-            self.cached_candle_count = candle_count;
-            self.cached_zoom_level = self.zoom_level;
-            self.cached_data_hash = data_hash;
-            self.update_cached_geometry(vertices, instances, uniforms);
         }
 
-        // Skip empty check for simple shader - we don't use instances
-        if self.cached_vertices.is_empty() {
+        if self.cached_instances.is_empty() && self.cached_vertices.is_empty() {
             return Ok(());
         }
 
-        let num_vertices = self.template_vertices;
-
         // Get surface texture and start rendering
         let output = self.surface.get_current_texture().map_err(|e| {
             let error_msg = format!("Failed to get surface texture: {:?}", e);
@@ -170,32 +375,7 @@ impl WebGpuRenderer {
             label: Some("Render Encoder"),
         });
 
-        {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &self.msaa_view,
-                    resolve_target: Some(&surface_view),
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 0.145,
-                            g: 0.196,
-                            b: 0.259,
-                            a: 1.0, // Chart background color
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                occlusion_query_set: None,
-                timestamp_writes: None,
-            });
-
-            render_pass.set_pipeline(&self.render_pipeline);
-            render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
-            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-            render_pass.draw(0..num_vertices, 0..1);
-        }
+        self.encode_chart_pass(&mut encoder, &surface_view);
 
         self.queue.submit(std::iter::once(encoder.finish()));
 
@@ -205,6 +385,7 @@ impl WebGpuRenderer {
         {
             let end = perf.now();
             let duration = end - start;
+            self.last_render_ms = duration;
             log_info!(
                 LogComponent::Infrastructure("WebGpuRenderer"),
                 "\u{23f1}\u{fe0f} Render pass took {:.2} ms",
@@ -214,16 +395,96 @@ impl WebGpuRenderer {
 
         output.present();
 
+        if self.primary && crate::app::perf_hud_visible().get_untracked() {
+            crate::app::perf_stats().set(self.perf_stats(candle_count));
+        }
+
         Ok(())
     }
 
+    /// Id of the canvas this renderer draws on
+    pub fn canvas_id(&self) -> &str {
+        &self.canvas_id
+    }
+
+    /// Whether this renders the main chart
+    pub fn is_primary(&self) -> bool {
+        self.primary
+    }
+
+    /// Number of MSAA samples the pipelines were built with
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Descriptor of the MSAA target for the current surface size
+    pub fn msaa_target_descriptor(&self) -> wgpu::TextureDescriptor<'static> {
+        msaa_texture_descriptor(self.width, self.height, self.config.format, self.sample_count)
+    }
+
+    /// Size and sample count of the MSAA texture currently allocated
+    pub fn msaa_texture_size(&self) -> (u32, u32, u32) {
+        let size = self.msaa_texture.size();
+        (size.width, size.height, self.msaa_texture.sample_count())
+    }
+
+    /// Color attachment drawing into `target`, through the MSAA texture when enabled
+    fn color_attachment<'a>(
+        &'a self,
+        target: &'a wgpu::TextureView,
+        load: wgpu::LoadOp<wgpu::Color>,
+    ) -> wgpu::RenderPassColorAttachment<'a> {
+        let (view, resolve_target) =
+            if self.sample_count > 1 { (&self.msaa_view, Some(target)) } else { (target, None) };
+        wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target,
+            ops: wgpu::Operations { load, store: wgpu::StoreOp::Store },
+        }
+    }
+
+    /// Draw the cached geometry into `target` through the MSAA texture
+    pub(super) fn encode_chart_pass(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+    ) {
+        let template_vertices = self.template_vertices;
+        let instance_count = self.instance_count();
+        let num_vertices = self.cached_vertices.len() as u32;
+        // The grid leads the vertex list so it can be drawn behind the candles
+        let grid_vertices =
+            self.cached_vertices.iter().take_while(|v| v.element_type == 3.0).count() as u32;
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Render Pass"),
+            color_attachments: &[Some(
+                self.color_attachment(target, wgpu::LoadOp::Clear(self.clear_color())),
+            )],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+
+        render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.draw(template_vertices..template_vertices + grid_vertices, 0..1);
+
+        // Candles: one template expanded per instance
+        render_pass.set_pipeline(&self.candle_pipeline);
+        render_pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
+        render_pass.draw(CandleGeometry::template_draw_range(self.show_wicks), 0..instance_count);
+
+        // Volume, indicators and overlays follow the template
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.draw(template_vertices + grid_vertices..template_vertices + num_vertices, 0..1);
+    }
+
     /// Get renderer performance information
     pub fn get_performance_info(&self) -> String {
-        let avg_fps = if self.fps_log.is_empty() {
-            0.0
-        } else {
-            self.fps_log.iter().sum::<f64>() / self.fps_log.len() as f64
-        };
+        let avg_fps = self.average_fps();
 
         serde_json::json!({
             "backend": "WebGPU",
@@ -261,31 +522,7 @@ impl WebGpuRenderer {
 
     /// Toggle indicator line visibility
     pub fn toggle_line_visibility(&mut self, line_name: &str) {
-        let state = match line_name {
-            "sma20" => {
-                self.line_visibility.sma_20 = !self.line_visibility.sma_20;
-                Some(self.line_visibility.sma_20)
-            }
-            "sma50" => {
-                self.line_visibility.sma_50 = !self.line_visibility.sma_50;
-                Some(self.line_visibility.sma_50)
-            }
-            "sma200" => {
-                self.line_visibility.sma_200 = !self.line_visibility.sma_200;
-                Some(self.line_visibility.sma_200)
-            }
-            "ema12" => {
-                self.line_visibility.ema_12 = !self.line_visibility.ema_12;
-                Some(self.line_visibility.ema_12)
-            }
-            "ema26" => {
-                self.line_visibility.ema_26 = !self.line_visibility.ema_26;
-                Some(self.line_visibility.ema_26)
-            }
-            _ => None,
-        };
-
-        if let Some(state) = state {
+        if let Some(state) = self.line_visibility.toggle(line_name) {
             log_info!(
                 LogComponent::Infrastructure("LegendToggle"),
                 "Line {} visible: {}",
@@ -296,10 +533,369 @@ impl WebGpuRenderer {
         crate::app::global_line_visibility().set(self.line_visibility.clone());
     }
 
+    /// Add a configured indicator line; returns false if it exists or no slot is free
+    pub fn add_indicator_line(&mut self, line: IndicatorLine) -> bool {
+        let added = self.line_visibility.add_line(line);
+        if added {
+            crate::app::global_line_visibility().set(self.line_visibility.clone());
+        }
+        added
+    }
+
+    /// Replace the configured moving averages with the given list
+    pub fn set_ma_configs(&mut self, configs: &[MaConfig]) {
+        self.line_visibility.set_configs(configs);
+        crate::app::global_line_visibility().set(self.line_visibility.clone());
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    /// 🎨 Recolor an indicator line; false if no line has that name
+    pub fn set_indicator_color(&mut self, line_name: &str, color: [f32; 4]) -> bool {
+        if !self.line_visibility.set_color(line_name, color) {
+            return false;
+        }
+        crate::app::global_line_visibility().set(self.line_visibility.clone());
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+        true
+    }
+
+    /// Remove a configured indicator line by name
+    pub fn remove_indicator_line(&mut self, line_name: &str) {
+        self.line_visibility.remove_line(line_name);
+        crate::app::global_line_visibility().set(self.line_visibility.clone());
+    }
+
+    /// Follow the main chart's legend without publishing it back
+    pub fn mirror_line_visibility(&mut self, line_visibility: &LineVisibility) {
+        if self.line_visibility != *line_visibility {
+            self.line_visibility = line_visibility.clone();
+        }
+    }
+
     pub fn line_visibility(&self) -> LineVisibility {
         self.line_visibility.clone()
     }
 
+    /// 🐞 Enable or disable debug outlines around panes and regions
+    pub fn set_debug_mode(&mut self, enabled: bool) {
+        self.debug_mode = enabled;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    /// 🐞 Toggle debug outlines and return the new state
+    pub fn toggle_debug_mode(&mut self) -> bool {
+        self.set_debug_mode(!self.debug_mode);
+        log_info!(
+            LogComponent::Infrastructure("WebGpuRenderer"),
+            "🐞 Debug outlines: {}",
+            self.debug_mode
+        );
+        self.debug_mode
+    }
+
+    pub fn debug_mode(&self) -> bool {
+        self.debug_mode
+    }
+
+    /// ✂️ Exclude indicator values from the price range so candles stay readable
+    pub fn set_clip_indicators(&mut self, enabled: bool) {
+        self.clip_indicators = enabled;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn clip_indicators(&self) -> bool {
+        self.clip_indicators
+    }
+
+    /// 🕯️ Show or hide candle wicks; the price range still covers highs and lows
+    pub fn set_show_wicks(&mut self, show: bool) {
+        // Only the per-instance draw range changes; geometry stays cached
+        self.show_wicks = show;
+        crate::app::show_wicks().set(show);
+    }
+
+    pub fn show_wicks(&self) -> bool {
+        self.show_wicks
+    }
+
+    /// 👀 Draw `interval` instead of the current timeframe, or stop previewing with `None`
+    pub fn set_preview_interval(&mut self, interval: Option<TimeInterval>) {
+        self.preview_interval = interval;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn preview_interval(&self) -> Option<TimeInterval> {
+        self.preview_interval
+    }
+
+    /// Timeframe of the series being drawn
+    pub fn series_interval(&self) -> TimeInterval {
+        self.preview_interval.unwrap_or_else(|| crate::app::current_interval().get_untracked())
+    }
+
+    /// 🔲 Pin the price axis to `(min, max)`, or fit it to the visible data again with `None`
+    pub fn set_price_range(&mut self, range: Option<(f64, f64)>) {
+        self.price_range = range.filter(|(min, max)| max > min);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn price_range(&self) -> Option<(f64, f64)> {
+        self.price_range
+    }
+
+    /// 🔲 Draw a border around candle bodies, or remove it with `None`
+    pub fn set_candle_outline(&mut self, outline: Option<CandleOutline>) {
+        self.candle_outline = outline;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn candle_outline(&self) -> Option<CandleOutline> {
+        self.candle_outline
+    }
+
+    /// 🕯️ Set candle width limits and spacing
+    pub fn set_candle_sizing(&mut self, sizing: CandleSizing) {
+        self.candle_sizing = sizing;
+        crate::app::candle_sizing().set(sizing);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn candle_sizing(&self) -> CandleSizing {
+        self.candle_sizing
+    }
+
+    /// 📚 Set the order book drawn beside the price axis; `None` hides the depth bars
+    pub fn set_order_book(&mut self, book: Option<OrderBook>) {
+        self.order_book = book;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn order_book(&self) -> Option<&OrderBook> {
+        self.order_book.as_ref()
+    }
+
+    /// 🆚 Set the candles of the symbol overlaid as a percent-change line; an empty
+    /// list hides the overlay
+    pub fn set_compare_overlay(&mut self, candles: Vec<Candle>) {
+        self.compare_overlay = candles;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn compare_overlay(&self) -> &[Candle] {
+        &self.compare_overlay
+    }
+
+    /// 📈 Use moving averages cached by the ECS indicator system; stale caches are ignored.
+    ///
+    /// The values only depend on the candles, so the cached geometry stays valid.
+    pub fn set_indicator_cache(&mut self, cache: Option<IndicatorComponent>) {
+        self.indicator_cache = cache;
+    }
+
+    /// 📐 Switch between linear and logarithmic price scaling
+    pub fn set_price_scale_mode(&mut self, mode: PriceScaleMode) {
+        self.price_scale_mode = mode;
+        crate::app::global_price_scale_mode().set(mode);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn price_scale_mode(&self) -> PriceScaleMode {
+        self.price_scale_mode
+    }
+
+    /// 📉 Set the height of each oscillator panel in NDC units
+    pub fn set_sub_panel_height(&mut self, height: f32) {
+        self.sub_panel_height = height.max(0.0);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    /// ➕ Show the crosshair at an NDC position, or hide it with `None`
+    pub fn set_crosshair(&mut self, position: Option<(f32, f32)>) {
+        if self.crosshair == position {
+            return;
+        }
+        self.crosshair = position;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn crosshair(&self) -> Option<(f32, f32)> {
+        self.crosshair
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        crate::app::chart_theme().set(theme);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    /// 🎨 Recolor the chart background of the active theme
+    pub fn set_background_color(&mut self, color: [f32; 4]) {
+        self.set_theme(Theme { background: color, ..self.theme });
+    }
+
+    pub fn background_color(&self) -> [f32; 4] {
+        self.theme.background
+    }
+
+    /// Color the chart pass clears the canvas to before drawing
+    pub fn clear_color(&self) -> wgpu::Color {
+        self.theme.clear_color()
+    }
+
+    /// #️⃣ Show or hide the background grid
+    pub fn set_grid_visible(&mut self, visible: bool) {
+        self.grid_visible = visible;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn grid_visible(&self) -> bool {
+        self.grid_visible
+    }
+
+    /// #️⃣ Set the grid density as (time columns, price rows); zero counts are raised to one
+    pub fn set_grid_divisions(&mut self, divisions: (usize, usize)) {
+        self.grid_divisions = (divisions.0.max(1), divisions.1.max(1));
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn grid_divisions(&self) -> (usize, usize) {
+        self.grid_divisions
+    }
+
+    /// ⏸️ Set the gap threshold as a multiple of the interval duration
+    pub fn set_gap_threshold(&mut self, threshold: f64) {
+        self.gap_threshold = threshold.max(1.0);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn gap_threshold(&self) -> f64 {
+        self.gap_threshold
+    }
+
+    /// 📊 Set the window of the volume average, at least one candle
+    pub fn set_volume_ma_period(&mut self, period: usize) {
+        self.volume_ma_period = period.max(1);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn volume_ma_period(&self) -> usize {
+        self.volume_ma_period
+    }
+
+    /// ↕️ Set the headroom above and below the fitted price window
+    pub fn set_price_padding(&mut self, padding: PricePadding) {
+        self.price_padding = padding;
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn price_padding(&self) -> PricePadding {
+        self.price_padding
+    }
+
+    /// 🙃 Draw higher prices further down, as on inverted futures charts
+    pub fn set_invert_y(&mut self, invert: bool) {
+        self.invert_y = invert;
+        crate::app::invert_y().set(invert);
+        // Force geometry refresh on next render
+        self.cached_zoom_level = f64::MAX;
+    }
+
+    pub fn invert_y(&self) -> bool {
+        self.invert_y
+    }
+
+    /// Price under a vertical NDC position of the last rendered frame
+    pub fn price_at_ndc_y(&self, y: f32) -> Option<f64> {
+        self.price_axis_range()?.price_at_ndc_y(y)
+    }
+
+    /// Vertical NDC position of `price` in the last rendered frame; `None` outside the candle area
+    pub fn ndc_y_at_price(&self, price: f64) -> Option<f32> {
+        self.price_axis_range()?.ndc_y(price)
+    }
+
+    /// Price window of the candle area in the last rendered frame
+    pub fn price_axis_range(&self) -> Option<PriceAxisRange> {
+        let [_, _, min, max] = self.cached_uniforms.viewport;
+        (max > min).then(|| PriceAxisRange {
+            min_price: min as f64,
+            max_price: max as f64,
+            scale: self.price_scale_mode,
+            main_bottom: self.panel_layout().main_bottom,
+            inverted: self.invert_y,
+        })
+    }
+
+    /// 📊 Close of the first visible candle in the last frame, the 0% of percent mode
+    pub fn percent_base(&self) -> Option<f64> {
+        let base = self.cached_uniforms.time_range[3] as f64;
+        (base > 0.0).then_some(base)
+    }
+
+    /// 📐 Labels of the chart's Fibonacci levels that fall inside the candle area
+    pub fn fib_labels(&self, chart: &Chart) -> Vec<FibLabel> {
+        let Some(fib) = &chart.fib_retracement else { return Vec::new() };
+        fib.levels()
+            .into_iter()
+            .filter_map(|(ratio, price)| {
+                self.ndc_y_at_price(price).map(|y| FibLabel { ratio, price, y })
+            })
+            .collect()
+    }
+
+    /// 🕘 Labels of the session open, high and low lines inside the candle area
+    pub fn session_labels(&self, chart: &Chart) -> Vec<SessionLabel> {
+        let Some(session) = self.session_levels(chart) else { return Vec::new() };
+        session
+            .levels()
+            .into_iter()
+            .filter_map(|(kind, price)| {
+                self.ndc_y_at_price(price).map(|y| SessionLabel { kind, price, y })
+            })
+            .collect()
+    }
+
+    /// 🔺 Labels of the visible high and low inside the candle area
+    pub fn extreme_labels(&self, chart: &Chart) -> Vec<ExtremeLabel> {
+        let Some((start, bars)) = self.visible_bars(chart) else { return Vec::new() };
+        let Some(extremes) = self.visible_extremes(chart) else { return Vec::new() };
+        [(extremes.high_index, extremes.high, true), (extremes.low_index, extremes.low, false)]
+            .into_iter()
+            .filter_map(|(index, price, high)| {
+                let x = self.candle_sizing.x_position(index - start, bars.len());
+                self.ndc_y_at_price(price).map(|y| ExtremeLabel { index, price, high, x, y })
+            })
+            .collect()
+    }
+
+    /// Current split between the candles and the enabled sub-panels
+    pub fn panel_layout(&self) -> PanelLayout {
+        PanelLayout::new(&self.line_visibility.sub_panels(), self.sub_panel_height)
+    }
+
     /// Check if the legend checkbox was clicked
     pub fn check_legend_checkbox_click(&self, mouse_x: f32, mouse_y: f32) -> Option<String> {
         const LEGEND_LEFT: f32 = 10.0;
@@ -307,7 +903,7 @@ impl WebGpuRenderer {
         const BOX_SIZE: f32 = 20.0;
         const BOX_GAP: f32 = 30.0;
 
-        let lines = ["sma20", "sma50", "sma200", "ema12", "ema26"];
+        let lines = self.line_visibility.names();
 
         for (i, name) in lines.iter().enumerate() {
             let x0 = LEGEND_LEFT;
@@ -315,7 +911,7 @@ impl WebGpuRenderer {
             let x1 = x0 + BOX_SIZE;
             let y1 = y0 + BOX_SIZE;
             if mouse_x >= x0 && mouse_x <= x1 && mouse_y >= y0 && mouse_y <= y1 {
-                return Some((*name).to_string());
+                return Some(name.clone());
             }
         }
 
@@ -342,19 +938,15 @@ impl WebGpuRenderer {
         {
             let _render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Clear Only Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &self.msaa_view,
-                    resolve_target: Some(&surface_view),
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 1.0,
-                            g: 1.0,
-                            b: 0.0,
-                            a: 1.0, // bright yellow
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.color_attachment(
+                    &surface_view,
+                    wgpu::LoadOp::Clear(wgpu::Color {
+                        r: 1.0,
+                        g: 1.0,
+                        b: 0.0,
+                        a: 1.0, // bright yellow
+                    }),
+                ))],
                 depth_stencil_attachment: None,
                 occlusion_query_set: None,
                 timestamp_writes: None,
@@ -445,19 +1037,15 @@ impl WebGpuRenderer {
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Test Simple Quad Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &self.msaa_view,
-                    resolve_target: Some(&surface_view),
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 0.2,
-                            g: 0.0,
-                            b: 0.5,
-                            a: 1.0, // purple background for contrast
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.color_attachment(
+                    &surface_view,
+                    wgpu::LoadOp::Clear(wgpu::Color {
+                        r: 0.2,
+                        g: 0.0,
+                        b: 0.5,
+                        a: 1.0, // purple background for contrast
+                    }),
+                ))],
                 depth_stencil_attachment: None,
                 occlusion_query_set: None,
                 timestamp_writes: None,
@@ -527,19 +1115,15 @@ impl WebGpuRenderer {
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Test Rectangle Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &self.msaa_view,
-                    resolve_target: Some(&surface_view),
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 0.1,
-                            g: 0.1,
-                            b: 0.3,
-                            a: 1.0, // dark blue background
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.color_attachment(
+                    &surface_view,
+                    wgpu::LoadOp::Clear(wgpu::Color {
+                        r: 0.1,
+                        g: 0.1,
+                        b: 0.3,
+                        a: 1.0, // dark blue background
+                    }),
+                ))],
                 depth_stencil_attachment: None,
                 occlusion_query_set: None,
                 timestamp_writes: None,
@@ -606,19 +1190,15 @@ impl WebGpuRenderer {
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Test Triangle Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &self.msaa_view,
-                    resolve_target: Some(&surface_view),
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 0.0,
-                            g: 0.0,
-                            b: 0.3,
-                            a: 1.0, // dark blue background
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
+                color_attachments: &[Some(self.color_attachment(
+                    &surface_view,
+                    wgpu::LoadOp::Clear(wgpu::Color {
+                        r: 0.0,
+                        g: 0.0,
+                        b: 0.3,
+                        a: 1.0, // dark blue background
+                    }),
+                ))],
                 depth_stencil_attachment: None,
                 occlusion_query_set: None,
                 timestamp_writes: None,
@@ -651,46 +1231,19 @@ impl WebGpuRenderer {
 mod tests {
     use super::*;
 
-    #[allow(invalid_value)]
     fn dummy_renderer() -> WebGpuRenderer {
-        unsafe {
-            WebGpuRenderer {
-                _canvas_id: String::new(),
-                width: 0,
-                height: 0,
-                surface: std::mem::MaybeUninit::zeroed().assume_init(),
-                device: std::mem::MaybeUninit::zeroed().assume_init(),
-                queue: std::mem::MaybeUninit::zeroed().assume_init(),
-                config: std::mem::MaybeUninit::zeroed().assume_init(),
-                render_pipeline: std::mem::MaybeUninit::zeroed().assume_init(),
-                vertex_buffer: std::mem::MaybeUninit::zeroed().assume_init(),
-                uniform_buffer: std::mem::MaybeUninit::zeroed().assume_init(),
-                uniform_bind_group: std::mem::MaybeUninit::zeroed().assume_init(),
-                msaa_texture: std::mem::MaybeUninit::zeroed().assume_init(),
-                msaa_view: std::mem::MaybeUninit::zeroed().assume_init(),
-                template_vertices: 0,
-                cached_vertices: Vec::new(),
-                cached_uniforms: ChartUniforms::new(),
-                cached_candle_count: 0,
-                cached_zoom_level: 1.0,
-                cached_hash: 0,
-                cached_data_hash: 0,
-                cached_line_visibility: LineVisibility::default(),
-                zoom_level: 1.0,
-                pan_offset: 0.0,
-                last_frame_time: 0.0,
-                fps_log: VecDeque::new(),
-                line_visibility: LineVisibility::default(),
-            }
-        }
+        let mut r = super::super::dummy_renderer();
+        r.width = 0;
+        r.height = 0;
+        r
     }
 
     #[test]
     fn toggles_visibility() {
         let mut r = dummy_renderer();
-        assert!(r.line_visibility.sma_20);
+        assert!(r.line_visibility.is_visible("sma20"));
         r.toggle_line_visibility("sma20");
-        assert!(!r.line_visibility.sma_20);
+        assert!(!r.line_visibility.is_visible("sma20"));
     }
 
     #[test]
@@ -700,9 +1253,9 @@ mod tests {
         global_line_visibility().set(LineVisibility::default());
         let mut r = dummy_renderer();
         r.toggle_line_visibility("sma20");
-        assert!(!global_line_visibility().get_untracked().sma_20);
+        assert!(!global_line_visibility().get_untracked().is_visible("sma20"));
         r.toggle_line_visibility("sma20");
-        assert!(global_line_visibility().get_untracked().sma_20);
+        assert!(global_line_visibility().get_untracked().is_visible("sma20"));
     }
 
     #[test]
@@ -716,10 +1269,7 @@ mod tests {
     fn fps_ring_buffer() {
         let mut r = dummy_renderer();
         for i in 0..65 {
-            r.fps_log.push_back(i as f64);
-            if r.fps_log.len() > 60 {
-                r.fps_log.pop_front();
-            }
+            r.record_fps(i as f64);
         }
         assert_eq!(r.fps_log.len(), 60);
         assert_eq!(r.fps_log.front().copied(), Some(5.0));
@@ -773,6 +1323,7 @@ mod tests {
             },
         ];
         assert!(r.update_cached_geometry(verts, inst.clone(), ChartUniforms::default()));
+        assert_eq!(r.instance_count(), inst.len() as u32);
     }
 
     #[test]
@@ -3,10 +3,18 @@ use futures::{
     StreamExt,
     channel::mpsc::{UnboundedSender, unbounded},
 };
-use std::cell::RefCell;
+use gloo_timers::future::sleep;
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+/// Renders per second allowed through the queue by default
+pub const DEFAULT_MAX_FPS: u32 = 60;
+/// Frame rate caps offered in the UI, 0 meaning uncapped
+pub const FPS_CAP_OPTIONS: [u32; 4] = [0, 30, 60, 120];
 
 thread_local! {
     static RENDER_QUEUE: RefCell<Option<UnboundedSender<RenderTask>>> = const { RefCell::new(None) };
+    static MAX_FPS: Cell<u32> = const { Cell::new(DEFAULT_MAX_FPS) };
 }
 
 type RenderTask = Box<dyn FnOnce(&mut WebGpuRenderer) + 'static>;
@@ -27,6 +35,72 @@ where
     leptos::spawn_local(fut);
 }
 
+/// Cap queued renders at `fps` per second, 0 to render every task
+pub fn set_max_fps(fps: u32) {
+    MAX_FPS.with(|cap| cap.set(fps));
+}
+
+pub fn max_fps() -> u32 {
+    MAX_FPS.with(Cell::get)
+}
+
+/// Shortest time between two renders under a cap of `max_fps`
+pub fn min_frame_interval_ms(max_fps: u32) -> f64 {
+    if max_fps == 0 { 0.0 } else { 1000.0 / max_fps as f64 }
+}
+
+/// Coalesces render tasks arriving faster than the minimum frame interval.
+///
+/// Only the newest task is held while waiting; older ones are dropped since
+/// each task redraws the whole chart from the current state.
+#[derive(Debug)]
+pub struct RenderThrottle<T> {
+    min_interval_ms: f64,
+    /// When the last task was released (ms)
+    last_run_ms: Option<f64>,
+    pending: Option<T>,
+}
+
+impl<T> RenderThrottle<T> {
+    pub fn new(min_interval_ms: f64) -> Self {
+        Self { min_interval_ms: min_interval_ms.max(0.0), last_run_ms: None, pending: None }
+    }
+
+    pub fn set_min_interval_ms(&mut self, min_interval_ms: f64) {
+        self.min_interval_ms = min_interval_ms.max(0.0);
+    }
+
+    /// Hold `task` in place of any task still waiting
+    pub fn hold(&mut self, task: T) {
+        self.pending = Some(task);
+    }
+
+    /// Hold `task` and release it right away if the interval has passed
+    pub fn offer(&mut self, task: T, now: f64) -> Option<T> {
+        self.hold(task);
+        self.take_due(now)
+    }
+
+    /// Release the held task once the interval since the last run has passed
+    pub fn take_due(&mut self, now: f64) -> Option<T> {
+        if self.wait_ms(now) > 0.0 {
+            return None;
+        }
+        let task = self.pending.take()?;
+        self.last_run_ms = Some(now);
+        Some(task)
+    }
+
+    /// Milliseconds until the next task may run
+    pub fn wait_ms(&self, now: f64) -> f64 {
+        self.last_run_ms.map_or(0.0, |last| (last + self.min_interval_ms - now).max(0.0))
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
 pub fn init_render_queue() {
     RENDER_QUEUE.with(|cell| {
         if cell.borrow().is_some() {
@@ -35,7 +109,20 @@ pub fn init_render_queue() {
         let (tx, mut rx) = unbounded::<RenderTask>();
         *cell.borrow_mut() = Some(tx);
         spawn_async(async move {
+            let mut throttle = RenderThrottle::new(0.0);
             while let Some(task) = rx.next().await {
+                throttle.set_min_interval_ms(min_frame_interval_ms(max_fps()));
+                let mut ready = throttle.offer(task, js_sys::Date::now());
+                while ready.is_none() && throttle.has_pending() {
+                    let wait = throttle.wait_ms(js_sys::Date::now());
+                    sleep(Duration::from_millis(wait.ceil() as u64)).await;
+                    // Tasks queued meanwhile supersede the held one
+                    while let Ok(Some(task)) = rx.try_next() {
+                        throttle.hold(task);
+                    }
+                    ready = throttle.take_due(js_sys::Date::now());
+                }
+                let Some(task) = ready else { continue };
                 if with_global_renderer(|r| {
                     task(r);
                 })
@@ -0,0 +1,157 @@
+//! PNG export of the rendered chart.
+//!
+//! The chart is drawn into an offscreen texture, copied into a mappable
+//! buffer and encoded by the browser through a temporary 2D canvas.
+
+use super::*;
+use crate::app::current_symbol;
+use crate::global_state::get_chart_signal;
+use futures::channel::oneshot;
+use leptos::{SignalGetUntracked, SignalWithUntracked};
+use std::future::Future;
+
+/// Bytes per pixel of the 8-bit RGBA/BGRA surface formats
+const BYTES_PER_PIXEL: u32 = 4;
+
+/// Row stride of a texture-to-buffer copy, padded to `COPY_BYTES_PER_ROW_ALIGNMENT` (256 bytes)
+pub fn padded_bytes_per_row(width: u32) -> u32 {
+    let unpadded = width * BYTES_PER_PIXEL;
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    unpadded.div_ceil(align) * align
+}
+
+/// Strip the row padding from copied pixels, returning tightly packed RGBA.
+///
+/// `bgra` swaps the red and blue channels of BGRA surface formats.
+pub fn unpad_rows(data: &[u8], width: u32, height: u32, bgra: bool) -> Vec<u8> {
+    let padded = padded_bytes_per_row(width) as usize;
+    let row_len = (width * BYTES_PER_PIXEL) as usize;
+    let mut pixels = Vec::with_capacity(row_len * height as usize);
+    for row in data.chunks(padded).take(height as usize) {
+        pixels.extend_from_slice(&row[..row_len]);
+    }
+    if bgra {
+        for pixel in pixels.chunks_exact_mut(BYTES_PER_PIXEL as usize) {
+            pixel.swap(0, 2);
+        }
+    }
+    pixels
+}
+
+/// Encode RGBA pixels as a PNG data URL using a detached canvas
+fn encode_png_data_url(pixels: &[u8], width: u32, height: u32) -> Result<String, JsValue> {
+    let canvas: HtmlCanvasElement = document().create_element("canvas")?.dyn_into()?;
+    canvas.set_width(width);
+    canvas.set_height(height);
+    let context: web_sys::CanvasRenderingContext2d = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2D context unavailable"))?
+        .dyn_into()?;
+    let image = web_sys::ImageData::new_with_u8_clamped_array_and_sh(
+        wasm_bindgen::Clamped(pixels),
+        width,
+        height,
+    )?;
+    context.put_image_data(&image, 0.0, 0.0)?;
+    canvas.to_data_url_with_type("image/png")
+}
+
+impl WebGpuRenderer {
+    /// Render the chart offscreen and read it back as a PNG data URL.
+    ///
+    /// The GPU work is submitted immediately; the returned future only owns
+    /// the readback buffer, so the renderer is not borrowed while it waits
+    /// for the browser to map it.
+    pub fn export_png(
+        &mut self,
+        chart: &Chart,
+    ) -> Result<impl Future<Output = Result<String, JsValue>> + use<>, JsValue> {
+        // Bring the cached geometry up to date
+        self.render(chart)?;
+
+        let (width, height) = (self.width, self.height);
+        let size = wgpu::Extent3d { width, height, depth_or_array_layers: 1 };
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Screenshot Texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.config.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let padded = padded_bytes_per_row(width);
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Screenshot Buffer"),
+            size: (padded * height) as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Screenshot Encoder"),
+        });
+        self.encode_chart_pass(&mut encoder, &view);
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded),
+                    rows_per_image: Some(height),
+                },
+            },
+            size,
+        );
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let (tx, rx) = oneshot::channel();
+        buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        let bgra = matches!(
+            self.config.format,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+        );
+
+        Ok(async move {
+            rx.await
+                .map_err(|_| JsValue::from_str("Screenshot readback cancelled"))?
+                .map_err(|e| JsValue::from_str(&format!("Screenshot readback failed: {e}")))?;
+            let pixels = {
+                let data = buffer.slice(..).get_mapped_range();
+                unpad_rows(&data, width, height, bgra)
+            };
+            buffer.unmap();
+            encode_png_data_url(&pixels, width, height)
+        })
+    }
+}
+
+/// Export the current symbol's chart as a PNG data URL
+pub async fn export_chart_png() -> Result<String, JsValue> {
+    let chart = get_chart_signal(&current_symbol().get_untracked())
+        .ok_or_else(|| JsValue::from_str("Chart not loaded"))?;
+    let pending = chart
+        .with_untracked(|c| with_global_renderer(|r| r.export_png(c)))
+        .ok_or_else(|| JsValue::from_str("Renderer not ready"))??;
+    pending.await
+}
+
+/// Save a data URL as a file through a temporary anchor element
+pub fn download_data_url(url: &str, file_name: &str) -> Result<(), JsValue> {
+    let anchor: web_sys::HtmlAnchorElement = document().create_element("a")?.dyn_into()?;
+    anchor.set_href(url);
+    anchor.set_download(file_name);
+    anchor.click();
+    Ok(())
+}
@@ -0,0 +1,163 @@
+//! Persistence of user chart settings in `window.localStorage`.
+//!
+//! Settings are stored as one JSON document. Missing, malformed or outdated
+//! data is ignored so the application falls back to its defaults.
+
+use crate::app::{
+    DEFAULT_ZOOM_SENSITIVITY, HistoryWindow, MAX_ZOOM_SENSITIVITY, MIN_ZOOM_SENSITIVITY,
+    current_interval, current_symbol, global_line_visibility, history_window, settings_panel_open,
+    time_zone, zoom_sensitivity,
+};
+use crate::domain::logging::LogComponent;
+use crate::domain::market_data::{Symbol, TimeInterval};
+use crate::infrastructure::rendering::renderer::LineVisibility;
+use crate::log_info;
+use crate::time_utils::TimeZone;
+use leptos::{SignalGet, SignalSet, create_effect};
+use serde::{Deserialize, Serialize};
+
+/// localStorage key holding the settings document
+pub const SETTINGS_KEY: &str = "price-chart-settings";
+/// Schema version written with every document; other versions are discarded
+pub const SETTINGS_VERSION: u32 = 1;
+/// localStorage key holding whether the settings panel is expanded
+pub const SETTINGS_PANEL_KEY: &str = "price-chart-settings-panel";
+/// Prefix of the per-symbol localStorage keys holding price alerts
+pub const PRICE_ALERTS_KEY_PREFIX: &str = "price-chart-alerts-";
+
+/// User settings restored on startup
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartSettings {
+    pub version: u32,
+    pub symbol: Symbol,
+    pub interval: TimeInterval,
+    pub line_visibility: LineVisibility,
+    #[serde(default)]
+    pub history_window: HistoryWindow,
+    #[serde(default)]
+    pub time_zone: TimeZone,
+    #[serde(default = "default_zoom_sensitivity")]
+    pub zoom_sensitivity: f64,
+}
+
+fn default_zoom_sensitivity() -> f64 {
+    DEFAULT_ZOOM_SENSITIVITY
+}
+
+impl Default for ChartSettings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            symbol: Symbol::from("BTCUSDT"),
+            interval: TimeInterval::OneMinute,
+            line_visibility: LineVisibility::default(),
+            history_window: HistoryWindow::default(),
+            time_zone: TimeZone::default(),
+            zoom_sensitivity: DEFAULT_ZOOM_SENSITIVITY,
+        }
+    }
+}
+
+impl ChartSettings {
+    /// Snapshot of the current global signals; tracks them when read inside an effect
+    pub fn from_signals() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            symbol: current_symbol().get(),
+            interval: current_interval().get(),
+            line_visibility: global_line_visibility().get(),
+            history_window: history_window().get(),
+            time_zone: time_zone().get(),
+            zoom_sensitivity: zoom_sensitivity().get(),
+        }
+    }
+
+    /// Push the settings into the global signals
+    pub fn apply(self) {
+        current_symbol().set(self.symbol);
+        current_interval().set(self.interval);
+        global_line_visibility().set(self.line_visibility);
+        let window = self.history_window;
+        history_window().set(HistoryWindow::new(window.fetch_limit, window.buffer_size));
+        time_zone().set(self.time_zone);
+        zoom_sensitivity()
+            .set(self.zoom_sensitivity.clamp(MIN_ZOOM_SENSITIVITY, MAX_ZOOM_SENSITIVITY));
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Parse a stored document; `None` for malformed JSON or another schema version
+    pub fn from_json(json: &str) -> Option<Self> {
+        serde_json::from_str::<Self>(json).ok().filter(|s| s.version == SETTINGS_VERSION)
+    }
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+/// Write settings to localStorage, returning whether it succeeded
+pub fn save_settings(settings: &ChartSettings) -> bool {
+    local_storage().is_some_and(|s| s.set_item(SETTINGS_KEY, &settings.to_json()).is_ok())
+}
+
+/// Read settings from localStorage, if present and compatible
+pub fn load_settings() -> Option<ChartSettings> {
+    let json = local_storage()?.get_item(SETTINGS_KEY).ok().flatten()?;
+    let settings = ChartSettings::from_json(&json);
+    if settings.is_none() {
+        log_info!(LogComponent::Infrastructure("Storage"), "Ignoring incompatible stored settings");
+    }
+    settings
+}
+
+/// Apply stored settings to the global signals; keeps defaults when nothing is stored
+pub fn restore_settings() {
+    if let Some(settings) = load_settings() {
+        settings.apply();
+    }
+    if let Some(open) = load_settings_panel_open() {
+        settings_panel_open().set(open);
+    }
+}
+
+/// Save settings whenever the symbol, interval, indicator toggles, history window, time
+/// zone, zoom sensitivity or settings panel state change
+pub fn persist_settings_on_change() {
+    create_effect(move |_| {
+        save_settings(&ChartSettings::from_signals());
+    });
+    create_effect(move |_| {
+        save_settings_panel_open(settings_panel_open().get());
+    });
+}
+
+/// Remember whether the settings panel is expanded, returning whether it succeeded
+pub fn save_settings_panel_open(open: bool) -> bool {
+    local_storage().is_some_and(|s| s.set_item(SETTINGS_PANEL_KEY, &open.to_string()).is_ok())
+}
+
+/// Stored expanded state of the settings panel; `None` when missing or malformed
+pub fn load_settings_panel_open() -> Option<bool> {
+    local_storage()?.get_item(SETTINGS_PANEL_KEY).ok().flatten()?.parse().ok()
+}
+
+fn price_alerts_key(symbol: &Symbol) -> String {
+    format!("{PRICE_ALERTS_KEY_PREFIX}{}", symbol.value())
+}
+
+/// Write the price alerts of `symbol`, returning whether it succeeded
+pub fn save_price_alerts(symbol: &Symbol, alerts: &[f64]) -> bool {
+    let Ok(json) = serde_json::to_string(alerts) else { return false };
+    local_storage().is_some_and(|s| s.set_item(&price_alerts_key(symbol), &json).is_ok())
+}
+
+/// Read the price alerts of `symbol`; empty when missing or malformed
+pub fn load_price_alerts(symbol: &Symbol) -> Vec<f64> {
+    local_storage()
+        .and_then(|s| s.get_item(&price_alerts_key(symbol)).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
@@ -0,0 +1,112 @@
+//! Shareable chart links through the `symbol` and `interval` query parameters.
+//!
+//! A link such as `?symbol=ETHUSDT&interval=5m` opens on that symbol and
+//! timeframe. Unknown values are ignored so the stored settings or defaults
+//! stay in effect, and the URL follows later symbol and interval changes.
+
+use crate::app::{current_interval, current_symbol};
+use crate::domain::logging::LogComponent;
+use crate::domain::market_data::{Symbol, TimeInterval};
+use crate::infrastructure::websocket::Exchange;
+use crate::log_info;
+use leptos::{SignalGet, SignalSet, create_effect};
+
+/// Query parameter naming the symbol
+pub const SYMBOL_PARAM: &str = "symbol";
+/// Query parameter naming the timeframe
+pub const INTERVAL_PARAM: &str = "interval";
+
+/// Symbol and interval requested by a link; invalid values are `None`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UrlChartParams {
+    pub symbol: Option<Symbol>,
+    pub interval: Option<TimeInterval>,
+}
+
+impl UrlChartParams {
+    /// Parse a `location.search` string, keeping only known symbols and intervals
+    pub fn parse(search: &str) -> Self {
+        let mut params = Self::default();
+        for (key, value) in query_pairs(search) {
+            match key {
+                SYMBOL_PARAM => params.symbol = parse_symbol(value),
+                INTERVAL_PARAM => params.interval = value.parse::<TimeInterval>().ok(),
+                _ => {}
+            }
+        }
+        params
+    }
+
+    /// Push the requested values into the global signals
+    pub fn apply(self) {
+        if let Some(symbol) = self.symbol {
+            current_symbol().set(symbol);
+        }
+        if let Some(interval) = self.interval {
+            current_interval().set(interval);
+        }
+    }
+}
+
+fn query_pairs(search: &str) -> impl Iterator<Item = (&str, &str)> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
+fn parse_symbol(value: &str) -> Option<Symbol> {
+    let symbol = Symbol::from(value.trim());
+    Exchange::ALL.iter().any(|e| e.symbols().contains(&symbol)).then_some(symbol)
+}
+
+/// `search` with the symbol and interval parameters replaced, other parameters kept
+pub fn with_chart_params(search: &str, symbol: &Symbol, interval: TimeInterval) -> String {
+    let mut pairs: Vec<String> = query_pairs(search)
+        .filter(|(key, _)| *key != SYMBOL_PARAM && *key != INTERVAL_PARAM)
+        .map(
+            |(key, value)| {
+                if value.is_empty() { key.to_string() } else { format!("{key}={value}") }
+            },
+        )
+        .collect();
+    pairs.push(format!("{SYMBOL_PARAM}={}", symbol.value()));
+    pairs.push(format!("{INTERVAL_PARAM}={interval}"));
+    format!("?{}", pairs.join("&"))
+}
+
+fn location_search() -> Option<String> {
+    web_sys::window()?.location().search().ok()
+}
+
+/// Apply the symbol and interval of the page URL; keeps current values when absent
+pub fn restore_from_url() {
+    let Some(search) = location_search() else {
+        return;
+    };
+    let params = UrlChartParams::parse(&search);
+    if params != UrlChartParams::default() {
+        log_info!(LogComponent::Infrastructure("UrlParams"), "Opening chart from link: {search}");
+    }
+    params.apply();
+}
+
+/// Rewrite the page URL whenever the symbol or interval changes
+pub fn sync_url_on_change() {
+    create_effect(move |_| {
+        let symbol = current_symbol().get();
+        let interval = current_interval().get();
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let search = location_search().unwrap_or_default();
+        let url = with_chart_params(&search, &symbol, interval);
+        if url == search {
+            return;
+        }
+        if let Ok(history) = window.history() {
+            let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url));
+        }
+    });
+}
@@ -1,20 +1,143 @@
 use crate::domain::{
-    logging::{LogComponent, get_logger},
+    AppError, NetworkResult,
+    logging::{LogComponent, get_logger, get_time_provider},
     market_data::{
-        entities::{Candle, OHLCV},
+        entities::{Candle, OHLCV, OrderBook},
         value_objects::{Price, Symbol, TimeInterval, Timestamp, Volume},
     },
 };
+use crate::infrastructure::websocket::dto::{BinanceDepthSnapshot, with_taker_buy};
 use futures::StreamExt;
 use gloo_net::http::Request;
 use gloo_net::websocket::futures::WebSocket;
 use serde::Deserialize;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 
 /// Binance WebSocket client based on gloo
 pub struct BinanceWebSocketClient {
     symbol: Symbol,
     interval: TimeInterval,
+    /// Give up after this many consecutive failed reconnects (`None` retries forever)
+    max_retries: Option<u32>,
+    backoff: ReconnectBackoff,
+    /// When the last candle was received (ms)
+    last_message_at: Option<u64>,
+}
+
+/// Exponential reconnect delay: 1s, 2s, 4s, ... capped at 30s
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectBackoff {
+    base_ms: u64,
+    max_ms: u64,
+    attempt: u32,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self::new(1_000, 30_000)
+    }
+}
+
+impl ReconnectBackoff {
+    pub fn new(base_ms: u64, max_ms: u64) -> Self {
+        Self { base_ms, max_ms, attempt: 0 }
+    }
+
+    /// Register a failure and return the delay before the next attempt
+    pub fn next_delay(&mut self) -> Duration {
+        let factor = 1u64.checked_shl(self.attempt).unwrap_or(u64::MAX);
+        self.attempt = self.attempt.saturating_add(1);
+        Duration::from_millis(self.base_ms.saturating_mul(factor).min(self.max_ms))
+    }
+
+    /// Forget previous failures after the stream delivered data again
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    /// Number of consecutive failures so far
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+}
+
+/// Binance pushes kline updates about this often while the stream is healthy
+pub const STREAM_PUSH_INTERVAL_MS: u64 = 2_000;
+/// Missed pushes after which the stream counts as stale
+pub const STREAM_STALE_AFTER_PUSHES: u64 = 3;
+/// Silence after which the stream counts as disconnected, at least
+pub const STREAM_DISCONNECTED_MIN_MS: u64 = 30_000;
+/// Silence after which the stream counts as disconnected, at most
+pub const STREAM_DISCONNECTED_MAX_MS: u64 = 5 * 60_000;
+
+/// Levels per side of the partial depth stream (Binance allows 5, 10 or 20)
+pub const DEPTH_LEVELS: u32 = 20;
+/// Minimum time between two order book updates passed to the handler
+pub const DEPTH_UPDATE_INTERVAL_MS: u64 = 500;
+
+/// Lets an update through at most once per `interval_ms`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateThrottle {
+    interval_ms: u64,
+    last_at: Option<u64>,
+}
+
+impl UpdateThrottle {
+    pub fn new(interval_ms: u64) -> Self {
+        Self { interval_ms, last_at: None }
+    }
+
+    /// Whether an update arriving at `now` (ms) should be forwarded
+    pub fn ready(&mut self, now: u64) -> bool {
+        if self.last_at.is_some_and(|at| now.saturating_sub(at) < self.interval_ms) {
+            return false;
+        }
+        self.last_at = Some(now);
+        true
+    }
+}
+
+/// Health of the real-time stream judged by the age of its latest message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamHealth {
+    /// Messages arrive on time; holds the age of the latest one (ms)
+    Live(u64),
+    /// The latest message is overdue
+    Stale,
+    /// Nothing received for too long, or nothing at all
+    Disconnected,
+}
+
+impl StreamHealth {
+    /// Classify the age of the latest message of an `interval` kline stream.
+    ///
+    /// The stream is stale after a few missed pushes and disconnected after
+    /// roughly one candle without data, bounded to 30 s..5 min.
+    pub fn classify(age_ms: Option<u64>, interval: TimeInterval) -> Self {
+        let Some(age) = age_ms else {
+            return Self::Disconnected;
+        };
+        let push_interval = interval.duration_ms().min(STREAM_PUSH_INTERVAL_MS);
+        let disconnected_after =
+            interval.duration_ms().clamp(STREAM_DISCONNECTED_MIN_MS, STREAM_DISCONNECTED_MAX_MS);
+        if age <= push_interval * STREAM_STALE_AFTER_PUSHES {
+            Self::Live(age)
+        } else if age <= disconnected_after {
+            Self::Stale
+        } else {
+            Self::Disconnected
+        }
+    }
+
+    /// Header text, e.g. "🟢 Live (120ms)"
+    pub fn label(&self) -> String {
+        match self {
+            Self::Live(age) => format!("🟢 Live ({age}ms)"),
+            Self::Stale => "🟡 Stale".to_string(),
+            Self::Disconnected => "🔴 Disconnected".to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -37,6 +160,8 @@ struct KlineInfo {
     close: String,
     #[serde(rename = "v")]
     volume: String,
+    #[serde(rename = "V", default)]
+    taker_buy_volume: Option<String>,
 }
 
 /// Structure for historical Binance Klines API data
@@ -51,14 +176,41 @@ struct BinanceHistoricalKline(
     serde::de::IgnoredAny, // Close time
     serde::de::IgnoredAny, // Quote asset volume
     serde::de::IgnoredAny, // Number of trades
-    serde::de::IgnoredAny, // Taker buy base asset volume
+    String,                // Taker buy base asset volume
     serde::de::IgnoredAny, // Taker buy quote asset volume
     serde::de::IgnoredAny, // Ignore
 );
 
 impl BinanceWebSocketClient {
     pub fn new(symbol: Symbol, interval: TimeInterval) -> Self {
-        Self { symbol, interval }
+        Self {
+            symbol,
+            interval,
+            max_retries: None,
+            backoff: ReconnectBackoff::default(),
+            last_message_at: None,
+        }
+    }
+
+    /// Limit consecutive reconnect attempts before the stream returns an error
+    pub fn with_max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn set_max_retries(&mut self, max_retries: Option<u32>) {
+        self.max_retries = max_retries;
+    }
+
+    /// Consecutive failed connection attempts since the last received message
+    pub fn retry_attempt(&self) -> u32 {
+        self.backoff.attempt()
+    }
+
+    /// Milliseconds since the last received candle, `None` before the first one
+    pub fn last_message_age_ms(&self) -> Option<u64> {
+        let now = get_time_provider().current_timestamp();
+        self.last_message_at.map(|at| now.saturating_sub(at))
     }
 
     /// Connect to the Binance WebSocket stream
@@ -107,6 +259,8 @@ impl BinanceWebSocketClient {
             Volume::new(volume),
         );
 
+        let ohlcv = with_taker_buy(ohlcv, kline.taker_buy_volume.as_deref());
+
         // Create a candle
         let candle = Candle::new(Timestamp::new(kline.open_time), ohlcv);
 
@@ -118,9 +272,10 @@ impl BinanceWebSocketClient {
     where
         F: FnMut(Candle) + 'static,
     {
-        self.run_stream(handler, || {}).await
+        self.run_stream(handler, |_| {}).await
     }
 
+    /// Start the stream, calling `on_reconnect` with the attempt number before each retry
     pub async fn start_stream_with_callback<F, R>(
         &mut self,
         handler: F,
@@ -128,7 +283,7 @@ impl BinanceWebSocketClient {
     ) -> Result<(), String>
     where
         F: FnMut(Candle) + 'static,
-        R: FnMut(),
+        R: FnMut(u32),
     {
         self.run_stream(handler, on_reconnect).await
     }
@@ -136,85 +291,179 @@ impl BinanceWebSocketClient {
     async fn run_stream<F, R>(&mut self, mut handler: F, mut on_reconnect: R) -> Result<(), String>
     where
         F: FnMut(Candle) + 'static,
-        R: FnMut(),
+        R: FnMut(u32),
     {
         use gloo_timers::future::sleep;
-        use std::time::Duration;
 
-        let mut delay = 1u64;
+        self.backoff.reset();
         loop {
-            let mut stream = match self.connect().await {
-                Ok(ws) => {
+            match self.connect().await {
+                Ok(mut stream) => {
                     get_logger().info(
                         LogComponent::Infrastructure("BinanceWS"),
                         "🚀 Starting Binance WebSocket stream processing...",
                     );
-                    delay = 1;
-                    ws
+                    self.process_messages(&mut stream, &mut handler).await;
                 }
                 Err(e) => {
                     get_logger().error(
                         LogComponent::Infrastructure("BinanceWS"),
                         &format!("❌ Connection error: {e}"),
                     );
-                    on_reconnect();
-                    sleep(Duration::from_secs(delay)).await;
-                    delay = (delay * 2).min(32);
-                    continue;
                 }
-            };
-
-            while let Some(msg) = stream.next().await {
-                match msg {
-                    Ok(gloo_net::websocket::Message::Text(data)) => match self.parse_message(&data)
-                    {
-                        Ok(candle) => {
-                            get_logger().debug(
-                                    LogComponent::Infrastructure("BinanceWS"),
-                                    &format!(
-                                        "📊 Received candle: {} - O:{:.2} H:{:.2} L:{:.2} C:{:.2} V:{:.2}",
-                                        self.symbol.value(),
-                                        candle.ohlcv.open.value(),
-                                        candle.ohlcv.high.value(),
-                                        candle.ohlcv.low.value(),
-                                        candle.ohlcv.close.value(),
-                                        candle.ohlcv.volume.value()
-                                    ),
-                                );
-                            handler(candle);
-                        }
-                        Err(e) => {
-                            get_logger().error(
-                                LogComponent::Infrastructure("BinanceWS"),
-                                &format!("❌ Failed to parse message: {e}"),
-                            );
-                        }
-                    },
-                    Ok(_) => {
-                        // Ignore binary messages
+            }
+
+            if self.max_retries.is_some_and(|max| self.backoff.attempt() >= max) {
+                return Err(format!(
+                    "WebSocket reconnect failed after {} attempts",
+                    self.backoff.attempt()
+                ));
+            }
+            let delay = self.backoff.next_delay();
+            get_logger().warn(
+                LogComponent::Infrastructure("BinanceWS"),
+                &format!(
+                    "🔌 Reconnecting in {}s (attempt {})",
+                    delay.as_secs_f64(),
+                    self.backoff.attempt()
+                ),
+            );
+            on_reconnect(self.backoff.attempt());
+            sleep(delay).await;
+        }
+    }
+
+    /// Forward candles to the handler until the socket closes or errors
+    async fn process_messages<F>(&mut self, stream: &mut WebSocket, handler: &mut F)
+    where
+        F: FnMut(Candle),
+    {
+        while let Some(msg) = stream.next().await {
+            match msg {
+                Ok(gloo_net::websocket::Message::Text(data)) => match self.parse_message(&data) {
+                    Ok(candle) => {
+                        get_logger().debug(
+                            LogComponent::Infrastructure("BinanceWS"),
+                            &format!(
+                                "📊 Received candle: {} - O:{:.2} H:{:.2} L:{:.2} C:{:.2} V:{:.2}",
+                                self.symbol.value(),
+                                candle.ohlcv.open.value(),
+                                candle.ohlcv.high.value(),
+                                candle.ohlcv.low.value(),
+                                candle.ohlcv.close.value(),
+                                candle.ohlcv.volume.value()
+                            ),
+                        );
+                        // Data is flowing again, so the next failure starts from the base delay
+                        self.backoff.reset();
+                        self.last_message_at = Some(get_time_provider().current_timestamp());
+                        handler(candle);
                     }
                     Err(e) => {
                         get_logger().error(
                             LogComponent::Infrastructure("BinanceWS"),
-                            &format!("❌ WebSocket error: {e:?}"),
+                            &format!("❌ Failed to parse message: {e}"),
                         );
-                        break;
                     }
+                },
+                Ok(_) => {
+                    // Ignore binary messages
+                }
+                Err(e) => {
+                    get_logger().error(
+                        LogComponent::Infrastructure("BinanceWS"),
+                        &format!("❌ WebSocket error: {e:?}"),
+                    );
+                    break;
                 }
             }
+        }
+    }
 
-            get_logger().warn(
-                LogComponent::Infrastructure("BinanceWS"),
-                &format!("🔌 Reconnecting in {delay}s"),
-            );
-            on_reconnect();
-            sleep(Duration::from_secs(delay)).await;
-            delay = (delay * 2).min(32);
+    /// URL of the partial depth stream for this symbol
+    pub fn depth_stream_url(&self) -> String {
+        format!(
+            "wss://stream.binance.com:9443/ws/{}@depth{DEPTH_LEVELS}@100ms",
+            self.symbol.value().to_lowercase()
+        )
+    }
+
+    /// Parse a message of the partial depth stream
+    pub fn parse_depth_message(&self, data: &str) -> Result<OrderBook, String> {
+        let snapshot: BinanceDepthSnapshot = serde_json::from_str(data)
+            .map_err(|e| format!("Failed to parse Binance depth message: {e}"))?;
+        snapshot.to_domain_order_book()
+    }
+
+    /// 📚 Stream order book snapshots from the `@depth` channel.
+    ///
+    /// Snapshots arrive every 100 ms; the handler receives at most one per
+    /// [`DEPTH_UPDATE_INTERVAL_MS`]. Reconnects like the kline stream.
+    pub async fn start_depth_stream<F>(&mut self, mut handler: F) -> Result<(), String>
+    where
+        F: FnMut(OrderBook),
+    {
+        use gloo_timers::future::sleep;
+
+        let mut throttle = UpdateThrottle::new(DEPTH_UPDATE_INTERVAL_MS);
+        self.backoff.reset();
+        loop {
+            let url = self.depth_stream_url();
+            match WebSocket::open(&url) {
+                Ok(mut stream) => {
+                    get_logger().info(
+                        LogComponent::Infrastructure("BinanceWS"),
+                        &format!("📚 Connected to depth stream: {url}"),
+                    );
+                    while let Some(msg) = stream.next().await {
+                        match msg {
+                            Ok(gloo_net::websocket::Message::Text(data)) => {
+                                let now = get_time_provider().current_timestamp();
+                                if !throttle.ready(now) {
+                                    continue;
+                                }
+                                match self.parse_depth_message(&data) {
+                                    Ok(book) => {
+                                        self.backoff.reset();
+                                        handler(book);
+                                    }
+                                    Err(e) => get_logger().error(
+                                        LogComponent::Infrastructure("BinanceWS"),
+                                        &format!("❌ Failed to parse depth message: {e}"),
+                                    ),
+                                }
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                get_logger().error(
+                                    LogComponent::Infrastructure("BinanceWS"),
+                                    &format!("❌ Depth WebSocket error: {e:?}"),
+                                );
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    get_logger().error(
+                        LogComponent::Infrastructure("BinanceWS"),
+                        &format!("❌ Depth connection error: {e:?}"),
+                    );
+                }
+            }
+
+            if self.max_retries.is_some_and(|max| self.backoff.attempt() >= max) {
+                return Err(format!(
+                    "Depth stream reconnect failed after {} attempts",
+                    self.backoff.attempt()
+                ));
+            }
+            sleep(self.backoff.next_delay()).await;
         }
     }
 
     /// 📈 Load historical data from Binance REST API
-    pub async fn fetch_historical_data(&self, limit: u32) -> Result<Vec<Candle>, String> {
+    pub async fn fetch_historical_data(&self, limit: u32) -> NetworkResult<Vec<Candle>> {
         let symbol_upper = self.symbol.value().to_uppercase();
         let interval_str = self.interval.to_binance_str();
 
@@ -227,26 +476,28 @@ impl BinanceWebSocketClient {
             &format!("📈 Fetching {limit} historical candles from: {url}"),
         );
 
-        let response = Request::get(&url)
-            .send()
-            .await
-            .map_err(|e| format!("Failed to fetch historical data: {e:?}"))?;
+        let response = Request::get(&url).send().await.map_err(|e| {
+            AppError::NetworkError(format!("Failed to fetch historical data: {e:?}"))
+        })?;
 
         if !response.ok() {
-            return Err(format!("HTTP error: {}", response.status()));
+            return Err(AppError::from_http_status(response.status()));
         }
 
-        let klines: Vec<BinanceHistoricalKline> =
-            response.json().await.map_err(|e| format!("Failed to parse JSON: {e:?}"))?;
+        let klines: Vec<BinanceHistoricalKline> = response
+            .json()
+            .await
+            .map_err(|e| AppError::ValidationError(format!("Failed to parse JSON: {e:?}")))?;
 
+        let invalid = |field: &str| AppError::ValidationError(format!("Invalid {field}"));
         let mut candles = Vec::new();
 
         for kline in klines {
-            let open = kline.1.parse::<f64>().map_err(|_| "Invalid open price")?;
-            let high = kline.2.parse::<f64>().map_err(|_| "Invalid high price")?;
-            let low = kline.3.parse::<f64>().map_err(|_| "Invalid low price")?;
-            let close = kline.4.parse::<f64>().map_err(|_| "Invalid close price")?;
-            let volume = kline.5.parse::<f64>().map_err(|_| "Invalid volume")?;
+            let open = kline.1.parse::<f64>().map_err(|_| invalid("open price"))?;
+            let high = kline.2.parse::<f64>().map_err(|_| invalid("high price"))?;
+            let low = kline.3.parse::<f64>().map_err(|_| invalid("low price"))?;
+            let close = kline.4.parse::<f64>().map_err(|_| invalid("close price"))?;
+            let volume = kline.5.parse::<f64>().map_err(|_| invalid("volume"))?;
 
             let ohlcv = OHLCV::new(
                 Price::new(open),
@@ -255,6 +506,7 @@ impl BinanceWebSocketClient {
                 Price::new(close),
                 Volume::new(volume),
             );
+            let ohlcv = with_taker_buy(ohlcv, Some(&kline.9));
 
             let candle = Candle::new(
                 Timestamp::new(kline.0), // open_time
@@ -318,6 +570,7 @@ impl BinanceWebSocketClient {
                 Price::new(close),
                 Volume::new(volume),
             );
+            let ohlcv = with_taker_buy(ohlcv, Some(&kline.9));
 
             let candle = Candle::new(Timestamp::new(kline.0), ohlcv);
 
@@ -378,6 +631,7 @@ impl BinanceWebSocketClient {
                 Price::new(close),
                 Volume::new(volume),
             );
+            let ohlcv = with_taker_buy(ohlcv, Some(&kline.9));
 
             let candle = Candle::new(Timestamp::new(kline.0), ohlcv);
 
@@ -0,0 +1,56 @@
+use crate::domain::market_data::Candle;
+
+/// Collapses bursts of streamed candles into one chart update per flush.
+///
+/// Updates to the same candle replace each other so the latest one wins,
+/// while a candle that was superseded by a newer bucket keeps its final state.
+/// While paused, candles are held back until [`CandleCoalescer::set_paused`] resumes.
+#[derive(Debug, Default)]
+pub struct CandleCoalescer {
+    pending: Vec<Candle>,
+    flush_scheduled: bool,
+    paused: bool,
+}
+
+impl CandleCoalescer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffer `candle`; returns true when the caller should schedule a flush
+    pub fn push(&mut self, candle: Candle) -> bool {
+        match self.pending.iter_mut().find(|c| c.timestamp == candle.timestamp) {
+            Some(existing) => *existing = candle,
+            None => self.pending.push(candle),
+        }
+        !self.paused && !std::mem::replace(&mut self.flush_scheduled, true)
+    }
+
+    /// Drain the buffered candles in arrival order; nothing is drained while paused
+    pub fn take(&mut self) -> Vec<Candle> {
+        self.flush_scheduled = false;
+        if self.paused {
+            return Vec::new();
+        }
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Hold back or release buffered candles; returns true when resuming leaves
+    /// candles that the caller should schedule a flush for
+    pub fn set_paused(&mut self, paused: bool) -> bool {
+        self.paused = paused;
+        !paused && !self.pending.is_empty() && !std::mem::replace(&mut self.flush_scheduled, true)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
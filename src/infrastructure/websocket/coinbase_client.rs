@@ -0,0 +1,346 @@
+use crate::domain::{
+    AppError, NetworkResult,
+    logging::{LogComponent, get_logger, get_time_provider},
+    market_data::{
+        Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume, services::Aggregator,
+    },
+};
+use crate::infrastructure::websocket::{
+    CoinbaseCandle, CoinbaseMessage, CoinbaseTicker, Exchange, MarketDataClient, ReconnectBackoff,
+};
+use futures::future::LocalBoxFuture;
+use futures::{SinkExt, StreamExt};
+use gloo_net::http::Request;
+use gloo_net::websocket::{Message, futures::WebSocket};
+
+/// Most candles Coinbase returns per REST request
+pub const COINBASE_MAX_CANDLES: u32 = 300;
+
+const REST_URL: &str = "https://api.exchange.coinbase.com";
+const FEED_URL: &str = "wss://ws-feed.exchange.coinbase.com";
+
+/// Coinbase Exchange client: REST candles for history, the `ticker` feed for real time.
+///
+/// The feed carries trades only, so live candles are built from them per interval.
+pub struct CoinbaseClient {
+    symbol: Symbol,
+    interval: TimeInterval,
+    max_retries: Option<u32>,
+    backoff: ReconnectBackoff,
+    /// Candle being built from the latest trades
+    current: Option<Candle>,
+}
+
+impl CoinbaseClient {
+    pub fn new(symbol: Symbol, interval: TimeInterval) -> Self {
+        Self {
+            symbol,
+            interval,
+            max_retries: None,
+            backoff: ReconnectBackoff::default(),
+            current: None,
+        }
+    }
+
+    /// Limit consecutive reconnect attempts before the stream returns an error
+    pub fn with_max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Product id such as `BTC-USD`
+    pub fn product_id(&self) -> String {
+        Exchange::Coinbase.format_symbol(&self.symbol)
+    }
+
+    /// Candle size in seconds, if Coinbase offers the interval
+    pub fn granularity(interval: TimeInterval) -> Option<u64> {
+        match interval {
+            TimeInterval::OneMinute => Some(60),
+            TimeInterval::FiveMinutes => Some(5 * 60),
+            TimeInterval::FifteenMinutes => Some(15 * 60),
+            TimeInterval::OneHour => Some(60 * 60),
+            TimeInterval::OneDay => Some(24 * 60 * 60),
+            _ => None,
+        }
+    }
+
+    /// Coinbase candles `interval` is built from: its own when Coinbase offers it, a
+    /// finer size rolled up otherwise; `None` for intervals under a minute
+    pub fn source_interval(interval: TimeInterval) -> Option<TimeInterval> {
+        if Self::granularity(interval).is_some() {
+            return Some(interval);
+        }
+        match interval {
+            TimeInterval::FourHours => Some(TimeInterval::OneHour),
+            TimeInterval::OneWeek | TimeInterval::OneMonth => Some(TimeInterval::OneDay),
+            _ => None,
+        }
+    }
+
+    /// Roll finer `candles` up into `interval` candles. A first bucket that starts
+    /// before the candles is dropped, since its open, high and low would be wrong.
+    pub fn aggregate_candles(candles: &[Candle], interval: TimeInterval) -> Vec<Candle> {
+        candles
+            .chunk_by(|a, b| {
+                interval.bucket_start(a.timestamp.value())
+                    == interval.bucket_start(b.timestamp.value())
+            })
+            .enumerate()
+            .filter(|(i, bucket)| {
+                *i > 0
+                    || interval.bucket_start(bucket[0].timestamp.value())
+                        == bucket[0].timestamp.value()
+            })
+            .filter_map(|(_, bucket)| Aggregator::aggregate(bucket, interval))
+            .collect()
+    }
+
+    /// Parse a REST candles response, which Coinbase sorts newest first
+    pub fn parse_candles(json: &str) -> Result<Vec<Candle>, String> {
+        let rows: Vec<CoinbaseCandle> =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse JSON: {e}"))?;
+        let mut candles: Vec<Candle> = rows.iter().map(CoinbaseCandle::to_domain_candle).collect();
+        candles.sort_by_key(|c| c.timestamp.value());
+        Ok(candles)
+    }
+
+    /// Continue the open candle from `candles` fetched over REST, so trades extend its
+    /// open, high, low and volume instead of starting them over.
+    ///
+    /// A last candle from an earlier bucket leaves the next trade to open a new one.
+    pub fn seed_current(&mut self, candles: &[Candle], now: u64) {
+        let open_bucket = self.interval.bucket_start(now);
+        self.current = candles.last().filter(|c| c.timestamp.value() == open_bucket).cloned();
+    }
+
+    /// Seed the open candle from REST; on failure the trades build it alone
+    async fn seed_from_rest(&mut self) {
+        let now = get_time_provider().current_timestamp();
+        match self.fetch_candles(now, 1).await {
+            Ok(candles) => self.seed_current(&candles, now),
+            Err(e) => get_logger().warn(
+                LogComponent::Infrastructure("CoinbaseWS"),
+                &format!("⚠️ Open candle unavailable, building it from trades: {e}"),
+            ),
+        }
+    }
+
+    /// Fold a trade into the candle of its interval bucket and return that candle
+    pub fn apply_ticker(&mut self, ticker: &CoinbaseTicker) -> Result<Candle, String> {
+        let price = ticker.price()?;
+        let size = ticker.size();
+        let bucket = self.interval.bucket_start(ticker.timestamp_ms()?);
+
+        let candle = match self.current.take() {
+            Some(mut candle) if candle.timestamp.value() == bucket => {
+                let ohlcv = &mut candle.ohlcv;
+                ohlcv.high = Price::from(ohlcv.high.value().max(price));
+                ohlcv.low = Price::from(ohlcv.low.value().min(price));
+                ohlcv.close = Price::from(price);
+                ohlcv.volume = Volume::from(ohlcv.volume.value() + size);
+                candle
+            }
+            Some(candle) if candle.timestamp.value() > bucket => {
+                self.current = Some(candle);
+                return Err("Trade older than the current candle".to_string());
+            }
+            _ => Candle::new(
+                Timestamp::from(bucket),
+                OHLCV::new(
+                    Price::from(price),
+                    Price::from(price),
+                    Price::from(price),
+                    Price::from(price),
+                    Volume::from(size),
+                ),
+            ),
+        };
+        self.current = Some(candle.clone());
+        Ok(candle)
+    }
+
+    /// 📈 Load up to `limit` candles opened at or before `end_time`, rolled up from a
+    /// finer Coinbase granularity when the interval has none of its own
+    async fn fetch_candles(&self, end_time: u64, limit: u32) -> NetworkResult<Vec<Candle>> {
+        let source = Self::source_interval(self.interval).ok_or_else(|| {
+            AppError::ValidationError(format!("Coinbase has no {} candles", self.interval))
+        })?;
+        let granularity = source.duration_ms() / 1000;
+        let limit = limit.min(COINBASE_MAX_CANDLES) as u64;
+        // One extra candle's worth of source candles completes the oldest bucket
+        let source_limit = if source == self.interval {
+            limit
+        } else {
+            (limit + 1) * (self.interval.duration_ms() / source.duration_ms())
+        }
+        .min(COINBASE_MAX_CANDLES as u64);
+        let start_time = end_time.saturating_sub(source_limit * granularity * 1000);
+        let iso = |ms: u64| -> String {
+            js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms as f64)).to_iso_string().into()
+        };
+        let url = format!(
+            "{REST_URL}/products/{}/candles?granularity={granularity}&start={}&end={}",
+            self.product_id(),
+            iso(start_time),
+            iso(end_time)
+        );
+
+        get_logger().info(
+            LogComponent::Infrastructure("CoinbaseAPI"),
+            &format!("📈 Fetching {source_limit} candles from: {url}"),
+        );
+
+        let response = Request::get(&url).send().await.map_err(|e| {
+            AppError::NetworkError(format!("Failed to fetch historical data: {e:?}"))
+        })?;
+        if !response.ok() {
+            return Err(AppError::from_http_status(response.status()));
+        }
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::NetworkError(format!("Failed to read body: {e:?}")))?;
+        let mut candles = Self::parse_candles(&body).map_err(AppError::ValidationError)?;
+        if source != self.interval {
+            candles = Self::aggregate_candles(&candles, self.interval);
+            let excess = candles.len().saturating_sub(limit as usize);
+            candles.drain(..excess);
+        }
+
+        get_logger().info(
+            LogComponent::Infrastructure("CoinbaseAPI"),
+            &format!("✅ Loaded {} historical candles for {}", candles.len(), self.product_id()),
+        );
+        Ok(candles)
+    }
+
+    /// Connect to the feed and subscribe to the product's trades
+    async fn connect(&self) -> Result<WebSocket, String> {
+        let mut ws =
+            WebSocket::open(FEED_URL).map_err(|e| format!("Failed to open WebSocket: {e:?}"))?;
+        let subscribe = serde_json::json!({
+            "type": "subscribe",
+            "product_ids": [self.product_id()],
+            "channels": ["ticker"],
+        });
+        ws.send(Message::Text(subscribe.to_string()))
+            .await
+            .map_err(|e| format!("Failed to subscribe: {e:?}"))?;
+
+        get_logger().info(
+            LogComponent::Infrastructure("CoinbaseWS"),
+            &format!("✅ Subscribed to Coinbase ticker: {}", self.product_id()),
+        );
+        Ok(ws)
+    }
+
+    async fn run_stream(
+        &mut self,
+        mut handler: Box<dyn FnMut(Candle)>,
+        mut on_reconnect: Box<dyn FnMut(u32)>,
+    ) -> Result<(), String> {
+        use gloo_timers::future::sleep;
+
+        self.backoff.reset();
+        loop {
+            // Trades missed while disconnected are in the REST candle
+            self.seed_from_rest().await;
+            match self.connect().await {
+                Ok(mut stream) => self.process_messages(&mut stream, &mut handler).await,
+                Err(e) => get_logger().error(
+                    LogComponent::Infrastructure("CoinbaseWS"),
+                    &format!("❌ Connection error: {e}"),
+                ),
+            }
+
+            if self.max_retries.is_some_and(|max| self.backoff.attempt() >= max) {
+                return Err(format!(
+                    "WebSocket reconnect failed after {} attempts",
+                    self.backoff.attempt()
+                ));
+            }
+            let delay = self.backoff.next_delay();
+            get_logger().warn(
+                LogComponent::Infrastructure("CoinbaseWS"),
+                &format!(
+                    "🔌 Reconnecting in {}s (attempt {})",
+                    delay.as_secs_f64(),
+                    self.backoff.attempt()
+                ),
+            );
+            on_reconnect(self.backoff.attempt());
+            sleep(delay).await;
+        }
+    }
+
+    /// Forward candles built from trades until the socket closes or errors
+    async fn process_messages(
+        &mut self,
+        stream: &mut WebSocket,
+        handler: &mut Box<dyn FnMut(Candle)>,
+    ) {
+        while let Some(msg) = stream.next().await {
+            let data = match msg {
+                Ok(Message::Text(data)) => data,
+                Ok(_) => continue,
+                Err(e) => {
+                    get_logger().error(
+                        LogComponent::Infrastructure("CoinbaseWS"),
+                        &format!("❌ WebSocket error: {e:?}"),
+                    );
+                    break;
+                }
+            };
+            match serde_json::from_str::<CoinbaseMessage>(&data) {
+                Ok(CoinbaseMessage::Ticker(ticker)) => match self.apply_ticker(&ticker) {
+                    Ok(candle) => {
+                        self.backoff.reset();
+                        handler(candle);
+                    }
+                    Err(e) => get_logger().warn(
+                        LogComponent::Infrastructure("CoinbaseWS"),
+                        &format!("⚠️ Skipped trade: {e}"),
+                    ),
+                },
+                Ok(CoinbaseMessage::Error { message }) => {
+                    get_logger().error(
+                        LogComponent::Infrastructure("CoinbaseWS"),
+                        &format!("❌ Feed error: {message}"),
+                    );
+                    break;
+                }
+                Ok(CoinbaseMessage::Other) => {}
+                Err(e) => get_logger().error(
+                    LogComponent::Infrastructure("CoinbaseWS"),
+                    &format!("❌ Failed to parse message: {e}"),
+                ),
+            }
+        }
+    }
+}
+
+impl MarketDataClient for CoinbaseClient {
+    fn fetch_historical_data(&self, limit: u32) -> LocalBoxFuture<'_, NetworkResult<Vec<Candle>>> {
+        let now = get_time_provider().current_timestamp();
+        Box::pin(self.fetch_candles(now, limit))
+    }
+
+    fn fetch_historical_data_before(
+        &self,
+        end_time: u64,
+        limit: u32,
+    ) -> LocalBoxFuture<'_, Result<Vec<Candle>, String>> {
+        Box::pin(
+            async move { self.fetch_candles(end_time, limit).await.map_err(|e| e.to_string()) },
+        )
+    }
+
+    fn start_stream(
+        &mut self,
+        handler: Box<dyn FnMut(Candle)>,
+        on_reconnect: Box<dyn FnMut(u32)>,
+    ) -> LocalBoxFuture<'_, Result<(), String>> {
+        Box::pin(self.run_stream(handler, on_reconnect))
+    }
+}
@@ -1,7 +1,17 @@
-use crate::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use crate::domain::market_data::{
+    Candle, OHLCV, OrderBook, OrderBookLevel, Price, Timestamp, Volume,
+};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::JsValue;
 
+/// `ohlcv` with the taker buy base volume of a Binance kline, when it parses
+pub fn with_taker_buy(ohlcv: OHLCV, taker_buy_volume: Option<&str>) -> OHLCV {
+    match taker_buy_volume.and_then(|v| v.parse::<f64>().ok()) {
+        Some(volume) => ohlcv.with_taker_buy_volume(Volume::from(volume)),
+        None => ohlcv,
+    }
+}
+
 /// DTO for Binance Kline data
 #[derive(Debug, Deserialize)]
 pub struct BinanceKlineData {
@@ -90,6 +100,7 @@ impl BinanceKline {
             return Err(JsValue::from_str("Invalid OHLCV data"));
         }
 
+        let ohlcv = with_taker_buy(ohlcv, Some(&self.taker_buy_base_asset_volume));
         Ok(Candle::new(timestamp, ohlcv))
     }
 }
@@ -134,6 +145,36 @@ pub struct BinanceError {
     pub msg: String,
 }
 
+/// DTO for the Binance partial book depth stream `<symbol>@depth<levels>`.
+///
+/// Levels are `[price, quantity]` string pairs.
+#[derive(Debug, Deserialize)]
+pub struct BinanceDepthSnapshot {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: u64,
+    pub bids: Vec<[String; 2]>,
+    pub asks: Vec<[String; 2]>,
+}
+
+impl BinanceDepthSnapshot {
+    /// Convert DTO to domain entity
+    pub fn to_domain_order_book(&self) -> Result<OrderBook, String> {
+        let levels = |side: &[[String; 2]]| -> Result<Vec<OrderBookLevel>, String> {
+            side.iter()
+                .map(|[price, quantity]| {
+                    let price =
+                        price.parse::<f64>().map_err(|e| format!("Parse price error: {e}"))?;
+                    let quantity = quantity
+                        .parse::<f64>()
+                        .map_err(|e| format!("Parse quantity error: {e}"))?;
+                    Ok(OrderBookLevel::new(price, quantity))
+                })
+                .collect()
+        };
+        Ok(OrderBook::new(self.last_update_id, levels(&self.bids)?, levels(&self.asks)?))
+    }
+}
+
 /// DTO for 24hr ticker statistics
 #[derive(Debug, Deserialize)]
 pub struct BinanceTicker24hr {
@@ -184,3 +225,71 @@ pub struct BinanceTicker24hr {
     #[serde(rename = "n")]
     pub total_number_of_trades: u64,
 }
+
+/// Row of Coinbase `/products/{id}/candles`: `[time, low, high, open, close, volume]`.
+///
+/// `time` is the bucket start in Unix seconds.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CoinbaseCandle(pub u64, pub f64, pub f64, pub f64, pub f64, pub f64);
+
+impl CoinbaseCandle {
+    /// Convert DTO to domain entity
+    pub fn to_domain_candle(&self) -> Candle {
+        let CoinbaseCandle(time, low, high, open, close, volume) = *self;
+        Candle::new(
+            Timestamp::from(time * 1000),
+            OHLCV::new(
+                Price::from(open),
+                Price::from(high),
+                Price::from(low),
+                Price::from(close),
+                Volume::from(volume),
+            ),
+        )
+    }
+}
+
+/// Message of the Coinbase Exchange WebSocket feed
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum CoinbaseMessage {
+    #[serde(rename = "ticker")]
+    Ticker(CoinbaseTicker),
+    #[serde(rename = "error")]
+    Error { message: String },
+    /// Subscription confirmations, heartbeats and other channels
+    #[serde(other)]
+    Other,
+}
+
+/// Trade update of the Coinbase `ticker` channel
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoinbaseTicker {
+    pub product_id: String,
+    pub price: String,
+    /// ISO 8601 trade time
+    pub time: String,
+    /// Size of the last trade
+    #[serde(default)]
+    pub last_size: String,
+}
+
+impl CoinbaseTicker {
+    pub fn price(&self) -> Result<f64, String> {
+        self.price.parse().map_err(|e| format!("Parse price error: {e}"))
+    }
+
+    /// Traded size, zero when missing
+    pub fn size(&self) -> f64 {
+        self.last_size.parse().unwrap_or(0.0)
+    }
+
+    /// Trade time in milliseconds
+    pub fn timestamp_ms(&self) -> Result<u64, String> {
+        let ms = js_sys::Date::parse(&self.time);
+        if ms.is_nan() {
+            return Err(format!("Invalid trade time: {}", self.time));
+        }
+        Ok(ms as u64)
+    }
+}
@@ -0,0 +1,128 @@
+//! Exchange-independent access to historical and real-time candles.
+
+use crate::domain::NetworkResult;
+use crate::domain::market_data::{Candle, Symbol, TimeInterval, default_symbols};
+use crate::infrastructure::websocket::{BinanceWebSocketClient, CoinbaseClient};
+use futures::future::LocalBoxFuture;
+use futures::lock::Mutex;
+use std::sync::Arc;
+
+/// Source of candles for one symbol and interval
+pub trait MarketDataClient: Send {
+    /// Load the latest `limit` candles, oldest first.
+    ///
+    /// Network errors are worth retrying; validation errors mean the response
+    /// was rejected or malformed and would fail again.
+    fn fetch_historical_data(&self, limit: u32) -> LocalBoxFuture<'_, NetworkResult<Vec<Candle>>>;
+
+    /// Load up to `limit` candles opened at or before `end_time`, oldest first
+    fn fetch_historical_data_before(
+        &self,
+        end_time: u64,
+        limit: u32,
+    ) -> LocalBoxFuture<'_, Result<Vec<Candle>, String>>;
+
+    /// Stream real-time candles to `handler`, reconnecting until the retry limit is hit.
+    ///
+    /// `on_reconnect` receives the attempt number before each retry.
+    fn start_stream(
+        &mut self,
+        handler: Box<dyn FnMut(Candle)>,
+        on_reconnect: Box<dyn FnMut(u32)>,
+    ) -> LocalBoxFuture<'_, Result<(), String>>;
+}
+
+/// Exchange serving a symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Exchange {
+    Binance,
+    Coinbase,
+}
+
+/// Quote assets recognized when splitting a concatenated symbol like `BTCUSDT`
+const QUOTE_ASSETS: [&str; 7] = ["USDT", "USDC", "USD", "EUR", "GBP", "BTC", "ETH"];
+
+impl Exchange {
+    /// Exchanges the symbol picker offers
+    pub const ALL: [Self; 2] = [Self::Binance, Self::Coinbase];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Binance => "Binance",
+            Self::Coinbase => "Coinbase",
+        }
+    }
+
+    /// The supported symbols in this exchange's format
+    pub fn symbols(&self) -> Vec<Symbol> {
+        default_symbols().iter().map(|s| Symbol::from(self.format_symbol(s).as_str())).collect()
+    }
+
+    /// Coinbase for `BASE-QUOTE` product ids, Binance otherwise
+    pub fn for_symbol(symbol: &Symbol) -> Self {
+        if symbol.value().contains('-') { Self::Coinbase } else { Self::Binance }
+    }
+
+    /// Whether the exchange can load candles of `interval`
+    pub fn supports_interval(&self, interval: TimeInterval) -> bool {
+        match self {
+            Self::Binance => true,
+            Self::Coinbase => CoinbaseClient::source_interval(interval).is_some(),
+        }
+    }
+
+    /// Symbol in the exchange's own format: `BTCUSDT` on Binance, `BTC-USDT` on Coinbase
+    pub fn format_symbol(&self, symbol: &Symbol) -> String {
+        let value = symbol.value().to_uppercase();
+        let (base, quote) = match value.split_once(['-', '/']) {
+            Some((base, quote)) => (base.to_string(), quote.to_string()),
+            None => {
+                match QUOTE_ASSETS.iter().find(|q| value.len() > q.len() && value.ends_with(*q)) {
+                    Some(quote) => {
+                        (value[..value.len() - quote.len()].to_string(), quote.to_string())
+                    }
+                    None => return value,
+                }
+            }
+        };
+        match self {
+            Self::Binance => format!("{base}{quote}"),
+            Self::Coinbase => format!("{base}-{quote}"),
+        }
+    }
+}
+
+/// Client for the exchange serving `symbol`
+pub fn create_market_data_client(
+    symbol: &Symbol,
+    interval: TimeInterval,
+) -> Arc<Mutex<dyn MarketDataClient>> {
+    match Exchange::for_symbol(symbol) {
+        Exchange::Binance => {
+            Arc::new(Mutex::new(BinanceWebSocketClient::new(symbol.clone(), interval)))
+        }
+        Exchange::Coinbase => Arc::new(Mutex::new(CoinbaseClient::new(symbol.clone(), interval))),
+    }
+}
+
+impl MarketDataClient for BinanceWebSocketClient {
+    fn fetch_historical_data(&self, limit: u32) -> LocalBoxFuture<'_, NetworkResult<Vec<Candle>>> {
+        Box::pin(BinanceWebSocketClient::fetch_historical_data(self, limit))
+    }
+
+    fn fetch_historical_data_before(
+        &self,
+        end_time: u64,
+        limit: u32,
+    ) -> LocalBoxFuture<'_, Result<Vec<Candle>, String>> {
+        Box::pin(BinanceWebSocketClient::fetch_historical_data_before(self, end_time, limit))
+    }
+
+    fn start_stream(
+        &mut self,
+        handler: Box<dyn FnMut(Candle)>,
+        on_reconnect: Box<dyn FnMut(u32)>,
+    ) -> LocalBoxFuture<'_, Result<(), String>> {
+        Box::pin(self.start_stream_with_callback(handler, on_reconnect))
+    }
+}
@@ -1,10 +1,14 @@
 //! WebSocket client implementations and data transfer objects.
 //!
-//! Currently this module provides a client for Binance streaming data.
+//! Binance and Coinbase clients share the [`MarketDataClient`] trait so the
+//! app can chart symbols from either exchange.
 
 pub mod binance_client;
 pub mod client_handle;
+pub mod coalescer;
+pub mod coinbase_client;
 pub mod dto;
+pub mod market_data_client;
 
 // Clean exports - only WebSocket client
 pub use binance_client::*;
@@ -12,4 +16,7 @@ pub use client_handle::{
     get_global_rest_client, get_global_stream_client, set_global_rest_client,
     set_global_stream_client,
 };
+pub use coalescer::CandleCoalescer;
+pub use coinbase_client::CoinbaseClient;
 pub use dto::*;
+pub use market_data_client::{Exchange, MarketDataClient, create_market_data_client};
@@ -13,12 +13,11 @@ pub mod global_state;
 pub mod infrastructure;
 pub mod macros;
 pub mod time_utils;
+pub mod touch_gestures;
 pub mod view_state;
 
 // === WASM EXPORTS ===
-use futures::lock::Mutex;
 use leptos::*;
-use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
@@ -33,17 +32,28 @@ pub fn start_app() {
     crate::infrastructure::initialize_infrastructure_services();
 
     // Initialize global clients
-    use crate::domain::market_data::{Symbol, TimeInterval};
+    use crate::app::{current_interval, current_symbol};
     use crate::infrastructure::websocket::{
-        BinanceWebSocketClient, set_global_rest_client, set_global_stream_client,
+        create_market_data_client, set_global_rest_client, set_global_stream_client,
     };
-    let symbol = Symbol::from("BTCUSDT");
-    let interval = TimeInterval::OneMinute;
-    set_global_rest_client(Arc::new(Mutex::new(BinanceWebSocketClient::new(
-        symbol.clone(),
-        interval,
-    ))));
-    set_global_stream_client(Arc::new(Mutex::new(BinanceWebSocketClient::new(symbol, interval))));
+    // Restore the last symbol, timeframe and indicator toggles before anything reads them
+    crate::infrastructure::storage::restore_settings();
+    // A shared link overrides the stored symbol and timeframe
+    crate::infrastructure::url_params::restore_from_url();
+    let symbol = current_symbol().get_untracked();
+    let interval = current_interval().get_untracked();
+
+    // Keep "now" aligned with the Binance server clock
+    use crate::infrastructure::http::{
+        binance_rest_client::BinanceRestClient,
+        server_time::{SERVER_TIME_REFRESH_MS, start_server_time_sync},
+    };
+    start_server_time_sync(
+        BinanceRestClient::new(symbol.clone(), interval),
+        SERVER_TIME_REFRESH_MS,
+    );
+    set_global_rest_client(create_market_data_client(&symbol, interval));
+    set_global_stream_client(create_market_data_client(&symbol, interval));
 
     // Mount Leptos app to body
     web_sys::console::log_1(&"🎯 Mounting Leptos app...".into());
@@ -56,7 +66,11 @@ pub fn start_app() {
         let _ = loading_div.set_attribute("style", "display: none;");
     }
 
-    leptos::mount_to_body(|| view! { <crate::app::App/> });
+    leptos::mount_to_body(|| {
+        crate::infrastructure::storage::persist_settings_on_change();
+        crate::infrastructure::url_params::sync_url_on_change();
+        view! { <crate::app::App/> }
+    });
 
     web_sys::console::log_1(&"✅ Leptos app mounted!".into());
 }
@@ -84,4 +98,27 @@ pub fn get_gpu_memory_usage() -> String {
         .unwrap_or_else(|| "{}".to_string())
 }
 
+/// Set the minimum log level ("trace", "debug", "info", "warn" or "error") without rebuilding
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn set_log_level(level: &str) -> Result<(), JsValue> {
+    let level = level.parse().map_err(|e: String| JsValue::from_str(&e))?;
+    crate::domain::logging::set_log_level(level);
+    Ok(())
+}
+
+/// Export the chart as a PNG data URL; the promise resolves after GPU readback
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub async fn export_chart_png() -> Result<String, JsValue> {
+    crate::infrastructure::rendering::renderer::export_chart_png().await
+}
+
+/// Serialize the active chart, its zoom/pan and indicator settings to JSON for bug reports
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn export_chart_state() -> Result<String, JsValue> {
+    crate::app::chart_state_json().map_err(|e| JsValue::from_str(&e))
+}
+
 // Clean WASM exports only
@@ -6,12 +6,11 @@ struct ChartUniforms {
     bullish_color: vec4<f32>,     // bullish candle color (green)
     bearish_color: vec4<f32>,     // bearish candle color (red)
     wick_color: vec4<f32>,        // wick color (gray)
-    sma20_color: vec4<f32>,       // SMA 20 color (red)
-    sma50_color: vec4<f32>,       // SMA 50 color (yellow)
-    sma200_color: vec4<f32>,      // SMA 200 color (blue)
-    ema12_color: vec4<f32>,       // EMA 12 color (purple)
-    ema26_color: vec4<f32>,       // EMA 26 color (cyan)
+    indicator_colors: array<vec4<f32>, 8>, // indicator line colors by color slot
+    line_colors: array<vec4<f32>, 25>, // built-in line colors by color type (10..34)
     current_price_color: vec4<f32>, // 💰 current price color (bright yellow)
+    outline_color: vec4<f32>,     // candle outline color
+    grid_color: vec4<f32>,        // grid line color
     render_params: vec4<f32>,     // candle_width, spacing, line_width, _padding
 }
 
@@ -53,23 +52,22 @@ fn vs_main(vertex: VertexInput) -> VertexOutput {
         // Candle wicks
         out.color = uniforms.wick_color; // gray
     } else if (vertex.element_type < 2.5) {
-        // Indicator lines with dedicated colors
-        if (vertex.color_type < 2.5) {
-            out.color = uniforms.sma20_color;
-        } else if (vertex.color_type < 3.5) {
-            out.color = uniforms.sma50_color;
-        } else if (vertex.color_type < 4.5) {
-            out.color = uniforms.sma200_color;
-        } else if (vertex.color_type < 5.5) {
-            out.color = uniforms.ema12_color;
-        } else if (vertex.color_type < 6.5) {
-            out.color = uniforms.ema26_color;
+        // Indicator lines colored by their slot (color_type 2..9)
+        if (vertex.color_type < 9.5) {
+            let slot = u32(max(vertex.color_type - 2.0, 0.0) + 0.5);
+            out.color = uniforms.indicator_colors[slot];
+        } else if (vertex.color_type > 26.5 && vertex.color_type < 27.5) {
+            out.color = uniforms.bullish_color; // close line of line/area charts
+        } else if (vertex.color_type < 34.5) {
+            // Built-in lines (Ichimoku, bands, oscillators, VWAP, overlay) by color type
+            let slot = u32(vertex.color_type - 10.0 + 0.5);
+            out.color = uniforms.line_colors[slot];
         } else {
             out.color = vec4<f32>(1.0, 1.0, 1.0, 1.0);
         }
     } else if (vertex.element_type < 3.5) {
         // Chart grid
-        out.color = vec4<f32>(0.3, 0.3, 0.3, 0.3); // semi-transparent gray
+        out.color = uniforms.grid_color;
     } else if (vertex.element_type < 4.5) {
         // 💰 Current price line
         out.color = uniforms.current_price_color; // bright yellow
@@ -82,6 +80,85 @@ fn vs_main(vertex: VertexInput) -> VertexOutput {
             // Bearish volume - red, slightly darker
             out.color = vec4<f32>(uniforms.bearish_color.rgb * 0.6, 0.8);
         }
+    } else if (vertex.element_type > 6.5 && vertex.element_type < 7.5) {
+        // 🐞 Debug outlines: price pane, volume pane, visible candles, sub-panels
+        if (vertex.color_type < 0.5) {
+            out.color = vec4<f32>(0.0, 1.0, 1.0, 1.0); // cyan
+        } else if (vertex.color_type < 1.5) {
+            out.color = vec4<f32>(1.0, 0.0, 1.0, 1.0); // magenta
+        } else if (vertex.color_type < 2.5) {
+            out.color = vec4<f32>(1.0, 0.6, 0.0, 1.0); // orange
+        } else {
+            out.color = vec4<f32>(0.5, 1.0, 0.0, 1.0); // lime
+        }
+    } else if (vertex.element_type > 7.5 && vertex.element_type < 8.5) {
+        // Candle body outline
+        out.color = uniforms.outline_color;
+    } else if (vertex.element_type > 8.5 && vertex.element_type < 9.5) {
+        // Mouse crosshair
+        out.color = vec4<f32>(0.8, 0.8, 0.8, 0.7); // light gray
+    } else if (vertex.element_type > 9.5 && vertex.element_type < 10.5) {
+        // Price alert
+        out.color = vec4<f32>(1.0, 0.75, 0.0, 0.9); // amber
+    } else if (vertex.element_type > 10.5 && vertex.element_type < 11.5) {
+        // Fibonacci retracement level
+        out.color = vec4<f32>(0.4, 0.7, 1.0, 0.85); // light blue
+    } else if (vertex.element_type > 11.5 && vertex.element_type < 12.5) {
+        // Session separator at a data gap
+        out.color = vec4<f32>(0.6, 0.6, 0.6, 0.35); // faint gray
+    } else if (vertex.element_type > 12.5 && vertex.element_type < 13.5) {
+        // 📚 Order book depth: bids in the buy color, asks in the sell color
+        if (vertex.color_type > 0.5) {
+            out.color = vec4<f32>(uniforms.bullish_color.rgb, 0.35);
+        } else {
+            out.color = vec4<f32>(uniforms.bearish_color.rgb, 0.35);
+        }
+    } else if (vertex.element_type > 13.5 && vertex.element_type < 14.5) {
+        // Area chart fill fading from the close line down to the bottom
+        out.color = vec4<f32>(uniforms.bullish_color.rgb, 0.4 * vertex.color_type);
+    } else if (vertex.element_type > 14.5 && vertex.element_type < 15.5) {
+        // 🔺 Visible high marker in the buy color, low marker in the sell color
+        if (vertex.color_type > 0.5) {
+            out.color = uniforms.bullish_color;
+        } else {
+            out.color = uniforms.bearish_color;
+        }
+    } else if (vertex.element_type > 15.5 && vertex.element_type < 16.5) {
+        // 📊 Volume average over the bars - translucent indicator yellow
+        out.color = vec4<f32>(1.0, 1.0, 0.0, 0.5);
+    } else if (vertex.element_type > 16.5 && vertex.element_type < 17.5) {
+        // 📊 Volume profile: the Point of Control in indicator yellow, other bins faint gray
+        if (vertex.color_type > 0.5) {
+            out.color = vec4<f32>(1.0, 1.0, 0.0, 0.45);
+        } else {
+            out.color = vec4<f32>(0.6, 0.6, 0.6, 0.25);
+        }
+    } else if (vertex.element_type > 17.5 && vertex.element_type < 18.5) {
+        // 🕘 Session open in indicator yellow, high in the buy color, low in the sell color
+        if (vertex.color_type < 0.5) {
+            out.color = vec4<f32>(1.0, 1.0, 0.0, 1.0);
+        } else if (vertex.color_type < 1.5) {
+            out.color = uniforms.bullish_color;
+        } else {
+            out.color = uniforms.bearish_color;
+        }
+    } else if (vertex.element_type > 18.5 && vertex.element_type < 19.5) {
+        // ⚖️ Delta volume: net taker buying in the buy color, selling in the sell color
+        if (vertex.color_type > 0.5) {
+            out.color = uniforms.bullish_color;
+        } else {
+            out.color = uniforms.bearish_color;
+        }
+    } else if (vertex.element_type > 19.5 && vertex.element_type < 20.5) {
+        // 🔵 Parabolic SAR dots in the buy color under an uptrend, sell color over a downtrend
+        if (vertex.color_type > 0.5) {
+            out.color = uniforms.bullish_color;
+        } else {
+            out.color = uniforms.bearish_color;
+        }
+    } else if (vertex.element_type > 20.5 && vertex.element_type < 21.5) {
+        // ⚡ Largest move highlight - faint indicator yellow behind the candle
+        out.color = vec4<f32>(1.0, 1.0, 0.0, 0.12);
     } else if (vertex.element_type > 98.0) {
         // ULTRA-SIMPLE TEST - bright red
         out.color = vec4<f32>(1.0, 0.0, 0.0, 1.0); // red
@@ -95,8 +172,53 @@ fn vs_main(vertex: VertexInput) -> VertexOutput {
     return out;
 }
 
+// Per-candle attributes for instanced drawing
+struct CandleInstanceInput {
+    @location(4) x: f32,
+    @location(5) width: f32,
+    @location(6) body_top: f32,
+    @location(7) body_bottom: f32,
+    @location(8) high: f32,
+    @location(9) low: f32,
+    @location(10) bullish: f32,
+};
+
+// Expand the unit candle template for one instance:
+// element_type 0 - body, 1 - upper wick, 2 - lower wick
+@vertex
+fn vs_candle(vertex: VertexInput, instance: CandleInstanceInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    let x = instance.x + vertex.position_x * instance.width;
+    var y: f32;
+    if (vertex.element_type < 0.5) {
+        y = mix(instance.body_bottom, instance.body_top, vertex.position_y);
+        if (instance.bullish > 0.5) {
+            out.color = uniforms.bullish_color;
+        } else {
+            out.color = uniforms.bearish_color;
+        }
+    } else if (vertex.element_type < 1.5) {
+        y = mix(instance.body_top, max(instance.high, instance.body_top), vertex.position_y);
+        out.color = uniforms.wick_color;
+    } else {
+        y = mix(min(instance.low, instance.body_bottom), instance.body_bottom, vertex.position_y);
+        out.color = uniforms.wick_color;
+    }
+
+    out.clip_position = uniforms.view_proj_matrix * vec4<f32>(x, y, 0.0, 1.0);
+    out.element_type = vertex.element_type;
+
+    return out;
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    // Simple fragment shader - return color from vertex shader
+    // Simple fragment shader - return color from vertex shader;
+    // only the translucent overlays (depth bars, area fill, volume average and profile) keep their alpha
+    if ((in.element_type > 12.5 && in.element_type < 14.5)
+        || (in.element_type > 15.5 && in.element_type < 17.5)) {
+        return in.color;
+    }
     return vec4<f32>(in.color.rgb, 1.0);
 } 
\ No newline at end of file
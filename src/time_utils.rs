@@ -1,13 +1,101 @@
+use crate::domain::market_data::TimeInterval;
 use js_sys::Date;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 use wasm_bindgen::JsValue;
 
+/// Time zone axis labels and tooltips are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeZone {
+    #[default]
+    Utc,
+    /// The browser's time zone, following daylight saving
+    Local,
+    /// Fixed offset east of UTC, in minutes
+    Offset(i32),
+}
+
+impl TimeZone {
+    /// Minutes east of UTC at `timestamp`
+    pub fn offset_minutes(self, timestamp: u64) -> i32 {
+        match self {
+            Self::Utc => 0,
+            Self::Local => {
+                -Date::new(&JsValue::from_f64(timestamp as f64)).get_timezone_offset() as i32
+            }
+            Self::Offset(minutes) => minutes,
+        }
+    }
+
+    /// Date whose UTC fields read the wall-clock time of `timestamp` in this zone
+    fn wall_clock(self, timestamp: u64) -> Date {
+        let shift_ms = self.offset_minutes(timestamp) as f64 * 60_000.0;
+        Date::new(&JsValue::from_f64(timestamp as f64 + shift_ms))
+    }
+
+    /// Zones offered by the selector: UTC, local time and whole-hour offsets
+    pub fn options() -> Vec<Self> {
+        [Self::Utc, Self::Local]
+            .into_iter()
+            .chain((-12..=14).filter(|h| *h != 0).map(|h| Self::Offset(h * 60)))
+            .collect()
+    }
+}
+
+/// `UTC`, `Local` or `UTC+02:00`
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Utc => write!(f, "UTC"),
+            Self::Local => write!(f, "Local"),
+            Self::Offset(minutes) => {
+                let sign = if minutes < 0 { '-' } else { '+' };
+                let abs = minutes.unsigned_abs();
+                write!(f, "UTC{sign}{:02}:{:02}", abs / 60, abs % 60)
+            }
+        }
+    }
+}
+
+impl FromStr for TimeZone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "UTC" => return Ok(Self::Utc),
+            "Local" => return Ok(Self::Local),
+            _ => {}
+        }
+        let invalid = || format!("Invalid time zone: {s}");
+        let offset = s.strip_prefix("UTC").ok_or_else(invalid)?;
+        let (sign, rest) = match offset.split_at_checked(1) {
+            Some(("+", rest)) => (1, rest),
+            Some(("-", rest)) => (-1, rest),
+            _ => return Err(invalid()),
+        };
+        let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+        let hours: i32 = hours.parse().map_err(|_| invalid())?;
+        let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+        if !(0..=14).contains(&hours) || !(0..60).contains(&minutes) {
+            return Err(invalid());
+        }
+        Ok(Self::Offset(sign * (hours * 60 + minutes)))
+    }
+}
+
 /// Format timestamp according to zoom level using UTC components.
 ///
 /// - `zoom >= 2.0` -> `HH:MM`
 /// - `1.0 <= zoom < 2.0` -> `DD.MM`
 /// - `zoom < 1.0` -> `MM.YYYY`
 pub fn format_time_label(timestamp: u64, zoom: f64) -> String {
-    let date = Date::new(&JsValue::from_f64(timestamp as f64));
+    format_time_label_in(timestamp, zoom, TimeZone::Utc)
+}
+
+/// [`format_time_label`] in `zone`
+pub fn format_time_label_in(timestamp: u64, zoom: f64, zone: TimeZone) -> String {
+    let date = zone.wall_clock(timestamp);
     if zoom >= 2.0 {
         format!("{:02}:{:02}", date.get_utc_hours(), date.get_utc_minutes())
     } else if zoom >= 1.0 {
@@ -17,6 +105,78 @@ pub fn format_time_label(timestamp: u64, zoom: f64) -> String {
     }
 }
 
+/// Format a full UTC date and time with a precision matching the candle interval.
+///
+/// - sub-minute intervals -> `YYYY-MM-DD HH:MM:SS`
+/// - intraday intervals -> `YYYY-MM-DD HH:MM`
+/// - daily and longer -> `YYYY-MM-DD`
+pub fn format_full_datetime(timestamp: u64, interval: TimeInterval) -> String {
+    format_full_datetime_in(timestamp, interval, TimeZone::Utc)
+}
+
+/// [`format_full_datetime`] in `zone`
+pub fn format_full_datetime_in(timestamp: u64, interval: TimeInterval, zone: TimeZone) -> String {
+    let date = zone.wall_clock(timestamp);
+    let day = format!(
+        "{}-{:02}-{:02}",
+        date.get_utc_full_year(),
+        date.get_utc_month() + 1,
+        date.get_utc_date()
+    );
+    let duration = interval.duration_ms();
+    if duration >= TimeInterval::OneDay.duration_ms() {
+        day
+    } else if duration < TimeInterval::OneMinute.duration_ms() {
+        format!(
+            "{day} {:02}:{:02}:{:02}",
+            date.get_utc_hours(),
+            date.get_utc_minutes(),
+            date.get_utc_seconds()
+        )
+    } else {
+        format!("{day} {:02}:{:02}", date.get_utc_hours(), date.get_utc_minutes())
+    }
+}
+
+/// Format a UTC timestamp as ISO 8601, e.g. `2024-01-15T13:45:07.000Z`
+pub fn format_iso_datetime(timestamp: u64) -> String {
+    Date::new(&JsValue::from_f64(timestamp as f64)).to_iso_string().into()
+}
+
+/// Format a duration with its two largest units, e.g. `2d 3h`, `1h 5m` or `45s`
+pub fn format_elapsed(ms: u64) -> String {
+    const UNITS: [(u64, &str); 4] =
+        [(24 * 60 * 60 * 1000, "d"), (60 * 60 * 1000, "h"), (60 * 1000, "m"), (1000, "s")];
+    let mut parts = Vec::new();
+    let mut rest = ms;
+    for (size, unit) in UNITS {
+        if rest >= size || (!parts.is_empty() && parts.len() < 2) {
+            parts.push(format!("{}{unit}", rest / size));
+            rest %= size;
+        }
+        if parts.len() == 2 {
+            break;
+        }
+    }
+    if parts.is_empty() { "0s".to_string() } else { parts.join(" ") }
+}
+
+/// Format a countdown as `MM:SS`, `HH:MM:SS` past an hour or `Nd HH:MM:SS` past a day.
+///
+/// Partial seconds round up so the display reaches `00:00` only at the close.
+pub fn format_countdown(ms: u64) -> String {
+    let secs = ms.div_ceil(1000);
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else if hours > 0 {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::format_time_label;
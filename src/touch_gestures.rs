@@ -0,0 +1,99 @@
+//! Touch gesture tracking for one-finger panning and two-finger pinch zoom.
+//!
+//! The tracker is rebuilt from the browser's list of active touches on every
+//! `touchstart`/`touchend`, so fingers lifted in any order never leave stale
+//! identifiers behind.
+
+/// Active touch point: identifier and position in canvas pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchPoint {
+    pub id: i32,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl TouchPoint {
+    pub fn new(id: i32, x: f64, y: f64) -> Self {
+        Self { id, x, y }
+    }
+}
+
+/// Chart action requested by a touch move
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TouchAction {
+    /// Pan by a horizontal pixel delta
+    Pan(f64),
+    /// Zoom by `factor` around the canvas x pixel `center_x`
+    Zoom { factor: f32, center_x: f64 },
+}
+
+/// Current gesture derived from the active touches
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TouchGesture {
+    #[default]
+    Idle,
+    Pan {
+        id: i32,
+        last_x: f64,
+    },
+    Pinch {
+        ids: (i32, i32),
+        distance: f64,
+    },
+}
+
+/// Distance between two touch points in pixels
+pub fn touch_distance(a: &TouchPoint, b: &TouchPoint) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Zoom factor for a pinch: the ratio of the new finger distance to the previous one.
+///
+/// Degenerate distances yield `1.0`; a single event is clamped to `0.5..=2.0`
+/// so a jittery sensor cannot jump the zoom level.
+pub fn pinch_zoom_factor(prev_distance: f64, distance: f64) -> f32 {
+    if !(prev_distance > f64::EPSILON && distance > f64::EPSILON) {
+        return 1.0;
+    }
+    (distance / prev_distance).clamp(0.5, 2.0) as f32
+}
+
+impl TouchGesture {
+    /// Start a gesture from the currently active touches
+    pub fn from_touches(touches: &[TouchPoint]) -> Self {
+        match touches {
+            [] => Self::Idle,
+            [one] => Self::Pan { id: one.id, last_x: one.x },
+            [a, b, ..] => Self::Pinch { ids: (a.id, b.id), distance: touch_distance(a, b) },
+        }
+    }
+
+    /// Advance the gesture with moved touches, returning the chart action to apply.
+    ///
+    /// If the tracked identifiers are no longer active the gesture restarts
+    /// from `touches` and no action is emitted for this event.
+    pub fn update(&mut self, touches: &[TouchPoint]) -> Option<TouchAction> {
+        let find = |id: i32| touches.iter().find(|t| t.id == id);
+        match *self {
+            Self::Pan { id, last_x } if touches.len() == 1 => {
+                if let Some(touch) = find(id) {
+                    *self = Self::Pan { id, last_x: touch.x };
+                    return Some(TouchAction::Pan(touch.x - last_x));
+                }
+            }
+            Self::Pinch { ids, distance } if touches.len() >= 2 => {
+                if let (Some(a), Some(b)) = (find(ids.0), find(ids.1)) {
+                    let new_distance = touch_distance(a, b);
+                    *self = Self::Pinch { ids, distance: new_distance };
+                    return Some(TouchAction::Zoom {
+                        factor: pinch_zoom_factor(distance, new_distance),
+                        center_x: (a.x + b.x) / 2.0,
+                    });
+                }
+            }
+            _ => {}
+        }
+        *self = Self::from_touches(touches);
+        None
+    }
+}
@@ -8,6 +8,12 @@ pub struct ViewState {
     pub cursor_anchor_ratio: f32,
 }
 
+impl Default for ViewState {
+    fn default() -> Self {
+        Self::new(5.0, 1.0, 20.0)
+    }
+}
+
 impl ViewState {
     pub fn new(pixels_per_candle: f32, min_ppc: f32, max_ppc: f32) -> Self {
         Self { pixels_per_candle, pan_offset_px: 0.0, min_ppc, max_ppc, cursor_anchor_ratio: 0.5 }
@@ -0,0 +1,40 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{ARROW_PAN_CANDLES, ARROW_PAN_CANDLES_FAST, pan_step_factor};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn pan_step_is_a_fraction_of_the_visible_candles() {
+    assert!((pan_step_factor(ARROW_PAN_CANDLES, 32) - 5.0 / 32.0).abs() < f32::EPSILON);
+    assert!((pan_step_factor(-ARROW_PAN_CANDLES, 32) + 5.0 / 32.0).abs() < f32::EPSILON);
+    assert!(pan_step_factor(ARROW_PAN_CANDLES_FAST, 32) > pan_step_factor(ARROW_PAN_CANDLES, 32));
+    assert_eq!(pan_step_factor(ARROW_PAN_CANDLES, 0), 0.0);
+}
+
+#[wasm_bindgen_test]
+fn left_arrow_step_moves_viewport_back() {
+    let mut chart = Chart::new("arrows".to_string(), ChartType::Candlestick, 200);
+    chart.set_historical_data(
+        (0..100)
+            .map(|i| {
+                Candle::new(
+                    Timestamp::from_millis(i * 60_000),
+                    OHLCV::new(
+                        Price::from(1.0),
+                        Price::from(1.1),
+                        Price::from(0.9),
+                        Price::from(1.0),
+                        Volume::from(1.0),
+                    ),
+                )
+            })
+            .collect(),
+    );
+    chart.viewport.start_time = 50.0 * 60_000.0;
+    chart.viewport.end_time = 82.0 * 60_000.0;
+
+    chart.pan(pan_step_factor(-ARROW_PAN_CANDLES, 32), 0.0);
+    assert!((chart.viewport.start_time - 45.0 * 60_000.0).abs() < 1.0);
+    assert!((chart.viewport.end_time - 77.0 * 60_000.0).abs() < 1.0);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{SubPanelKind, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, open: f64, high: f64, low: f64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn true_range_uses_previous_close_across_gap() {
+    let candles = vec![
+        candle(0, 99.5, 101.0, 99.0, 100.0),
+        // Gap up: high - low is only 2, but the move from the previous close is 11
+        candle(1, 109.5, 111.0, 109.0, 110.0),
+        candle(2, 110.0, 112.0, 108.0, 111.0),
+    ];
+    let service = MarketAnalysisService::new();
+
+    let tr = service.calculate_atr(&candles, 1);
+    assert_eq!(tr, vec![2.0, 11.0, 4.0]);
+
+    let atr = service.calculate_atr(&candles, 2);
+    assert_eq!(atr.len(), candles.len());
+    assert!((atr[0] - 2.0).abs() < 1e-9);
+    assert!((atr[1] - 6.5).abs() < 1e-9);
+    assert!((atr[2] - 5.25).abs() < 1e-9);
+
+    assert!(service.calculate_atr(&candles, 0).is_empty());
+    assert!(service.calculate_atr(&[], 14).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn atr_panel_is_drawn_only_when_enabled() {
+    let candles: Vec<Candle> = (0..30)
+        .map(|i| {
+            let base = 100.0 + (i as f64 * 0.5).sin() * 5.0;
+            candle(i, base, base + 1.0 + (i % 3) as f64, base - 1.0, base + 0.5)
+        })
+        .collect();
+    let mut chart = Chart::new("atr".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    let mut renderer = dummy_renderer();
+
+    let is_atr = |v: &&CandleVertex| {
+        (v.element_type - 2.0).abs() < f32::EPSILON && (v.color_type - 23.0).abs() < f32::EPSILON
+    };
+
+    let (_, verts, plain) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(verts.iter().filter(is_atr).count(), 0);
+
+    renderer.toggle_line_visibility("atr");
+    let panel = *renderer.panel_layout().panel(SubPanelKind::Atr).expect("atr panel");
+    let (_, verts, with_atr) = renderer.create_geometry_for_test(&chart);
+    let atr: Vec<_> = verts.iter().filter(is_atr).collect();
+    assert!(!atr.is_empty());
+    assert!(
+        atr.iter().all(|v| v.position_y >= panel.bottom - 0.01 && v.position_y <= panel.top + 0.01)
+    );
+
+    // ATR scales its own panel only; the price range is unchanged
+    assert_eq!(plain.viewport[2], with_atr.viewport[2]);
+    assert_eq!(plain.viewport[3], with_atr.viewport[3]);
+}
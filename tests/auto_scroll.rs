@@ -1,8 +1,72 @@
 #![cfg(feature = "render")]
-use price_chart_wasm::app::should_auto_scroll;
+use leptos::*;
+use price_chart_wasm::app::{
+    at_live_edge, current_interval, current_symbol, follow_live, route_stream_candle,
+    should_auto_scroll,
+};
+use price_chart_wasm::domain::chart::Chart;
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::global_state::ensure_chart;
+use wasm_bindgen_test::*;
 
 #[test]
 fn detects_right_edge() {
     assert!(should_auto_scroll(100, 2.0, 0.0));
     assert!(!should_auto_scroll(100, 2.0, -1.0));
 }
+
+fn candle(i: u64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 2_000),
+        OHLCV::new(
+            Price::from(100.0),
+            Price::from(101.0),
+            Price::from(99.0),
+            Price::from(100.0),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+/// Chart of `symbol` holding 100 two-second candles, selected as the current one
+fn live_chart(symbol: &Symbol) -> RwSignal<Chart> {
+    current_symbol().set(symbol.clone());
+    current_interval().set(TimeInterval::TwoSeconds);
+    let chart = ensure_chart(symbol);
+    chart.update(|c| c.set_historical_data((0..100).map(candle).collect()));
+    chart
+}
+
+#[wasm_bindgen_test]
+fn following_live_keeps_the_view_at_the_edge() {
+    let symbol = Symbol::from("AUTOSCROLLON");
+    let chart = live_chart(&symbol);
+    follow_live().set(true);
+
+    assert!(route_stream_candle(&symbol, candle(100)));
+    let (end, at_edge) =
+        chart.with_untracked(|c| (c.viewport.end_time, at_live_edge(c, TimeInterval::TwoSeconds)));
+    assert_eq!(end, 100.0 * 2_000.0);
+    assert!(at_edge);
+}
+
+#[wasm_bindgen_test]
+fn panning_back_leaves_the_edge() {
+    let symbol = Symbol::from("AUTOSCROLLOFF");
+    let chart = live_chart(&symbol);
+    chart.update(|c| {
+        c.viewport.start_time = 10.0 * 2_000.0;
+        c.viewport.end_time = 40.0 * 2_000.0;
+    });
+    follow_live().set(false);
+
+    // A new candle while not following leaves the view in place
+    assert!(route_stream_candle(&symbol, candle(100)));
+    chart.with_untracked(|c| {
+        assert_eq!((c.viewport.start_time, c.viewport.end_time), (10.0 * 2_000.0, 40.0 * 2_000.0));
+        assert!(!at_live_edge(c, TimeInterval::TwoSeconds));
+        assert_eq!(c.get_candle_count(), 101);
+    });
+}
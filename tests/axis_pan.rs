@@ -1,6 +1,7 @@
 #![cfg(feature = "render")]
 use price_chart_wasm::app::price_levels;
 use price_chart_wasm::domain::chart::value_objects::Viewport;
+use price_chart_wasm::infrastructure::rendering::renderer::PriceScaleMode;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
@@ -16,9 +17,9 @@ fn price_levels_change_after_pan() {
         height: 600,
     };
 
-    let original = price_levels(&vp);
+    let original = price_levels(&vp, PriceScaleMode::Linear);
     vp.pan(0.0, 0.1);
-    let moved = price_levels(&vp);
+    let moved = price_levels(&vp, PriceScaleMode::Linear);
 
     assert_ne!(original, moved);
     assert!((moved[0] - 110.0).abs() < 1e-6);
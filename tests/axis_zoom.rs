@@ -2,6 +2,7 @@
 use price_chart_wasm::app::{price_levels, visible_range, visible_range_by_time};
 use price_chart_wasm::domain::chart::value_objects::Viewport;
 use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::PriceScaleMode;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
@@ -29,9 +30,9 @@ fn price_levels_change_after_zoom() {
         height: 600,
     };
 
-    let before = price_levels(&vp);
+    let before = price_levels(&vp, PriceScaleMode::Linear);
     vp.zoom_price(2.0, 0.5);
-    let after = price_levels(&vp);
+    let after = price_levels(&vp, PriceScaleMode::Linear);
 
     assert_ne!(before, after);
     assert!((after[0] - 75.0).abs() < 1e-6);
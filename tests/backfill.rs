@@ -0,0 +1,175 @@
+#![cfg(feature = "render")]
+use gloo_timers::future::sleep;
+use leptos::*;
+use price_chart_wasm::app::{
+    BackfillGoal, BackfillStep, HistoryWindow, MAX_HISTORY_BUFFER_SIZE, backfill_history,
+    current_interval, current_symbol, date_jump_goal, loading_more,
+};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::logging::get_time_provider;
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::global_state::ensure_chart;
+use price_chart_wasm::infrastructure::rate_limiter::with_history_rate_limiter;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+const CAPACITY: usize = MAX_HISTORY_BUFFER_SIZE;
+
+#[wasm_bindgen_test]
+fn first_step_fetches_before_the_oldest_candle() {
+    let goal = BackfillGoal::StartTime(0);
+    assert_eq!(
+        goal.next(Some(100 * MINUTE), 1000, None, CAPACITY),
+        BackfillStep::Fetch(100 * MINUTE - 1)
+    );
+}
+
+#[wasm_bindgen_test]
+fn empty_page_exhausts_the_backfill() {
+    let goal = BackfillGoal::StartTime(0);
+    assert_eq!(goal.next(Some(100 * MINUTE), 1000, Some(0), CAPACITY), BackfillStep::Exhausted);
+    assert_eq!(
+        BackfillGoal::CandleCount(5000).next(None, 0, None, CAPACITY),
+        BackfillStep::Exhausted
+    );
+}
+
+#[wasm_bindgen_test]
+fn pages_continue_until_the_start_time() {
+    let goal = BackfillGoal::StartTime(10 * MINUTE);
+    assert_eq!(
+        goal.next(Some(11 * MINUTE), 2000, Some(1000), CAPACITY),
+        BackfillStep::Fetch(11 * MINUTE - 1)
+    );
+    assert_eq!(goal.next(Some(10 * MINUTE), 3000, Some(1000), CAPACITY), BackfillStep::Reached);
+    assert_eq!(goal.next(Some(5 * MINUTE), 3000, Some(1000), CAPACITY), BackfillStep::Reached);
+}
+
+#[wasm_bindgen_test]
+fn pages_continue_until_the_candle_count() {
+    let goal = BackfillGoal::CandleCount(2500);
+    assert_eq!(
+        goal.next(Some(MINUTE), 2000, Some(1000), CAPACITY),
+        BackfillStep::Fetch(MINUTE - 1)
+    );
+    assert_eq!(goal.next(Some(MINUTE), 2500, Some(500), CAPACITY), BackfillStep::Reached);
+}
+
+#[wasm_bindgen_test]
+fn backfill_stops_at_the_buffer_limit_or_the_epoch() {
+    let goal = BackfillGoal::StartTime(0);
+    assert_eq!(goal.next(Some(MINUTE), CAPACITY, Some(1000), CAPACITY), BackfillStep::Full);
+    // A smaller history window fills sooner
+    assert_eq!(goal.next(Some(MINUTE), 2000, Some(1000), 2000), BackfillStep::Full);
+    assert_eq!(goal.next(Some(MINUTE), 2000, Some(1000), 3000), BackfillStep::Fetch(MINUTE - 1));
+    let goal = BackfillGoal::CandleCount(10);
+    assert_eq!(goal.next(Some(0), 5, Some(5), CAPACITY), BackfillStep::Exhausted);
+}
+
+#[wasm_bindgen_test]
+fn simulated_exchange_runs_out_of_pages() {
+    // Three pages of 100 one-minute candles, then nothing older
+    let mut oldest = 300 * MINUTE;
+    let mut loaded = 100;
+    let mut pages_left = 2;
+    let mut last_added = None;
+    let mut requests = 0;
+    let goal = BackfillGoal::StartTime(0);
+    let step = loop {
+        match goal.next(Some(oldest), loaded, last_added, CAPACITY) {
+            BackfillStep::Fetch(end_time) => {
+                assert_eq!(end_time, oldest - 1);
+                requests += 1;
+                let added = if pages_left > 0 { 100 } else { 0 };
+                pages_left -= added.min(1);
+                oldest -= added as u64 * MINUTE;
+                loaded += added;
+                last_added = Some(added);
+            }
+            step => break step,
+        }
+    };
+    assert_eq!(step, BackfillStep::Exhausted);
+    assert_eq!(requests, 3);
+    assert_eq!(loaded, 300);
+}
+
+#[wasm_bindgen_test]
+fn date_jump_prepends_pages_until_the_target_is_covered() {
+    let window = HistoryWindow::new(100, 1000);
+    let candle = |minute: u64| {
+        Candle::new(
+            Timestamp::from_millis(minute * MINUTE),
+            OHLCV::new(
+                Price::from(1.0),
+                Price::from(1.0),
+                Price::from(1.0),
+                Price::from(1.0),
+                Volume::from(1.0),
+            ),
+        )
+    };
+    let mut chart = Chart::new("jump".to_string(), ChartType::Candlestick, window.buffer_size);
+    chart.set_historical_data((1000..1100).map(candle).collect());
+
+    // The target is three pages back; each page is merged in front of the loaded candles
+    let goal = date_jump_goal(760 * MINUTE, &window, TimeInterval::OneMinute);
+    let mut last_added = None;
+    let mut requests = 0;
+    let step = loop {
+        let series = chart.get_series(TimeInterval::OneMinute).unwrap();
+        let (oldest, loaded) =
+            (series.get_candles().front().unwrap().timestamp.value(), series.count());
+        match goal.next(Some(oldest), loaded, last_added, window.buffer_size) {
+            BackfillStep::Fetch(end_time) => {
+                requests += 1;
+                let end = end_time / MINUTE;
+                for minute in end + 1 - window.fetch_limit as u64..=end {
+                    chart.add_candle(candle(minute));
+                }
+                last_added = Some(chart.get_candle_count() - loaded);
+            }
+            step => break step,
+        }
+    };
+    assert_eq!(step, BackfillStep::Reached);
+    assert_eq!(requests, 3);
+    let series = chart.get_series(TimeInterval::OneMinute).unwrap();
+    assert!(series.nearest_index(760 * MINUTE).is_some_and(|i| i >= 50));
+    // The newest candles are kept rather than replaced by the older pages
+    assert_eq!(series.get_candles().back().unwrap().timestamp.value(), 1099 * MINUTE);
+    assert_eq!(series.count(), 400);
+}
+
+#[wasm_bindgen_test(async)]
+async fn rate_limited_backfill_stops_when_the_symbol_changes() {
+    let symbol = Symbol::from("BFAUSDT");
+    current_symbol().set(symbol.clone());
+    current_interval().set(TimeInterval::OneMinute);
+    let candle = Candle::new(
+        Timestamp::from_millis(100 * MINUTE),
+        OHLCV::new(
+            Price::from(1.0),
+            Price::from(1.0),
+            Price::from(1.0),
+            Price::from(1.0),
+            Volume::from(1.0),
+        ),
+    );
+    ensure_chart(&symbol).update(|c| c.set_historical_data(vec![candle]));
+    // Spend the whole request budget so the backfill has to wait for a token
+    while with_history_rate_limiter(|l| l.try_acquire(get_time_provider().current_timestamp())) {}
+
+    let (_, set_status) = create_signal(String::new());
+    spawn_local(backfill_history(BackfillGoal::CandleCount(500), set_status));
+    sleep(Duration::from_millis(10)).await;
+    assert!(loading_more().get_untracked());
+
+    // The switch ends the backfill once its wait is over, without a request for the old symbol
+    current_symbol().set(Symbol::from("BFBUSDT"));
+    sleep(Duration::from_millis(1_300)).await;
+    assert!(!loading_more().get_untracked());
+}
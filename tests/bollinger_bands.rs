@@ -0,0 +1,73 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+/// Closes alternate between 100 and 102, giving a constant standard deviation of 1
+fn alternating_candles(count: u64) -> Vec<Candle> {
+    (0..count)
+        .map(|i| {
+            let close: f64 = if i % 2 == 0 { 100.0 } else { 102.0 };
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(101.0),
+                    Price::from(close.max(101.0)),
+                    Price::from(close.min(101.0)),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn band_vertices(verts: &[CandleVertex], color_type: f32) -> usize {
+    verts
+        .iter()
+        .filter(|v| {
+            (v.element_type - 2.0).abs() < f32::EPSILON
+                && (v.color_type - color_type).abs() < f32::EPSILON
+        })
+        .count()
+}
+
+#[wasm_bindgen_test]
+fn bands_are_ordered_for_constant_volatility() {
+    let candles = alternating_candles(40);
+    let bands = MarketAnalysisService::new().calculate_bollinger_bands(&candles, 20, 2.0);
+
+    assert_eq!(bands.middle.len(), 40 - 20 + 1);
+    assert_eq!(bands.upper.len(), bands.middle.len());
+    assert_eq!(bands.lower.len(), bands.middle.len());
+    for ((upper, middle), lower) in bands.upper.iter().zip(&bands.middle).zip(&bands.lower) {
+        assert!(upper.value() >= middle.value());
+        assert!(middle.value() >= lower.value());
+        assert!((middle.value() - 101.0).abs() < 1e-9);
+        assert!((upper.value() - 103.0).abs() < 1e-6);
+        assert!((lower.value() - 99.0).abs() < 1e-6);
+    }
+}
+
+#[wasm_bindgen_test]
+fn bands_render_only_when_enabled() {
+    let mut chart = Chart::new("bb".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(alternating_candles(30));
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(band_vertices(&verts, 15.0), 0);
+
+    renderer.toggle_line_visibility("bb");
+    assert!(renderer.line_visibility().is_visible("bb"));
+    let (_, verts, uniforms) = renderer.create_geometry_for_test(&chart);
+    for color_type in [15.0, 16.0, 17.0] {
+        assert_eq!(band_vertices(&verts, color_type), (30 - 20) * 6);
+    }
+    // The bands stretch the price range beyond the candle lows and highs
+    assert!(uniforms.viewport[2] < 99.0);
+    assert!(uniforms.viewport[3] > 103.0);
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{
+    ZoomBox, current_interval, current_symbol, select_interval, select_symbol,
+};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    CandleSizing, PriceAxisRange, PriceScaleMode, candle_x_position, dummy_renderer,
+    set_global_renderer, with_global_renderer,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+const CANVAS: (f64, f64) = (800.0, 600.0);
+
+fn candles(count: u64) -> Vec<Candle> {
+    (0..count)
+        .map(|i| {
+            Candle::new(
+                Timestamp::from(i * 60_000),
+                OHLCV::new(
+                    Price::from(100.0),
+                    Price::from(110.0),
+                    Price::from(90.0),
+                    Price::from(105.0),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn range() -> PriceAxisRange {
+    PriceAxisRange {
+        min_price: 100.0,
+        max_price: 200.0,
+        scale: PriceScaleMode::Linear,
+        main_bottom: -1.0,
+        inverted: false,
+    }
+}
+
+/// Canvas pixel column of candle `index` out of `len`
+fn candle_px(index: usize, len: usize) -> f64 {
+    (candle_x_position(index, len) as f64 + 1.0) / 2.0 * CANVAS.0
+}
+
+#[wasm_bindgen_test]
+fn corners_map_to_candle_times_and_prices() {
+    let visible = candles(10);
+    let zoom_box = ZoomBox { from: (candle_px(2, 10), 150.0), to: (candle_px(6, 10), 450.0) };
+    let viewport = zoom_box.viewport(&visible, &range(), CANVAS, CandleSizing::default()).unwrap();
+
+    assert_eq!(viewport.start_time, 2.0 * 60_000.0);
+    assert_eq!(viewport.end_time, 6.0 * 60_000.0);
+    assert!((viewport.max_price - 175.0).abs() < 1e-3);
+    assert!((viewport.min_price - 125.0).abs() < 1e-3);
+    assert_eq!((viewport.width, viewport.height), (800, 600));
+}
+
+#[wasm_bindgen_test]
+fn corner_order_does_not_matter() {
+    let visible = candles(10);
+    let a = ZoomBox { from: (candle_px(6, 10), 450.0), to: (candle_px(2, 10), 150.0) };
+    let b = ZoomBox { from: (candle_px(2, 10), 150.0), to: (candle_px(6, 10), 450.0) };
+    let sizing = CandleSizing::default();
+    assert_eq!(
+        a.viewport(&visible, &range(), CANVAS, sizing),
+        b.viewport(&visible, &range(), CANVAS, sizing)
+    );
+}
+
+#[wasm_bindgen_test]
+fn corners_below_candle_area_pin_to_its_bottom() {
+    let visible = candles(10);
+    let range = PriceAxisRange { main_bottom: 0.0, ..range() };
+    let zoom_box = ZoomBox { from: (100.0, 0.0), to: (300.0, 590.0) };
+    let viewport = zoom_box.viewport(&visible, &range, CANVAS, CandleSizing::default()).unwrap();
+    assert!((viewport.min_price - 100.0).abs() < 1e-3);
+    assert!((viewport.max_price - 200.0).abs() < 1e-3);
+}
+
+#[wasm_bindgen_test]
+fn tiny_box_is_a_click_not_a_selection() {
+    let visible = candles(10);
+    let zoom_box = ZoomBox { from: (100.0, 100.0), to: (102.0, 300.0) };
+    assert!(zoom_box.viewport(&visible, &range(), CANVAS, CandleSizing::default()).is_none());
+}
+
+#[wasm_bindgen_test]
+fn pinned_price_range_replaces_the_fitted_one() {
+    let mut chart = Chart::new("box".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles(20));
+    let mut renderer = dummy_renderer();
+
+    renderer.set_price_range(Some((95.0, 105.0)));
+    let (_, _, uniforms) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(uniforms.viewport[2..4], [95.0, 105.0]);
+
+    renderer.set_price_range(None);
+    let (_, _, uniforms) = renderer.create_geometry_for_test(&chart);
+    assert!(uniforms.viewport[2] < 90.0 && uniforms.viewport[3] > 110.0);
+}
+
+#[wasm_bindgen_test]
+fn pinned_price_range_is_released_by_symbol_and_interval_changes() {
+    set_global_renderer(Rc::new(RefCell::new(dummy_renderer())));
+    let pinned = || with_global_renderer(|r| r.price_range()).unwrap();
+
+    with_global_renderer(|r| r.set_price_range(Some((95.0, 105.0))));
+    select_symbol(Symbol::from("ETHUSDT"));
+    assert_eq!(pinned(), None);
+    assert_eq!(current_symbol().get_untracked(), Symbol::from("ETHUSDT"));
+
+    with_global_renderer(|r| r.set_price_range(Some((95.0, 105.0))));
+    select_interval(TimeInterval::FiveMinutes);
+    assert_eq!(pinned(), None);
+    assert_eq!(current_interval().get_untracked(), TimeInterval::FiveMinutes);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::TimeInterval;
+use price_chart_wasm::time_utils::format_countdown;
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+
+#[wasm_bindgen_test]
+fn remaining_time_counts_down_to_the_bucket_close() {
+    let open = 1_700_000_040_000; // a minute boundary
+    let interval = TimeInterval::OneMinute;
+    assert_eq!(interval.remaining_ms(open, open), MINUTE);
+    assert_eq!(interval.remaining_ms(open, open + 17_000), 43_000);
+    assert_eq!(interval.remaining_ms(open, open + MINUTE - 1), 1);
+    assert_eq!(format_countdown(interval.remaining_ms(open, open + 17_000)), "00:43");
+}
+
+#[wasm_bindgen_test]
+fn short_and_long_intervals_are_supported() {
+    assert_eq!(TimeInterval::TwoSeconds.remaining_ms(10_000, 10_500), 1_500);
+    let hour = 60 * MINUTE;
+    assert_eq!(TimeInterval::FourHours.remaining_ms(0, hour), 3 * hour);
+    assert_eq!(format_countdown(3 * hour), "03:00:00");
+    assert_eq!(format_countdown(TimeInterval::OneDay.duration_ms() + 61_000), "1d 00:01:01");
+}
+
+#[wasm_bindgen_test]
+fn overdue_candle_counts_down_the_bucket_of_now() {
+    // No new candle arrived yet: the countdown follows the current bucket
+    let interval = TimeInterval::OneMinute;
+    assert_eq!(interval.remaining_ms(0, 2 * MINUTE + 20_000), 40_000);
+}
+
+#[wasm_bindgen_test]
+fn partial_seconds_round_up() {
+    assert_eq!(format_countdown(42_300), "00:43");
+    assert_eq!(format_countdown(0), "00:00");
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    candles_per_element, downsample_candles, downsample_runs, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, open: f64, high: f64, low: f64, close: f64, volume: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Volume::from(volume),
+        ),
+    )
+}
+
+fn sample_chart(count: usize) -> Chart {
+    let mut chart = Chart::new("downsample".to_string(), ChartType::Candlestick, count + 10);
+    let candles = (0..count as u64)
+        .map(|i| {
+            let base = 100.0 + (i as f64 * 0.01).sin() * 20.0;
+            candle(i, base, base + 2.0, base - 2.0, base + 1.0, 1.0)
+        })
+        .collect();
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn summaries_keep_open_close_and_extremes() {
+    let candles = vec![
+        candle(0, 10.0, 12.0, 9.0, 11.0, 1.0),
+        candle(1, 11.0, 15.0, 10.0, 14.0, 2.0),
+        candle(2, 14.0, 14.5, 6.0, 8.0, 3.0),
+        candle(3, 8.0, 9.0, 7.0, 8.5, 4.0),
+        candle(4, 8.5, 10.0, 8.0, 9.5, 5.0),
+    ];
+    let summaries = downsample_candles(&candles, 0, 3);
+    assert_eq!(summaries.len(), 2);
+
+    let first = &summaries[0];
+    assert_eq!(first.timestamp.value(), 0);
+    assert_eq!(first.ohlcv.open.value(), 10.0);
+    assert_eq!(first.ohlcv.high.value(), 15.0);
+    assert_eq!(first.ohlcv.low.value(), 6.0);
+    assert_eq!(first.ohlcv.close.value(), 8.0);
+    assert_eq!(first.ohlcv.volume.value(), 6.0);
+
+    // The last run may be shorter
+    let last = &summaries[1];
+    assert_eq!(last.ohlcv.open.value(), 8.0);
+    assert_eq!(last.ohlcv.high.value(), 10.0);
+    assert_eq!(last.ohlcv.low.value(), 7.0);
+    assert_eq!(last.ohlcv.close.value(), 9.5);
+}
+
+#[wasm_bindgen_test]
+fn runs_stay_aligned_to_series_indices() {
+    // Runs of three from index 4: the first run only holds index 5
+    assert_eq!(downsample_runs(7, 4, 3), vec![0..2, 2..5, 5..7]);
+    assert_eq!(downsample_runs(4, 0, 3), vec![0..3, 3..4]);
+    assert_eq!(downsample_runs(0, 2, 3), Vec::<std::ops::Range<usize>>::new());
+
+    let candles: Vec<Candle> = (0..40u64)
+        .map(|i| {
+            let base = 100.0 + (i as f64 * 0.7).sin() * 10.0;
+            candle(i, base, base + 1.0 + i as f64 % 3.0, base - 2.0, base + 0.5, i as f64)
+        })
+        .collect();
+    // Panning by one candle keeps every summary away from the edges
+    let summaries = downsample_candles(&candles[10..34], 10, 4);
+    let panned = downsample_candles(&candles[11..35], 11, 4);
+    assert_eq!(summaries[1..summaries.len() - 1], panned[1..panned.len() - 1]);
+    assert_eq!(summaries[1].timestamp.value(), 12 * 60_000);
+}
+
+#[wasm_bindgen_test]
+fn candles_per_element_rounds_up() {
+    assert_eq!(candles_per_element(300, 800), 1);
+    assert_eq!(candles_per_element(800, 800), 1);
+    assert_eq!(candles_per_element(801, 800), 2);
+    assert_eq!(candles_per_element(5000, 800), 7);
+    assert_eq!(candles_per_element(10, 0), 10);
+}
+
+#[wasm_bindgen_test]
+fn drawn_elements_never_exceed_canvas_width() {
+    let mut renderer = dummy_renderer();
+    for count in [300usize, 1_000, 5_000, 20_000] {
+        let chart = sample_chart(count);
+        // Zoom out far enough to show every candle
+        renderer.set_zoom_params(32.0 / count as f64, 0.0);
+        let (instances, _, _) = renderer.create_geometry_for_test(&chart);
+        assert!(!instances.is_empty());
+        assert!(instances.len() <= 800, "{count} candles drew {} elements", instances.len());
+        if count <= 800 {
+            assert_eq!(instances.len(), count);
+        }
+    }
+}
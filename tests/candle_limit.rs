@@ -0,0 +1,84 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, MaKind, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use wasm_bindgen_test::*;
+
+const STEP_MS: u64 = 2_000;
+
+fn candle(i: u64) -> Candle {
+    let close = i as f64;
+    Candle::new(
+        Timestamp::from_millis(i * STEP_MS),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close),
+            Price::from(close),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn stream(max_candles: usize, total: u64) -> Chart {
+    let mut chart = Chart::new("limit".to_string(), ChartType::Candlestick, max_candles);
+    for i in 0..total {
+        chart.add_realtime_candle(candle(i));
+    }
+    chart
+}
+
+fn sma(chart: &Chart, period: usize) -> Vec<f64> {
+    let engine = chart.ma_engines.get(&TimeInterval::TwoSeconds).unwrap();
+    engine.values(MaKind::Sma, period).unwrap().iter().map(|p| p.value()).collect()
+}
+
+#[wasm_bindgen_test]
+fn realtime_candles_beyond_the_limit_evict_the_oldest() {
+    let chart = stream(250, 400);
+    assert_eq!(chart.max_candles(), 250);
+    let base = chart.get_series(TimeInterval::TwoSeconds).unwrap();
+    assert_eq!(base.count(), 250);
+    assert_eq!(base.get_candles().front().unwrap().timestamp.value(), 150 * STEP_MS);
+    assert_eq!(base.latest().unwrap().timestamp.value(), 399 * STEP_MS);
+}
+
+#[wasm_bindgen_test]
+fn moving_averages_stay_aligned_with_the_kept_candles() {
+    let chart = stream(250, 400);
+    // One SMA200 value per kept candle after its 199-candle lead-in
+    let sma200 = sma(&chart, 200);
+    assert_eq!(sma200.len(), 250 - 199);
+    // The first value averages the first 200 kept candles, closes 150..=349
+    assert!((sma200[0] - 249.5).abs() < 1e-9);
+    assert!((sma200.last().unwrap() - 299.5).abs() < 1e-9);
+}
+
+#[wasm_bindgen_test]
+fn a_limit_below_the_lead_in_leaves_no_misplaced_values() {
+    let chart = stream(100, 400);
+    assert_eq!(chart.get_candle_count(), 100);
+    assert!(sma(&chart, 200).is_empty());
+    assert_eq!(sma(&chart, 20).len(), 100 - 19);
+    assert!((sma(&chart, 20).last().unwrap() - 389.5).abs() < 1e-9);
+}
+
+#[wasm_bindgen_test]
+fn history_beyond_the_limit_keeps_moving_averages_aligned() {
+    let mut chart = Chart::new("limit".to_string(), ChartType::Candlestick, 250);
+    chart.set_historical_data((0..400).map(candle).collect());
+    assert_eq!(chart.get_candle_count(), 250);
+    assert!((sma(&chart, 200)[0] - 249.5).abs() < 1e-9);
+}
+
+#[wasm_bindgen_test]
+fn aggregates_follow_the_limit() {
+    // 30 two-second candles per minute
+    let chart = stream(10, 30 * 15);
+    let minutes = chart.get_series(TimeInterval::OneMinute).unwrap();
+    assert_eq!(minutes.count(), 10);
+    let engine = chart.ma_engines.get(&TimeInterval::OneMinute).unwrap();
+    // The open minute is not in the engine yet
+    assert_eq!(engine.values(MaKind::Ema, 12).unwrap().len(), 9);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{CandleOutline, dummy_renderer};
+use price_chart_wasm::infrastructure::rendering::{CandleGeometry, CandleVertex};
+use wasm_bindgen_test::*;
+
+fn make_chart(count: u64) -> Chart {
+    let candles: Vec<Candle> = (0..count)
+        .map(|i| {
+            let base = 100.0 + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 2.0),
+                    Price::from(base - 1.0),
+                    Price::from(base + 1.0),
+                    Volume::from(5.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("outline".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+fn outline_vertices(verts: &[CandleVertex]) -> Vec<&CandleVertex> {
+    verts.iter().filter(|v| (v.element_type - 8.0).abs() < f32::EPSILON).collect()
+}
+
+#[wasm_bindgen_test]
+fn outline_emitted_per_candle_only_when_enabled() {
+    let chart = make_chart(10);
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(outline_vertices(&verts).is_empty());
+
+    let color = [0.1, 0.2, 0.3, 1.0];
+    renderer.set_candle_outline(Some(CandleOutline { color, width_px: 1.0 }));
+    let (instances, verts, uniforms) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(outline_vertices(&verts).len(), instances.len() * 24);
+    assert_eq!(uniforms.outline_color, color);
+
+    renderer.set_candle_outline(None);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(outline_vertices(&verts).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn outline_thickness_scales_with_candle_width() {
+    // A thick border on a narrow candle is capped to a quarter of its width
+    let verts = CandleGeometry::create_candle_outline(0.0, 0.02, 0.5, -0.5, 0.1);
+    let left_strip_right =
+        verts[12..18].iter().map(|v| v.position_x).fold(f32::NEG_INFINITY, f32::max);
+    assert!((left_strip_right - (-0.01 + 0.005)).abs() < 1e-6);
+    assert!(verts.iter().all(|v| v.position_x >= -0.01 - 1e-6 && v.position_x <= 0.01 + 1e-6));
+}
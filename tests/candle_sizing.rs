@@ -0,0 +1,108 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    CandleSizing, MAX_USER_ELEMENT_WIDTH, MAX_USER_SPACING_RATIO, MIN_USER_ELEMENT_WIDTH,
+    candle_x_position, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn sample_chart(count: u64) -> Chart {
+    let mut chart = Chart::new("sizing".to_string(), ChartType::Candlestick, 100);
+    let candles = (0..count)
+        .map(|i| {
+            let base = 100.0 + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 2.0),
+                    Price::from(base - 2.0),
+                    Price::from(base + 1.0),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn extreme_values_are_clamped() {
+    let huge = CandleSizing::new(10.0, 10.0, 5.0);
+    assert_eq!(huge.max_width, MAX_USER_ELEMENT_WIDTH);
+    assert_eq!(huge.min_width, MAX_USER_ELEMENT_WIDTH);
+    assert_eq!(huge.spacing_ratio, MAX_USER_SPACING_RATIO);
+
+    let tiny = CandleSizing::new(-1.0, 0.0, -0.5);
+    assert_eq!(tiny.min_width, MIN_USER_ELEMENT_WIDTH);
+    assert_eq!(tiny.max_width, MIN_USER_ELEMENT_WIDTH);
+    assert_eq!(tiny.spacing_ratio, 0.0);
+
+    // Min never exceeds max
+    let inverted = CandleSizing::new(0.2, 0.1, 0.2);
+    assert_eq!((inverted.min_width, inverted.max_width), (0.1, 0.1));
+}
+
+#[wasm_bindgen_test]
+fn non_finite_values_fall_back_to_defaults() {
+    let sizing = CandleSizing::new(f32::NAN, f32::INFINITY, f32::NAN);
+    assert_eq!(sizing, CandleSizing::default());
+}
+
+#[wasm_bindgen_test]
+fn width_never_exceeds_the_step() {
+    let wide = CandleSizing::new(MAX_USER_ELEMENT_WIDTH, MAX_USER_ELEMENT_WIDTH, 0.0);
+    for len in [1usize, 3, 10, 100, 1_000] {
+        let step = 2.0 / len as f32;
+        let width = wide.element_width(len);
+        assert!(width / 2.0 <= step / 2.0 + f32::EPSILON, "len {len}: {width} > {step}");
+        if len > 1 {
+            let gap = wide.x_position(1, len) - wide.x_position(0, len);
+            assert!(gap >= width - f32::EPSILON, "len {len}: neighbors overlap");
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn wide_minimum_keeps_the_gap_between_bodies() {
+    let chart = sample_chart(150);
+    let mut renderer = dummy_renderer();
+    // The minimum width alone would be wider than each candle's step
+    let sizing = CandleSizing::new(MAX_USER_ELEMENT_WIDTH, MAX_USER_ELEMENT_WIDTH, 0.3);
+    renderer.set_candle_sizing(sizing);
+    let (instances, _, _) = renderer.create_geometry_for_test(&chart);
+    assert!(instances.len() > 1);
+
+    let step = instances[1].x - instances[0].x;
+    let spacing = sizing.spacing_ratio_for(instances.len());
+    assert!(spacing > 0.0);
+    for pair in instances.windows(2) {
+        let (left, right) = (&pair[0], &pair[1]);
+        let gap = (right.x - right.width / 2.0) - (left.x + left.width / 2.0);
+        assert!(gap >= step * spacing - 1e-5, "bodies at {} and {} overlap", left.x, right.x);
+    }
+}
+
+#[wasm_bindgen_test]
+fn default_sizing_matches_free_functions() {
+    let sizing = CandleSizing::default();
+    for len in [1usize, 7, 120] {
+        assert_eq!(sizing.x_position(len - 1, len), candle_x_position(len - 1, len));
+    }
+}
+
+#[wasm_bindgen_test]
+fn geometry_uses_renderer_sizing() {
+    let chart = sample_chart(20);
+    let mut renderer = dummy_renderer();
+    let (instances, _, _) = renderer.create_geometry_for_test(&chart);
+    let default_width = instances[0].width;
+
+    renderer.set_candle_sizing(CandleSizing::new(0.001, 0.004, 0.0));
+    assert_eq!(renderer.candle_sizing().max_width, 0.004);
+    let (instances, _, _) = renderer.create_geometry_for_test(&chart);
+    assert!(instances[0].width < default_width);
+    assert!(instances.iter().all(|i| i.width <= 0.004 + f32::EPSILON));
+}
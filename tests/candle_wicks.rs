@@ -0,0 +1,65 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleGeometry;
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn chart() -> Chart {
+    let candles = (0..20)
+        .map(|i| {
+            let price = 100.0 + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i as u64 * 60_000),
+                OHLCV::new(
+                    Price::from(price),
+                    Price::from(price + 5.0),
+                    Price::from(price - 5.0),
+                    Price::from(price + 0.5),
+                    Volume::from(10.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("wicks".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn hidden_wicks_draw_only_body_vertices() {
+    let template = CandleGeometry::candle_template();
+    let range = CandleGeometry::template_draw_range(false);
+    let drawn = &template[range.start as usize..range.end as usize];
+
+    assert_eq!(drawn.len(), 6);
+    assert!(drawn.iter().all(|v| v.element_type == 0.0));
+}
+
+#[wasm_bindgen_test]
+fn shown_wicks_draw_the_whole_template() {
+    let template = CandleGeometry::candle_template();
+    let range = CandleGeometry::template_draw_range(true);
+    let drawn = &template[range.start as usize..range.end as usize];
+
+    assert_eq!(drawn.len(), template.len());
+    assert!(drawn.iter().any(|v| v.element_type == 1.0));
+    assert!(drawn.iter().any(|v| v.element_type == 2.0));
+    assert_eq!(drawn.iter().filter(|v| v.element_type == 0.0).count(), 6);
+}
+
+#[wasm_bindgen_test]
+fn hiding_wicks_keeps_the_price_range() {
+    let chart = chart();
+    let mut renderer = dummy_renderer();
+    assert!(renderer.show_wicks());
+    let (_, _, with_wicks) = renderer.create_geometry_for_test(&chart);
+
+    renderer.set_show_wicks(false);
+    assert!(!renderer.show_wicks());
+    let (instances, _, without_wicks) = renderer.create_geometry_for_test(&chart);
+
+    assert_eq!(instances.len(), 20);
+    assert_eq!(with_wicks.viewport[2], without_wicks.viewport[2]);
+    assert_eq!(with_wicks.viewport[3], without_wicks.viewport[3]);
+}
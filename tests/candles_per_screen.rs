@@ -0,0 +1,18 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{visible_range, zoom_for_visible_count};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn count_to_zoom_round_trips() {
+    for count in 1..=300 {
+        let zoom = zoom_for_visible_count(count);
+        assert_eq!(visible_range(1000, zoom, 0.0).1, count, "count {count} -> zoom {zoom}");
+    }
+}
+
+#[wasm_bindgen_test]
+fn count_to_zoom_respects_limits() {
+    assert_eq!(visible_range(1000, zoom_for_visible_count(0), 0.0).1, 1);
+    assert_eq!(visible_range(5000, zoom_for_visible_count(5000), 0.0).1, 300);
+    assert!(zoom_for_visible_count(1) <= 32.0);
+}
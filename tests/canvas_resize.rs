@@ -0,0 +1,41 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{DEFAULT_CANVAS_HEIGHT, DEFAULT_CANVAS_WIDTH, fit_canvas_size};
+use price_chart_wasm::event_utils::Debouncer;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn debouncer_keeps_only_the_last_value_of_a_burst() {
+    let mut debouncer = Debouncer::new();
+    let first = debouncer.schedule((800, 500));
+    let second = debouncer.schedule((900, 560));
+    let last = debouncer.schedule((1000, 625));
+
+    // Timers of superseded events fire without effect
+    assert_eq!(debouncer.take(first), None);
+    assert_eq!(debouncer.take(second), None);
+    assert_eq!(debouncer.take(last), Some((1000, 625)));
+    // The value is applied once
+    assert_eq!(debouncer.take(last), None);
+}
+
+#[wasm_bindgen_test]
+fn debouncer_applies_separate_events() {
+    let mut debouncer = Debouncer::new();
+    let token = debouncer.schedule(1);
+    assert_eq!(debouncer.take(token), Some(1));
+    let token = debouncer.schedule(2);
+    assert_eq!(debouncer.take(token), Some(2));
+}
+
+#[wasm_bindgen_test]
+fn canvas_follows_container_width_with_default_aspect() {
+    let (width, height) = fit_canvas_size(1340.0, 2000.0);
+    assert_eq!(width, 1200);
+    assert_eq!(height, 1200 * DEFAULT_CANVAS_HEIGHT / DEFAULT_CANVAS_WIDTH);
+
+    // Short windows cap the height, tiny containers keep a minimum size
+    let (_, short) = fit_canvas_size(1340.0, 700.0);
+    assert_eq!(short, 380);
+    let (narrow, low) = fit_canvas_size(100.0, 100.0);
+    assert_eq!((narrow, low), (320, 200));
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{chart_state_json, current_symbol};
+use price_chart_wasm::domain::chart::{
+    Chart,
+    value_objects::{ChartType, FibRetracement},
+};
+use price_chart_wasm::domain::market_data::{
+    Candle, MaKind, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::global_state::ensure_chart;
+use wasm_bindgen_test::*;
+
+fn candles() -> Vec<Candle> {
+    (0..40)
+        .map(|i| {
+            let close = 100.0 + (i as f64 * 0.7).sin() * 5.0;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(close - 1.0),
+                    Price::from(close + 2.0),
+                    Price::from(close - 2.0),
+                    Price::from(close),
+                    Volume::from(10.0 + i as f64),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn series_candles(chart: &Chart, interval: TimeInterval) -> Vec<Candle> {
+    chart.get_series(interval).unwrap().get_candles().iter().cloned().collect()
+}
+
+#[wasm_bindgen_test]
+fn chart_round_trips_through_json() {
+    let mut chart = Chart::new("state".to_string(), ChartType::HeikinAshi, 100);
+    chart.set_historical_data(candles());
+    chart.update_viewport_for_data();
+    chart.add_price_alert(101.5);
+    chart.fib_retracement = Some(FibRetracement::new(
+        (Timestamp::from_millis(0), 95.0),
+        (Timestamp::from_millis(600_000), 105.0),
+    ));
+
+    let json = serde_json::to_string(&chart).unwrap();
+    let restored: Chart = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.id, chart.id);
+    assert_eq!(restored.chart_type, ChartType::HeikinAshi);
+    for interval in [TimeInterval::TwoSeconds, TimeInterval::OneMinute, TimeInterval::FiveMinutes] {
+        assert_eq!(series_candles(&restored, interval), series_candles(&chart, interval));
+    }
+    assert_eq!(restored.viewport, chart.viewport);
+    assert_eq!(restored.price_alerts, chart.price_alerts);
+    assert_eq!(restored.fib_retracement, chart.fib_retracement);
+    assert_eq!(restored.ma_configs(), chart.ma_configs());
+
+    // Moving averages are rebuilt from the restored candles
+    let engine = |c: &Chart| c.ma_engines[&TimeInterval::OneMinute].clone();
+    assert_eq!(
+        engine(&restored).values(MaKind::Sma, 20).map(<[Price]>::to_vec),
+        engine(&chart).values(MaKind::Sma, 20).map(<[Price]>::to_vec)
+    );
+}
+
+#[wasm_bindgen_test]
+fn debug_export_describes_the_current_chart() {
+    let symbol = Symbol::from("STATEX");
+    ensure_chart(&symbol).update(|c| c.set_historical_data(candles()));
+    current_symbol().set(symbol);
+
+    let json: serde_json::Value = serde_json::from_str(&chart_state_json().unwrap()).unwrap();
+    assert_eq!(json["symbol"], "STATEX");
+    assert!(json["zoom"].is_number());
+    assert!(json["line_visibility"]["lines"].is_array());
+    let base = json["chart"]["series"]["2s"]["candles"].as_array().unwrap();
+    assert_eq!(base.len(), 40);
+}
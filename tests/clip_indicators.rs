@@ -0,0 +1,53 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+/// 30 minute candles: first 20 around 100, last 10 around 200
+fn jump_chart() -> Chart {
+    let candles: Vec<Candle> = (0..30)
+        .map(|i| {
+            let base = if i < 20 { 100.0 } else { 200.0 };
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 1.0),
+                    Price::from(base - 1.0),
+                    Price::from(base),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("clip".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn clipped_indicators_do_not_stretch_price_range() {
+    let mut chart = jump_chart();
+    chart.viewport.start_time = (20 * 60_000) as f64;
+    let mut renderer = dummy_renderer();
+    // Show only the last 10 candles around 200 while SMA20 still lags near 150
+    renderer.set_zoom_params(3.2, 0.0);
+
+    let (_, _, fitted) = renderer.create_geometry_for_test(&chart);
+    renderer.set_clip_indicators(true);
+    let (_, verts, clipped) = renderer.create_geometry_for_test(&chart);
+
+    // Candle-only range 199..201 plus 5% padding
+    assert!((clipped.viewport[2] - 198.9).abs() < 1e-3);
+    assert!((clipped.viewport[3] - 201.1).abs() < 1e-3);
+    assert!(fitted.viewport[2] < clipped.viewport[2] - 10.0);
+
+    // The SMA20 line is still emitted and leaves the pane below -1
+    let sma20_min_y = verts
+        .iter()
+        .filter(|v| (v.element_type - 2.0).abs() < f32::EPSILON && v.color_type == 2.0)
+        .map(|v| v.position_y)
+        .fold(f32::INFINITY, f32::min);
+    assert!(sma20_min_y < -1.0);
+}
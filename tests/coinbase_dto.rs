@@ -0,0 +1,174 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::{Symbol, TimeInterval};
+use price_chart_wasm::infrastructure::websocket::{
+    CoinbaseClient, CoinbaseMessage, CoinbaseTicker, Exchange,
+};
+use wasm_bindgen_test::*;
+
+fn ticker(price: &str, size: &str, time: &str) -> CoinbaseTicker {
+    CoinbaseTicker {
+        product_id: "BTC-USD".to_string(),
+        price: price.to_string(),
+        time: time.to_string(),
+        last_size: size.to_string(),
+    }
+}
+
+#[wasm_bindgen_test]
+fn rest_candles_map_to_domain_candles_oldest_first() {
+    // Coinbase rows are [time, low, high, open, close, volume], newest first
+    let json = "[[1700000060, 99.0, 103.0, 100.5, 102.0, 3.5], \
+                 [1700000000, 98.0, 101.0, 100.0, 100.5, 1.25]]";
+    let candles = CoinbaseClient::parse_candles(json).unwrap();
+
+    assert_eq!(candles.len(), 2);
+    assert_eq!(candles[0].timestamp.value(), 1_700_000_000_000);
+    assert_eq!(candles[1].timestamp.value(), 1_700_000_060_000);
+    let ohlcv = &candles[1].ohlcv;
+    assert_eq!(ohlcv.open.value(), 100.5);
+    assert_eq!(ohlcv.high.value(), 103.0);
+    assert_eq!(ohlcv.low.value(), 99.0);
+    assert_eq!(ohlcv.close.value(), 102.0);
+    assert_eq!(ohlcv.volume.value(), 3.5);
+}
+
+#[wasm_bindgen_test]
+fn malformed_rest_response_is_an_error() {
+    assert!(CoinbaseClient::parse_candles(r#"{"message":"NotFound"}"#).is_err());
+}
+
+#[wasm_bindgen_test]
+fn feed_messages_parse_by_type() {
+    let json = r#"{"type":"ticker","sequence":1,"product_id":"BTC-USD","price":"43000.12",
+        "side":"buy","time":"2024-01-01T00:00:30.500000Z","trade_id":7,"last_size":"0.015"}"#;
+    match serde_json::from_str::<CoinbaseMessage>(json).unwrap() {
+        CoinbaseMessage::Ticker(t) => {
+            assert_eq!(t.product_id, "BTC-USD");
+            assert_eq!(t.price().unwrap(), 43000.12);
+            assert_eq!(t.size(), 0.015);
+            assert_eq!(t.timestamp_ms().unwrap(), 1_704_067_230_500);
+        }
+        other => panic!("unexpected message: {other:?}"),
+    }
+
+    let json = r#"{"type":"subscriptions","channels":[]}"#;
+    assert!(matches!(serde_json::from_str(json).unwrap(), CoinbaseMessage::Other));
+
+    let json = r#"{"type":"error","message":"Failed to subscribe"}"#;
+    assert!(matches!(
+        serde_json::from_str(json).unwrap(),
+        CoinbaseMessage::Error { message } if message == "Failed to subscribe"
+    ));
+}
+
+#[wasm_bindgen_test]
+fn trades_build_interval_candles() {
+    let mut client = CoinbaseClient::new(Symbol::from("BTC-USD"), TimeInterval::OneMinute);
+    let first = client.apply_ticker(&ticker("100", "1", "2024-01-01T00:00:05Z")).unwrap();
+    assert_eq!(first.timestamp.value(), 1_704_067_200_000);
+
+    client.apply_ticker(&ticker("104", "0.5", "2024-01-01T00:00:20Z")).unwrap();
+    let candle = client.apply_ticker(&ticker("98", "2", "2024-01-01T00:00:59Z")).unwrap();
+    assert_eq!(candle.timestamp.value(), 1_704_067_200_000);
+    assert_eq!(candle.ohlcv.open.value(), 100.0);
+    assert_eq!(candle.ohlcv.high.value(), 104.0);
+    assert_eq!(candle.ohlcv.low.value(), 98.0);
+    assert_eq!(candle.ohlcv.close.value(), 98.0);
+    assert_eq!(candle.ohlcv.volume.value(), 3.5);
+
+    // The next minute opens a new candle, late trades of the old one are skipped
+    let next = client.apply_ticker(&ticker("99", "1", "2024-01-01T00:01:00Z")).unwrap();
+    assert_eq!(next.timestamp.value(), 1_704_067_260_000);
+    assert_eq!(next.ohlcv.open.value(), 99.0);
+    assert!(client.apply_ticker(&ticker("97", "1", "2024-01-01T00:00:58Z")).is_err());
+}
+
+#[wasm_bindgen_test]
+fn trades_continue_the_open_rest_candle() {
+    let json = "[[1704067200, 90.0, 110.0, 100.0, 105.0, 5.0], \
+                 [1704067140, 95.0, 101.0, 96.0, 100.0, 2.0]]";
+    let candles = CoinbaseClient::parse_candles(json).unwrap();
+    let mut client = CoinbaseClient::new(Symbol::from("BTC-USD"), TimeInterval::OneMinute);
+    client.seed_current(&candles, 1_704_067_230_000);
+
+    let candle = client.apply_ticker(&ticker("103", "1", "2024-01-01T00:00:31Z")).unwrap();
+    assert_eq!(candle.timestamp.value(), 1_704_067_200_000);
+    assert_eq!(candle.ohlcv.open.value(), 100.0);
+    assert_eq!(candle.ohlcv.high.value(), 110.0);
+    assert_eq!(candle.ohlcv.low.value(), 90.0);
+    assert_eq!(candle.ohlcv.close.value(), 103.0);
+    assert_eq!(candle.ohlcv.volume.value(), 6.0);
+
+    // A REST candle of a closed bucket is not continued
+    client.seed_current(&candles, 1_704_067_260_000);
+    let next = client.apply_ticker(&ticker("99", "1", "2024-01-01T00:01:02Z")).unwrap();
+    assert_eq!(next.ohlcv.open.value(), 99.0);
+    assert_eq!(next.ohlcv.volume.value(), 1.0);
+}
+
+#[wasm_bindgen_test]
+fn symbols_follow_each_exchange_format() {
+    assert_eq!(Exchange::Coinbase.format_symbol(&Symbol::from("BTCUSDT")), "BTC-USDT");
+    assert_eq!(Exchange::Coinbase.format_symbol(&Symbol::from("eth-usd")), "ETH-USD");
+    assert_eq!(Exchange::Binance.format_symbol(&Symbol::from("BTC-USDT")), "BTCUSDT");
+    assert_eq!(Exchange::Binance.format_symbol(&Symbol::from("ETHBTC")), "ETHBTC");
+
+    assert_eq!(Exchange::for_symbol(&Symbol::from("BTC-USD")), Exchange::Coinbase);
+    assert_eq!(Exchange::for_symbol(&Symbol::from("BTCUSDT")), Exchange::Binance);
+    assert_eq!(CoinbaseClient::granularity(TimeInterval::FiveMinutes), Some(300));
+    assert_eq!(CoinbaseClient::granularity(TimeInterval::TwoSeconds), None);
+}
+
+#[wasm_bindgen_test]
+fn intervals_without_coinbase_candles_roll_up_finer_ones() {
+    use TimeInterval::*;
+    let source = CoinbaseClient::source_interval;
+    for interval in [OneMinute, FiveMinutes, FifteenMinutes, OneHour, OneDay] {
+        assert_eq!(source(interval), Some(interval));
+    }
+    assert_eq!(source(FourHours), Some(OneHour));
+    assert_eq!(source(OneWeek), Some(OneDay));
+    assert_eq!(source(OneMonth), Some(OneDay));
+    assert_eq!(source(TwoSeconds), None);
+
+    assert!(!Exchange::Coinbase.supports_interval(TwoSeconds));
+    assert!(Exchange::Coinbase.supports_interval(FourHours));
+    assert!(Exchange::Binance.supports_interval(TwoSeconds));
+}
+
+#[wasm_bindgen_test]
+fn hourly_candles_aggregate_into_four_hour_candles() {
+    // 03:00 to 08:00 on 2024-01-01: the 00:00 bucket misses its first three hours
+    let json = "[[1704096000, 6.0, 8.0, 7.0, 7.5, 1.0], \
+                 [1704092400, 5.0, 9.0, 6.0, 7.0, 1.0], \
+                 [1704088800, 4.0, 6.0, 5.0, 6.0, 1.0], \
+                 [1704085200, 3.0, 5.0, 4.0, 5.0, 1.0], \
+                 [1704081600, 1.0, 4.0, 3.0, 4.0, 1.0], \
+                 [1704078000, 0.5, 3.0, 2.0, 3.0, 1.0]]";
+    let hourly = CoinbaseClient::parse_candles(json).unwrap();
+    let candles = CoinbaseClient::aggregate_candles(&hourly, TimeInterval::FourHours);
+
+    assert_eq!(candles.len(), 2);
+    let full = &candles[0];
+    assert_eq!(full.timestamp.value(), 1_704_081_600_000);
+    assert_eq!(full.ohlcv.open.value(), 3.0);
+    assert_eq!(full.ohlcv.high.value(), 9.0);
+    assert_eq!(full.ohlcv.low.value(), 1.0);
+    assert_eq!(full.ohlcv.close.value(), 7.0);
+    assert_eq!(full.ohlcv.volume.value(), 4.0);
+    // The open bucket keeps its candles so far
+    assert_eq!(candles[1].timestamp.value(), 1_704_096_000_000);
+    assert_eq!(candles[1].ohlcv.close.value(), 7.5);
+}
+
+#[wasm_bindgen_test]
+fn each_exchange_offers_its_own_products() {
+    let coinbase = Exchange::Coinbase.symbols();
+    assert_eq!(coinbase[0], Symbol::from("BTC-USDT"));
+    // Every offered product is routed back to the exchange that listed it
+    for exchange in Exchange::ALL {
+        let symbols = exchange.symbols();
+        assert_eq!(symbols.len(), Exchange::Binance.symbols().len());
+        assert!(symbols.iter().all(|s| Exchange::for_symbol(s) == exchange));
+    }
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{
+    apply_overlay_candle, current_symbol, merge_overlay_page, overlay_candles, overlay_covers,
+    overlay_missing_page, overlay_symbol, route_stream_candle,
+};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume, rebase_on_timestamps,
+};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+
+fn candle(minute: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(minute * MINUTE),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn overlay_vertices(verts: &[CandleVertex]) -> usize {
+    verts.iter().filter(|v| v.element_type == 2.0 && v.color_type == 34.0).count()
+}
+
+#[wasm_bindgen_test]
+fn series_align_on_timestamps_and_rebase_to_percent() {
+    // The secondary series starts a minute later and misses minute 3
+    let primary: Vec<Candle> = [100.0, 110.0, 120.0, 90.0, 150.0]
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| candle(i as u64, c))
+        .collect();
+    let secondary = vec![candle(1, 50.0), candle(2, 55.0), candle(4, 40.0), candle(5, 60.0)];
+
+    let points = rebase_on_timestamps(&primary, &secondary);
+    let indices: Vec<usize> = points.iter().map(|p| p.index).collect();
+    assert_eq!(indices, vec![1, 2, 4]);
+    assert_eq!(points[0].timestamp, MINUTE);
+    // Both series start at zero from the first shared candle
+    assert_eq!((points[0].primary, points[0].secondary), (0.0, 0.0));
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(points[1].primary, 120.0 / 110.0 * 100.0 - 100.0));
+    assert!(close(points[1].secondary, 10.0));
+    assert!(close(points[2].primary, 150.0 / 110.0 * 100.0 - 100.0));
+    assert!(close(points[2].secondary, -20.0));
+}
+
+#[wasm_bindgen_test]
+fn disjoint_series_have_no_points() {
+    let primary = vec![candle(0, 100.0), candle(1, 101.0)];
+    let secondary = vec![candle(5, 50.0), candle(6, 51.0)];
+    assert!(rebase_on_timestamps(&primary, &secondary).is_empty());
+    assert!(rebase_on_timestamps(&primary, &[]).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn overlay_coverage_needs_both_ends() {
+    let candles = vec![candle(2, 1.0), candle(3, 1.0), candle(4, 1.0)];
+    assert!(overlay_covers(&candles, 2 * MINUTE, 4 * MINUTE));
+    assert!(overlay_covers(&candles, 3 * MINUTE, 3 * MINUTE));
+    assert!(!overlay_covers(&candles, MINUTE, 4 * MINUTE));
+    assert!(!overlay_covers(&candles, 2 * MINUTE, 5 * MINUTE));
+    assert!(!overlay_covers(&[], 0, 0));
+}
+
+fn minutes(candles: &[Candle]) -> Vec<u64> {
+    candles.iter().map(|c| c.timestamp.value() / MINUTE).collect()
+}
+
+#[wasm_bindgen_test]
+fn overlay_fetches_only_the_missing_side() {
+    let candles = vec![candle(10, 1.0), candle(11, 1.0), candle(12, 1.0)];
+    // Newer candles first, then the page just before the held ones
+    assert_eq!(overlay_missing_page(&candles, 5 * MINUTE, 14 * MINUTE), Some(14 * MINUTE));
+    assert_eq!(overlay_missing_page(&candles, 5 * MINUTE, 12 * MINUTE), Some(10 * MINUTE - 1));
+    assert_eq!(overlay_missing_page(&candles, 10 * MINUTE, 12 * MINUTE), None);
+    assert_eq!(overlay_missing_page(&[], 5 * MINUTE, 12 * MINUTE), Some(12 * MINUTE));
+}
+
+#[wasm_bindgen_test]
+fn overlay_pages_extend_the_held_candles_without_gaps() {
+    let interval = TimeInterval::OneMinute;
+    let mut candles = vec![candle(10, 1.0), candle(11, 1.0)];
+
+    // An older page bordering the held candles is prepended; held copies win
+    let page = vec![candle(8, 2.0), candle(9, 2.0), candle(10, 2.0)];
+    assert!(merge_overlay_page(&mut candles, page, interval));
+    assert_eq!(minutes(&candles), vec![8, 9, 10, 11]);
+    assert_eq!(candles[2].ohlcv.close.value(), 1.0);
+
+    // A page inside the held range adds nothing, so the fetch run stops
+    assert!(!merge_overlay_page(&mut candles, vec![candle(9, 3.0)], interval));
+    assert!(!merge_overlay_page(&mut candles, Vec::new(), interval));
+
+    // A page far from the held candles replaces them instead of leaving a hole
+    assert!(merge_overlay_page(&mut candles, vec![candle(20, 4.0), candle(21, 4.0)], interval));
+    assert_eq!(minutes(&candles), vec![20, 21]);
+}
+
+#[wasm_bindgen_test]
+fn streamed_candles_update_the_overlay_tail() {
+    let interval = TimeInterval::OneMinute;
+    let mut candles = vec![candle(1, 1.0), candle(2, 1.0)];
+
+    apply_overlay_candle(&mut candles, candle(2, 5.0), interval);
+    apply_overlay_candle(&mut candles, candle(3, 6.0), interval);
+    // Older candles and ones past a gap are dropped
+    apply_overlay_candle(&mut candles, candle(1, 7.0), interval);
+    apply_overlay_candle(&mut candles, candle(5, 8.0), interval);
+
+    assert_eq!(minutes(&candles), vec![1, 2, 3]);
+    let closes: Vec<f64> = candles.iter().map(|c| c.ohlcv.close.value()).collect();
+    assert_eq!(closes, vec![1.0, 5.0, 6.0]);
+
+    // Nothing is streamed into an overlay that has no history yet
+    let mut empty = Vec::new();
+    apply_overlay_candle(&mut empty, candle(3, 6.0), interval);
+    assert!(empty.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn overlaid_symbol_stream_reaches_the_overlay() {
+    let main = Symbol::from("OVMAIN");
+    let overlaid = Symbol::from("OVSIDE");
+    current_symbol().set(main);
+    overlay_symbol().set(Some(overlaid.clone()));
+    overlay_candles().set(vec![candle(1, 1.0), candle(2, 1.0)]);
+
+    route_stream_candle(&overlaid, candle(2, 9.0));
+    route_stream_candle(&overlaid, candle(3, 10.0));
+    route_stream_candle(&Symbol::from("OVOTHER"), candle(4, 11.0));
+
+    let closes: Vec<f64> =
+        overlay_candles().with_untracked(|c| c.iter().map(|c| c.ohlcv.close.value()).collect());
+    assert_eq!(closes, vec![1.0, 9.0, 10.0]);
+    overlay_symbol().set(None);
+}
+
+#[wasm_bindgen_test]
+fn overlay_renders_one_segment_per_shared_pair() {
+    let mut chart = Chart::new("overlay".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data((0..10).map(|i| candle(i, 100.0 + i as f64)).collect());
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(overlay_vertices(&verts), 0);
+
+    // Six shared timestamps draw five segments
+    renderer.set_compare_overlay((4..12).map(|i| candle(i, 50.0 - i as f64)).collect());
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(overlay_vertices(&verts), 5 * 6);
+
+    renderer.set_compare_overlay(Vec::new());
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(overlay_vertices(&verts), 0);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::cursor_price;
+use price_chart_wasm::infrastructure::rendering::renderer::{PriceAxisRange, PriceScaleMode};
+use wasm_bindgen_test::*;
+
+fn range(scale: PriceScaleMode, main_bottom: f32) -> PriceAxisRange {
+    PriceAxisRange { min_price: 100.0, max_price: 200.0, scale, main_bottom, inverted: false }
+}
+
+fn close(a: Option<f64>, b: f64) -> bool {
+    a.is_some_and(|a| (a - b).abs() < 1e-3)
+}
+
+#[wasm_bindgen_test]
+fn cursor_maps_to_price_on_a_linear_axis() {
+    let r = range(PriceScaleMode::Linear, -1.0);
+    assert!(close(cursor_price(&r, 0.0, 600.0), 200.0));
+    assert!(close(cursor_price(&r, 300.0, 600.0), 150.0));
+    assert!(close(cursor_price(&r, 600.0, 600.0), 100.0));
+    assert!(close(cursor_price(&r, 150.0, 600.0), 175.0));
+}
+
+#[wasm_bindgen_test]
+fn cursor_below_the_candle_area_has_no_price() {
+    // Candles use the upper half when sub panels are shown
+    let r = range(PriceScaleMode::Linear, 0.0);
+    assert!(close(cursor_price(&r, 150.0, 600.0), 150.0));
+    assert!(close(cursor_price(&r, 300.0, 600.0), 100.0));
+    assert_eq!(cursor_price(&r, 450.0, 600.0), None);
+    assert_eq!(cursor_price(&r, 10.0, 0.0), None);
+}
+
+#[wasm_bindgen_test]
+fn cursor_price_round_trips_with_the_price_tag() {
+    let r = PriceAxisRange {
+        min_price: 10.0,
+        max_price: 1000.0,
+        scale: PriceScaleMode::Logarithmic,
+        main_bottom: -1.0,
+        inverted: false,
+    };
+    assert!(close(cursor_price(&r, 300.0, 600.0), 100.0));
+    for price in [12.5, 100.0, 640.0] {
+        let y = r.percent_from_top(price).unwrap() / 100.0 * 600.0;
+        assert!(close(cursor_price(&r, y, 600.0), price), "{price}");
+    }
+}
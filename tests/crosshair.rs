@@ -0,0 +1,68 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn make_chart() -> Chart {
+    let candles: Vec<Candle> = (0..20)
+        .map(|i| {
+            let base = 100.0 + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 1.0),
+                    Price::from(base - 1.0),
+                    Price::from(base + 0.5),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("crosshair".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+fn crosshair_vertices(verts: &[CandleVertex]) -> Vec<&CandleVertex> {
+    verts.iter().filter(|v| (v.element_type - 9.0).abs() < f32::EPSILON).collect()
+}
+
+#[wasm_bindgen_test]
+fn crosshair_only_when_position_set() {
+    let chart = make_chart();
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(crosshair_vertices(&verts).is_empty());
+
+    renderer.set_crosshair(Some((0.25, -0.5)));
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    let cross = crosshair_vertices(&verts);
+    assert_eq!(cross.len(), 12);
+    // Horizontal strip spans the width at the cursor Y, vertical strip spans the height
+    assert!(cross[..6].iter().all(|v| (v.position_y + 0.5).abs() < 0.01));
+    assert!(cross[6..].iter().all(|v| (v.position_x - 0.25).abs() < 0.01));
+
+    renderer.set_crosshair(None);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(crosshair_vertices(&verts).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn price_readout_matches_rendered_range() {
+    let chart = make_chart();
+    let mut renderer = dummy_renderer();
+    renderer.cache_geometry_for_test(&chart);
+    let (_, _, uniforms) = renderer.create_geometry_for_test(&chart);
+    let [_, _, min, max] = uniforms.viewport;
+
+    let top = renderer.price_at_ndc_y(1.0).unwrap();
+    let bottom = renderer.price_at_ndc_y(-1.0).unwrap();
+    let middle = renderer.price_at_ndc_y(0.0).unwrap();
+    assert!((top - max as f64).abs() < 1e-3);
+    assert!((bottom - min as f64).abs() < 1e-3);
+    assert!((middle - (min as f64 + max as f64) / 2.0).abs() < 1e-3);
+}
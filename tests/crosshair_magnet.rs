@@ -0,0 +1,75 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{MAGNET_THRESHOLD_PX, snap_to_ohlc};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{PriceAxisRange, PriceScaleMode};
+use wasm_bindgen_test::*;
+
+/// 100..200 over a 400 px tall canvas: four pixels per price unit
+const HEIGHT: f64 = 400.0;
+
+fn range() -> PriceAxisRange {
+    PriceAxisRange {
+        min_price: 100.0,
+        max_price: 200.0,
+        scale: PriceScaleMode::Linear,
+        main_bottom: -1.0,
+        inverted: false,
+    }
+}
+
+fn candle() -> Candle {
+    Candle::new(
+        Timestamp::from_millis(0),
+        OHLCV::new(
+            Price::from(140.0),
+            Price::from(180.0),
+            Price::from(120.0),
+            Price::from(160.0),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+/// Pixel row of `price` from the top of the canvas
+fn y(price: f64) -> f64 {
+    (200.0 - price) * 4.0
+}
+
+#[wasm_bindgen_test]
+fn cursor_near_each_level_snaps_to_it() {
+    for price in [140.0, 180.0, 120.0, 160.0] {
+        for offset in [-3.0, 0.0, 5.0] {
+            let snapped = snap_to_ohlc(&candle(), &range(), y(price) + offset, HEIGHT);
+            assert_eq!(snapped.map(|(p, _)| p), Some(price), "{price} {offset}");
+            assert!((snapped.unwrap().1 - y(price)).abs() < 1e-3);
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn cursor_between_levels_picks_the_closer_one() {
+    // 0.4 px per unit puts the close at 336 px and the open at 344 px, 8 px apart, so
+    // both are inside the snap window of a cursor between them
+    let range = PriceAxisRange { min_price: 0.0, max_price: 1000.0, ..range() };
+    let y = |price: f64| (1000.0 - price) * 0.4;
+    for (cursor, expected) in [(y(160.0) + 2.0, 160.0), (y(140.0) - 2.0, 140.0)] {
+        assert!((cursor - y(160.0)).abs() <= MAGNET_THRESHOLD_PX);
+        assert!((cursor - y(140.0)).abs() <= MAGNET_THRESHOLD_PX);
+        let snapped = snap_to_ohlc(&candle(), &range, cursor, HEIGHT);
+        assert_eq!(snapped.map(|(p, _)| p), Some(expected));
+    }
+}
+
+#[wasm_bindgen_test]
+fn cursor_far_from_every_level_does_not_snap() {
+    let between = (y(140.0) + y(160.0)) / 2.0;
+    assert!(y(140.0) - between > MAGNET_THRESHOLD_PX);
+    assert_eq!(snap_to_ohlc(&candle(), &range(), between, HEIGHT), None);
+}
+
+#[wasm_bindgen_test]
+fn inverted_axis_snaps_at_the_mirrored_rows() {
+    let range = PriceAxisRange { inverted: true, ..range() };
+    let snapped = snap_to_ohlc(&candle(), &range, HEIGHT - y(180.0), HEIGHT);
+    assert_eq!(snapped.map(|(p, _)| p), Some(180.0));
+}
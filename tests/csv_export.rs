@@ -0,0 +1,54 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::{
+    CSV_HEADER, Candle, CandleSeries, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+    csv_file_name, export_csv,
+};
+use wasm_bindgen_test::*;
+
+// 2024-01-15 13:45:00 UTC
+const TS: u64 = 1_705_326_300_000;
+
+fn series(count: u64) -> CandleSeries {
+    let mut series = CandleSeries::new(100);
+    for i in 0..count {
+        let base = 100.0 + i as f64;
+        series.add_candle(Candle::new(
+            Timestamp::from_millis(TS + i * 60_000),
+            OHLCV::new(
+                Price::from(base),
+                Price::from(base + 2.5),
+                Price::from(base - 1.0),
+                Price::from(base + 0.5),
+                Volume::from(12.25),
+            ),
+        ));
+    }
+    series
+}
+
+#[wasm_bindgen_test]
+fn csv_has_header_and_iso_rows() {
+    let csv = export_csv(&series(3), 0, 3);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "timestamp,open,high,low,close,volume");
+    assert_eq!(lines[0], CSV_HEADER);
+    assert_eq!(lines[1], "2024-01-15T13:45:00.000Z,100,102.5,99,100.5,12.25");
+    assert_eq!(lines.len(), 4);
+}
+
+#[wasm_bindgen_test]
+fn only_the_visible_window_is_exported() {
+    let csv = export_csv(&series(10), 4, 3);
+    let rows: Vec<&str> = csv.lines().skip(1).collect();
+    assert_eq!(rows.len(), 3);
+    assert!(rows[0].starts_with("2024-01-15T13:49:00.000Z,104,"));
+    assert!(rows[2].starts_with("2024-01-15T13:51:00.000Z,106,"));
+    // A window past the end is cut to the data
+    assert_eq!(export_csv(&series(10), 8, 5).lines().count(), 3);
+}
+
+#[wasm_bindgen_test]
+fn file_name_includes_symbol_and_interval() {
+    let name = csv_file_name(&Symbol::from("BTCUSDT"), TimeInterval::FiveMinutes, TS);
+    assert_eq!(name, format!("BTCUSDT-5m-{TS}.csv"));
+}
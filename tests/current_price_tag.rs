@@ -0,0 +1,101 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::axis_label_positions;
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    PriceAxisRange, PriceScaleMode, RSI_NAME, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn range(scale: PriceScaleMode, main_bottom: f32) -> PriceAxisRange {
+    PriceAxisRange { min_price: 100.0, max_price: 200.0, scale, main_bottom, inverted: false }
+}
+
+fn close(a: Option<f64>, b: f64) -> bool {
+    a.is_some_and(|a| (a - b).abs() < 1e-4)
+}
+
+#[wasm_bindgen_test]
+fn linear_prices_map_to_percent_from_top() {
+    let r = range(PriceScaleMode::Linear, -1.0);
+    assert!(close(r.percent_from_top(200.0), 0.0));
+    assert!(close(r.percent_from_top(150.0), 50.0));
+    assert!(close(r.percent_from_top(100.0), 100.0));
+    assert_eq!(r.ndc_y(150.0), Some(0.0));
+}
+
+#[wasm_bindgen_test]
+fn tag_stays_inside_the_candle_area() {
+    // Candle area ends at NDC 0.0 when sub panels are shown
+    let r = range(PriceScaleMode::Linear, 0.0);
+    assert!(close(r.percent_from_top(150.0), 25.0));
+    assert!(close(r.percent_from_top(50.0), 50.0));
+    assert!(close(r.percent_from_top(500.0), 0.0));
+    assert_eq!(r.ndc_y(500.0), None);
+}
+
+#[wasm_bindgen_test]
+fn logarithmic_scale_uses_log_distances() {
+    let r = PriceAxisRange {
+        min_price: 10.0,
+        max_price: 1000.0,
+        scale: PriceScaleMode::Logarithmic,
+        main_bottom: -1.0,
+        inverted: false,
+    };
+    assert!(close(r.percent_from_top(100.0), 50.0));
+    let empty = PriceAxisRange { max_price: 10.0, ..r };
+    assert_eq!(empty.percent_from_top(10.0), None);
+}
+
+#[wasm_bindgen_test]
+fn axis_labels_follow_the_candle_area() {
+    let labels = axis_label_positions(&range(PriceScaleMode::Linear, 0.0), 4);
+    let prices: Vec<f64> = labels.iter().map(|l| l.0).collect();
+    assert_eq!(prices, vec![200.0, 175.0, 150.0, 125.0, 100.0]);
+    // The lowest label sits at the bottom of the candle area, not of the canvas
+    for (i, (_, percent)) in labels.iter().enumerate() {
+        assert!((percent - i as f64 * 12.5).abs() < 1e-4);
+    }
+}
+
+#[wasm_bindgen_test]
+fn axis_labels_line_up_with_grid_rows_under_sub_panels() {
+    let mut chart = Chart::new("labels".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(
+        (0..40)
+            .map(|i| {
+                let close = 100.0 + (i % 7) as f64 * 3.0;
+                Candle::new(
+                    Timestamp::from_millis(i * 60_000),
+                    OHLCV::new(
+                        Price::from(close),
+                        Price::from(close + 2.0),
+                        Price::from(close - 2.0),
+                        Price::from(close),
+                        Volume::from(1.0),
+                    ),
+                )
+            })
+            .collect(),
+    );
+    let mut renderer = dummy_renderer();
+    renderer.toggle_line_visibility(RSI_NAME);
+    renderer.cache_geometry_for_test(&chart);
+    let range = renderer.price_axis_range().unwrap();
+    assert!(range.main_bottom > -1.0);
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    // Horizontal grid rows span the full width; their center is the row position
+    let rows: Vec<f32> = verts
+        .chunks(6)
+        .filter(|q| q[0].element_type == 3.0 && q[0].position_x == -1.0 && q[1].position_x == 1.0)
+        .map(|q| (q[0].position_y + q[2].position_y) / 2.0)
+        .collect();
+    let labels = axis_label_positions(&range, renderer.grid_divisions().1);
+    assert_eq!(labels.len(), renderer.grid_divisions().1 + 1);
+    for (_, percent) in labels {
+        let y = 1.0 - percent as f32 / 50.0;
+        assert!(rows.iter().any(|row| (row - y).abs() < 1e-4), "no grid row at {y}");
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn make_chart() -> Chart {
+    let candles: Vec<Candle> = (0..20)
+        .map(|i| {
+            let base = 100.0 + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 1.0),
+                    Price::from(base - 1.0),
+                    Price::from(base + 0.5),
+                    Volume::from(10.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("debug".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+fn outline_count(verts: &[CandleVertex], region: f32) -> usize {
+    verts
+        .iter()
+        .filter(|v| {
+            (v.element_type - 7.0).abs() < f32::EPSILON
+                && (v.color_type - region).abs() < f32::EPSILON
+        })
+        .count()
+}
+
+#[wasm_bindgen_test]
+fn debug_outlines_only_when_enabled() {
+    let chart = make_chart();
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(verts.iter().all(|v| (v.element_type - 7.0).abs() > f32::EPSILON));
+
+    renderer.set_debug_mode(true);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    for region in [0.0, 1.0, 2.0] {
+        assert_eq!(outline_count(&verts, region), 24);
+    }
+
+    assert!(!renderer.toggle_debug_mode());
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(verts.iter().all(|v| (v.element_type - 7.0).abs() > f32::EPSILON));
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::Aggregator;
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::http::binance_rest_client::parse_klines;
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{DELTA_VOLUME_NAME, dummy_renderer};
+use price_chart_wasm::infrastructure::websocket::binance_client::BinanceWebSocketClient;
+use wasm_bindgen_test::*;
+
+fn candle(ts: u64, volume: f64, taker_buy: Option<f64>) -> Candle {
+    let ohlcv = OHLCV::new(
+        Price::from(100.0),
+        Price::from(101.0),
+        Price::from(99.0),
+        Price::from(100.5),
+        Volume::from(volume),
+    );
+    let ohlcv = match taker_buy {
+        Some(buy) => ohlcv.with_taker_buy_volume(Volume::from(buy)),
+        None => ohlcv,
+    };
+    Candle::new(Timestamp::from_millis(ts), ohlcv)
+}
+
+fn delta_vertices(verts: &[CandleVertex]) -> Vec<&CandleVertex> {
+    verts.iter().filter(|v| v.element_type == 19.0).collect()
+}
+
+#[wasm_bindgen_test]
+fn rest_klines_keep_the_taker_buy_volume() {
+    let json =
+        r#"[[60000,"100.0","101.0","99.0","100.5","10.0",119999,"1000.0",42,"7.5","750.0","0"]]"#;
+    let candles = parse_klines(json).unwrap();
+    assert_eq!(candles.len(), 1);
+    assert_eq!(candles[0].ohlcv.taker_buy_volume, Some(Volume::from(7.5)));
+    assert_eq!(candles[0].ohlcv.delta_volume(), Some(5.0));
+}
+
+#[wasm_bindgen_test]
+fn stream_klines_keep_the_taker_buy_volume() {
+    let client = BinanceWebSocketClient::new(Symbol::from("BTCUSDT"), TimeInterval::OneMinute);
+    let with_taker =
+        r#"{"k":{"t":60000,"o":"100.0","h":"101.0","l":"99.0","c":"100.5","v":"10.0","V":"2.0"}}"#;
+    let candle = client.parse_message(with_taker).unwrap();
+    assert_eq!(candle.ohlcv.delta_volume(), Some(-6.0));
+
+    let without = r#"{"k":{"t":60000,"o":"100.0","h":"101.0","l":"99.0","c":"100.5","v":"10.0"}}"#;
+    let candle = client.parse_message(without).unwrap();
+    assert_eq!(candle.ohlcv.taker_buy_volume, None);
+    assert_eq!(candle.ohlcv.delta_volume(), None);
+}
+
+#[wasm_bindgen_test]
+fn merged_candles_sum_taker_buys() {
+    let merged = Aggregator::aggregate(
+        &[candle(0, 10.0, Some(6.0)), candle(60_000, 5.0, Some(1.0))],
+        TimeInterval::FiveMinutes,
+    )
+    .unwrap();
+    assert_eq!(merged.ohlcv.taker_buy_volume, Some(Volume::from(7.0)));
+    assert_eq!(merged.ohlcv.delta_volume(), Some(-1.0));
+
+    let unknown = Aggregator::aggregate(&[candle(0, 10.0, None)], TimeInterval::FiveMinutes);
+    assert_eq!(unknown.unwrap().ohlcv.taker_buy_volume, None);
+}
+
+#[wasm_bindgen_test]
+fn delta_bars_are_drawn_for_candles_with_taker_data() {
+    let mut chart = Chart::new("delta".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(vec![
+        candle(0, 10.0, Some(8.0)),
+        candle(60_000, 10.0, Some(2.0)),
+        candle(120_000, 10.0, None),
+    ]);
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(delta_vertices(&verts).is_empty());
+
+    renderer.toggle_line_visibility(DELTA_VOLUME_NAME);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    let bars = delta_vertices(&verts);
+    assert_eq!(bars.len(), 12);
+    assert!(bars[..6].iter().all(|v| v.color_type == 1.0));
+    assert!(bars[6..].iter().all(|v| v.color_type == 0.0));
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    DONCHIAN_NAME, DONCHIAN_PERIOD, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+/// Candles whose highs and lows swing independently of their closes
+fn swinging_candles(count: u64) -> Vec<Candle> {
+    (0..count)
+        .map(|i| {
+            let base = 100.0 + (i % 7) as f64 * 2.0;
+            let high = base + 1.0 + (i % 3) as f64;
+            let low = base - 1.0 - (i % 5) as f64;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(high),
+                    Price::from(low),
+                    Price::from(base),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn line_vertices(verts: &[CandleVertex], color_type: f32) -> usize {
+    verts
+        .iter()
+        .filter(|v| {
+            (v.element_type - 2.0).abs() < f32::EPSILON
+                && (v.color_type - color_type).abs() < f32::EPSILON
+        })
+        .count()
+}
+
+#[wasm_bindgen_test]
+fn channels_follow_window_high_and_low() {
+    let candles = swinging_candles(40);
+    let period = 10;
+    let channels = MarketAnalysisService::new().calculate_donchian(&candles, period);
+
+    assert_eq!(channels.upper.len(), 40 - period + 1);
+    assert_eq!(channels.lower.len(), channels.upper.len());
+    assert_eq!(channels.middle.len(), channels.upper.len());
+    for (i, window) in candles.windows(period).enumerate() {
+        let max_high = window.iter().map(|c| c.ohlcv.high.value()).fold(f64::MIN, f64::max);
+        let min_low = window.iter().map(|c| c.ohlcv.low.value()).fold(f64::MAX, f64::min);
+        assert_eq!(channels.upper[i], max_high);
+        assert_eq!(channels.lower[i], min_low);
+        assert_eq!(channels.middle[i], (max_high + min_low) / 2.0);
+    }
+}
+
+#[wasm_bindgen_test]
+fn short_history_has_no_channel() {
+    let service = MarketAnalysisService::new();
+    assert!(service.calculate_donchian(&swinging_candles(5), 10).upper.is_empty());
+    assert!(service.calculate_donchian(&swinging_candles(5), 0).upper.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn channels_render_only_when_enabled() {
+    let mut chart = Chart::new("donchian".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(swinging_candles(30));
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(line_vertices(&verts, 28.0), 0);
+
+    renderer.toggle_line_visibility(DONCHIAN_NAME);
+    assert!(renderer.line_visibility().is_visible(DONCHIAN_NAME));
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    for color_type in [28.0, 29.0, 30.0] {
+        assert_eq!(line_vertices(&verts, color_type), (30 - DONCHIAN_PERIOD) * 6);
+    }
+}
@@ -17,11 +17,11 @@ fn websocket_to_webgpu_pipeline() {
     let client = BinanceWebSocketClient::new(symbol.clone(), TimeInterval::OneMinute);
     let msg = r#"{"k":{"t":0,"o":"1.0","h":"1.1","l":"0.9","c":"1.05","v":"1.0"}}"#;
     let candle = client.parse_message(msg).unwrap();
-    push_realtime_candle(candle);
+    push_realtime_candle(&symbol, candle);
 
     let msg2 = r#"{"k":{"t":60000,"o":"1.05","h":"1.1","l":"1.0","c":"1.08","v":"1.5"}}"#;
     let candle2 = client.parse_message(msg2).unwrap();
-    push_realtime_candle(candle2);
+    push_realtime_candle(&symbol, candle2);
 
     assert_eq!(chart.with_untracked(|c| c.get_candle_count()), 2);
 
@@ -43,12 +43,12 @@ fn websocket_multiple_symbols() {
     let client_a = BinanceWebSocketClient::new(sym_a.clone(), TimeInterval::OneMinute);
     let msg_a = r#"{"k":{"t":0,"o":"1.0","h":"1.1","l":"0.9","c":"1.05","v":"1.0"}}"#;
     let candle_a = client_a.parse_message(msg_a).unwrap();
-    push_realtime_candle(candle_a);
+    push_realtime_candle(&sym_a, candle_a);
 
     let client_b = BinanceWebSocketClient::new(sym_b.clone(), TimeInterval::OneMinute);
     let msg_b = r#"{"k":{"t":0,"o":"2.0","h":"2.1","l":"1.9","c":"2.05","v":"2.0"}}"#;
     let candle_b = client_b.parse_message(msg_b).unwrap();
-    push_realtime_candle(candle_b);
+    push_realtime_candle(&sym_b, candle_b);
 
     assert_eq!(chart_a.with_untracked(|c| c.get_candle_count()), 1);
     assert_eq!(chart_b.with_untracked(|c| c.get_candle_count()), 1);
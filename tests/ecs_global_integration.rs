@@ -31,7 +31,7 @@ fn push_candle_updates_world() {
             Volume::from(1.0),
         ),
     );
-    push_realtime_candle(candle);
+    push_realtime_candle(&symbol, candle);
     let world_ref = ecs_world().lock().unwrap();
     let mut query = world_ref.world.query::<&ChartComponent>();
     let chart_comp = query.iter().next().expect("chart component").1;
@@ -63,13 +63,13 @@ fn push_candle_without_charts() {
             Volume::from(1.0),
         ),
     );
-    push_realtime_candle(candle);
+    push_realtime_candle(&Symbol::from("NONE"), candle);
     let world_ref = ecs_world().lock().unwrap();
     assert_eq!(world_ref.world.len(), 0);
 }
 
 #[test]
-fn push_candle_updates_multiple_charts() {
+fn push_candle_updates_only_its_symbol() {
     ecs_world().lock().unwrap().world = hecs::World::new();
     let sym_a = Symbol::from("AAA");
     let sym_b = Symbol::from("BBB");
@@ -85,9 +85,9 @@ fn push_candle_updates_multiple_charts() {
             Volume::from(1.0),
         ),
     );
-    push_realtime_candle(candle);
+    push_realtime_candle(&sym_a, candle);
     assert_eq!(chart_a.with_untracked(|c| c.get_candle_count()), 1);
-    assert_eq!(chart_b.with_untracked(|c| c.get_candle_count()), 1);
+    assert_eq!(chart_b.with_untracked(|c| c.get_candle_count()), 0);
 }
 
 #[test]
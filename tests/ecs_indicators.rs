@@ -0,0 +1,107 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, MaConfig, MaKind, OHLCV, Price, TimeInterval, Timestamp, Volume, moving_average,
+};
+use price_chart_wasm::ecs::EcsWorld;
+use price_chart_wasm::ecs::components::{CandleComponent, ChartComponent};
+use wasm_bindgen_test::*;
+
+const CONFIGS: [MaConfig; 3] =
+    [MaConfig::new(MaKind::Sma, 3), MaConfig::new(MaKind::Sma, 5), MaConfig::new(MaKind::Ema, 4)];
+
+fn candle(minute: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(minute * 60_000),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn assert_matches_full_recompute(world: &EcsWorld, chart: RwSignal<Chart>) {
+    let closes: Vec<f64> = chart.with_untracked(|c| {
+        let series = c.get_series(TimeInterval::TwoSeconds).unwrap();
+        series.get_candles().iter().map(|c| c.ohlcv.close.value()).collect()
+    });
+    let cache = world.indicators("ind").expect("indicator component attached");
+    for config in CONFIGS {
+        let cached = cache.values(config.kind, config.period).unwrap();
+        let full = moving_average(config.kind, config.period, &closes);
+        assert_eq!(cached.len(), full.len(), "{}", config.name());
+        for (a, b) in cached.iter().zip(&full) {
+            assert!((a.value() - b.value()).abs() < 1e-9, "{}", config.name());
+        }
+    }
+}
+
+/// Values of a full series match the averages over every streamed close, minus
+/// the values of the candles evicted from the front
+fn assert_matches_rolling_stream(world: &EcsWorld, streamed: &[f64], evicted: usize) {
+    let cache = world.indicators("ind").expect("indicator component attached");
+    for config in CONFIGS {
+        let cached = cache.values(config.kind, config.period).unwrap();
+        let full = moving_average(config.kind, config.period, streamed);
+        let expected = &full[evicted.min(full.len())..];
+        assert_eq!(cached.len(), expected.len(), "{}", config.name());
+        for (a, b) in cached.iter().zip(expected) {
+            assert!((a.value() - b.value()).abs() < 1e-9, "{}", config.name());
+        }
+    }
+}
+
+fn stream(capacity: usize) -> (EcsWorld, RwSignal<Chart>) {
+    let mut world = EcsWorld::new();
+    let entity = world.spawn_chart(Chart::new("ind".into(), ChartType::Candlestick, capacity));
+    let chart = world.world.get::<&ChartComponent>(entity).unwrap().0;
+    (world, chart)
+}
+
+#[wasm_bindgen_test]
+fn cached_values_match_full_recomputation() {
+    let (mut world, chart) = stream(100);
+    for minute in 0..12u64 {
+        // Each candle gets a few in-progress updates before the next one opens
+        for tick in 0..3 {
+            let close = 100.0 + (minute * 7 % 5) as f64 + tick as f64 * 0.25;
+            world.world.spawn((CandleComponent(candle(minute, close)),));
+            world.run_candle_system();
+            world.run_indicator_system(TimeInterval::TwoSeconds, &CONFIGS);
+            assert_matches_full_recompute(&world, chart);
+        }
+    }
+    let cache = world.indicators("ind").unwrap();
+    assert_eq!(cache.values(MaKind::Sma, 3).unwrap().len(), 10);
+    assert!(cache.values(MaKind::Sma, 7).is_none());
+}
+
+#[wasm_bindgen_test]
+fn cache_rebuilds_when_history_changes() {
+    let (mut world, chart) = stream(5);
+    let mut streamed = Vec::new();
+    for minute in 0..9u64 {
+        let close = 50.0 + (minute * 3 % 7) as f64;
+        streamed.push(close);
+        world.world.spawn((CandleComponent(candle(minute, close)),));
+        world.run_candle_system();
+        world.run_indicator_system(TimeInterval::TwoSeconds, &CONFIGS);
+        // Old candles drop out once the series is full; the rolling windows and the
+        // EMA keep running instead of restarting from the remaining candles
+        assert_matches_rolling_stream(&world, &streamed, streamed.len().saturating_sub(5));
+    }
+
+    chart.update(|c| c.set_historical_data((0..4).map(|m| candle(m, 10.0 * m as f64)).collect()));
+    world.run_indicator_system(TimeInterval::TwoSeconds, &CONFIGS);
+    assert_matches_full_recompute(&world, chart);
+    chart.with_untracked(|c| {
+        let series = c.get_series(TimeInterval::TwoSeconds).unwrap();
+        let cache = world.indicators("ind").unwrap();
+        assert!(cache.is_current("ind", TimeInterval::TwoSeconds, series));
+        assert!(!cache.is_current("other", TimeInterval::TwoSeconds, series));
+    });
+}
@@ -22,7 +22,7 @@ fn push_candle_syncs_signal() {
             Volume::from(1.0),
         ),
     );
-    push_realtime_candle(candle);
+    push_realtime_candle(&symbol, candle);
     assert_eq!(chart.with_untracked(|c| c.get_candle_count()), 1);
 }
 
@@ -0,0 +1,88 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{
+    Chart,
+    value_objects::{ChartType, FIB_RATIOS, FibRetracement},
+};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{WebGpuRenderer, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn chart() -> Chart {
+    let mut chart = Chart::new("fib".to_string(), ChartType::Candlestick, 10);
+    chart.set_historical_data(
+        (0..5)
+            .map(|i| {
+                let base = 100.0 + i as f64 * 10.0;
+                Candle::new(
+                    Timestamp::from_millis(i * 60_000),
+                    OHLCV::new(
+                        Price::from(base),
+                        Price::from(base + 5.0),
+                        Price::from(base - 5.0),
+                        Price::from(base + 2.0),
+                        Volume::from(1.0),
+                    ),
+                )
+            })
+            .collect(),
+    );
+    chart
+}
+
+#[wasm_bindgen_test]
+fn level_prices_between_endpoints() {
+    let fib = FibRetracement::new(
+        (Timestamp::from_millis(0), 100.0),
+        (Timestamp::from_millis(60_000), 200.0),
+    );
+    let levels = fib.levels();
+    assert_eq!(levels.len(), FIB_RATIOS.len());
+
+    let expected = [200.0, 176.4, 161.8, 150.0, 138.2, 100.0];
+    for ((ratio, price), (want_ratio, want_price)) in
+        levels.iter().zip(FIB_RATIOS.iter().zip(expected))
+    {
+        assert_eq!(ratio, want_ratio);
+        assert!((price - want_price).abs() < 1e-9, "{ratio}: {price} != {want_price}");
+    }
+}
+
+#[wasm_bindgen_test]
+fn downward_retracement_levels() {
+    let fib = FibRetracement::new(
+        (Timestamp::from_millis(0), 200.0),
+        (Timestamp::from_millis(60_000), 100.0),
+    );
+    let prices: Vec<f64> = fib.levels().into_iter().map(|(_, p)| p).collect();
+    assert!((prices[0] - 100.0).abs() < 1e-9);
+    assert!((prices[1] - 123.6).abs() < 1e-9);
+    assert!((prices[5] - 200.0).abs() < 1e-9);
+}
+
+#[wasm_bindgen_test]
+fn levels_render_as_lines_with_labels() {
+    let mut chart = chart();
+    let renderer_without = dummy_renderer();
+    let (_, before, _) = renderer_without.create_geometry_for_test(&chart);
+    assert!(before.iter().all(|v| v.element_type != 11.0));
+    let hash_before = WebGpuRenderer::data_hash(&chart, 1.0);
+
+    chart.fib_retracement = Some(FibRetracement::new(
+        (Timestamp::from_millis(60_000), 110.0),
+        (Timestamp::from_millis(180_000), 130.0),
+    ));
+    assert_ne!(WebGpuRenderer::data_hash(&chart, 1.0), hash_before);
+
+    let mut renderer = dummy_renderer();
+    let (_, vertices, _) = renderer.create_geometry_for_test(&chart);
+    let fib_vertices = vertices.iter().filter(|v| v.element_type == 11.0).count();
+    assert_eq!(fib_vertices, FIB_RATIOS.len() * 6);
+
+    renderer.cache_geometry_for_test(&chart);
+    let labels = renderer.fib_labels(&chart);
+    assert_eq!(labels.len(), FIB_RATIOS.len());
+    // 0% at the end price sits above 100% at the start price
+    assert!(labels[0].y > labels[5].y);
+    assert!((labels[3].price - 120.0).abs() < 1e-9);
+    assert!((labels[3].y - (labels[0].y + labels[5].y) / 2.0).abs() < 1e-4);
+}
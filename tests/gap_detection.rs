@@ -0,0 +1,63 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, CandleSeries, GAP_THRESHOLD, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+
+fn candle(ts: u64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(ts),
+        OHLCV::new(
+            Price::from(100.0),
+            Price::from(101.0),
+            Price::from(99.0),
+            Price::from(100.5),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+/// One-minute candles with the bar at minute 3 missing
+fn timestamps() -> Vec<u64> {
+    vec![0, MINUTE, 2 * MINUTE, 4 * MINUTE, 5 * MINUTE]
+}
+
+#[wasm_bindgen_test]
+fn missing_bar_is_detected_at_its_index() {
+    let mut series = CandleSeries::new(10);
+    for ts in timestamps() {
+        series.add_candle(candle(ts));
+    }
+    let gaps = series.detect_gaps(TimeInterval::OneMinute, GAP_THRESHOLD);
+    assert_eq!(gaps, vec![2..3]);
+}
+
+#[wasm_bindgen_test]
+fn threshold_controls_gap_size() {
+    let mut series = CandleSeries::new(10);
+    for ts in timestamps() {
+        series.add_candle(candle(ts));
+    }
+    // A two-minute step is within 2.5 intervals
+    assert!(series.detect_gaps(TimeInterval::OneMinute, 2.5).is_empty());
+    // Regular one-minute steps never count as gaps
+    assert!(series.detect_gaps(TimeInterval::FiveMinutes, GAP_THRESHOLD).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn gap_renders_one_separator() {
+    let mut chart = Chart::new("gaps".to_string(), ChartType::Candlestick, 10);
+    chart.set_historical_data(timestamps().into_iter().map(candle).collect());
+
+    let mut renderer = dummy_renderer();
+    let (_, vertices, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(vertices.iter().filter(|v| v.element_type == 12.0).count(), 6);
+
+    renderer.set_gap_threshold(3.0);
+    let (_, vertices, _) = renderer.create_geometry_for_test(&chart);
+    assert!(vertices.iter().all(|v| v.element_type != 12.0));
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::price_levels_between;
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    GRID_DIVISIONS, PriceScaleMode, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn grid_count(vertices: &[CandleVertex]) -> usize {
+    vertices.iter().filter(|v| v.element_type == 3.0).count()
+}
+
+fn chart() -> Chart {
+    let mut chart = Chart::new("grid".to_string(), ChartType::Candlestick, 10);
+    chart.set_historical_data(
+        (0..5)
+            .map(|i| {
+                let base = 100.0 + i as f64;
+                Candle::new(
+                    Timestamp::from_millis(i * 60_000),
+                    OHLCV::new(
+                        Price::from(base),
+                        Price::from(base + 2.0),
+                        Price::from(base - 2.0),
+                        Price::from(base + 1.0),
+                        Volume::from(1.0),
+                    ),
+                )
+            })
+            .collect(),
+    );
+    chart
+}
+
+#[wasm_bindgen_test]
+fn grid_is_visible_by_default() {
+    let renderer = dummy_renderer();
+    assert!(renderer.grid_visible());
+    assert_eq!(renderer.grid_divisions(), GRID_DIVISIONS);
+}
+
+#[wasm_bindgen_test]
+fn hidden_grid_has_no_vertices() {
+    let chart = chart();
+    let mut renderer = dummy_renderer();
+    renderer.set_grid_visible(false);
+    let (_, vertices, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(grid_count(&vertices), 0);
+}
+
+#[wasm_bindgen_test]
+fn grid_count_follows_divisions() {
+    let chart = chart();
+    let mut renderer = dummy_renderer();
+    renderer.set_grid_divisions((4, 3));
+    let (_, vertices, _) = renderer.create_geometry_for_test(&chart);
+    // (columns + 1) vertical and (rows + 1) horizontal lines of 6 vertices each
+    assert_eq!(grid_count(&vertices), ((4 + 1) + (3 + 1)) * 6);
+    // Grid vertices lead the list so they are drawn behind the candles
+    assert!(vertices.iter().take(grid_count(&vertices)).all(|v| v.element_type == 3.0));
+}
+
+#[wasm_bindgen_test]
+fn price_levels_match_divisions() {
+    let levels = price_levels_between(100.0, 200.0, PriceScaleMode::Linear, 4);
+    assert_eq!(levels, vec![200.0, 175.0, 150.0, 125.0, 100.0]);
+}
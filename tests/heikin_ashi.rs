@@ -0,0 +1,73 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, o: f64, h: f64, l: f64, c: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(o),
+            Price::from(h),
+            Price::from(l),
+            Price::from(c),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn ohlc(c: &Candle) -> [f64; 4] {
+    [c.ohlcv.open.value(), c.ohlcv.high.value(), c.ohlcv.low.value(), c.ohlcv.close.value()]
+}
+
+#[wasm_bindgen_test]
+fn heikin_ashi_known_sequence() {
+    let candles = vec![
+        candle(0, 10.0, 12.0, 9.0, 11.0),
+        candle(1, 11.0, 13.0, 10.0, 12.0),
+        candle(2, 12.0, 12.5, 8.0, 9.0),
+    ];
+    let ha = MarketAnalysisService::new().calculate_heikin_ashi(&candles);
+
+    assert_eq!(ha.len(), 3);
+    // First bar falls back to the raw OHLC
+    assert_eq!(ohlc(&ha[0]), [10.0, 12.0, 9.0, 11.0]);
+    // open = (10 + 11) / 2, close = (11 + 13 + 10 + 12) / 4
+    assert_eq!(ohlc(&ha[1]), [10.5, 13.0, 10.0, 11.5]);
+    // open = (10.5 + 11.5) / 2, close = (12 + 12.5 + 8 + 9) / 4
+    assert_eq!(ohlc(&ha[2]), [11.0, 12.5, 8.0, 10.375]);
+    assert_eq!(ha[2].timestamp, candles[2].timestamp);
+}
+
+#[wasm_bindgen_test]
+fn heikin_ashi_mode_keeps_series_and_indicators() {
+    let candles: Vec<Candle> = (0..30)
+        .map(|i| {
+            let base = 100.0 + (i as f64 * 0.5).sin() * 4.0;
+            candle(i, base, base + 2.0, base - 2.0, base + 1.0)
+        })
+        .collect();
+    let mut chart = Chart::new("ha".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles.clone());
+
+    let renderer = dummy_renderer();
+    let (plain_instances, plain_verts, _) = renderer.create_geometry_for_test(&chart);
+    chart.chart_type = ChartType::HeikinAshi;
+    let (ha_instances, ha_verts, _) = renderer.create_geometry_for_test(&chart);
+
+    assert_eq!(plain_instances.len(), ha_instances.len());
+    assert_ne!(
+        plain_instances.iter().map(|i| i.body_top).collect::<Vec<_>>(),
+        ha_instances.iter().map(|i| i.body_top).collect::<Vec<_>>()
+    );
+    let line_count = |verts: &[price_chart_wasm::infrastructure::rendering::CandleVertex]| {
+        verts.iter().filter(|v| (v.element_type - 2.0).abs() < f32::EPSILON).count()
+    };
+    assert_eq!(line_count(&plain_verts), line_count(&ha_verts));
+
+    let series = chart.get_series(price_chart_wasm::domain::market_data::TimeInterval::OneMinute);
+    let stored: Vec<[f64; 4]> = series.unwrap().get_candles().iter().map(ohlc).collect();
+    assert_eq!(stored, candles.iter().map(ohlc).collect::<Vec<_>>());
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::infrastructure::rendering::renderer::line_thickness_ndc;
+use wasm_bindgen_test::*;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+#[wasm_bindgen_test]
+fn logical_line_width_is_independent_of_device_pixel_ratio() {
+    // A 500 CSS px tall canvas at 1x and at 2x
+    let standard = line_thickness_ndc(2.0, 500, 1.0);
+    let retina = line_thickness_ndc(2.0, 1000, 2.0);
+    assert!(approx(standard, 2.0 / 500.0 * 2.0));
+    assert!(approx(retina, standard));
+}
+
+#[wasm_bindgen_test]
+fn line_width_snaps_to_whole_device_pixels() {
+    // 1 CSS px at 1.5x covers two device pixels instead of a blurry 1.5
+    assert!(approx(line_thickness_ndc(1.0, 750, 1.5), 2.0 / 750.0 * 2.0));
+    // Zoomed-out pages never draw lines thinner than one device pixel
+    assert!(approx(line_thickness_ndc(1.0, 250, 0.5), 1.0 / 250.0 * 2.0));
+}
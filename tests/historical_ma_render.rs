@@ -1,8 +1,9 @@
 #![cfg(feature = "render")]
+use leptos::{SignalGetUntracked, SignalSet};
 use price_chart_wasm::app::{current_interval, visible_range_by_time};
 use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
 use price_chart_wasm::domain::market_data::{
-    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume,
+    Candle, MaKind, OHLCV, Price, TimeInterval, Timestamp, Volume,
 };
 use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
@@ -83,7 +84,7 @@ fn minute_ma_tracks_closed_buckets() {
 
     let engine =
         chart.ma_engines.get(&TimeInterval::OneMinute).expect("ma engine for minute interval");
-    let ema12 = &engine.data().ema_12;
+    let ema12 = &engine.data().get(MaKind::Ema, 12).unwrap();
     assert_eq!(ema12.len(), closed_closes.len());
 
     let alpha = 2.0 / (EMA_PERIOD as f64 + 1.0);
@@ -119,7 +120,7 @@ fn minute_ma_tracks_closed_buckets() {
         .enumerate()
         .filter(|(idx, _)| {
             let candle_idx = idx + period_offset;
-            *candle_idx >= start_idx && *candle_idx < start_idx + visible_len
+            candle_idx >= start_idx && candle_idx < start_idx + visible_len
         })
         .count();
 
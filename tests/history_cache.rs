@@ -0,0 +1,125 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::missing_candle_count;
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::history_cache::HistoryCache;
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+const TTL: u64 = 10 * MINUTE;
+
+fn candles(from: u64, to: u64, close: f64) -> Vec<Candle> {
+    (from..=to)
+        .map(|i| {
+            Candle::new(
+                Timestamp::from_millis(i * MINUTE),
+                OHLCV::new(
+                    Price::from(close),
+                    Price::from(close),
+                    Price::from(close),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn bounds(cache: &HistoryCache, symbol: &Symbol) -> Vec<(u64, u64)> {
+    cache
+        .ranges(symbol, TimeInterval::OneMinute)
+        .iter()
+        .map(|r| (r.start() / MINUTE, r.end() / MINUTE))
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn overlapping_and_adjacent_ranges_merge() {
+    let symbol = Symbol::from("BTCUSDT");
+    let mut cache = HistoryCache::new(4, TTL);
+    cache.insert(&symbol, TimeInterval::OneMinute, &candles(10, 19, 1.0), 0);
+    cache.insert(&symbol, TimeInterval::OneMinute, &candles(30, 39, 1.0), 0);
+    assert_eq!(bounds(&cache, &symbol), vec![(10, 19), (30, 39)]);
+
+    // Overlaps the first range
+    cache.insert(&symbol, TimeInterval::OneMinute, &candles(15, 24, 2.0), 0);
+    assert_eq!(bounds(&cache, &symbol), vec![(10, 24), (30, 39)]);
+    // Fills the hole exactly, joining both ranges
+    cache.insert(&symbol, TimeInterval::OneMinute, &candles(25, 29, 2.0), 0);
+    assert_eq!(bounds(&cache, &symbol), vec![(10, 39)]);
+
+    let merged = &cache.ranges(&symbol, TimeInterval::OneMinute)[0];
+    assert_eq!(merged.candles.len(), 30);
+    // Newer data replaces cached candles with the same timestamp
+    assert_eq!(merged.candles[5].ohlcv.close.value(), 2.0);
+    assert_eq!(merged.candles[4].ohlcv.close.value(), 1.0);
+}
+
+#[wasm_bindgen_test]
+fn before_returns_cached_history_up_to_limit() {
+    let symbol = Symbol::from("ETHUSDT");
+    let mut cache = HistoryCache::new(4, TTL);
+    cache.insert(&symbol, TimeInterval::OneMinute, &candles(10, 99, 1.0), 0);
+
+    let older = cache.before(&symbol, TimeInterval::OneMinute, 49 * MINUTE, 20, 0).unwrap();
+    assert_eq!(older.len(), 20);
+    assert_eq!(older.first().unwrap().timestamp.value(), 30 * MINUTE);
+    assert_eq!(older.last().unwrap().timestamp.value(), 49 * MINUTE);
+
+    // Nothing cached before the first candle
+    assert!(cache.before(&symbol, TimeInterval::OneMinute, 9 * MINUTE, 20, 0).is_none());
+    // Other intervals are cached separately
+    assert!(cache.latest(&symbol, TimeInterval::FiveMinutes, 0).is_none());
+}
+
+#[wasm_bindgen_test]
+fn least_recently_used_symbol_is_evicted() {
+    let (a, b, c) = (Symbol::from("AAA"), Symbol::from("BBB"), Symbol::from("CCC"));
+    let mut cache = HistoryCache::new(2, TTL);
+    cache.insert(&a, TimeInterval::OneMinute, &candles(0, 9, 1.0), 0);
+    cache.insert(&b, TimeInterval::OneMinute, &candles(0, 9, 1.0), 0);
+    // Reading A makes B the least recently used
+    assert!(cache.latest(&a, TimeInterval::OneMinute, 0).is_some());
+    assert_eq!(cache.symbols(), vec![b.clone(), a.clone()]);
+
+    cache.insert(&c, TimeInterval::OneMinute, &candles(0, 9, 1.0), 0);
+    assert_eq!(cache.symbols(), vec![a.clone(), c.clone()]);
+    assert!(cache.latest(&b, TimeInterval::OneMinute, 0).is_none());
+    assert!(cache.latest(&a, TimeInterval::OneMinute, 0).is_some());
+}
+
+#[wasm_bindgen_test]
+fn entries_expire_after_ttl() {
+    let symbol = Symbol::from("SOLUSDT");
+    let mut cache = HistoryCache::new(4, TTL);
+    cache.insert(&symbol, TimeInterval::OneMinute, &candles(0, 9, 1.0), 0);
+    assert!(cache.latest(&symbol, TimeInterval::OneMinute, TTL - 1).is_some());
+    assert!(cache.latest(&symbol, TimeInterval::OneMinute, TTL).is_none());
+    assert!(cache.ranges(&symbol, TimeInterval::OneMinute).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn range_ending_before_the_open_candle_is_topped_up() {
+    let symbol = Symbol::from("XRPUSDT");
+    let interval = TimeInterval::OneMinute;
+    let mut cache = HistoryCache::new(4, TTL);
+    cache.insert(&symbol, interval, &candles(0, 9, 1.0), 9 * MINUTE);
+
+    // Two minutes later candles 10 and 11 are missing and candle 9 has closed
+    let now = 11 * MINUTE + 5_000;
+    assert!(cache.latest(&symbol, interval, now).is_none());
+    let end = cache.newest(&symbol, interval, now).unwrap();
+    assert_eq!(end, 9 * MINUTE);
+    assert_eq!(missing_candle_count(end, now, interval), 3);
+
+    // The fetched candles refresh the stale close and join the cached range
+    cache.insert(&symbol, interval, &candles(9, 11, 2.0), now);
+    let served = cache.latest(&symbol, interval, now).unwrap();
+    assert_eq!(served.len(), 12);
+    assert_eq!(served[9].ohlcv.close.value(), 2.0);
+    assert_eq!(bounds(&cache, &symbol), vec![(0, 11)]);
+
+    // Within the open candle nothing is missing but the candle itself
+    assert_eq!(missing_candle_count(11 * MINUTE, now, interval), 1);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{HISTORY_LOAD_ATTEMPTS, should_retry_history};
+use price_chart_wasm::domain::AppError;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn network_errors_are_retried_until_the_attempt_limit() {
+    let error = AppError::NetworkError("connection reset".to_string());
+    for attempt in 1..HISTORY_LOAD_ATTEMPTS {
+        assert!(should_retry_history(&error, attempt), "attempt {attempt}");
+    }
+    assert!(!should_retry_history(&error, HISTORY_LOAD_ATTEMPTS));
+}
+
+#[wasm_bindgen_test]
+fn parse_errors_are_not_retried() {
+    let error = AppError::ValidationError("Failed to parse JSON".to_string());
+    assert!(!should_retry_history(&error, 1));
+}
+
+#[wasm_bindgen_test]
+fn http_status_decides_the_error_variant() {
+    for status in [500, 502, 503, 408, 429] {
+        let error = AppError::from_http_status(status);
+        assert!(matches!(error, AppError::NetworkError(_)), "status {status}");
+        assert!(error.is_retryable());
+    }
+    for status in [400, 403, 404] {
+        let error = AppError::from_http_status(status);
+        assert!(matches!(error, AppError::ValidationError(_)), "status {status}");
+        assert!(!error.is_retryable());
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{HistoryWindow, MAX_HISTORY_BUFFER_SIZE, MAX_HISTORY_FETCH_LIMIT};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use wasm_bindgen_test::*;
+
+fn candles(count: u64) -> Vec<Candle> {
+    (0..count)
+        .map(|i| {
+            Candle::new(
+                Timestamp::from_millis(i * 2_000),
+                OHLCV::new(
+                    Price::from(100.0),
+                    Price::from(101.0),
+                    Price::from(99.0),
+                    Price::from(100.5),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn fetch_limit_is_clamped_to_the_exchange_max() {
+    assert_eq!(HistoryWindow::new(5000, 8000).fetch_limit, MAX_HISTORY_FETCH_LIMIT);
+    assert_eq!(HistoryWindow::new(0, 8000).fetch_limit, 1);
+    assert_eq!(HistoryWindow::new(250, 8000).fetch_limit, 250);
+}
+
+#[wasm_bindgen_test]
+fn buffer_holds_at_least_one_page() {
+    assert_eq!(HistoryWindow::new(500, 100).buffer_size, 500);
+    assert_eq!(HistoryWindow::new(500, usize::MAX).buffer_size, MAX_HISTORY_BUFFER_SIZE);
+}
+
+#[wasm_bindgen_test]
+fn half_page_follows_the_fetch_limit() {
+    let window = HistoryWindow::new(200, 1000);
+    assert_eq!(window.half_page_ms(TimeInterval::OneMinute), 100 * 60_000);
+}
+
+#[wasm_bindgen_test]
+fn chart_keeps_the_configured_buffer() {
+    let window = HistoryWindow::new(100, 150);
+    let mut chart = Chart::new("buffer".to_string(), ChartType::Candlestick, 1000);
+    chart.set_capacity(window.buffer_size);
+    chart.set_historical_data(candles(400));
+
+    let base = chart.get_series(TimeInterval::TwoSeconds).unwrap();
+    assert_eq!(base.count(), 150);
+    assert_eq!(base.capacity(), 150);
+    // The newest candles are kept
+    assert_eq!(base.latest().unwrap().timestamp.value(), 399 * 2_000);
+}
+
+#[wasm_bindgen_test]
+fn shrinking_drops_the_oldest_candles() {
+    let mut chart = Chart::new("buffer".to_string(), ChartType::Candlestick, 1000);
+    chart.set_historical_data(candles(400));
+    chart.set_capacity(100);
+    let base = chart.get_series(TimeInterval::TwoSeconds).unwrap();
+    assert_eq!(base.count(), 100);
+    assert_eq!(base.get_candles().front().unwrap().timestamp.value(), 300 * 2_000);
+}
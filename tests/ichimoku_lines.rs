@@ -0,0 +1,59 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    CHIKOU_NAME, CHIKOU_SHIFT, KIJUN_NAME, TENKAN_NAME, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64) -> Candle {
+    let close = 100.0 + (i as f64 * 0.2).sin() * 6.0;
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(close - 0.5),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn line_vertices(verts: &[CandleVertex], color_type: f32) -> usize {
+    verts.iter().filter(|v| v.element_type == 2.0 && v.color_type == color_type).count()
+}
+
+#[wasm_bindgen_test]
+fn ichimoku_lines_are_drawn_when_enabled() {
+    let mut chart = Chart::new("ichimoku".to_string(), ChartType::Candlestick, 200);
+    chart.set_historical_data((0..120).map(candle).collect());
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    for color_type in [10.0, 11.0, 14.0] {
+        assert_eq!(line_vertices(&verts, color_type), 0);
+    }
+
+    for name in [TENKAN_NAME, KIJUN_NAME, CHIKOU_NAME] {
+        renderer.toggle_line_visibility(name);
+        assert!(renderer.line_visibility().is_visible(name));
+    }
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    // Tenkan-sen, Kijun-sen and the Chikou span keep their own colors
+    for color_type in [10.0, 11.0, 14.0] {
+        assert!(line_vertices(&verts, color_type) >= 6, "color {color_type}");
+    }
+}
+
+#[wasm_bindgen_test]
+fn chikou_repeats_closes_shifted_back() {
+    let candles: Vec<Candle> = (0..40).map(candle).collect();
+    let chikou = MarketAnalysisService::new().calculate_chikou_span(&candles, CHIKOU_SHIFT);
+    assert_eq!(chikou.len(), candles.len() - CHIKOU_SHIFT);
+    // Drawn at candle 0, the first value is the close 26 candles later
+    assert_eq!(chikou[0], candles[CHIKOU_SHIFT].ohlcv.close);
+    assert_eq!(chikou.last(), candles.last().map(|c| &c.ohlcv.close));
+}
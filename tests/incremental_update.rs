@@ -0,0 +1,163 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    BOLLINGER_BANDS_NAME, CandleOutline, GeometryUpdate, WebGpuRenderer, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, close: f64, volume: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(105.0),
+            Price::from(110.0),
+            Price::from(100.0),
+            Price::from(close),
+            Volume::from(volume),
+        ),
+    )
+}
+
+fn chart() -> Chart {
+    let mut chart = Chart::new("incremental".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data((0..30).map(|i| candle(i, 105.0, 2.0)).collect());
+    chart
+}
+
+#[wasm_bindgen_test]
+fn last_candle_tick_takes_the_partial_path() {
+    let mut chart = chart();
+    let mut renderer = dummy_renderer();
+    assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Full));
+    let instances = renderer.instance_count();
+
+    // Same timestamp, new close inside the current price window
+    chart.add_candle(candle(29, 107.0, 1.5));
+    assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Partial));
+    assert_eq!(renderer.last_geometry_update(), GeometryUpdate::Partial);
+    assert_eq!(renderer.instance_count(), instances);
+
+    // The patched cache matches a from-scratch build
+    let mut fresh = dummy_renderer();
+    fresh.cache_geometry_for_test(&chart);
+    assert_eq!(renderer.cached_hash_for_test(), fresh.cached_hash_for_test());
+}
+
+#[wasm_bindgen_test]
+fn unchanged_data_skips_the_update() {
+    let chart = chart();
+    let mut renderer = dummy_renderer();
+    renderer.refresh_geometry(&chart);
+    assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Unchanged));
+}
+
+#[wasm_bindgen_test]
+fn new_candle_rebuilds_everything() {
+    let mut chart = chart();
+    let mut renderer = dummy_renderer();
+    renderer.refresh_geometry(&chart);
+
+    chart.add_candle(candle(30, 105.0, 2.0));
+    assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Full));
+}
+
+#[wasm_bindgen_test]
+fn last_candle_moving_the_price_window_rebuilds_everything() {
+    let mut chart = chart();
+    let mut renderer = dummy_renderer();
+    renderer.refresh_geometry(&chart);
+
+    chart.add_candle(Candle::new(
+        Timestamp::from_millis(29 * 60_000),
+        OHLCV::new(
+            Price::from(105.0),
+            Price::from(130.0),
+            Price::from(100.0),
+            Price::from(125.0),
+            Volume::from(2.0),
+        ),
+    ));
+    assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Full));
+}
+
+/// Ticks on the last candle that stay inside the 100..110 window of the base chart
+fn ticks() -> impl Iterator<Item = Candle> {
+    [(107.0f64, 1.5), (103.0, 2.0), (109.5, 0.5), (100.5, 1.0)].into_iter().map(
+        |(close, volume)| {
+            Candle::new(
+                Timestamp::from_millis(29 * 60_000),
+                OHLCV::new(
+                    Price::from(105.0),
+                    Price::from(close.max(105.0)),
+                    Price::from(close.min(105.0)),
+                    Price::from(close),
+                    Volume::from(volume),
+                ),
+            )
+        },
+    )
+}
+
+fn assert_matches_fresh_build(
+    renderer: &WebGpuRenderer,
+    chart: &Chart,
+    configure: fn(&mut WebGpuRenderer),
+) {
+    let mut fresh = dummy_renderer();
+    configure(&mut fresh);
+    fresh.cache_geometry_for_test(chart);
+    assert_eq!(renderer.cached_hash_for_test(), fresh.cached_hash_for_test());
+}
+
+#[wasm_bindgen_test]
+fn moving_averages_and_markers_follow_patched_ticks() {
+    let configs: [fn(&mut WebGpuRenderer); 3] = [
+        |_| {},
+        |r| r.set_candle_outline(Some(CandleOutline::default())),
+        |r| r.set_clip_indicators(true),
+    ];
+    for configure in configs {
+        let mut chart = chart();
+        let mut renderer = dummy_renderer();
+        configure(&mut renderer);
+        renderer.refresh_geometry(&chart);
+        // The default 20, 12 and 26 candle averages end on the ticking candle
+        for tick in ticks() {
+            chart.add_candle(tick);
+            assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Partial));
+            assert_matches_fresh_build(&renderer, &chart, configure);
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn charts_outside_the_fast_path_still_update_partially() {
+    let mut chart = chart();
+    let mut renderer = dummy_renderer();
+    renderer.toggle_line_visibility(BOLLINGER_BANDS_NAME);
+    renderer.refresh_geometry(&chart);
+
+    for tick in ticks() {
+        chart.add_candle(tick);
+        assert_ne!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Unchanged));
+        assert_matches_fresh_build(&renderer, &chart, |r| {
+            r.toggle_line_visibility(BOLLINGER_BANDS_NAME)
+        });
+    }
+}
+
+#[wasm_bindgen_test]
+fn full_build_after_a_patched_tick_is_uploaded() {
+    let mut chart = chart();
+    let mut renderer = dummy_renderer();
+    renderer.refresh_geometry(&chart);
+
+    chart.add_candle(ticks().next().unwrap());
+    assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Partial));
+    // The patch leaves no stale hash a rebuild could be mistaken for
+    chart.add_candle(candle(29, 105.0, 2.0));
+    renderer.set_clip_indicators(false);
+    assert_eq!(renderer.refresh_geometry(&chart), Some(GeometryUpdate::Full));
+    assert_matches_fresh_build(&renderer, &chart, |_| {});
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, MaConfig, MaKind, OHLCV, Price, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    BOLLINGER_BANDS_NAME, INDICATOR_PALETTE, LineVisibility, VOLUME_MA_NAME, VWAP_NAME,
+    color_from_hex, color_to_hex, dummy_renderer,
+};
+use price_chart_wasm::infrastructure::rendering::{DEFAULT_LINE_COLORS, FIRST_LINE_COLOR_TYPE};
+use wasm_bindgen_test::*;
+
+fn make_chart() -> Chart {
+    let candles: Vec<Candle> = (0..60)
+        .map(|i| {
+            let base = 100.0 + (i as f64 * 0.3).sin() * 5.0;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 1.0),
+                    Price::from(base - 1.0),
+                    Price::from(base + 0.5),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("colors".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn default_moving_averages_get_distinct_colors() {
+    let lines = LineVisibility::default().lines;
+    for (i, a) in lines.iter().enumerate() {
+        assert_eq!(a.color, INDICATOR_PALETTE[i]);
+        assert!(lines[i + 1..].iter().all(|b| b.color != a.color), "{} shares a color", a.name());
+    }
+}
+
+#[wasm_bindgen_test]
+fn setting_a_color_updates_its_uniform_slot() {
+    let chart = make_chart();
+    let mut renderer = dummy_renderer();
+    let red = [1.0, 0.0, 0.0, 0.9];
+
+    assert!(renderer.set_indicator_color("sma50", red));
+    let (_, _, uniforms) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(uniforms.indicator_colors[1], red);
+    assert_eq!(uniforms.indicator_colors[0], INDICATOR_PALETTE[0]);
+
+    assert!(!renderer.set_indicator_color("sma999", red));
+}
+
+#[wasm_bindgen_test]
+fn builtin_line_colors_update_their_uniform_slots() {
+    let chart = make_chart();
+    let mut renderer = dummy_renderer();
+    let red = [1.0, 0.0, 0.0, 0.9];
+    let (_, _, defaults) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(defaults.line_colors, DEFAULT_LINE_COLORS);
+
+    assert!(renderer.set_indicator_color(VWAP_NAME, red));
+    assert!(renderer.set_indicator_color(BOLLINGER_BANDS_NAME, red));
+    assert!(!renderer.set_indicator_color(VOLUME_MA_NAME, red));
+    let (_, _, uniforms) = renderer.create_geometry_for_test(&chart);
+    let slot = |color_type: usize| uniforms.line_colors[color_type - FIRST_LINE_COLOR_TYPE];
+    // VWAP, then the Bollinger upper and lower bands around an unchanged middle
+    assert_eq!(slot(22), red);
+    assert_eq!((slot(15), slot(17)), (red, red));
+    assert_eq!(slot(16), DEFAULT_LINE_COLORS[16 - FIRST_LINE_COLOR_TYPE]);
+    assert_eq!(renderer.line_visibility().color(VWAP_NAME), Some(red));
+    assert_eq!(renderer.line_visibility().color(VOLUME_MA_NAME), None);
+}
+
+#[wasm_bindgen_test]
+fn reconfiguring_lines_keeps_chosen_colors() {
+    let mut visibility = LineVisibility::default();
+    let red = [1.0, 0.0, 0.0, 0.9];
+    assert!(visibility.set_color("ema26", red));
+
+    visibility.set_configs(&[MaConfig::new(MaKind::Ema, 26), MaConfig::new(MaKind::Sma, 100)]);
+    assert_eq!(visibility.lines[0].color, red);
+    assert_eq!(visibility.lines[1].color, INDICATOR_PALETTE[1]);
+}
+
+#[wasm_bindgen_test]
+fn hex_colors_round_trip() {
+    assert_eq!(color_to_hex([1.0, 0.4, 0.0, 0.9]), "#ff6600");
+    assert_eq!(color_from_hex("#ff00ff", 0.9), Some([1.0, 0.0, 1.0, 0.9]));
+    assert_eq!(color_from_hex("#74c787", 1.0).map(color_to_hex), Some("#74c787".to_string()));
+    assert_eq!(color_from_hex("#fff", 1.0), None);
+    assert_eq!(color_from_hex("#gg0000", 1.0), None);
+}
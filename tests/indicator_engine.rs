@@ -1,7 +1,7 @@
 #![cfg(feature = "render")]
 use price_chart_wasm::domain::market_data::{
-    Candle, OHLCV, Price, Timestamp, Volume, indicator_engine::MovingAverageEngine,
-    services::MarketAnalysisService,
+    Candle, DEFAULT_MA_CONFIGS, MaKind, OHLCV, Price, Timestamp, Volume,
+    indicator_engine::MovingAverageEngine, services::MarketAnalysisService,
 };
 
 fn make_candle(ts: u64, close: f64) -> Candle {
@@ -21,10 +21,10 @@ fn make_candle(ts: u64, close: f64) -> Candle {
 fn engine_matches_service() {
     let candles: Vec<Candle> = (1..=30).map(|i| make_candle(i, i as f64)).collect();
     let svc = MarketAnalysisService::new();
-    let expected = svc.calculate_multiple_mas(&candles);
+    let expected = svc.calculate_multiple_mas(&candles, &DEFAULT_MA_CONFIGS);
     let mut eng = MovingAverageEngine::new();
     eng.compute_historical(&candles);
     let data = eng.data();
-    assert_eq!(data.sma_20, expected.sma_20);
-    assert_eq!(data.ema_12, expected.ema_12);
+    assert_eq!(data.get(MaKind::Sma, 20).unwrap(), expected.get(MaKind::Sma, 20).unwrap());
+    assert_eq!(data.get(MaKind::Ema, 12).unwrap(), expected.get(MaKind::Ema, 12).unwrap());
 }
@@ -0,0 +1,74 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::{
+    MaKind, Price, indicator_engine::MovingAverageEngine, moving_average,
+};
+use wasm_bindgen_test::*;
+
+fn assert_series_eq(actual: &[Price], expected: &[Price]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a.value() - e.value()).abs() < 1e-9, "{} != {}", a.value(), e.value());
+    }
+}
+
+#[wasm_bindgen_test]
+fn tail_updates_match_full_recompute() {
+    let mut engine = MovingAverageEngine::new();
+    let mut closes = Vec::new();
+
+    // Each candle gets several ticks: the first opens it, the rest replace its close
+    for i in 0..260 {
+        for tick in 0..3 {
+            let close = 100.0 + (i as f64 * 0.37).sin() * 10.0 + tick as f64 * 0.25;
+            if tick == 0 {
+                closes.push(close);
+            } else {
+                *closes.last_mut().unwrap() = close;
+            }
+            engine.update_tail(close, tick == 0);
+        }
+    }
+
+    for (kind, period) in [
+        (MaKind::Sma, 20),
+        (MaKind::Sma, 50),
+        (MaKind::Sma, 200),
+        (MaKind::Ema, 12),
+        (MaKind::Ema, 26),
+    ] {
+        let expected = moving_average(kind, period, &closes);
+        assert_series_eq(engine.values(kind, period).unwrap(), &expected);
+    }
+}
+
+#[wasm_bindgen_test]
+fn tail_update_on_empty_engine_appends() {
+    let mut engine = MovingAverageEngine::new();
+    engine.update_tail(42.0, false);
+    assert_eq!(engine.data().get(MaKind::Ema, 12).unwrap().len(), 1);
+    assert_eq!(engine.data().get(MaKind::Ema, 12).unwrap()[0].value(), 42.0);
+}
+
+#[wasm_bindgen_test]
+fn evicting_one_candle_per_tick_keeps_values_aligned() {
+    let mut engine = MovingAverageEngine::new();
+    let mut closes = Vec::new();
+    let window = 300;
+    // Stream through several compactions of the dropped values
+    for i in 0..2_000 {
+        let close = 100.0 + (i as f64 * 0.11).cos() * 5.0;
+        closes.push(close);
+        engine.update_tail(close, true);
+        if closes.len() > window {
+            engine.drop_oldest(1);
+        }
+        let evicted = closes.len().saturating_sub(window);
+        for (kind, period) in [(MaKind::Sma, 20), (MaKind::Ema, 12)] {
+            let full = moving_average(kind, period, &closes);
+            assert_series_eq(
+                engine.values(kind, period).unwrap(),
+                &full[evicted.min(full.len())..],
+            );
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::{CandleGeometry, CandleInstance};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn chart(count: usize) -> Chart {
+    let candles: Vec<Candle> = (0..count)
+        .map(|i| {
+            let base = 100.0 + i as f64;
+            let close = if i % 2 == 0 { base + 1.0 } else { base - 1.0 };
+            Candle::new(
+                Timestamp::from_millis(i as u64 * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 2.0),
+                    Price::from(base - 2.0),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("instanced".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn instance_count_matches_visible_candles() {
+    let chart = chart(12);
+    let mut renderer = dummy_renderer();
+    let (instances, vertices, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(instances.len(), 12);
+
+    // Bodies and wicks come from the template, not from raw triangles
+    assert!(vertices.iter().all(|v| v.element_type > 1.5));
+
+    renderer.cache_geometry_for_test(&chart);
+    assert_eq!(renderer.instance_count(), 12);
+    assert_eq!(renderer.template_vertex_count(), CandleGeometry::TEMPLATE_VERTEX_COUNT);
+}
+
+#[wasm_bindgen_test]
+fn instance_buffer_scales_with_candles() {
+    let mut renderer = dummy_renderer();
+    renderer.cache_geometry_for_test(&chart(8));
+    let small = renderer.instance_buffer_bytes();
+    renderer.cache_geometry_for_test(&chart(16));
+    let large = renderer.instance_buffer_bytes();
+
+    assert_eq!(small, 8 * std::mem::size_of::<CandleInstance>());
+    assert_eq!(large, 2 * small);
+}
+
+#[wasm_bindgen_test]
+fn template_covers_body_and_both_wicks() {
+    let template = CandleGeometry::candle_template();
+    assert_eq!(template.len(), CandleGeometry::TEMPLATE_VERTEX_COUNT as usize);
+    for part in [0.0, 1.0, 2.0] {
+        let quad: Vec<_> = template.iter().filter(|v| v.element_type == part).collect();
+        assert_eq!(quad.len(), 6);
+        assert!(quad.iter().all(|v| (0.0..=1.0).contains(&v.position_y)));
+    }
+    let body_half = template
+        .iter()
+        .filter(|v| v.element_type == 0.0)
+        .map(|v| v.position_x.abs())
+        .fold(0.0, f32::max);
+    assert!((body_half - 0.5).abs() < f32::EPSILON);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+const DAY: u64 = 24 * 60 * MINUTE;
+/// Monday 2024-01-08 00:00 UTC
+const MONDAY: u64 = 1_704_672_000_000;
+
+fn minute_candle(ts: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(ts),
+        OHLCV::new(
+            Price::from(close - 0.5),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(2.0),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn weeks_start_on_monday() {
+    let week = TimeInterval::OneWeek;
+    assert_eq!(week.bucket_start(MONDAY), MONDAY);
+    assert_eq!(week.bucket_start(MONDAY - 1), MONDAY - 7 * DAY);
+    assert_eq!(week.bucket_start(MONDAY + 6 * DAY + 1), MONDAY);
+    // Days before the first Monday of 1970 clamp to the epoch
+    assert_eq!(week.bucket_start(0), 0);
+    assert_eq!(
+        TimeInterval::FourHours.bucket_start(MONDAY + 5 * 3_600_000),
+        MONDAY + 4 * 3_600_000
+    );
+}
+
+#[wasm_bindgen_test]
+fn minute_candles_roll_up_into_daily_and_weekly_buckets() {
+    // Sunday 23:57 .. Monday 00:02, closes 1..=6
+    let candles: Vec<Candle> = (0..6)
+        .map(|i| minute_candle(MONDAY - 3 * MINUTE + i * MINUTE, 1.0 + i as f64 * 10.0))
+        .collect();
+    let mut chart = Chart::new("buckets".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+
+    for interval in [TimeInterval::OneDay, TimeInterval::OneWeek] {
+        let series = chart.get_series(interval).expect("series for interval");
+        let buckets: Vec<&Candle> = series.get_candles().iter().collect();
+        assert_eq!(buckets.len(), 2, "{interval}");
+
+        let (sunday, monday) = (buckets[0], buckets[1]);
+        assert_eq!(monday.timestamp.value(), MONDAY);
+        assert_eq!(sunday.timestamp.value(), interval.bucket_start(MONDAY - 1));
+
+        // Three minutes on each side of midnight
+        assert_eq!(sunday.ohlcv.open.value(), 0.5);
+        assert_eq!(sunday.ohlcv.close.value(), 21.0);
+        assert_eq!(sunday.ohlcv.high.value(), 22.0);
+        assert_eq!(sunday.ohlcv.volume.value(), 6.0);
+        assert_eq!(monday.ohlcv.open.value(), 30.5);
+        assert_eq!(monday.ohlcv.close.value(), 51.0);
+        assert_eq!(monday.ohlcv.low.value(), 30.0);
+        assert_eq!(monday.ohlcv.volume.value(), 6.0);
+    }
+    assert_eq!(chart.get_series(TimeInterval::FourHours).unwrap().count(), 2);
+}
@@ -44,7 +44,7 @@ async fn candle_after_interval_change() {
             Volume::from(1.0),
         ),
     );
-    push_realtime_candle(candle);
+    push_realtime_candle(&current_symbol().get_untracked(), candle);
     sleep(Duration::from_millis(10)).await;
     assert!(chart_signal.with(|c| c.get_candle_count()) > before);
 
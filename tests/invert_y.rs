@@ -0,0 +1,73 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::invert_y;
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    PriceAxisRange, PriceScaleMode, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn chart() -> Chart {
+    let mut chart = Chart::new("invert".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(
+        (0..30)
+            .map(|i| {
+                Candle::new(
+                    Timestamp::from_millis(i * 60_000),
+                    OHLCV::new(
+                        Price::from(104.0),
+                        Price::from(110.0),
+                        Price::from(100.0),
+                        Price::from(106.0),
+                        Volume::from(1.0),
+                    ),
+                )
+            })
+            .collect(),
+    );
+    chart
+}
+
+fn range(inverted: bool) -> PriceAxisRange {
+    PriceAxisRange {
+        min_price: 100.0,
+        max_price: 200.0,
+        scale: PriceScaleMode::Linear,
+        main_bottom: -1.0,
+        inverted,
+    }
+}
+
+#[wasm_bindgen_test]
+fn high_price_maps_to_the_top_unless_inverted() {
+    assert!(range(false).ndc_y(190.0).unwrap() > 0.5);
+    assert!(range(true).ndc_y(190.0).unwrap() < -0.5);
+    assert!((range(true).ndc_y(200.0).unwrap() + 1.0).abs() < 1e-6);
+}
+
+#[wasm_bindgen_test]
+fn inverted_cursor_reads_back_the_same_price() {
+    let r = range(true);
+    let y = r.ndc_y(130.0).unwrap();
+    assert!((r.price_at_ndc_y(y).unwrap() - 130.0).abs() < 1e-3);
+}
+
+#[wasm_bindgen_test]
+fn inverted_candles_keep_their_color_and_wicks() {
+    let chart = chart();
+    let renderer = dummy_renderer();
+    let (upright, _, _) = renderer.create_geometry_for_test(&chart);
+
+    let mut renderer = dummy_renderer();
+    renderer.set_invert_y(true);
+    assert!(invert_y().get_untracked());
+    let (inverted, _, _) = renderer.create_geometry_for_test(&chart);
+    renderer.set_invert_y(false);
+
+    let (a, b) = (upright.last().unwrap(), inverted.last().unwrap());
+    assert_eq!(a.bullish, b.bullish);
+    assert!(b.high >= b.body_top && b.low <= b.body_bottom);
+    // The candles sit in the middle of the window, so their bodies mirror around zero
+    assert!((a.body_top + b.body_bottom).abs() < 1e-3);
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::centered_range;
+use price_chart_wasm::domain::market_data::{
+    Candle, CandleSeries, OHLCV, Price, Timestamp, Volume,
+};
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+
+fn series(minutes: &[u64]) -> CandleSeries {
+    let mut series = CandleSeries::new(100);
+    for &m in minutes {
+        series.add_candle(Candle::new(
+            Timestamp::from_millis(m * MINUTE),
+            OHLCV::new(
+                Price::from(1.0),
+                Price::from(1.0),
+                Price::from(1.0),
+                Price::from(1.0),
+                Volume::from(1.0),
+            ),
+        ));
+    }
+    series
+}
+
+#[wasm_bindgen_test]
+fn nearest_candle_is_found_by_timestamp() {
+    // A gap between minutes 3 and 10
+    let s = series(&[0, 1, 2, 3, 10, 11]);
+    assert_eq!(s.nearest_index(2 * MINUTE), Some(2));
+    assert_eq!(s.nearest_index(2 * MINUTE + 20_000), Some(2));
+    assert_eq!(s.nearest_index(2 * MINUTE + 40_000), Some(3));
+    assert_eq!(s.nearest_index(5 * MINUTE), Some(3));
+    assert_eq!(s.nearest_index(8 * MINUTE), Some(4));
+    // Halfway between two candles picks the earlier one
+    assert_eq!(s.nearest_index(6 * MINUTE + 30_000), Some(3));
+}
+
+#[wasm_bindgen_test]
+fn targets_outside_the_data_snap_to_the_ends() {
+    let s = series(&[5, 6, 7]);
+    assert_eq!(s.nearest_index(0), Some(0));
+    assert_eq!(s.nearest_index(100 * MINUTE), Some(2));
+    assert_eq!(series(&[]).nearest_index(MINUTE), None);
+}
+
+#[wasm_bindgen_test]
+fn window_is_centered_and_kept_inside_the_data() {
+    assert_eq!(centered_range(100, 50, 20), (40, 60));
+    assert_eq!(centered_range(100, 3, 20), (0, 20));
+    assert_eq!(centered_range(100, 98, 20), (80, 100));
+    // More visible candles than data shows everything
+    assert_eq!(centered_range(10, 5, 50), (0, 10));
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    KELTNER_EMA_PERIOD, KELTNER_NAME, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+/// Flat closes at 100 whose high-low range is `range(i)`
+fn candles_with_range(count: u64, range: impl Fn(u64) -> f64) -> Vec<Candle> {
+    (0..count)
+        .map(|i| {
+            let half = range(i) / 2.0;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(100.0),
+                    Price::from(100.0 + half),
+                    Price::from(100.0 - half),
+                    Price::from(100.0),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn line_vertices(verts: &[CandleVertex], color_type: f32) -> usize {
+    verts
+        .iter()
+        .filter(|v| {
+            (v.element_type - 2.0).abs() < f32::EPSILON
+                && (v.color_type - color_type).abs() < f32::EPSILON
+        })
+        .count()
+}
+
+#[wasm_bindgen_test]
+fn bands_sit_a_multiple_of_atr_around_the_ema() {
+    let candles = candles_with_range(30, |_| 2.0);
+    let service = MarketAnalysisService::new();
+    let channels = service.calculate_keltner(&candles, 10, 5, 1.5);
+    let ema = service.calculate_ema(&candles, 10);
+    let atr = service.calculate_atr(&candles, 5);
+
+    assert_eq!(channels.middle.len(), ema.len());
+    for (i, middle) in channels.middle.iter().enumerate() {
+        assert_eq!(*middle, ema[i].value());
+        let width = 1.5 * atr[i + 10 - 1];
+        assert!((channels.upper[i] - (middle + width)).abs() < 1e-9);
+        assert!((channels.lower[i] - (middle - width)).abs() < 1e-9);
+    }
+}
+
+#[wasm_bindgen_test]
+fn band_width_tracks_atr() {
+    // Ranges widen from 1 to 5 halfway through the series
+    let candles = candles_with_range(40, |i| if i < 20 { 1.0 } else { 5.0 });
+    let service = MarketAnalysisService::new();
+    let channels = service.calculate_keltner(&candles, 10, 5, 2.0);
+    let atr = service.calculate_atr(&candles, 5);
+
+    let widths: Vec<f64> =
+        channels.upper.iter().zip(&channels.lower).map(|(upper, lower)| upper - lower).collect();
+    // Band width is always 2 * mult ATRs
+    for (i, width) in widths.iter().enumerate() {
+        assert!((width - 4.0 * atr[i + 9]).abs() < 1e-9);
+    }
+    // Calm candles keep a constant narrow band, volatile ones widen it
+    let calm = widths[20 - 10];
+    assert!((calm - 4.0).abs() < 1e-9);
+    for pair in widths[20 - 9..].windows(2) {
+        assert!(pair[1] > pair[0]);
+    }
+    assert!(*widths.last().unwrap() > calm * 4.0);
+}
+
+#[wasm_bindgen_test]
+fn invalid_periods_have_no_channel() {
+    let service = MarketAnalysisService::new();
+    let candles = candles_with_range(5, |_| 2.0);
+    assert!(service.calculate_keltner(&candles, 10, 5, 2.0).upper.is_empty());
+    assert!(service.calculate_keltner(&candles, 0, 5, 2.0).upper.is_empty());
+    assert!(service.calculate_keltner(&candles, 3, 0, 2.0).upper.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn channels_render_only_when_enabled() {
+    let mut chart = Chart::new("keltner".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles_with_range(30, |i| 1.0 + (i % 4) as f64));
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(line_vertices(&verts, 31.0), 0);
+
+    renderer.toggle_line_visibility(KELTNER_NAME);
+    assert!(renderer.line_visibility().is_visible(KELTNER_NAME));
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    for color_type in [31.0, 32.0, 33.0] {
+        assert_eq!(line_vertices(&verts, color_type), (30 - KELTNER_EMA_PERIOD) * 6);
+    }
+}
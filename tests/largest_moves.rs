@@ -0,0 +1,70 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::MoveKind;
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, CandleSeries, LargestMoves, OHLCV, Price, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn series(closes: &[f64]) -> CandleSeries {
+    let mut series = CandleSeries::new(closes.len());
+    for (i, &close) in closes.iter().enumerate() {
+        series.add_candle(candle(i as u64, close));
+    }
+    series
+}
+
+#[wasm_bindgen_test]
+fn max_move_candle_is_identified() {
+    // +1, -4, +2: the drop into index 2 is the largest move both ways
+    let moves = series(&[100.0, 101.0, 97.0, 99.0]).largest_moves();
+    assert_eq!(moves, LargestMoves { absolute: Some(2), percent: Some(2) });
+}
+
+#[wasm_bindgen_test]
+fn absolute_and_percent_moves_can_differ() {
+    // 100 -> 130 is the largest change in percent (+30%), 160 -> 200 in price (+40)
+    let moves = series(&[100.0, 130.0, 160.0, 200.0, 235.0]).largest_moves();
+    assert_eq!(moves.absolute, Some(3));
+    assert_eq!(moves.percent, Some(1));
+    assert_eq!(MoveKind::Absolute.index_in(moves), Some(3));
+    assert_eq!(MoveKind::Percent.index_in(moves), Some(1));
+}
+
+#[wasm_bindgen_test]
+fn flat_or_short_series_has_no_moves() {
+    assert_eq!(series(&[100.0]).largest_moves(), LargestMoves::default());
+    assert_eq!(series(&[5.0, 5.0, 5.0]).largest_moves(), LargestMoves::default());
+}
+
+#[wasm_bindgen_test]
+fn largest_move_renders_one_highlight() {
+    let mut chart = Chart::new("moves".to_string(), ChartType::Candlestick, 10);
+    chart.set_historical_data(
+        (0..5).map(|i| candle(i, [100.0, 101.0, 97.0, 99.0, 98.0][i as usize])).collect(),
+    );
+
+    let renderer = dummy_renderer();
+    let (_, vertices, _) = renderer.create_geometry_for_test(&chart);
+    let bands: Vec<_> = vertices.iter().filter(|v| v.element_type == 21.0).collect();
+    // Both slots are emitted; the second one is collapsed since one candle has both moves
+    assert_eq!(bands.len(), 12);
+    let width = |band: &[&CandleVertex]| band[1].position_x - band[0].position_x;
+    assert!(width(&bands[..6]) > 0.0);
+    assert_eq!(width(&bands[6..]), 0.0);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn sample_chart(chart_type: ChartType) -> Chart {
+    let candles = (0..40)
+        .map(|i| {
+            let close = 100.0 + (i as f64 * 0.3).sin() * 5.0;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(close - 0.5),
+                    Price::from(close + 20.0),
+                    Price::from(close - 20.0),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("line".to_string(), chart_type, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+fn close_line(verts: &[CandleVertex]) -> usize {
+    verts.iter().filter(|v| v.element_type == 2.0 && v.color_type == 27.0).count()
+}
+
+fn area_fill(verts: &[CandleVertex]) -> usize {
+    verts.iter().filter(|v| v.element_type == 14.0).count()
+}
+
+#[wasm_bindgen_test]
+fn line_mode_connects_visible_closes() {
+    let renderer = dummy_renderer();
+    let (instances, verts, uniforms) =
+        renderer.create_geometry_for_test(&sample_chart(ChartType::Line));
+    let visible = uniforms.time_range[1] as usize;
+    assert!(visible > 1);
+
+    // No candle bodies, one segment (two triangles) between each pair of closes
+    assert!(instances.is_empty());
+    assert_eq!(close_line(&verts), (visible - 1) * 6);
+    assert_eq!(area_fill(&verts), 0);
+}
+
+#[wasm_bindgen_test]
+fn area_mode_fills_below_the_line() {
+    let renderer = dummy_renderer();
+    let (_, verts, uniforms) = renderer.create_geometry_for_test(&sample_chart(ChartType::Area));
+    let visible = uniforms.time_range[1] as usize;
+    assert_eq!(close_line(&verts), (visible - 1) * 6);
+    assert_eq!(area_fill(&verts), (visible - 1) * 6);
+
+    let bottom = renderer.panel_layout().main_bottom;
+    let fill: Vec<_> = verts.iter().filter(|v| v.element_type == 14.0).collect();
+    // Opaque at the line, transparent at the panel bottom
+    assert!(fill.iter().filter(|v| v.color_type == 0.0).all(|v| v.position_y == bottom));
+    assert!(fill.iter().filter(|v| v.color_type == 1.0).all(|v| v.position_y > bottom));
+}
+
+#[wasm_bindgen_test]
+fn price_range_uses_closes_only() {
+    let renderer = dummy_renderer();
+    let (candle_instances, _, candle_uniforms) =
+        renderer.create_geometry_for_test(&sample_chart(ChartType::Candlestick));
+    assert!(!candle_instances.is_empty());
+    // Wicks reach 20 beyond the closes
+    assert!(candle_uniforms.viewport[3] > 115.0);
+
+    let (_, _, line_uniforms) = renderer.create_geometry_for_test(&sample_chart(ChartType::Line));
+    let [_, _, min, max] = line_uniforms.viewport;
+    assert!(min > 90.0 && min < 96.0, "min {min}");
+    assert!(max > 104.0 && max < 110.0, "max {max}");
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{live_candle_handler, live_paused, toggle_live_paused};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::global_state::{ensure_chart, next_connection_id};
+use price_chart_wasm::infrastructure::websocket::CandleCoalescer;
+use wasm_bindgen_test::*;
+
+fn candle(minute: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(minute * 60_000),
+        OHLCV::new(
+            Price::from(100.0),
+            Price::from(close.max(100.0)),
+            Price::from(close.min(100.0)),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn candles_received_while_paused_are_applied_on_resume() {
+    let mut chart = Chart::new("pause".to_string(), ChartType::Candlestick, 100);
+    chart.add_realtime_candle(candle(0, 100.0));
+    let mut coalescer = CandleCoalescer::new();
+
+    assert!(!coalescer.set_paused(true));
+    for (minute, close) in [(1, 101.0), (2, 102.0), (3, 103.0)] {
+        // No flush is scheduled while paused
+        assert!(!coalescer.push(candle(minute, close)));
+    }
+    // A flush that was already on its way applies nothing
+    assert!(coalescer.take().is_empty());
+    assert_eq!(chart.get_candle_count(), 1);
+
+    assert!(coalescer.set_paused(false));
+    for candle in coalescer.take() {
+        chart.add_realtime_candle(candle);
+    }
+    let closes: Vec<f64> = chart
+        .get_series(TimeInterval::TwoSeconds)
+        .unwrap()
+        .get_candles()
+        .iter()
+        .map(|c| c.ohlcv.close.value())
+        .collect();
+    assert_eq!(closes, vec![100.0, 101.0, 102.0, 103.0]);
+}
+
+#[wasm_bindgen_test]
+fn resume_without_buffered_candles_schedules_nothing() {
+    let mut coalescer = CandleCoalescer::new();
+    coalescer.set_paused(true);
+    assert!(coalescer.is_paused());
+    assert!(!coalescer.set_paused(false));
+    assert!(coalescer.push(candle(1, 101.0)));
+}
+
+#[wasm_bindgen_test]
+fn pause_button_toggles_the_signal() {
+    live_paused().set(false);
+    toggle_live_paused();
+    assert!(live_paused().get_untracked());
+    toggle_live_paused();
+    assert!(!live_paused().get_untracked());
+}
+
+#[wasm_bindgen_test]
+fn streamed_candles_are_held_while_paused_and_applied_on_resume() {
+    let symbol = Symbol::from("PAUSEUSDT");
+    let chart = ensure_chart(&symbol);
+    let closes = move || -> Vec<f64> {
+        chart.with_untracked(|c| {
+            c.get_series(TimeInterval::TwoSeconds)
+                .map(|s| s.get_candles().iter().map(|c| c.ohlcv.close.value()).collect())
+                .unwrap_or_default()
+        })
+    };
+    live_paused().set(false);
+    let (abort_handle, _) = futures::future::AbortHandle::new_pair();
+    let (_, set_status) = create_signal(String::new());
+    let handler =
+        live_candle_handler(symbol.clone(), next_connection_id(&symbol), abort_handle, set_status);
+
+    // Without a window the flush runs right away
+    handler(candle(0, 100.0));
+    assert_eq!(closes(), vec![100.0]);
+
+    toggle_live_paused();
+    for (minute, close) in [(1, 101.0), (2, 102.0)] {
+        handler(candle(minute, close));
+    }
+    assert_eq!(closes(), vec![100.0]);
+
+    toggle_live_paused();
+    assert_eq!(closes(), vec![100.0, 101.0, 102.0]);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::logging::{LogLevel, Logger, get_logger, init_logger, set_log_level};
+use price_chart_wasm::infrastructure::services::ConsoleLogger;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn level_change_moves_the_threshold() {
+    let logger = ConsoleLogger::new_production();
+    assert!(!logger.is_enabled(LogLevel::Debug));
+    assert!(logger.is_enabled(LogLevel::Warn));
+    assert!(logger.is_enabled(LogLevel::Error));
+
+    logger.set_min_level(LogLevel::Debug);
+    assert_eq!(logger.min_level(), LogLevel::Debug);
+    assert!(!logger.is_enabled(LogLevel::Trace));
+    assert!(logger.is_enabled(LogLevel::Debug));
+    assert!(logger.is_enabled(LogLevel::Info));
+
+    logger.set_min_level(LogLevel::Error);
+    assert!(!logger.is_enabled(LogLevel::Warn));
+    assert!(logger.is_enabled(LogLevel::Error));
+}
+
+#[wasm_bindgen_test]
+fn global_logger_follows_set_log_level() {
+    init_logger(Box::new(ConsoleLogger::new_production()));
+    assert!(!get_logger().is_enabled(LogLevel::Info));
+
+    set_log_level("debug".parse().unwrap());
+    assert!(get_logger().is_enabled(LogLevel::Debug));
+    assert!(!get_logger().is_enabled(LogLevel::Trace));
+
+    set_log_level(LogLevel::Warn);
+    assert!(!get_logger().is_enabled(LogLevel::Info));
+}
+
+#[wasm_bindgen_test]
+fn level_names_parse_case_insensitively() {
+    assert_eq!(" WARN ".parse::<LogLevel>(), Ok(LogLevel::Warn));
+    assert_eq!("Trace".parse::<LogLevel>(), Ok(LogLevel::Trace));
+    assert!("verbose".parse::<LogLevel>().is_err());
+    assert_eq!(LogLevel::from_u8(LogLevel::Info as u8), LogLevel::Info);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "render")]
+use leptos::SignalSet;
+use price_chart_wasm::app::{global_current_price, price_levels};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType, value_objects::Viewport};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{PriceScaleMode, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn flat_candle(i: u64, price: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(price),
+            Price::from(price),
+            Price::from(price),
+            Price::from(price),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+/// Vertical center of the current price line
+fn current_price_y(chart: &Chart, mode: PriceScaleMode) -> f32 {
+    let mut renderer = dummy_renderer();
+    renderer.set_price_scale_mode(mode);
+    let (_, verts, _) = renderer.create_geometry_for_test(chart);
+    let ys: Vec<f32> = verts
+        .iter()
+        .filter(|v| (v.element_type - 4.0).abs() < f32::EPSILON)
+        .map(|v| v.position_y)
+        .collect();
+    assert!(!ys.is_empty());
+    ys.iter().sum::<f32>() / ys.len() as f32
+}
+
+#[wasm_bindgen_test]
+fn log_mode_centers_geometric_mean() {
+    let mut chart = Chart::new("log".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(vec![flat_candle(0, 10.0), flat_candle(1, 100.0)]);
+
+    global_current_price().set(10f64.sqrt() * 10.0);
+    assert!(current_price_y(&chart, PriceScaleMode::Logarithmic).abs() < 1e-3);
+    assert!(current_price_y(&chart, PriceScaleMode::Linear) < -0.3);
+
+    global_current_price().set(55.0);
+    assert!(current_price_y(&chart, PriceScaleMode::Linear).abs() < 1e-3);
+    assert!(current_price_y(&chart, PriceScaleMode::Logarithmic) > 0.3);
+}
+
+#[wasm_bindgen_test]
+fn log_price_levels_are_geometric() {
+    let vp = Viewport { min_price: 1.0, max_price: 256.0, ..Viewport::default() };
+    let levels = price_levels(&vp, PriceScaleMode::Logarithmic);
+    assert_eq!(levels.len(), 9);
+    for (level, expected) in levels.iter().zip([256.0, 128.0, 64.0, 32.0, 16.0, 8.0, 4.0, 2.0, 1.0])
+    {
+        assert!((level - expected).abs() < 1e-6 * expected, "{level} != {expected}");
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{
+    Candle, MaConfig, MaKind, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::{INDICATOR_PALETTE, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn candles(count: u64) -> Vec<Candle> {
+    (0..count)
+        .map(|i| {
+            let close = 100.0 + (i as f64 * 0.2).cos() * 4.0;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(close),
+                    Price::from(close + 1.0),
+                    Price::from(close - 1.0),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn configured_periods_produce_expected_point_counts() {
+    let candles = candles(100);
+    let configs = [MaConfig::new(MaKind::Sma, 10), MaConfig::new(MaKind::Ema, 30)];
+    let data = MarketAnalysisService::new().calculate_multiple_mas(&candles, &configs);
+
+    assert_eq!(data.lines().count(), 2);
+    assert_eq!(data.get(MaKind::Sma, 10).unwrap().len(), 100 - 10 + 1);
+    assert_eq!(data.get(MaKind::Ema, 30).unwrap().len(), 100 - 30 + 1);
+    assert!(data.get(MaKind::Sma, 20).is_none());
+}
+
+#[wasm_bindgen_test]
+fn chart_engines_follow_configured_periods() {
+    let mut chart = Chart::new("ma".to_string(), ChartType::Candlestick, 200);
+    chart.set_historical_data(candles(100));
+    let configs = [MaConfig::new(MaKind::Sma, 10), MaConfig::new(MaKind::Ema, 30)];
+    chart.set_ma_configs(&configs);
+
+    assert_eq!(chart.ma_configs(), configs.to_vec());
+    let engine = chart.ma_engines.get(&TimeInterval::TwoSeconds).unwrap();
+    assert_eq!(engine.values(MaKind::Sma, 10).unwrap().len(), 100 - 10 + 1);
+    assert!(engine.values(MaKind::Sma, 20).is_none());
+
+    // Reloading history keeps the configured set
+    chart.set_historical_data(candles(50));
+    let engine = chart.ma_engines.get(&TimeInterval::TwoSeconds).unwrap();
+    assert_eq!(engine.values(MaKind::Sma, 10).unwrap().len(), 50 - 10 + 1);
+}
+
+#[wasm_bindgen_test]
+fn renderer_lines_follow_configs_with_cyclic_colors() {
+    let mut renderer = dummy_renderer();
+    renderer.toggle_line_visibility("sma20");
+    let configs: Vec<MaConfig> =
+        ["sma20", "EMA7", "bogus", "sma0", "sma10", "sma10", "ema1", "ema2", "ema3", "ema4"]
+            .into_iter()
+            .filter_map(MaConfig::parse)
+            .collect();
+    renderer.set_ma_configs(&configs);
+
+    let lines = renderer.line_visibility().lines;
+    let names: Vec<String> = lines.iter().map(|l| l.name()).collect();
+    assert_eq!(names, ["sma20", "ema7", "sma10", "ema1", "ema2", "ema3", "ema4"]);
+    // Previously hidden line stays hidden
+    assert!(!lines[0].visible && lines[1].visible);
+    for (i, line) in lines.iter().enumerate() {
+        assert_eq!(line.color, INDICATOR_PALETTE[i % INDICATOR_PALETTE.len()]);
+    }
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{SubPanelKind, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn candles_from(closes: &[f64]) -> Vec<Candle> {
+    closes
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| {
+            Candle::new(
+                Timestamp::from_millis(i as u64 * 60_000),
+                OHLCV::new(
+                    Price::from(close),
+                    Price::from(close + 0.5),
+                    Price::from(close - 0.5),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn histogram_is_macd_minus_signal() {
+    let closes: Vec<f64> = (0..120).map(|i| 100.0 + (i as f64 * 0.3).sin() * 10.0).collect();
+    let macd = MarketAnalysisService::new().calculate_macd(&candles_from(&closes), 12, 26, 9);
+
+    assert_eq!(macd.macd.len(), 120 - (26 + 9 - 2));
+    assert_eq!(macd.signal.len(), macd.macd.len());
+    assert_eq!(macd.histogram.len(), macd.macd.len());
+    for ((m, s), h) in macd.macd.iter().zip(&macd.signal).zip(&macd.histogram) {
+        assert!((h - (m - s)).abs() < 1e-12);
+    }
+}
+
+#[wasm_bindgen_test]
+fn macd_panel_is_drawn_only_when_enabled() {
+    let closes: Vec<f64> = (0..80).map(|i| 100.0 + (i as f64 * 0.4).sin() * 5.0).collect();
+    let mut chart = Chart::new("macd".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles_from(&closes));
+    chart.viewport.start_time = (48 * 60_000) as f64;
+    let mut renderer = dummy_renderer();
+
+    let is_macd_line = |v: &&CandleVertex| {
+        (v.element_type - 2.0).abs() < f32::EPSILON && (v.color_type - 20.0).abs() < f32::EPSILON
+    };
+    let is_bar = |v: &&CandleVertex| (v.element_type - 5.0).abs() < f32::EPSILON;
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(verts.iter().filter(is_macd_line).count(), 0);
+    let plain_bars = verts.iter().filter(is_bar).count();
+
+    renderer.toggle_line_visibility("macd");
+    let panel = *renderer.panel_layout().panel(SubPanelKind::Macd).expect("macd panel");
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(verts.iter().filter(is_macd_line).count() > 0);
+    assert!(verts.iter().filter(is_bar).count() > plain_bars);
+
+    // Zero guide sits in the middle of the panel
+    let middle = (panel.bottom + panel.top) / 2.0;
+    assert!(verts.iter().any(
+        |v| (v.color_type - 19.0).abs() < f32::EPSILON && (v.position_y - middle).abs() < 0.01
+    ));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::MeasureOverlay;
+use price_chart_wasm::domain::chart::value_objects::PriceMeasurement;
+use price_chart_wasm::domain::market_data::TimeInterval;
+use price_chart_wasm::time_utils::format_elapsed;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn measurement_reports_change_percent_and_bars() {
+    let up = PriceMeasurement::new((10, 200.0), (22, 250.0));
+    assert_eq!(up.price_change(), 50.0);
+    assert_eq!(up.percent_change(), Some(25.0));
+    assert_eq!(up.bar_count(), 12);
+    assert_eq!(up.elapsed_ms(TimeInterval::FiveMinutes), 12 * 5 * 60 * 1000);
+
+    // Dragging right to left measures a move back in time from A
+    let down = PriceMeasurement::new((40, 100.0), (30, 80.0));
+    assert_eq!(down.price_change(), -20.0);
+    assert_eq!(down.percent_change(), Some(-20.0));
+    assert_eq!(down.bar_count(), 10);
+}
+
+#[wasm_bindgen_test]
+fn zero_start_price_has_no_percent() {
+    let m = PriceMeasurement::new((0, 0.0), (3, 5.0));
+    assert_eq!(m.percent_change(), None);
+    assert_eq!(PriceMeasurement::new((4, 1.0), (4, 1.0)).bar_count(), 0);
+}
+
+#[wasm_bindgen_test]
+fn elapsed_time_uses_two_largest_units() {
+    assert_eq!(format_elapsed(0), "0s");
+    assert_eq!(format_elapsed(45_000), "45s");
+    assert_eq!(format_elapsed(12 * 60_000), "12m 0s");
+    assert_eq!(format_elapsed(65 * 60_000), "1h 5m");
+    assert_eq!(format_elapsed((2 * 24 + 3) * 3_600_000 + 60_000), "2d 3h");
+}
+
+#[wasm_bindgen_test]
+fn overlay_label_combines_the_readouts() {
+    let overlay = MeasureOverlay {
+        measurement: PriceMeasurement::new((0, 100.0), (3, 101.5)),
+        from: (10.0, 40.0),
+        to: (60.0, 20.0),
+        dragging: false,
+    };
+    assert_eq!(overlay.label(TimeInterval::OneHour), "+1.50 (+1.50%) • 3 bars • 3h 0m");
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{
+    MAX_CANDLES_PER_SCREEN, MINIMAP_EDGE_PX, MinimapDrag, MinimapHandle, minimap_handle_at,
+    minimap_points, minimap_window, sparkline_path,
+};
+use wasm_bindgen_test::*;
+
+/// 1000 candles on a 500 px strip: two candles per pixel
+const LEN: usize = 1000;
+const WIDTH: f64 = 500.0;
+
+fn drag(handle: MinimapHandle, from_x: f64) -> MinimapDrag {
+    MinimapDrag { handle, from_x, start: 400, visible: 100 }
+}
+
+#[wasm_bindgen_test]
+fn dragging_the_window_pans_by_the_covered_candles() {
+    let d = drag(MinimapHandle::Window, 220.0);
+    assert_eq!(d.range_at(270.0, WIDTH, LEN), (500, 100));
+    assert_eq!(d.range_at(170.0, WIDTH, LEN), (300, 100));
+    assert_eq!(d.range_at(220.0, WIDTH, LEN), (400, 100));
+}
+
+#[wasm_bindgen_test]
+fn window_drag_stops_at_the_history_edges() {
+    let d = drag(MinimapHandle::Window, 220.0);
+    assert_eq!(d.range_at(WIDTH * 2.0, WIDTH, LEN), (900, 100));
+    assert_eq!(d.range_at(-WIDTH, WIDTH, LEN), (0, 100));
+}
+
+#[wasm_bindgen_test]
+fn dragging_an_edge_resizes_the_window() {
+    // Left edge out by 50 px widens the view by 100 candles, keeping its right end
+    assert_eq!(drag(MinimapHandle::Left, 200.0).range_at(150.0, WIDTH, LEN), (300, 200));
+    // Right edge in by 25 px narrows it by 50 candles, keeping its left end
+    assert_eq!(drag(MinimapHandle::Right, 250.0).range_at(225.0, WIDTH, LEN), (400, 50));
+}
+
+#[wasm_bindgen_test]
+fn resizing_keeps_between_one_candle_and_a_full_screen() {
+    assert_eq!(drag(MinimapHandle::Right, 250.0).range_at(0.0, WIDTH, LEN), (400, 1));
+    assert_eq!(drag(MinimapHandle::Left, 200.0).range_at(WIDTH, WIDTH, LEN), (499, 1));
+    let (start, visible) = drag(MinimapHandle::Left, 200.0).range_at(-WIDTH, WIDTH, LEN);
+    assert_eq!(visible, MAX_CANDLES_PER_SCREEN.min(LEN));
+    assert_eq!(start + visible, 500);
+}
+
+#[wasm_bindgen_test]
+fn window_covers_the_visible_share_of_the_strip() {
+    assert_eq!(minimap_window(400, 100, LEN, WIDTH), (200.0, 50.0));
+    assert_eq!(minimap_window(0, 0, 0, WIDTH), (0.0, WIDTH));
+}
+
+#[wasm_bindgen_test]
+fn handles_are_found_at_the_edges_and_inside() {
+    let window = (200.0, 50.0);
+    assert_eq!(minimap_handle_at(200.0 + MINIMAP_EDGE_PX, window), Some(MinimapHandle::Left));
+    assert_eq!(minimap_handle_at(249.0, window), Some(MinimapHandle::Right));
+    assert_eq!(minimap_handle_at(225.0, window), Some(MinimapHandle::Window));
+    assert_eq!(minimap_handle_at(100.0, window), None);
+}
+
+#[wasm_bindgen_test]
+fn closes_trace_across_the_strip_with_higher_prices_on_top() {
+    let points = minimap_points(&[1.0, 3.0, 2.0], 100.0, 40.0);
+    assert_eq!(points, "0.0,40.0 50.0,0.0 100.0,20.0");
+    assert!(minimap_points(&[], 100.0, 40.0).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn long_histories_draw_at_most_one_point_per_pixel() {
+    let closes: Vec<f64> = (0..LEN).map(|i| i as f64).collect();
+    let points = minimap_points(&closes, WIDTH, 40.0);
+    assert!(points.split(' ').count() <= WIDTH as usize);
+}
+
+#[wasm_bindgen_test]
+fn flat_history_runs_through_the_middle_like_the_sparklines() {
+    assert_eq!(minimap_points(&[5.0, 5.0, 5.0], 100.0, 40.0), "0.0,20.0 50.0,20.0 100.0,20.0");
+    let sparkline = sparkline_path(&[5.0, 5.0, 5.0], 100.0, 40.0);
+    assert_eq!(sparkline, "M0.0,20.0 L50.0,20.0 L100.0,20.0");
+}
@@ -2,7 +2,7 @@
 
 use price_chart_wasm::domain::{
     chart::{Chart, value_objects::ChartType},
-    market_data::{Candle, OHLCV, Price, TimeInterval, Timestamp, Volume},
+    market_data::{Candle, MaKind, OHLCV, Price, TimeInterval, Timestamp, Volume},
 };
 use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
 
@@ -52,22 +52,34 @@ fn moving_averages_continue_after_capacity_overflow() {
 
     let base_engine = chart.ma_engines.get(&TimeInterval::TwoSeconds).expect("base engine");
     let base_data = base_engine.data();
-    assert_eq!(base_data.ema_12.len(), total_candles);
-    let expected_base_sma20_len = total_candles - 20 + 1;
-    assert_eq!(base_data.sma_20.len(), expected_base_sma20_len);
+    // Values of evicted candles are dropped with them
+    assert_eq!(base_data.get(MaKind::Ema, 12).unwrap().len(), max_candles);
+    let expected_base_sma20_len = max_candles - 20 + 1;
+    assert_eq!(base_data.get(MaKind::Sma, 20).unwrap().len(), expected_base_sma20_len);
     let last_index = total_candles - 1;
     let expected_base_avg = ((last_index - 19) as f64 + last_index as f64) / 2.0;
-    let base_last = base_data.sma_20.last().expect("latest SMA20 value for base interval");
+    let base_last = base_data
+        .get(MaKind::Sma, 20)
+        .unwrap()
+        .last()
+        .expect("latest SMA20 value for base interval");
     assert!((base_last.value() - expected_base_avg).abs() < 1e-9);
 
     let minute_engine = chart.ma_engines.get(&TimeInterval::OneMinute).expect("minute engine");
     let minute_data = minute_engine.data();
-    assert_eq!(minute_data.ema_12.len(), minute_closes.len());
-    let expected_minute_sma_len = minute_closes.len() - 20 + 1;
-    assert_eq!(minute_data.sma_20.len(), expected_minute_sma_len);
+    // The latest minute is still open, so it is not in the engine yet
+    let closed_minutes = chart.get_series(TimeInterval::OneMinute).unwrap().count() - 1;
+    assert_eq!(minute_data.get(MaKind::Ema, 12).unwrap().len(), closed_minutes);
+    let expected_minute_sma_len = closed_minutes - 20 + 1;
+    assert_eq!(minute_data.get(MaKind::Sma, 20).unwrap().len(), expected_minute_sma_len);
+    let last_closed = minute_closes.len() - 1;
     let minute_avg: f64 =
-        minute_closes[minute_closes.len() - 20..].iter().copied().sum::<f64>() / 20.0;
-    let minute_last = minute_data.sma_20.last().expect("latest SMA20 value for minute interval");
+        minute_closes[last_closed - 20..last_closed].iter().copied().sum::<f64>() / 20.0;
+    let minute_last = minute_data
+        .get(MaKind::Sma, 20)
+        .unwrap()
+        .last()
+        .expect("latest SMA20 value for minute interval");
     assert!((minute_last.value() - minute_avg).abs() < 1e-9);
 
     let renderer = dummy_renderer();
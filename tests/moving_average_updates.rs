@@ -3,7 +3,8 @@
 use price_chart_wasm::domain::chart::Chart;
 use price_chart_wasm::domain::chart::value_objects::ChartType;
 use price_chart_wasm::domain::market_data::{
-    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume, indicator_engine::MovingAverageEngine,
+    Candle, MaKind, OHLCV, Price, TimeInterval, Timestamp, Volume,
+    indicator_engine::MovingAverageEngine,
 };
 use wasm_bindgen_test::*;
 
@@ -41,11 +42,26 @@ fn partial_realtime_updates_refresh_mas() {
     expected.update_on_close(40.0);
 
     let base_engine = chart.ma_engines.get(&TimeInterval::TwoSeconds).expect("base engine");
-    assert_eq!(base_engine.data().sma_20.last(), expected.data().sma_20.last());
-    assert_eq!(base_engine.data().ema_12.last(), expected.data().ema_12.last());
-    assert_eq!(base_engine.data().ema_26.last(), expected.data().ema_26.last());
+    assert_eq!(
+        base_engine.data().get(MaKind::Sma, 20).unwrap().last(),
+        expected.data().get(MaKind::Sma, 20).unwrap().last()
+    );
+    assert_eq!(
+        base_engine.data().get(MaKind::Ema, 12).unwrap().last(),
+        expected.data().get(MaKind::Ema, 12).unwrap().last()
+    );
+    assert_eq!(
+        base_engine.data().get(MaKind::Ema, 26).unwrap().last(),
+        expected.data().get(MaKind::Ema, 26).unwrap().last()
+    );
 
     let minute_engine = chart.ma_engines.get(&TimeInterval::OneMinute).expect("minute engine");
-    assert_eq!(minute_engine.data().ema_12.last().map(|price| price.value()), Some(40.0));
-    assert_eq!(minute_engine.data().ema_26.last().map(|price| price.value()), Some(40.0));
+    assert_eq!(
+        minute_engine.data().get(MaKind::Ema, 12).unwrap().last().map(|price| price.value()),
+        Some(40.0)
+    );
+    assert_eq!(
+        minute_engine.data().get(MaKind::Ema, 26).unwrap().last().map(|price| price.value()),
+        Some(40.0)
+    );
 }
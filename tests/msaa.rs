@@ -1,9 +1,78 @@
 #![cfg(feature = "render")]
-use price_chart_wasm::infrastructure::rendering::renderer::MSAA_SAMPLE_COUNT;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    MSAA_SAMPLE_COUNT, WebGpuRenderer, dummy_renderer, msaa_texture_descriptor, multisample_state,
+    normalized_sample_count,
+};
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn setup_canvas(id: &str) {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let canvas = document
+        .create_element("canvas")
+        .unwrap()
+        .dyn_into::<web_sys::HtmlCanvasElement>()
+        .unwrap();
+    canvas.set_id(id);
+    document.body().unwrap().append_child(&canvas).unwrap();
+}
+
 #[wasm_bindgen_test]
 fn msaa_sample_count_is_four() {
     assert_eq!(MSAA_SAMPLE_COUNT, 4);
 }
+
+#[wasm_bindgen_test]
+fn sample_count_snaps_to_supported_values() {
+    assert_eq!(normalized_sample_count(0), 1);
+    assert_eq!(normalized_sample_count(1), 1);
+    assert_eq!(normalized_sample_count(2), MSAA_SAMPLE_COUNT);
+    assert_eq!(normalized_sample_count(8), MSAA_SAMPLE_COUNT);
+}
+
+#[wasm_bindgen_test]
+fn pipeline_and_target_use_configured_sample_count() {
+    for requested in [1, 4] {
+        let count = normalized_sample_count(requested);
+        assert_eq!(multisample_state(count).count, requested);
+        let desc = msaa_texture_descriptor(800, 600, wgpu::TextureFormat::Bgra8Unorm, count);
+        assert_eq!(desc.sample_count, requested);
+    }
+
+    let renderer = dummy_renderer();
+    assert_eq!(renderer.sample_count(), MSAA_SAMPLE_COUNT);
+    assert_eq!(renderer.msaa_target_descriptor().sample_count, renderer.sample_count());
+}
+
+#[wasm_bindgen_test(async)]
+async fn resize_recreates_the_msaa_texture() {
+    if !WebGpuRenderer::is_webgpu_supported().await {
+        web_sys::console::log_1(&"Skipping test: WebGPU not supported".into());
+        return;
+    }
+    setup_canvas("msaa-canvas");
+    let mut renderer = match WebGpuRenderer::new("msaa-canvas", 320, 240).await {
+        Ok(r) => r,
+        Err(e) => {
+            web_sys::console::log_1(&format!("Skipping test: {e:?}").into());
+            return;
+        }
+    };
+    let before = renderer.msaa_texture_size();
+    let sample_count = renderer.sample_count();
+
+    renderer.resize(640, 480);
+    let expected = renderer.msaa_target_descriptor().size;
+    let after = renderer.msaa_texture_size();
+    assert_eq!(after, (expected.width, expected.height, sample_count));
+    assert_ne!((after.0, after.1), (before.0, before.1));
+}
+
+#[wasm_bindgen_test]
+fn msaa_target_follows_surface_size() {
+    let renderer = dummy_renderer();
+    let size = renderer.msaa_target_descriptor().size;
+    assert_eq!((size.width, size.height), (800, 600));
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{COMPARE_CANVAS_ID, global_current_price};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::ecs::EcsWorld;
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    dummy_renderer_for, register_renderer, remove_renderer, renderer_canvas_ids,
+    set_global_renderer, with_global_renderer, with_renderer,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+fn chart(id: &str, base: f64) -> Chart {
+    let mut chart = Chart::new(id.to_string(), ChartType::Candlestick, 100);
+    let candles = (0..30)
+        .map(|i| {
+            let price = base + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(price),
+                    Price::from(price + 2.0),
+                    Price::from(price - 2.0),
+                    Price::from(price + 1.0),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    chart.set_historical_data(candles);
+    chart
+}
+
+fn price_line_y(verts: &[CandleVertex]) -> f32 {
+    let line: Vec<f32> = verts
+        .iter()
+        .filter(|v| (v.element_type - 4.0).abs() < f32::EPSILON)
+        .map(|v| v.position_y)
+        .collect();
+    line.iter().sum::<f32>() / line.len() as f32
+}
+
+#[wasm_bindgen_test]
+fn two_renderers_coexist_and_draw_their_own_chart() {
+    set_global_renderer(Rc::new(RefCell::new(dummy_renderer_for("chart-canvas"))));
+    register_renderer(Rc::new(RefCell::new(dummy_renderer_for(COMPARE_CANVAS_ID))));
+    assert_eq!(renderer_canvas_ids(), vec!["chart-canvas".to_string(), COMPARE_CANVAS_ID.into()]);
+    assert_eq!(
+        with_global_renderer(|r| (r.canvas_id().to_string(), r.is_primary())),
+        Some(("chart-canvas".to_string(), true))
+    );
+    assert_eq!(with_renderer(COMPARE_CANVAS_ID, |r| r.is_primary()), Some(false));
+
+    let btc = chart("BTCUSDT", 100.0);
+    let eth = chart("ETHUSDT", 2_000.0);
+    let (_, _, main) = with_global_renderer(|r| r.create_geometry_for_test(&btc)).unwrap();
+    let (_, _, compare) =
+        with_renderer(COMPARE_CANVAS_ID, |r| r.create_geometry_for_test(&eth)).unwrap();
+    assert!(main.viewport[3] < 200.0);
+    assert!(compare.viewport[2] > 1_900.0);
+
+    assert!(remove_renderer(COMPARE_CANVAS_ID));
+    assert!(with_renderer(COMPARE_CANVAS_ID, |_| ()).is_none());
+    assert!(with_global_renderer(|_| ()).is_some());
+    assert!(remove_renderer("chart-canvas"));
+    assert!(with_global_renderer(|_| ()).is_none());
+}
+
+#[wasm_bindgen_test]
+fn additional_renderers_mark_their_own_last_close() {
+    let eth = chart("ETHUSDT", 2_000.0);
+    let last_close = 2_000.0 + 29.0 + 1.0;
+
+    // The main renderer follows the global price, here the compared chart's close
+    global_current_price().set(last_close);
+    let (_, verts, _) = dummy_renderer_for("chart-canvas").create_geometry_for_test(&eth);
+    let expected = price_line_y(&verts);
+
+    global_current_price().set(1_000_000.0);
+    register_renderer(Rc::new(RefCell::new(dummy_renderer_for(COMPARE_CANVAS_ID))));
+    let (_, verts, _) =
+        with_renderer(COMPARE_CANVAS_ID, |r| r.create_geometry_for_test(&eth)).unwrap();
+    assert!((price_line_y(&verts) - expected).abs() < 1e-5);
+    remove_renderer(COMPARE_CANVAS_ID);
+}
+
+#[wasm_bindgen_test]
+fn ecs_binds_each_canvas_to_one_chart() {
+    let mut world = EcsWorld::new();
+    world.spawn_chart(chart("BTCUSDT", 100.0));
+    world.spawn_chart(chart("ETHUSDT", 2_000.0));
+    assert!(world.chart_for_canvas(COMPARE_CANVAS_ID).is_none());
+
+    assert!(world.bind_canvas("ETHUSDT", COMPARE_CANVAS_ID));
+    let bound = world.chart_for_canvas(COMPARE_CANVAS_ID).unwrap();
+    assert_eq!(bound.with_untracked(|c| c.id.clone()), "ETHUSDT");
+
+    assert!(world.bind_canvas("BTCUSDT", COMPARE_CANVAS_ID));
+    let bound = world.chart_for_canvas(COMPARE_CANVAS_ID).unwrap();
+    assert_eq!(bound.with_untracked(|c| c.id.clone()), "BTCUSDT");
+
+    assert!(!world.bind_canvas("SOLUSDT", COMPARE_CANVAS_ID));
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{
+    current_symbol, follow_order_book, global_current_price, order_book_stream,
+    route_stream_candle, stream_abort_handles, take_running_streams,
+};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::global_state::{
+    ecs_world, ensure_chart, is_current_connection, next_connection_id,
+};
+use wasm_bindgen_test::*;
+
+fn candle(price: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(0),
+        OHLCV::new(
+            Price::from(price),
+            Price::from(price),
+            Price::from(price),
+            Price::from(price),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn closes(symbol: &Symbol) -> Vec<f64> {
+    ensure_chart(symbol).with_untracked(|c| {
+        c.get_series(TimeInterval::OneMinute)
+            .unwrap()
+            .get_candles()
+            .iter()
+            .map(|c| c.ohlcv.close.value())
+            .collect()
+    })
+}
+
+#[wasm_bindgen_test]
+fn streams_update_only_their_own_chart() {
+    ecs_world().lock().unwrap().world = hecs::World::new();
+    let sym_a = Symbol::from("WLA");
+    let sym_b = Symbol::from("WLB");
+    ensure_chart(&sym_a);
+    ensure_chart(&sym_b);
+    current_symbol().set(sym_a.clone());
+
+    assert!(route_stream_candle(&sym_a, candle(10.0)));
+    assert!(!route_stream_candle(&sym_b, candle(20.0)));
+
+    assert_eq!(closes(&sym_a), vec![10.0]);
+    assert_eq!(closes(&sym_b), vec![20.0]);
+    assert_eq!(global_current_price().get_untracked(), 10.0);
+}
+
+#[wasm_bindgen_test]
+fn connection_ids_are_per_symbol() {
+    let sym_a = Symbol::from("CIA");
+    let sym_b = Symbol::from("CIB");
+    let id_a = next_connection_id(&sym_a);
+    let id_b = next_connection_id(&sym_b);
+    assert!(is_current_connection(&sym_a, id_a));
+    assert!(is_current_connection(&sym_b, id_b));
+
+    let id_b2 = next_connection_id(&sym_b);
+    assert!(is_current_connection(&sym_a, id_a));
+    assert!(!is_current_connection(&sym_b, id_b));
+    assert!(is_current_connection(&sym_b, id_b2));
+}
+
+#[wasm_bindgen_test]
+fn interval_switch_stops_every_stream() {
+    let symbols = [Symbol::from("RSA"), Symbol::from("RSB"), Symbol::from("RSC")];
+    let handles: Vec<_> = symbols
+        .iter()
+        .map(|symbol| {
+            let (handle, _) = futures::future::AbortHandle::new_pair();
+            stream_abort_handles().update(|m| {
+                m.insert(symbol.clone(), handle.clone());
+            });
+            handle
+        })
+        .collect();
+    current_symbol().set(symbols[0].clone());
+
+    // Background symbols are stopped too so they can resubscribe on the new interval
+    assert_eq!(take_running_streams(), symbols.to_vec());
+    assert!(handles.iter().all(|h| h.is_aborted()));
+    assert!(stream_abort_handles().with_untracked(|m| m.is_empty()));
+}
+
+#[wasm_bindgen_test]
+fn only_the_selected_symbol_streams_its_order_book() {
+    let first = Symbol::from("OBAUSDT");
+    follow_order_book(&first);
+    let (symbol, handle) = order_book_stream().unwrap();
+    assert_eq!(symbol, first);
+
+    // Selecting the same symbol again keeps its stream
+    follow_order_book(&first);
+    assert!(!handle.is_aborted());
+
+    follow_order_book(&Symbol::from("OBBUSDT"));
+    assert!(handle.is_aborted());
+    let (symbol, next) = order_book_stream().unwrap();
+    assert_eq!(symbol, Symbol::from("OBBUSDT"));
+
+    // Coinbase products have no depth stream
+    follow_order_book(&Symbol::from("OBC-USD"));
+    assert!(next.is_aborted());
+    assert!(order_book_stream().is_none());
+}
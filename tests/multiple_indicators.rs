@@ -0,0 +1,75 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, MaKind, OHLCV, Price, Timestamp, Volume, moving_average,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::{IndicatorLine, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn make_chart(count: u64) -> Chart {
+    let candles: Vec<Candle> = (0..count)
+        .map(|i| {
+            let base = 100.0 + (i as f64 * 0.3).sin() * 5.0;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 1.0),
+                    Price::from(base - 1.0),
+                    Price::from(base + 0.5),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("multi-ma".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn two_smas_render_as_distinct_lines() {
+    let chart = make_chart(30);
+    let mut renderer = dummy_renderer();
+    for name in renderer.line_visibility().names() {
+        renderer.remove_indicator_line(&name);
+    }
+    assert!(renderer.add_indicator_line(IndicatorLine::new(MaKind::Sma, 5, [1.0, 0.0, 0.0, 1.0])));
+    assert!(renderer.add_indicator_line(IndicatorLine::new(MaKind::Sma, 10, [0.0, 0.0, 1.0, 1.0])));
+    assert!(!renderer.add_indicator_line(IndicatorLine::new(
+        MaKind::Sma,
+        10,
+        [0.0, 1.0, 0.0, 1.0]
+    )));
+
+    let (_, verts, uniforms) = renderer.create_geometry_for_test(&chart);
+    let line_vertices = |color_type: f32| {
+        verts
+            .iter()
+            .filter(|v| {
+                (v.element_type - 2.0).abs() < f32::EPSILON
+                    && (v.color_type - color_type).abs() < f32::EPSILON
+            })
+            .count()
+    };
+
+    // All 30 minute candles are visible, so each SMA draws (len - period + 1) points
+    assert_eq!(line_vertices(2.0), (30 - 5) * 6);
+    assert_eq!(line_vertices(3.0), (30 - 10) * 6);
+    assert_eq!(line_vertices(4.0), 0);
+    assert_eq!(uniforms.indicator_colors[0], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(uniforms.indicator_colors[1], [0.0, 0.0, 1.0, 1.0]);
+}
+
+#[wasm_bindgen_test]
+fn moving_average_matches_window_mean() {
+    let closes: Vec<f64> = (1..=6).map(|i| i as f64).collect();
+    let sma = moving_average(MaKind::Sma, 3, &closes);
+    let values: Vec<f64> = sma.iter().map(|p| p.value()).collect();
+    assert_eq!(values, vec![2.0, 3.0, 4.0, 5.0]);
+
+    let ema = moving_average(MaKind::Ema, 3, &closes);
+    assert_eq!(ema.len(), closes.len());
+    assert!((ema[0].value() - 1.0).abs() < f64::EPSILON);
+    assert!((ema[1].value() - 1.5).abs() < f64::EPSILON);
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, DepthBucket, OHLCV, OrderBook, OrderBookLevel, Price, Symbol, TimeInterval, Timestamp,
+    Volume,
+};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{DEPTH_MAX_WIDTH, dummy_renderer};
+use price_chart_wasm::infrastructure::websocket::{
+    BinanceDepthSnapshot, BinanceWebSocketClient, UpdateThrottle,
+};
+use wasm_bindgen_test::*;
+
+const DEPTH_MESSAGE: &str = r#"{
+    "lastUpdateId": 160,
+    "bids": [["99.50", "2.0"], ["100.00", "1.5"], ["98.00", "0.00000000"]],
+    "asks": [["101.20", "0.5"], ["100.50", "3.0"]]
+}"#;
+
+fn depth_bars(verts: &[CandleVertex]) -> Vec<&CandleVertex> {
+    verts.iter().filter(|v| v.element_type == 13.0).collect()
+}
+
+#[wasm_bindgen_test]
+fn depth_snapshot_parses_into_sorted_book() {
+    let snapshot: BinanceDepthSnapshot = serde_json::from_str(DEPTH_MESSAGE).unwrap();
+    let book = snapshot.to_domain_order_book().unwrap();
+
+    assert_eq!(book.last_update_id(), 160);
+    // Empty levels are dropped, the best price leads each side
+    assert_eq!(book.bids(), &[OrderBookLevel::new(100.0, 1.5), OrderBookLevel::new(99.5, 2.0)]);
+    assert_eq!(book.asks(), &[OrderBookLevel::new(100.5, 3.0), OrderBookLevel::new(101.2, 0.5)]);
+    assert_eq!(book.best_bid(), Some(100.0));
+    assert_eq!(book.best_ask(), Some(100.5));
+
+    let client = BinanceWebSocketClient::new(Symbol::from("BTCUSDT"), TimeInterval::OneMinute);
+    assert_eq!(client.parse_depth_message(DEPTH_MESSAGE).unwrap(), book);
+    assert!(
+        client.parse_depth_message(r#"{"lastUpdateId":1,"bids":[["x","1"]],"asks":[]}"#).is_err()
+    );
+    assert_eq!(client.depth_stream_url(), "wss://stream.binance.com:9443/ws/btcusdt@depth20@100ms");
+}
+
+#[wasm_bindgen_test]
+fn levels_are_summed_into_price_buckets() {
+    let book = OrderBook::new(
+        1,
+        vec![
+            OrderBookLevel::new(99.2, 1.0),
+            OrderBookLevel::new(99.9, 2.0),
+            OrderBookLevel::new(98.5, 4.0),
+        ],
+        vec![OrderBookLevel::new(100.1, 3.0), OrderBookLevel::new(100.8, 1.0)],
+    );
+
+    let buckets = book.aggregate(1.0);
+    assert_eq!(
+        buckets,
+        vec![
+            DepthBucket { price: 98.0, bid_quantity: 4.0, ask_quantity: 0.0 },
+            DepthBucket { price: 99.0, bid_quantity: 3.0, ask_quantity: 0.0 },
+            DepthBucket { price: 100.0, bid_quantity: 0.0, ask_quantity: 4.0 },
+        ]
+    );
+    assert!(book.aggregate(0.0).is_empty());
+    assert!(OrderBook::default().aggregate(1.0).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn throttle_forwards_at_most_one_update_per_interval() {
+    let mut throttle = UpdateThrottle::new(500);
+    assert!(throttle.ready(1_000));
+    assert!(!throttle.ready(1_100));
+    assert!(!throttle.ready(1_499));
+    assert!(throttle.ready(1_500));
+    assert!(!throttle.ready(1_900));
+}
+
+#[wasm_bindgen_test]
+fn depth_bars_sit_at_the_right_edge_within_the_price_area() {
+    let candles: Vec<Candle> = (0..50)
+        .map(|i| {
+            let price = 100.0 + (i % 5) as f64;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(price),
+                    Price::from(price + 1.0),
+                    Price::from(price - 1.0),
+                    Price::from(price),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("depth".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(depth_bars(&verts).is_empty());
+
+    let book = OrderBook::new(
+        1,
+        vec![OrderBookLevel::new(101.0, 4.0), OrderBookLevel::new(100.0, 2.0)],
+        // Far above the visible range, so it is left out
+        vec![OrderBookLevel::new(102.5, 1.0), OrderBookLevel::new(500.0, 9.0)],
+    );
+    renderer.set_order_book(Some(book));
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    let bars = depth_bars(&verts);
+    assert_eq!(bars.len(), 3 * 6);
+    let main_bottom = renderer.panel_layout().main_bottom;
+    assert!(bars.iter().all(|v| v.position_x >= 1.0 - DEPTH_MAX_WIDTH - 1e-6));
+    assert!(bars.iter().all(|v| v.position_y >= main_bottom && v.position_y <= 1.0));
+    // The largest bucket spans the full width
+    assert!(bars.iter().any(|v| (v.position_x - (1.0 - DEPTH_MAX_WIDTH)).abs() < 1e-6));
+    assert_eq!(bars.iter().filter(|v| v.color_type == 1.0).count(), 2 * 6);
+
+    renderer.set_order_book(None);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(depth_bars(&verts).is_empty());
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, CandleOrder, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use wasm_bindgen_test::*;
+
+fn candle(ts: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(ts),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn closes(chart: &Chart, interval: TimeInterval) -> Vec<(u64, f64)> {
+    chart
+        .get_series(interval)
+        .unwrap()
+        .get_candles()
+        .iter()
+        .map(|c| (c.timestamp.value(), c.ohlcv.close.value()))
+        .collect()
+}
+
+fn ma_values(chart: &Chart) -> Vec<Vec<f64>> {
+    let engine = &chart.ma_engines[&TimeInterval::TwoSeconds];
+    engine
+        .configs()
+        .iter()
+        .map(|c| {
+            engine.values(c.kind, c.period).unwrap_or_default().iter().map(|p| p.value()).collect()
+        })
+        .collect()
+}
+
+fn streamed_chart() -> Chart {
+    let mut chart = Chart::new("ordering".to_string(), ChartType::Candlestick, 100);
+    for i in 0..5 {
+        assert_eq!(
+            chart.add_realtime_candle(candle(i * 60_000, 100.0 + i as f64)),
+            CandleOrder::New
+        );
+    }
+    chart
+}
+
+#[wasm_bindgen_test]
+fn stale_candle_is_rejected_without_touching_the_series() {
+    let mut chart = streamed_chart();
+    let base_before = closes(&chart, TimeInterval::TwoSeconds);
+    let minute_before = closes(&chart, TimeInterval::OneMinute);
+    let ma_before = ma_values(&chart);
+
+    assert_eq!(chart.add_realtime_candle(candle(2 * 60_000, 500.0)), CandleOrder::Stale);
+
+    assert_eq!(closes(&chart, TimeInterval::TwoSeconds), base_before);
+    assert_eq!(closes(&chart, TimeInterval::OneMinute), minute_before);
+    assert_eq!(ma_values(&chart), ma_before);
+}
+
+#[wasm_bindgen_test]
+fn same_timestamp_updates_the_latest_bucket() {
+    let mut chart = streamed_chart();
+    assert_eq!(chart.add_realtime_candle(candle(4 * 60_000, 150.0)), CandleOrder::Update);
+
+    let base = closes(&chart, TimeInterval::TwoSeconds);
+    assert_eq!(base.len(), 5);
+    assert_eq!(base.last(), Some(&(4 * 60_000, 150.0)));
+}
+
+#[wasm_bindgen_test]
+fn newer_candle_is_appended_after_a_rejection() {
+    let mut chart = streamed_chart();
+    chart.add_realtime_candle(candle(60_000, 1.0));
+    assert_eq!(chart.add_realtime_candle(candle(5 * 60_000, 105.0)), CandleOrder::New);
+
+    let base = closes(&chart, TimeInterval::TwoSeconds);
+    assert_eq!(base.len(), 6);
+    assert!(base.windows(2).all(|w| w[0].0 < w[1].0));
+}
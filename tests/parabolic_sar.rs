@@ -0,0 +1,94 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    PSAR_MAX_STEP, PSAR_NAME, PSAR_STEP, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+/// Ten candles climbing by 2, then a gap down and a sell-off from candle 10
+fn rally_then_selloff() -> Vec<Candle> {
+    (0..16)
+        .map(|i| {
+            let close = if i < 10 { 100.0 + 2.0 * i as f64 } else { 90.0 - 4.0 * (i - 10) as f64 };
+            candle(i, close)
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn sar_trails_below_the_rally_and_flips_above_on_the_selloff() {
+    let candles = rally_then_selloff();
+    let psar = MarketAnalysisService::new().calculate_psar(&candles, PSAR_STEP, PSAR_MAX_STEP);
+    assert_eq!(psar.sar.len(), candles.len());
+
+    // Starts at the first low and never enters the previous candles' range
+    assert_eq!(psar.sar[0], 99.0);
+    assert_eq!(psar.sar[1], 99.0);
+    // Moves 6% of the way to the new high once two higher highs raised the factor
+    assert!((psar.sar[3] - (99.0 + 0.06 * (105.0 - 99.0))).abs() < 1e-9);
+
+    for (i, c) in candles.iter().enumerate().take(10) {
+        assert!(psar.rising[i], "candle {i}");
+        assert!(psar.sar[i] <= c.ohlcv.low.value(), "candle {i}");
+    }
+
+    // The sell-off breaks the SAR: it restarts at the rally's high above the candles
+    assert!(!psar.rising[10]);
+    assert_eq!(psar.sar[10], 119.0);
+    for (i, c) in candles.iter().enumerate().skip(10) {
+        assert!(!psar.rising[i], "candle {i}");
+        assert!(psar.sar[i] > c.ohlcv.high.value(), "candle {i}");
+    }
+}
+
+#[wasm_bindgen_test]
+fn acceleration_is_capped() {
+    let candles: Vec<Candle> = (0..60).map(|i| candle(i, 100.0 + 5.0 * i as f64)).collect();
+    let psar = MarketAnalysisService::new().calculate_psar(&candles, 0.02, 0.2);
+    let gaps: Vec<f64> =
+        candles.iter().zip(&psar.sar).map(|(c, sar)| c.ohlcv.high.value() - sar).collect();
+    // With the factor capped the distance to the extreme settles instead of shrinking to 0
+    assert!(gaps.iter().all(|gap| *gap > 0.0));
+    assert!((gaps[58] - gaps[59]).abs() < 1e-2);
+}
+
+#[wasm_bindgen_test]
+fn too_few_candles_give_no_sar() {
+    let psar = MarketAnalysisService::new().calculate_psar(&[candle(0, 100.0)], 0.02, 0.2);
+    assert!(psar.sar.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn sar_dots_follow_the_legend_toggle() {
+    let mut chart = Chart::new("psar".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(rally_then_selloff());
+    let mut renderer = dummy_renderer();
+
+    let dots = |verts: &[price_chart_wasm::infrastructure::rendering::CandleVertex]| {
+        verts.iter().filter(|v| v.element_type == 20.0).cloned().collect::<Vec<_>>()
+    };
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(dots(&verts).is_empty());
+
+    renderer.toggle_line_visibility(PSAR_NAME);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    let dots = dots(&verts);
+    assert_eq!(dots.len(), 16 * 6);
+    assert!(dots[..60].iter().all(|v| v.color_type == 1.0));
+    assert!(dots[60..].iter().all(|v| v.color_type == 0.0));
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    PriceDisplayMode, dummy_renderer, percent_from_base,
+};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn first_visible_close_is_zero_percent() {
+    let candles = vec![candle(0, 100.0), candle(1, 105.0), candle(2, 110.0)];
+    let mut chart = Chart::new("pct".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    let mut renderer = dummy_renderer();
+    renderer.cache_geometry_for_test(&chart);
+
+    let base = renderer.percent_base().unwrap();
+    assert_eq!(base, 100.0);
+    assert_eq!(percent_from_base(100.0, base), 0.0);
+    assert!((percent_from_base(110.0, base) - 10.0).abs() < 1e-9);
+}
+
+#[wasm_bindgen_test]
+fn percent_labels_are_signed() {
+    let mode = PriceDisplayMode::Percent;
+    assert_eq!(mode.label(110.0, Some(100.0), 2), "+10.00%");
+    assert_eq!(mode.label(95.0, Some(100.0), 2), "-5.00%");
+    assert_eq!(mode.label(100.0, Some(100.0), 2), "+0.00%");
+}
+
+#[wasm_bindgen_test]
+fn price_labels_ignore_the_base() {
+    assert_eq!(PriceDisplayMode::Price.label(110.0, Some(100.0), 2), "110.00");
+    assert_eq!(PriceDisplayMode::Percent.label(110.0, None, 2), "110.00");
+}
+
+#[wasm_bindgen_test]
+fn base_follows_the_visible_range() {
+    let candles = (0..40).map(|i| candle(i, 100.0 + i as f64)).collect();
+    let mut chart = Chart::new("pct".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    let mut renderer = dummy_renderer();
+
+    chart.viewport.start_time = 5.0 * 60_000.0;
+    renderer.cache_geometry_for_test(&chart);
+    assert_eq!(renderer.percent_base(), Some(105.0));
+
+    chart.viewport.start_time = 2.0 * 60_000.0;
+    renderer.cache_geometry_for_test(&chart);
+    assert_eq!(renderer.percent_base(), Some(102.0));
+}
+
+#[wasm_bindgen_test]
+fn price_labels_use_the_symbol_precision() {
+    assert_eq!(PriceDisplayMode::Price.label(0.00001234, None, 8), "0.00001234");
+    assert_eq!(PriceDisplayMode::Percent.label(0.000011, Some(0.00001), 8), "+10.00%");
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::infrastructure::rendering::renderer::{average_fps, dummy_renderer};
+use std::collections::VecDeque;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn average_of_empty_log_is_zero() {
+    assert_eq!(average_fps(&VecDeque::new()), 0.0);
+}
+
+#[wasm_bindgen_test]
+fn average_is_mean_of_samples() {
+    let log: VecDeque<f64> = [30.0, 60.0, 90.0].into_iter().collect();
+    assert!((average_fps(&log) - 60.0).abs() < 1e-9);
+}
+
+#[wasm_bindgen_test]
+fn average_covers_only_last_sixty_frames() {
+    let mut r = dummy_renderer();
+    for _ in 0..60 {
+        r.record_fps(10.0);
+    }
+    for _ in 0..60 {
+        r.record_fps(50.0);
+    }
+    assert!((r.average_fps() - 50.0).abs() < 1e-9);
+
+    let stats = r.perf_stats(42);
+    assert_eq!(stats.candle_count, 42);
+    assert!((stats.avg_fps - 50.0).abs() < 1e-9);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{PriceScaleMode, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn chart() -> Chart {
+    let candles: Vec<Candle> = (0..30)
+        .map(|i| {
+            let base = 100.0 + i as f64 * 3.0;
+            Candle::new(
+                Timestamp::from_millis(i * 60_000),
+                OHLCV::new(
+                    Price::from(base),
+                    Price::from(base + 2.0),
+                    Price::from(base - 2.0),
+                    Price::from(base + 1.0),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("alerts".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn alerts_are_deduplicated_and_removed_by_tolerance() {
+    let mut chart = chart();
+    assert!(chart.add_price_alert(150.0));
+    assert!(!chart.add_price_alert(150.0));
+    assert!(chart.add_price_alert(150.5));
+    assert!(!chart.add_price_alert(f64::NAN));
+    assert!(!chart.add_price_alert(-1.0));
+    assert_eq!(chart.price_alerts, vec![150.0, 150.5]);
+
+    assert!(!chart.remove_price_alert(151.0, 0.25));
+    assert!(chart.remove_price_alert(150.4, 0.2));
+    assert_eq!(chart.price_alerts, vec![150.0]);
+    assert!(chart.remove_price_alert(150.0, 0.0));
+    assert!(chart.price_alerts.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn screen_y_maps_back_to_alert_price() {
+    for mode in [PriceScaleMode::Linear, PriceScaleMode::Logarithmic] {
+        let mut chart = chart();
+        chart.add_price_alert(140.0);
+        let mut renderer = dummy_renderer();
+        renderer.set_price_scale_mode(mode);
+        renderer.cache_geometry_for_test(&chart);
+
+        let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+        let alert_ys: Vec<f32> = verts
+            .iter()
+            .filter(|v| (v.element_type - 10.0).abs() < f32::EPSILON)
+            .map(|v| v.position_y)
+            .collect();
+        assert!(!alert_ys.is_empty());
+        let y = alert_ys.iter().sum::<f32>() / alert_ys.len() as f32;
+
+        let price = renderer.price_at_ndc_y(y).expect("price inside the candle area");
+        assert!((price - 140.0).abs() < 0.05, "{mode:?}: {price}");
+    }
+}
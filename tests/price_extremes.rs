@@ -0,0 +1,103 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{dummy_renderer, price_extremes};
+use wasm_bindgen_test::*;
+
+/// (low, high, close) of each candle: the high peaks at index 7, the low at index 3
+const RANGES: [(f64, f64, f64); 10] = [
+    (99.0, 102.0, 101.0),
+    (98.0, 103.0, 100.0),
+    (97.0, 101.0, 98.0),
+    (94.0, 99.0, 95.0),
+    (96.0, 104.0, 103.0),
+    (100.0, 107.0, 106.0),
+    (103.0, 109.0, 104.0),
+    (104.0, 112.0, 105.0),
+    (101.0, 108.0, 107.0),
+    (99.0, 108.0, 102.0),
+];
+
+fn candles() -> Vec<Candle> {
+    RANGES
+        .iter()
+        .enumerate()
+        .map(|(i, &(low, high, close))| {
+            Candle::new(
+                Timestamp::from_millis(i as u64 * 60_000),
+                OHLCV::new(
+                    Price::from((low + high) / 2.0),
+                    Price::from(high),
+                    Price::from(low),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn make_chart(chart_type: ChartType) -> Chart {
+    let mut chart = Chart::new("extremes".to_string(), chart_type, 100);
+    chart.set_historical_data(candles());
+    chart
+}
+
+fn marker_vertices(verts: &[CandleVertex]) -> Vec<&CandleVertex> {
+    verts.iter().filter(|v| (v.element_type - 15.0).abs() < f32::EPSILON).collect()
+}
+
+#[wasm_bindgen_test]
+fn finds_high_and_low_indices() {
+    let extremes = price_extremes(&candles(), false).unwrap();
+    assert_eq!(extremes.high_index, 7);
+    assert_eq!(extremes.high, 112.0);
+    assert_eq!(extremes.low_index, 3);
+    assert_eq!(extremes.low, 94.0);
+}
+
+#[wasm_bindgen_test]
+fn close_only_extremes_follow_the_closes() {
+    let extremes = price_extremes(&candles(), true).unwrap();
+    assert_eq!(extremes.high_index, 8);
+    assert_eq!(extremes.high, 107.0);
+    assert_eq!(extremes.low_index, 3);
+    assert_eq!(extremes.low, 95.0);
+}
+
+#[wasm_bindgen_test]
+fn ties_keep_the_earliest_candle() {
+    let candles = candles();
+    let tied = [candles[8].clone(), candles[9].clone()];
+    assert_eq!(price_extremes(&tied, false).unwrap().high_index, 0);
+    assert!(price_extremes(&[], false).is_none());
+}
+
+#[wasm_bindgen_test]
+fn renderer_exposes_visible_extremes() {
+    let chart = make_chart(ChartType::Candlestick);
+    let renderer = dummy_renderer();
+    let extremes = renderer.visible_extremes(&chart).unwrap();
+    assert_eq!((extremes.high_index, extremes.low_index), (7, 3));
+}
+
+#[wasm_bindgen_test]
+fn markers_point_at_the_high_and_low() {
+    let chart = make_chart(ChartType::Candlestick);
+    let renderer = dummy_renderer();
+    let (instances, verts, _) = renderer.create_geometry_for_test(&chart);
+    let markers = marker_vertices(&verts);
+    assert_eq!(markers.len(), 6);
+
+    // High marker in the buy color sits above its candle, tip first
+    let (high_tip, low_tip) = (markers[0], markers[3]);
+    assert_eq!(high_tip.color_type, 1.0);
+    assert_eq!(low_tip.color_type, 0.0);
+    assert!((high_tip.position_x - instances[7].x).abs() < 1e-6);
+    assert!((low_tip.position_x - instances[3].x).abs() < 1e-6);
+    assert!(high_tip.position_y > instances[7].high);
+    assert!(low_tip.position_y < instances[3].low);
+    assert!(markers[1].position_y > high_tip.position_y);
+    assert!(markers[4].position_y < low_tip.position_y);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    MAX_PRICE_PADDING_PCT, PricePadding, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+/// Candles all trading between 100 and 110 so every indicator stays inside
+fn flat_chart() -> Chart {
+    let mut chart = Chart::new("padding".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(
+        (0..30)
+            .map(|i| {
+                Candle::new(
+                    Timestamp::from_millis(i * 60_000),
+                    OHLCV::new(
+                        Price::from(105.0),
+                        Price::from(110.0),
+                        Price::from(100.0),
+                        Price::from(105.0),
+                        Volume::from(1.0),
+                    ),
+                )
+            })
+            .collect(),
+    );
+    chart
+}
+
+fn price_window(padding: Option<PricePadding>) -> (f32, f32) {
+    let mut renderer = dummy_renderer();
+    if let Some(padding) = padding {
+        renderer.set_price_padding(padding);
+    }
+    let (_, _, uniforms) = renderer.create_geometry_for_test(&flat_chart());
+    (uniforms.viewport[2], uniforms.viewport[3])
+}
+
+fn assert_close(actual: (f32, f32), expected: (f32, f32)) {
+    assert!(
+        (actual.0 - expected.0).abs() < 1e-3 && (actual.1 - expected.1).abs() < 1e-3,
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn default_padding_adds_five_percent_on_both_sides() {
+    assert_eq!(PricePadding::default(), PricePadding::new(5.0, 5.0));
+    assert_close(price_window(None), (99.5, 110.5));
+}
+
+#[wasm_bindgen_test]
+fn top_and_bottom_padding_are_applied_separately() {
+    assert_close(price_window(Some(PricePadding::new(20.0, 0.0))), (100.0, 112.0));
+    assert_close(price_window(Some(PricePadding::new(0.0, 50.0))), (95.0, 110.0));
+    assert_close(price_window(Some(PricePadding::new(0.0, 0.0))), (100.0, 110.0));
+}
+
+#[wasm_bindgen_test]
+fn padding_is_clamped() {
+    let padding = PricePadding::new(-5.0, 1_000.0);
+    assert_eq!(padding.top_pct, 0.0);
+    assert_eq!(padding.bottom_pct, MAX_PRICE_PADDING_PCT);
+    assert_eq!(PricePadding::new(f64::NAN, 5.0).top_pct, 0.0);
+}
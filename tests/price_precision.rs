@@ -0,0 +1,29 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::{
+    DEFAULT_PRICE_DECIMALS, decimals_for_tick_size, format_price,
+};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn decimals_follow_the_tick_size() {
+    assert_eq!(decimals_for_tick_size(0.01), 2);
+    assert_eq!(decimals_for_tick_size(0.001), 3);
+    assert_eq!(decimals_for_tick_size(0.0001), 4);
+    assert_eq!(decimals_for_tick_size(0.00000001), 8);
+    assert_eq!(decimals_for_tick_size(1.0), 0);
+    assert_eq!(decimals_for_tick_size(10.0), 0);
+}
+
+#[wasm_bindgen_test]
+fn invalid_tick_size_uses_default_decimals() {
+    assert_eq!(decimals_for_tick_size(0.0), DEFAULT_PRICE_DECIMALS);
+    assert_eq!(decimals_for_tick_size(f64::NAN), DEFAULT_PRICE_DECIMALS);
+}
+
+#[wasm_bindgen_test]
+fn prices_are_formatted_with_the_requested_precision() {
+    assert_eq!(format_price(50123.456, 2), "50123.46");
+    assert_eq!(format_price(0.12345, 4), "0.1235");
+    assert_eq!(format_price(0.00001234, 8), "0.00001234");
+    assert_eq!(format_price(1.5, 12), "1.50000000");
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{
+    MAX_CANDLES_PER_SCREEN, QuickRange, quick_range_candle_count, viewport_zoom_pan, visible_range,
+};
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn candle(minute: u64) -> Candle {
+    let close = 100.0 + (minute % 7) as f64;
+    Candle::new(
+        Timestamp::from_millis(minute * 60_000),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn durations_convert_to_candle_counts() {
+    let day = QuickRange::Day.duration_ms();
+    assert_eq!(quick_range_candle_count(day, TimeInterval::FifteenMinutes), 96);
+    assert_eq!(quick_range_candle_count(day, TimeInterval::OneHour), 24);
+    assert_eq!(
+        quick_range_candle_count(QuickRange::Week.duration_ms(), TimeInterval::OneHour),
+        168
+    );
+    assert_eq!(quick_range_candle_count(QuickRange::Month.duration_ms(), TimeInterval::OneDay), 30);
+    assert_eq!(
+        quick_range_candle_count(QuickRange::Month.duration_ms(), TimeInterval::FourHours),
+        180
+    );
+}
+
+#[wasm_bindgen_test]
+fn partial_candles_round_up() {
+    assert_eq!(quick_range_candle_count(QuickRange::Day.duration_ms(), TimeInterval::OneWeek), 1);
+    assert_eq!(quick_range_candle_count(QuickRange::Month.duration_ms(), TimeInterval::OneWeek), 5);
+}
+
+#[wasm_bindgen_test]
+fn ranges_wider_than_a_screen_are_downsampled_to_fit() {
+    let count = quick_range_candle_count(QuickRange::Day.duration_ms(), TimeInterval::OneMinute);
+    assert_eq!(count, 1440);
+    assert!(count > MAX_CANDLES_PER_SCREEN);
+    assert_eq!(
+        quick_range_candle_count(QuickRange::Month.duration_ms(), TimeInterval::OneMinute),
+        30 * 1440
+    );
+
+    // A day of minutes with an hour of older history, viewed like the 1D button does
+    let len = count + 60;
+    let mut chart = Chart::new("range".to_string(), ChartType::Candlestick, len);
+    chart.set_historical_data((0..len as u64).map(candle).collect());
+    let candles = chart.get_series(TimeInterval::OneMinute).unwrap().get_candles().clone();
+    chart.viewport.start_time = candles[len - count].timestamp.value() as f64;
+    chart.viewport.end_time = candles[len - 1].timestamp.value() as f64;
+    let (zoom, pan) = viewport_zoom_pan(&candles, &chart.viewport);
+    assert_eq!(visible_range(len, zoom, pan), (len - count, count));
+
+    let mut renderer = dummy_renderer();
+    renderer.set_zoom_params(zoom, pan);
+    let (instances, _, _) = renderer.create_geometry_for_test(&chart);
+    // Two candles per element fit 1440 candles into the 800 px canvas
+    assert_eq!(instances.len(), count / 2);
+}
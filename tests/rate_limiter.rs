@@ -0,0 +1,60 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::rate_limited_status;
+use price_chart_wasm::infrastructure::rate_limiter::{
+    HISTORY_RATE_CAPACITY, RateLimit, TokenBucket, with_history_rate_limiter,
+};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn bucket_allows_a_burst_then_rejects() {
+    let mut bucket = TokenBucket::new(RateLimit::new(3, 1.0), 10_000);
+    assert!((0..3).all(|_| bucket.try_acquire(10_000)));
+    assert!(!bucket.try_acquire(10_000));
+    assert!(!bucket.try_acquire(10_500));
+    assert_eq!(bucket.available(10_500), 0);
+}
+
+#[wasm_bindgen_test]
+fn bucket_refills_at_the_configured_rate() {
+    let mut bucket = TokenBucket::new(RateLimit::new(4, 2.0), 0);
+    for _ in 0..4 {
+        assert!(bucket.try_acquire(0));
+    }
+    assert_eq!(bucket.retry_after_ms(0), 500);
+    assert_eq!(bucket.retry_after_ms(250), 250);
+
+    // One second at two tokens per second allows exactly two more requests
+    assert_eq!(bucket.available(1_000), 2);
+    assert!(bucket.try_acquire(1_000));
+    assert!(bucket.try_acquire(1_000));
+    assert!(!bucket.try_acquire(1_000));
+
+    // A long pause never fills past the capacity
+    assert_eq!(bucket.available(60_000), 4);
+}
+
+#[wasm_bindgen_test]
+fn changing_the_limit_caps_the_tokens_left() {
+    let mut bucket = TokenBucket::new(RateLimit::new(10, 1.0), 0);
+    bucket.set_limit(RateLimit::new(2, 5.0));
+    assert_eq!(bucket.limit(), RateLimit::new(2, 5.0));
+    assert_eq!(bucket.available(0), 2);
+}
+
+#[wasm_bindgen_test]
+fn invalid_limits_fall_back_to_usable_values() {
+    let limit = RateLimit::new(0, 0.0);
+    assert_eq!(limit.capacity, 1);
+    assert_eq!(limit.refill_per_sec, 1.0);
+    assert_eq!(RateLimit::new(2, f64::NAN).refill_per_sec, 1.0);
+}
+
+#[wasm_bindgen_test]
+fn history_limiter_starts_with_a_full_burst() {
+    let now = 1_000_000;
+    let allowed = with_history_rate_limiter(|limiter| {
+        (0..HISTORY_RATE_CAPACITY + 2).filter(|_| limiter.try_acquire(now)).count()
+    });
+    assert_eq!(allowed, HISTORY_RATE_CAPACITY as usize);
+    assert_eq!(rate_limited_status(1_500), "⏳ Rate limited: next history request in 1.5s");
+}
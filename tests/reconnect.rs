@@ -13,7 +13,7 @@ async fn reconnect_called_on_failure() {
     let mut client = BinanceWebSocketClient::new(Symbol::from("BTCUSDT"), TimeInterval::OneMinute);
     let called = Rc::new(RefCell::new(0));
     let flag = called.clone();
-    let fut = client.start_stream_with_callback(|_| {}, || *flag.borrow_mut() += 1);
+    let fut = client.start_stream_with_callback(|_| {}, |_| *flag.borrow_mut() += 1);
     let _ = select(Box::pin(fut), Box::pin(sleep(Duration::from_millis(10)))).await;
     assert!(*called.borrow() > 0);
 }
@@ -0,0 +1,28 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::infrastructure::websocket::binance_client::ReconnectBackoff;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn backoff_grows_caps_and_resets() {
+    let mut backoff = ReconnectBackoff::default();
+
+    let delays: Vec<u64> = (0..7).map(|_| backoff.next_delay().as_secs()).collect();
+    assert_eq!(delays, vec![1, 2, 4, 8, 16, 30, 30]);
+    assert_eq!(backoff.attempt(), 7);
+
+    // A successful message resets the schedule
+    backoff.reset();
+    assert_eq!(backoff.attempt(), 0);
+    assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+    assert_eq!(backoff.next_delay(), Duration::from_secs(2));
+}
+
+#[wasm_bindgen_test]
+fn backoff_survives_many_failures() {
+    let mut backoff = ReconnectBackoff::new(1_000, 30_000);
+    for _ in 0..100 {
+        assert!(backoff.next_delay() <= Duration::from_secs(30));
+    }
+    assert_eq!(backoff.attempt(), 100);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    DEFAULT_MAX_FPS, RenderThrottle, max_fps, min_frame_interval_ms, set_max_fps,
+};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn first_task_runs_immediately() {
+    let mut throttle = RenderThrottle::new(16.0);
+    assert_eq!(throttle.offer(1, 1000.0), Some(1));
+    assert!(!throttle.has_pending());
+}
+
+#[wasm_bindgen_test]
+fn tasks_within_the_interval_are_coalesced() {
+    let mut throttle = RenderThrottle::new(16.0);
+    assert_eq!(throttle.offer(1, 0.0), Some(1));
+    assert_eq!(throttle.offer(2, 4.0), None);
+    assert_eq!(throttle.offer(3, 8.0), None);
+    assert_eq!(throttle.wait_ms(8.0), 8.0);
+    assert_eq!(throttle.take_due(15.0), None);
+
+    // Only the newest task survives; task 2 was dropped
+    assert_eq!(throttle.take_due(16.0), Some(3));
+    assert_eq!(throttle.take_due(40.0), None);
+}
+
+#[wasm_bindgen_test]
+fn tasks_spaced_beyond_the_interval_all_run() {
+    let mut throttle = RenderThrottle::new(16.0);
+    let ran: Vec<_> = (0..4).filter_map(|i| throttle.offer(i, i as f64 * 20.0)).collect();
+    assert_eq!(ran, vec![0, 1, 2, 3]);
+}
+
+#[wasm_bindgen_test]
+fn uncapped_throttle_runs_every_task() {
+    let mut throttle = RenderThrottle::new(min_frame_interval_ms(0));
+    assert_eq!(throttle.offer(1, 5.0), Some(1));
+    assert_eq!(throttle.offer(2, 5.0), Some(2));
+}
+
+#[wasm_bindgen_test]
+fn fps_cap_sets_the_frame_interval() {
+    assert_eq!(max_fps(), DEFAULT_MAX_FPS);
+    assert_eq!(min_frame_interval_ms(50), 20.0);
+    set_max_fps(30);
+    assert_eq!(max_fps(), 30);
+    set_max_fps(DEFAULT_MAX_FPS);
+}
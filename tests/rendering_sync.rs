@@ -36,14 +36,12 @@ fn sample_chart() -> Chart {
 fn body_positions_within_bounds() {
     let chart = sample_chart();
     let renderer = dummy_renderer();
-    let (_instances, vertices, _uniforms) = renderer.create_geometry_for_test(&chart);
+    let (instances, _vertices, _uniforms) = renderer.create_geometry_for_test(&chart);
 
-    let body: Vec<_> = vertices.iter().filter(|v| v.element_type == 0.0).collect();
+    assert_eq!(instances.len(), 2);
 
-    assert!(body.len() >= 36); // two candles * 18 vertices each
-
-    let first_body_x = body[0].position_x;
-    let second_body_x = body[18].position_x;
+    let first_body_x = instances[0].x;
+    let second_body_x = instances[1].x;
 
     assert_ne!(first_body_x, second_body_x);
     for &x in &[first_body_x, second_body_x] {
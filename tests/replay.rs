@@ -0,0 +1,122 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::replay_start_index;
+use price_chart_wasm::domain::chart::{
+    Chart, ReplayController, ReplaySpeed, ReplayState, value_objects::ChartType,
+};
+use price_chart_wasm::domain::market_data::{
+    Candle, CandleSeries, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64) -> Candle {
+    let price = 100.0 + i as f64;
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(price),
+            Price::from(price + 1.0),
+            Price::from(price - 1.0),
+            Price::from(price + 0.5),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn series(len: u64) -> CandleSeries {
+    let mut series = CandleSeries::new(100);
+    for i in 0..len {
+        series.add_candle(candle(i));
+    }
+    series
+}
+
+#[wasm_bindgen_test]
+fn replay_starts_paused_with_history_before_start() {
+    let replay = ReplayController::new(&series(10), 4);
+    assert_eq!(replay.state(), ReplayState::Paused);
+    assert_eq!(replay.history().len(), 4);
+    assert_eq!(replay.position(), 4);
+    assert_eq!(replay.len(), 10);
+}
+
+#[wasm_bindgen_test]
+fn ticks_only_advance_while_playing() {
+    let mut replay = ReplayController::new(&series(10), 4);
+    assert!(replay.tick().is_none());
+
+    replay.play();
+    assert_eq!(replay.state(), ReplayState::Playing);
+    assert_eq!(replay.tick().unwrap().timestamp, candle(4).timestamp);
+    assert_eq!(replay.tick().unwrap().timestamp, candle(5).timestamp);
+
+    replay.pause();
+    assert_eq!(replay.state(), ReplayState::Paused);
+    assert!(replay.tick().is_none());
+    assert_eq!(replay.position(), 6);
+}
+
+#[wasm_bindgen_test]
+fn step_advances_exactly_one_candle_and_pauses() {
+    let mut replay = ReplayController::new(&series(10), 4);
+    assert_eq!(replay.step().unwrap().timestamp, candle(4).timestamp);
+    assert_eq!(replay.position(), 5);
+
+    replay.play();
+    assert_eq!(replay.step().unwrap().timestamp, candle(5).timestamp);
+    assert_eq!(replay.state(), ReplayState::Paused);
+    assert_eq!(replay.position(), 6);
+}
+
+#[wasm_bindgen_test]
+fn replay_finishes_after_the_last_candle() {
+    let mut replay = ReplayController::new(&series(3), 1);
+    replay.play();
+    assert!(replay.tick().is_some());
+    assert!(replay.tick().is_some());
+    assert_eq!(replay.state(), ReplayState::Finished);
+    assert!(replay.tick().is_none());
+    assert!(replay.step().is_none());
+
+    replay.play();
+    assert_eq!(replay.state(), ReplayState::Finished);
+}
+
+#[wasm_bindgen_test]
+fn start_beyond_the_series_is_clamped() {
+    let replay = ReplayController::new(&series(5), 50);
+    assert_eq!(replay.history().len(), 5);
+    assert_eq!(replay.state(), ReplayState::Finished);
+}
+
+#[wasm_bindgen_test]
+fn speeds_shorten_the_tick() {
+    assert_eq!(ReplaySpeed::default(), ReplaySpeed::Normal);
+    let ticks: Vec<u32> = ReplaySpeed::ALL.iter().map(|s| s.tick_ms()).collect();
+    assert_eq!(ticks, vec![1_000, 500, 200]);
+    assert_eq!(ReplaySpeed::Fivefold.to_string(), "5x");
+
+    let mut replay = ReplayController::new(&series(5), 2);
+    replay.set_speed(ReplaySpeed::Double);
+    assert_eq!(replay.speed(), ReplaySpeed::Double);
+}
+
+#[wasm_bindgen_test]
+fn replayed_candles_rebuild_the_chart_like_realtime_ones() {
+    let mut replay = ReplayController::new(&series(10), 6);
+    let mut chart = Chart::new("replay".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(replay.history().to_vec());
+    replay.play();
+    while let Some(candle) = replay.tick() {
+        chart.add_realtime_candle(candle);
+    }
+    let replayed = chart.get_series(TimeInterval::OneMinute).unwrap();
+    assert_eq!(replayed.count(), 10);
+}
+
+#[wasm_bindgen_test]
+fn blank_start_input_replays_from_halfway() {
+    assert_eq!(replay_start_index("", 1000), 500);
+    assert_eq!(replay_start_index(" 120 ", 1000), 120);
+    assert_eq!(replay_start_index("5000", 1000), 1000);
+    assert_eq!(replay_start_index("abc", 10), 5);
+}
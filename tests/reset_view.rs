@@ -0,0 +1,18 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{DEFAULT_ZOOM_LEVEL, reset_view_params, visible_range};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn reset_view_uses_default_zoom_and_latest_candles() {
+    for count in [0usize, 1, 10, 32, 33, 500] {
+        let (zoom, pan, start) = reset_view_params(count);
+        assert_eq!(zoom, DEFAULT_ZOOM_LEVEL);
+        assert_eq!(pan, 0.0);
+        assert_eq!(start, count.saturating_sub(32), "count {count}");
+
+        // The window ends at the newest candle
+        let (range_start, visible) = visible_range(count, zoom, pan);
+        assert_eq!(range_start, start);
+        assert_eq!(range_start + visible, count);
+    }
+}
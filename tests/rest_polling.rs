@@ -0,0 +1,69 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{current_symbol, merge_polled_candles, route_stream_candle};
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, Symbol, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::global_state::ensure_chart;
+use wasm_bindgen_test::*;
+
+fn candle(ts: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(ts),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close),
+            Price::from(close),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn series(symbol: &Symbol) -> Vec<(u64, f64)> {
+    ensure_chart(symbol).with_untracked(|c| {
+        c.get_series(TimeInterval::OneMinute)
+            .unwrap()
+            .get_candles()
+            .iter()
+            .map(|c| (c.timestamp.value(), c.ohlcv.close.value()))
+            .collect()
+    })
+}
+
+#[wasm_bindgen_test]
+fn polled_candles_revise_the_last_one_and_append_newer_ones() {
+    let symbol = Symbol::from("POLLA");
+    current_symbol().set(symbol.clone());
+    for (ts, close) in [(0, 10.0), (60_000, 11.0), (120_000, 12.0)] {
+        route_stream_candle(&symbol, candle(ts, close));
+    }
+
+    let merged = merge_polled_candles(
+        &symbol,
+        vec![
+            candle(240_000, 15.0),
+            candle(60_000, 99.0),
+            candle(120_000, 12.5),
+            candle(180_000, 13.0),
+            candle(180_000, 14.0),
+        ],
+    );
+
+    assert_eq!(merged, 3);
+    assert_eq!(
+        series(&symbol),
+        vec![(0, 10.0), (60_000, 11.0), (120_000, 12.5), (180_000, 14.0), (240_000, 15.0)]
+    );
+}
+
+#[wasm_bindgen_test]
+fn polling_an_unchanged_window_adds_nothing_new() {
+    let symbol = Symbol::from("POLLB");
+    current_symbol().set(symbol.clone());
+    let polled = vec![candle(0, 10.0), candle(60_000, 11.0)];
+    assert_eq!(merge_polled_candles(&symbol, polled.clone()), 2);
+    // Only the in-progress candle is revised again
+    assert_eq!(merge_polled_candles(&symbol, polled), 1);
+    assert_eq!(series(&symbol), vec![(0, 10.0), (60_000, 11.0)]);
+}
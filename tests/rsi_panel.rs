@@ -0,0 +1,77 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{SubPanelKind, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn candles_from(closes: &[f64]) -> Vec<Candle> {
+    closes
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| {
+            Candle::new(
+                Timestamp::from_millis(i as u64 * 60_000),
+                OHLCV::new(
+                    Price::from(close),
+                    Price::from(close + 0.5),
+                    Price::from(close - 0.5),
+                    Price::from(close),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn rsi_is_bounded() {
+    let closes: Vec<f64> = (0..200).map(|i| 100.0 + (i as f64 * 0.7).sin() * 15.0).collect();
+    let rsi = MarketAnalysisService::new().calculate_rsi(&candles_from(&closes), 14);
+    assert_eq!(rsi.len(), 200 - 14);
+    assert!(rsi.iter().all(|v| (0.0..=100.0).contains(v)));
+}
+
+#[wasm_bindgen_test]
+fn rsi_of_rising_series_approaches_100() {
+    let closes: Vec<f64> = (0..60).map(|i| 100.0 + i as f64).collect();
+    let rsi = MarketAnalysisService::new().calculate_rsi(&candles_from(&closes), 14);
+    assert!(rsi.iter().all(|v| *v > 99.0));
+
+    let falling: Vec<f64> = closes.iter().rev().copied().collect();
+    let rsi = MarketAnalysisService::new().calculate_rsi(&candles_from(&falling), 14);
+    assert!(rsi.iter().all(|v| *v < 1.0));
+}
+
+#[wasm_bindgen_test]
+fn rsi_panel_compresses_main_area() {
+    let closes: Vec<f64> = (0..30).map(|i| 100.0 + (i as f64 * 0.5).sin() * 3.0).collect();
+    let mut chart = Chart::new("rsi".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles_from(&closes));
+    let mut renderer = dummy_renderer();
+
+    let (plain, _, _) = renderer.create_geometry_for_test(&chart);
+    assert!(plain.iter().any(|i| i.low < -0.8));
+
+    renderer.toggle_line_visibility("rsi");
+    let layout = renderer.panel_layout();
+    let panel = *layout.panel(SubPanelKind::Rsi).expect("rsi panel");
+    assert!((panel.bottom + 1.0).abs() < f32::EPSILON);
+    assert!((layout.main_bottom - panel.top).abs() < f32::EPSILON);
+
+    let (instances, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(instances.iter().all(|i| i.low >= layout.main_bottom - 1e-6));
+
+    let rsi_verts: Vec<_> = verts
+        .iter()
+        .filter(|v| (v.element_type - 2.0).abs() < f32::EPSILON && v.color_type == 18.0)
+        .collect();
+    assert_eq!(rsi_verts.len(), (30 - 14 - 1) * 6);
+    assert!(
+        rsi_verts
+            .iter()
+            .all(|v| v.position_y >= panel.bottom - 0.01 && v.position_y <= panel.top + 0.01)
+    );
+    let guides = verts.iter().filter(|v| v.color_type == 19.0).count();
+    assert_eq!(guides, 2 * 6);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::infrastructure::rendering::renderer::{padded_bytes_per_row, unpad_rows};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn row_stride_is_aligned_to_256_bytes() {
+    assert_eq!(padded_bytes_per_row(1), 256);
+    assert_eq!(padded_bytes_per_row(64), 256);
+    assert_eq!(padded_bytes_per_row(65), 512);
+    assert_eq!(padded_bytes_per_row(800), 3328);
+    assert_eq!(padded_bytes_per_row(1024), 4096);
+    for width in 1..600 {
+        let padded = padded_bytes_per_row(width);
+        assert_eq!(padded % 256, 0);
+        assert!(padded >= width * 4 && padded < width * 4 + 256);
+    }
+}
+
+#[wasm_bindgen_test]
+fn unpadding_keeps_pixels_and_swaps_bgra() {
+    let (width, height) = (3, 2);
+    let padded = padded_bytes_per_row(width) as usize;
+    let mut data = vec![0xAAu8; padded * height as usize];
+    for row in 0..height as usize {
+        for col in 0..width as usize {
+            let offset = row * padded + col * 4;
+            data[offset..offset + 4].copy_from_slice(&[row as u8, col as u8, 7, 255]);
+        }
+    }
+
+    let rgba = unpad_rows(&data, width, height, false);
+    assert_eq!(rgba.len(), (width * height * 4) as usize);
+    assert_eq!(&rgba[4 * 4..4 * 5], &[1, 1, 7, 255]);
+    assert!(!rgba.contains(&0xAA));
+
+    let swapped = unpad_rows(&data, width, height, true);
+    assert_eq!(&swapped[4 * 4..4 * 5], &[7, 1, 1, 255]);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::infrastructure::http::server_time::{
+    apply_offset, compute_offset, server_now, set_server_time_offset,
+};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn offset_applied_to_local_timestamp() {
+    assert_eq!(apply_offset(1_000_000, 250), 1_000_250);
+    assert_eq!(apply_offset(1_000_000, -1_500), 998_500);
+    assert_eq!(apply_offset(100, -1_000), 0);
+}
+
+#[wasm_bindgen_test]
+fn offset_uses_request_midpoint() {
+    // Request took 200ms, server answered at local midpoint + 500ms
+    assert_eq!(compute_offset(10_600, 10_000, 10_200), 500);
+    assert_eq!(compute_offset(9_600, 10_000, 10_200), -500);
+}
+
+#[wasm_bindgen_test]
+fn server_now_follows_offset() {
+    set_server_time_offset(60_000);
+    let local = js_sys::Date::now() as u64;
+    let corrected = server_now();
+    set_server_time_offset(0);
+    assert!(corrected >= local + 60_000);
+    assert!(corrected < local + 61_000);
+}
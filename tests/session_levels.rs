@@ -0,0 +1,88 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::{
+    MarketAnalysisService, SESSION_MS, SessionLevelKind, utc_session,
+};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    LineVisibility, SESSION_LEVELS_NAME, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn candle(ts: u64, open: f64, high: f64, low: f64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(ts),
+        OHLCV::new(
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn two_sessions() -> Vec<Candle> {
+    vec![
+        // Previous session with a wider range
+        candle(SESSION_MS - 120_000, 90.0, 200.0, 50.0, 95.0),
+        candle(SESSION_MS - 60_000, 95.0, 96.0, 94.0, 99.0),
+        // Current session
+        candle(SESSION_MS, 100.0, 104.0, 98.0, 103.0),
+        candle(SESSION_MS + 60_000, 103.0, 110.0, 101.0, 108.0),
+        candle(SESSION_MS + 120_000, 108.0, 109.0, 97.0, 99.0),
+    ]
+}
+
+fn session_vertices(verts: &[CandleVertex]) -> usize {
+    verts.iter().filter(|v| v.element_type == 18.0).count()
+}
+
+#[wasm_bindgen_test]
+fn sessions_start_at_utc_midnight() {
+    assert_eq!(utc_session(SESSION_MS - 1), 0);
+    assert_eq!(utc_session(SESSION_MS), 1);
+}
+
+#[wasm_bindgen_test]
+fn open_comes_from_the_first_candle_after_the_boundary() {
+    let levels = MarketAnalysisService::new().calculate_session_levels(&two_sessions()).unwrap();
+    assert_eq!(levels.start, SESSION_MS);
+    assert_eq!(levels.open, 100.0);
+    assert_eq!(levels.high, 110.0);
+    assert_eq!(levels.low, 97.0);
+}
+
+#[wasm_bindgen_test]
+fn high_and_low_track_new_candles() {
+    let service = MarketAnalysisService::new();
+    let mut candles = two_sessions();
+    candles.truncate(3);
+    let levels = service.calculate_session_levels(&candles).unwrap();
+    assert_eq!((levels.open, levels.high, levels.low), (100.0, 104.0, 98.0));
+
+    candles.push(candle(SESSION_MS + 60_000, 103.0, 110.0, 101.0, 108.0));
+    let levels = service.calculate_session_levels(&candles).unwrap();
+    assert_eq!((levels.open, levels.high, levels.low), (100.0, 110.0, 98.0));
+
+    assert!(service.calculate_session_levels(&[]).is_none());
+}
+
+#[wasm_bindgen_test]
+fn session_lines_are_drawn_when_enabled() {
+    let mut chart = Chart::new("session".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(two_sessions());
+    let mut renderer = dummy_renderer();
+    assert!(!LineVisibility::default().session_levels);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(session_vertices(&verts), 0);
+    assert!(renderer.session_levels(&chart).is_none());
+
+    renderer.toggle_line_visibility(SESSION_LEVELS_NAME);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(session_vertices(&verts), 18);
+    let kinds: Vec<_> =
+        renderer.session_levels(&chart).unwrap().levels().iter().map(|(k, _)| *k).collect();
+    assert_eq!(kinds, [SessionLevelKind::Open, SessionLevelKind::High, SessionLevelKind::Low]);
+}
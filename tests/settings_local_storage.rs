@@ -0,0 +1,30 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::HistoryWindow;
+use price_chart_wasm::domain::market_data::{Symbol, TimeInterval};
+use price_chart_wasm::infrastructure::rendering::renderer::LineVisibility;
+use price_chart_wasm::infrastructure::storage::{
+    ChartSettings, SETTINGS_VERSION, load_settings, save_settings,
+};
+use price_chart_wasm::time_utils::TimeZone;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn settings_round_trip_through_local_storage() {
+    let mut line_visibility = LineVisibility::default();
+    line_visibility.toggle("ema12");
+    line_visibility.toggle("vwap");
+    let settings = ChartSettings {
+        version: SETTINGS_VERSION,
+        symbol: Symbol::from("SOLUSDT"),
+        interval: TimeInterval::OneHour,
+        line_visibility,
+        history_window: HistoryWindow::new(500, 2000),
+        time_zone: TimeZone::Offset(120),
+        zoom_sensitivity: 2.5,
+    };
+
+    assert!(save_settings(&settings));
+    assert_eq!(load_settings(), Some(settings));
+}
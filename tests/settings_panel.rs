@@ -0,0 +1,55 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{
+    candle_sizing, chart_theme, settings_panel_open, show_wicks, toggle_light_theme,
+    toggle_settings_panel, toggle_wicks,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    CandleSizing, dummy_renderer, set_global_renderer, with_global_renderer,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn wicks_toggle_updates_signal() {
+    set_global_renderer(Rc::new(RefCell::new(dummy_renderer())));
+    assert!(show_wicks().get_untracked());
+
+    toggle_wicks();
+    assert!(!show_wicks().get_untracked());
+    assert_eq!(with_global_renderer(|r| r.show_wicks()), Some(false));
+
+    toggle_wicks();
+    assert!(show_wicks().get_untracked());
+}
+
+#[wasm_bindgen_test]
+fn theme_toggle_updates_signal() {
+    set_global_renderer(Rc::new(RefCell::new(dummy_renderer())));
+    let was_light = chart_theme().get_untracked().is_light();
+
+    toggle_light_theme();
+    assert_eq!(chart_theme().get_untracked().is_light(), !was_light);
+    assert_eq!(with_global_renderer(|r| r.theme().is_light()), Some(!was_light));
+
+    toggle_light_theme();
+    assert_eq!(chart_theme().get_untracked().is_light(), was_light);
+}
+
+#[wasm_bindgen_test]
+fn candle_sizing_is_mirrored() {
+    let mut renderer = dummy_renderer();
+    let sizing = CandleSizing::new(0.002, 0.05, 0.4);
+    renderer.set_candle_sizing(sizing);
+    assert_eq!(candle_sizing().get_untracked(), renderer.candle_sizing());
+}
+
+#[wasm_bindgen_test]
+fn panel_toggle_flips_open_state() {
+    let open = settings_panel_open().get_untracked();
+    toggle_settings_panel();
+    assert_eq!(settings_panel_open().get_untracked(), !open);
+    toggle_settings_panel();
+    assert_eq!(settings_panel_open().get_untracked(), open);
+}
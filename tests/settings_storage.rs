@@ -0,0 +1,67 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{DEFAULT_ZOOM_SENSITIVITY, HistoryWindow, time_zone, zoom_sensitivity};
+use price_chart_wasm::domain::market_data::{Symbol, TimeInterval};
+use price_chart_wasm::infrastructure::rendering::renderer::LineVisibility;
+use price_chart_wasm::infrastructure::storage::{ChartSettings, SETTINGS_VERSION};
+use price_chart_wasm::time_utils::TimeZone;
+use wasm_bindgen_test::*;
+
+fn custom_settings() -> ChartSettings {
+    let mut line_visibility = LineVisibility::default();
+    line_visibility.toggle("sma50");
+    line_visibility.toggle("rsi");
+    ChartSettings {
+        version: SETTINGS_VERSION,
+        symbol: Symbol::from("ETHUSDT"),
+        interval: TimeInterval::FifteenMinutes,
+        line_visibility,
+        history_window: HistoryWindow::new(500, 2000),
+        time_zone: TimeZone::Offset(120),
+        zoom_sensitivity: 0.5,
+    }
+}
+
+#[wasm_bindgen_test]
+fn settings_round_trip_through_json() {
+    let settings = custom_settings();
+    assert_eq!(ChartSettings::from_json(&settings.to_json()), Some(settings));
+}
+
+#[wasm_bindgen_test]
+fn missing_visibility_fields_fall_back_to_defaults() {
+    let json = format!(
+        r#"{{"version":{SETTINGS_VERSION},"symbol":"ETHUSDT","interval":"1h","line_visibility":{{"bb":true}}}}"#
+    );
+    let settings = ChartSettings::from_json(&json).expect("older document still loads");
+    assert_eq!(settings.interval, TimeInterval::OneHour);
+    assert!(settings.line_visibility.bb);
+    assert_eq!(settings.line_visibility.lines, LineVisibility::default().lines);
+    assert_eq!(settings.history_window, HistoryWindow::default());
+    assert_eq!(settings.time_zone, TimeZone::Utc);
+    assert_eq!(settings.zoom_sensitivity, DEFAULT_ZOOM_SENSITIVITY);
+}
+
+#[wasm_bindgen_test]
+fn incompatible_documents_are_ignored() {
+    let mut other_version = custom_settings();
+    other_version.version = SETTINGS_VERSION + 1;
+    assert_eq!(ChartSettings::from_json(&other_version.to_json()), None);
+    assert_eq!(ChartSettings::from_json(r#"{"symbol":"ETHUSDT"}"#), None);
+    assert_eq!(ChartSettings::from_json("not json"), None);
+}
+
+#[wasm_bindgen_test]
+fn snapshot_tracks_every_persisted_signal() {
+    custom_settings().apply();
+    assert_eq!(ChartSettings::from_signals(), custom_settings());
+
+    // The persistence effect sees changes to any of the stored signals
+    let snapshot = create_memo(move |_| ChartSettings::from_signals());
+    time_zone().set(TimeZone::Utc);
+    zoom_sensitivity().set(2.0);
+    let updated = snapshot.get_untracked();
+    assert_eq!(updated.time_zone, TimeZone::Utc);
+    assert_eq!(updated.zoom_sensitivity, 2.0);
+    assert_eq!(updated.symbol, Symbol::from("ETHUSDT"));
+}
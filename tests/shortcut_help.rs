@@ -0,0 +1,35 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{SHORTCUTS, handle_shortcut_help_key, shortcut_help_visible};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn question_mark_toggles_the_overlay() {
+    shortcut_help_visible().set(false);
+    assert!(handle_shortcut_help_key("?"));
+    assert!(shortcut_help_visible().get_untracked());
+    assert!(handle_shortcut_help_key("?"));
+    assert!(!shortcut_help_visible().get_untracked());
+}
+
+#[wasm_bindgen_test]
+fn escape_closes_only_an_open_overlay() {
+    shortcut_help_visible().set(false);
+    assert!(!handle_shortcut_help_key("Escape"));
+
+    shortcut_help_visible().set(true);
+    assert!(handle_shortcut_help_key("Escape"));
+    assert!(!shortcut_help_visible().get_untracked());
+}
+
+#[wasm_bindgen_test]
+fn other_keys_are_left_alone() {
+    shortcut_help_visible().set(false);
+    assert!(!handle_shortcut_help_key("f"));
+    assert!(!shortcut_help_visible().get_untracked());
+}
+
+#[wasm_bindgen_test]
+fn overlay_lists_the_help_shortcut_itself() {
+    assert!(SHORTCUTS.iter().any(|(keys, _)| *keys == "?"));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::sparkline_path;
+use wasm_bindgen_test::*;
+
+fn points(path: &str) -> Vec<(f64, f64)> {
+    path.split(' ')
+        .map(|segment| {
+            let (x, y) = segment[1..].split_once(',').expect("x,y pair");
+            (x.parse().unwrap(), y.parse().unwrap())
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn path_spans_the_box_with_highest_close_on_top() {
+    let path = sparkline_path(&[10.0, 20.0, 15.0], 40.0, 10.0);
+    assert_eq!(path, "M0.0,10.0 L20.0,0.0 L40.0,5.0");
+}
+
+#[wasm_bindgen_test]
+fn path_moves_then_draws_lines() {
+    let closes = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+    let path = sparkline_path(&closes, 48.0, 14.0);
+    let segments: Vec<&str> = path.split(' ').collect();
+    assert_eq!(segments.len(), closes.len());
+    assert!(segments[0].starts_with('M'));
+    assert!(segments[1..].iter().all(|s| s.starts_with('L')));
+
+    let points = points(&path);
+    assert_eq!(points.first().map(|p| p.0), Some(0.0));
+    assert_eq!(points.last().map(|p| p.0), Some(48.0));
+    assert!(points.iter().all(|&(_, y)| (0.0..=14.0).contains(&y)));
+    // 9.0 is the highest close and 1.0 the lowest
+    assert_eq!(points[5].1, 0.0);
+    assert_eq!(points[1].1, 14.0);
+}
+
+#[wasm_bindgen_test]
+fn flat_series_runs_through_the_middle() {
+    let path = sparkline_path(&[7.0, 7.0, 7.0], 20.0, 10.0);
+    assert_eq!(path, "M0.0,5.0 L10.0,5.0 L20.0,5.0");
+}
+
+#[wasm_bindgen_test]
+fn too_few_closes_give_no_path() {
+    assert_eq!(sparkline_path(&[], 48.0, 14.0), "");
+    assert_eq!(sparkline_path(&[1.0], 48.0, 14.0), "");
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::gpu_structures::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{SubPanelKind, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, high: f64, low: f64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+fn count(verts: &[CandleVertex], color_type: f32) -> usize {
+    verts
+        .iter()
+        .filter(|v| (v.element_type - 2.0).abs() < f32::EPSILON && v.color_type == color_type)
+        .count()
+}
+
+#[wasm_bindgen_test]
+fn k_uses_rolling_range_and_d_smooths_it() {
+    let candles = vec![
+        candle(0, 10.0, 8.0, 9.0),
+        candle(1, 12.0, 9.0, 11.0),
+        candle(2, 11.0, 7.0, 10.0),
+        candle(3, 13.0, 10.0, 12.0),
+        candle(4, 12.0, 10.0, 10.0),
+    ];
+    let stoch = MarketAnalysisService::new().calculate_stochastic(&candles, 3, 2);
+
+    // Windows: [7, 12] close 10, [7, 13] close 12, [7, 13] close 10
+    let expected_k = [60.0, 500.0 / 6.0, 50.0];
+    assert_eq!(stoch.k.len(), expected_k.len());
+    for (k, expected) in stoch.k.iter().zip(expected_k) {
+        assert!((k - expected).abs() < 1e-9, "{k} != {expected}");
+    }
+
+    let expected_d = [(60.0 + 500.0 / 6.0) / 2.0, (500.0 / 6.0 + 50.0) / 2.0];
+    assert_eq!(stoch.d.len(), expected_d.len());
+    for (d, expected) in stoch.d.iter().zip(expected_d) {
+        assert!((d - expected).abs() < 1e-9, "{d} != {expected}");
+    }
+}
+
+#[wasm_bindgen_test]
+fn flat_window_defaults_to_50() {
+    let candles: Vec<Candle> = (0..5).map(|i| candle(i, 5.0, 5.0, 5.0)).collect();
+    let stoch = MarketAnalysisService::new().calculate_stochastic(&candles, 3, 3);
+    assert_eq!(stoch.k, vec![50.0; 3]);
+    assert_eq!(stoch.d, vec![50.0]);
+}
+
+#[wasm_bindgen_test]
+fn short_series_yields_nothing() {
+    let candles: Vec<Candle> = (0..2).map(|i| candle(i, 5.0, 4.0, 4.5)).collect();
+    let service = MarketAnalysisService::new();
+    assert!(service.calculate_stochastic(&candles, 3, 3).k.is_empty());
+    assert!(service.calculate_stochastic(&candles, 0, 3).d.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn stochastic_panel_draws_both_lines_and_guides() {
+    let candles: Vec<Candle> = (0..30)
+        .map(|i| {
+            let base = 100.0 + (i as f64 * 0.5).sin() * 5.0;
+            candle(i, base + 1.0, base - 1.0, base + (i % 3) as f64 * 0.3)
+        })
+        .collect();
+    let mut chart = Chart::new("stoch".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles);
+    let mut renderer = dummy_renderer();
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(count(&verts, 25.0), 0);
+
+    renderer.toggle_line_visibility("stoch");
+    let panel = *renderer.panel_layout().panel(SubPanelKind::Stochastic).expect("stoch panel");
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+
+    // 14-period %K starts at candle 13, 3-period %D two candles later
+    assert_eq!(count(&verts, 25.0), (30 - 13 - 1) * 6);
+    assert_eq!(count(&verts, 26.0), (30 - 15 - 1) * 6);
+    assert_eq!(count(&verts, 19.0), 2 * 6);
+    assert!(
+        verts
+            .iter()
+            .filter(|v| v.color_type == 25.0 || v.color_type == 26.0)
+            .all(|v| v.position_y >= panel.bottom - 0.01 && v.position_y <= panel.top + 0.01)
+    );
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::websocket::CandleCoalescer;
+use wasm_bindgen_test::*;
+
+fn candle(ts: u64, close: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(ts),
+        OHLCV::new(
+            Price::from(100.0),
+            Price::from(close.max(100.0)),
+            Price::from(close.min(100.0)),
+            Price::from(close),
+            Volume::from(1.0),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn burst_keeps_only_latest_update() {
+    let mut coalescer = CandleCoalescer::new();
+    assert!(coalescer.push(candle(60_000, 101.0)));
+    assert!(!coalescer.push(candle(60_000, 102.0)));
+    assert!(!coalescer.push(candle(60_000, 103.0)));
+
+    let flushed = coalescer.take();
+    assert_eq!(flushed, vec![candle(60_000, 103.0)]);
+    assert!(coalescer.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn new_bucket_keeps_final_state_of_previous_candle() {
+    let mut coalescer = CandleCoalescer::new();
+    coalescer.push(candle(60_000, 101.0));
+    coalescer.push(candle(60_000, 104.0));
+    coalescer.push(candle(120_000, 99.0));
+    coalescer.push(candle(120_000, 98.0));
+
+    assert_eq!(coalescer.len(), 2);
+    assert_eq!(coalescer.take(), vec![candle(60_000, 104.0), candle(120_000, 98.0)]);
+}
+
+#[wasm_bindgen_test]
+fn flush_rearms_scheduling() {
+    let mut coalescer = CandleCoalescer::new();
+    assert!(coalescer.push(candle(60_000, 101.0)));
+    coalescer.take();
+    assert!(coalescer.push(candle(60_000, 102.0)));
+    assert_eq!(coalescer.take(), vec![candle(60_000, 102.0)]);
+}
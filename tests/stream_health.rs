@@ -0,0 +1,49 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::{Symbol, TimeInterval};
+use price_chart_wasm::infrastructure::websocket::{BinanceWebSocketClient, StreamHealth};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn recent_messages_are_live() {
+    assert_eq!(StreamHealth::classify(Some(120), TimeInterval::OneMinute), StreamHealth::Live(120));
+    assert_eq!(
+        StreamHealth::classify(Some(6_000), TimeInterval::OneMinute),
+        StreamHealth::Live(6_000)
+    );
+    assert_eq!(
+        StreamHealth::classify(Some(120), TimeInterval::OneMinute).label(),
+        "🟢 Live (120ms)"
+    );
+}
+
+#[wasm_bindgen_test]
+fn overdue_messages_are_stale_until_disconnected() {
+    assert_eq!(StreamHealth::classify(Some(6_001), TimeInterval::OneMinute), StreamHealth::Stale);
+    assert_eq!(StreamHealth::classify(Some(60_000), TimeInterval::OneMinute), StreamHealth::Stale);
+    assert_eq!(
+        StreamHealth::classify(Some(60_001), TimeInterval::OneMinute),
+        StreamHealth::Disconnected
+    );
+    assert_eq!(StreamHealth::classify(None, TimeInterval::OneMinute), StreamHealth::Disconnected);
+}
+
+#[wasm_bindgen_test]
+fn disconnect_window_follows_the_interval() {
+    // Short intervals still allow 30 s of silence, long ones at most 5 min
+    assert_eq!(StreamHealth::classify(Some(20_000), TimeInterval::TwoSeconds), StreamHealth::Stale);
+    assert_eq!(
+        StreamHealth::classify(Some(31_000), TimeInterval::TwoSeconds),
+        StreamHealth::Disconnected
+    );
+    assert_eq!(StreamHealth::classify(Some(200_000), TimeInterval::OneHour), StreamHealth::Stale);
+    assert_eq!(
+        StreamHealth::classify(Some(301_000), TimeInterval::OneHour),
+        StreamHealth::Disconnected
+    );
+}
+
+#[wasm_bindgen_test]
+fn new_client_has_no_message_age() {
+    let client = BinanceWebSocketClient::new(Symbol::from("BTCUSDT"), TimeInterval::OneMinute);
+    assert_eq!(client.last_message_age_ms(), None);
+}
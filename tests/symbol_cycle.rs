@@ -0,0 +1,29 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::cycle_symbol;
+use price_chart_wasm::domain::market_data::value_objects::{Symbol, default_symbols};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn next_and_previous_wrap_around() {
+    let symbols = default_symbols();
+    let first = symbols[0].clone();
+    let last = symbols[symbols.len() - 1].clone();
+
+    assert_eq!(cycle_symbol(&symbols, &first, 1), Some(symbols[1].clone()));
+    assert_eq!(cycle_symbol(&symbols, &last, 1), Some(first.clone()));
+    assert_eq!(cycle_symbol(&symbols, &first, -1), Some(last.clone()));
+    assert_eq!(cycle_symbol(&symbols, &symbols[1], -1), Some(first));
+}
+
+#[wasm_bindgen_test]
+fn unlisted_symbol_enters_at_the_ends() {
+    let symbols = default_symbols();
+    let other = Symbol::from("DOGEUSDT");
+    assert_eq!(cycle_symbol(&symbols, &other, 1), Some(symbols[0].clone()));
+    assert_eq!(cycle_symbol(&symbols, &other, -1), symbols.last().cloned());
+}
+
+#[wasm_bindgen_test]
+fn empty_watchlist_has_no_next_symbol() {
+    assert_eq!(cycle_symbol(&[], &Symbol::from("BTCUSDT"), 1), None);
+}
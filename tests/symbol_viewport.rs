@@ -0,0 +1,87 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::current_symbol;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Symbol, Timestamp, Volume};
+use price_chart_wasm::global_state::{
+    ensure_chart, restore_symbol_view, save_symbol_view, view_state,
+};
+use wasm_bindgen_test::*;
+
+const MINUTE: u64 = 60_000;
+
+fn candles(count: u64, base: f64) -> Vec<Candle> {
+    (0..count)
+        .map(|i| {
+            let price = base + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i * MINUTE),
+                OHLCV::new(
+                    Price::from(price),
+                    Price::from(price + 1.0),
+                    Price::from(price - 1.0),
+                    Price::from(price),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Pan `symbol` to the given minutes and zoom to `ppc` pixels per candle
+fn move_view(symbol: &Symbol, from_minute: u64, to_minute: u64, ppc: f32) {
+    ensure_chart(symbol).update(|ch| {
+        ch.viewport.start_time = (from_minute * MINUTE) as f64;
+        ch.viewport.end_time = (to_minute * MINUTE) as f64;
+    });
+    view_state().update(|v| v.pixels_per_candle = ppc);
+}
+
+fn window(symbol: &Symbol) -> (f64, f64) {
+    ensure_chart(symbol).with_untracked(|ch| (ch.viewport.start_time, ch.viewport.end_time))
+}
+
+fn switch_to(from: &Symbol, to: &Symbol) -> bool {
+    save_symbol_view(from);
+    current_symbol().set(to.clone());
+    restore_symbol_view(to)
+}
+
+#[wasm_bindgen_test]
+fn viewports_restore_independently_per_symbol() {
+    let btc = Symbol::from("BTCUSDT");
+    let eth = Symbol::from("ETHUSDT");
+    ensure_chart(&btc).update(|ch| ch.set_historical_data(candles(100, 30_000.0)));
+    ensure_chart(&eth).update(|ch| ch.set_historical_data(candles(100, 2_000.0)));
+
+    current_symbol().set(btc.clone());
+    move_view(&btc, 20, 50, 10.0);
+
+    // ETH was never viewed, so it fits its data with the default zoom
+    assert!(!switch_to(&btc, &eth));
+    assert_eq!(window(&eth), (0.0, (99 * MINUTE) as f64));
+    assert_eq!(view_state().with_untracked(|v| v.pixels_per_candle), 5.0);
+    move_view(&eth, 60, 90, 15.0);
+
+    assert!(switch_to(&eth, &btc));
+    assert_eq!(window(&btc), ((20 * MINUTE) as f64, (50 * MINUTE) as f64));
+    assert_eq!(view_state().with_untracked(|v| v.pixels_per_candle), 10.0);
+
+    assert!(switch_to(&btc, &eth));
+    assert_eq!(window(&eth), ((60 * MINUTE) as f64, (90 * MINUTE) as f64));
+    assert_eq!(view_state().with_untracked(|v| v.pixels_per_candle), 15.0);
+}
+
+#[wasm_bindgen_test]
+fn restored_viewport_is_clamped_to_reloaded_data() {
+    let sol = Symbol::from("SOLUSDT");
+    let chart = ensure_chart(&sol);
+    chart.update(|ch| ch.set_historical_data(candles(100, 100.0)));
+    current_symbol().set(sol.clone());
+    move_view(&sol, 70, 99, 8.0);
+    save_symbol_view(&sol);
+
+    // The reloaded history ends earlier than the saved window
+    chart.update(|ch| ch.set_historical_data(candles(50, 100.0)));
+    assert!(restore_symbol_view(&sol));
+    assert_eq!(window(&sol), ((20 * MINUTE) as f64, (49 * MINUTE) as f64));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::{Theme, dummy_renderer};
+use wasm_bindgen_test::*;
+
+fn valid_color(color: &[f32; 4]) -> bool {
+    color.iter().all(|c| (0.0..=1.0).contains(c))
+}
+
+fn colors(theme: &Theme) -> Vec<[f32; 4]> {
+    let mut colors = vec![theme.background, theme.bullish, theme.bearish, theme.wick, theme.grid];
+    colors.extend_from_slice(&theme.indicator_colors);
+    colors
+}
+
+#[wasm_bindgen_test]
+fn themes_have_distinct_backgrounds_and_valid_colors() {
+    let dark = Theme::dark();
+    let light = Theme::light();
+    assert_ne!(dark.clear_color(), light.clear_color());
+    assert!(light.is_light());
+    assert!(!dark.is_light());
+    assert_eq!(Theme::default(), dark);
+
+    for theme in [dark, light] {
+        assert!(colors(&theme).iter().all(valid_color));
+        assert!((theme.clear_color().a - 1.0).abs() < f64::EPSILON);
+    }
+}
+
+#[wasm_bindgen_test]
+fn geometry_uses_active_theme_colors() {
+    let mut chart = Chart::new("theme".to_string(), ChartType::Candlestick, 10);
+    chart.set_historical_data(vec![Candle::new(
+        Timestamp::from_millis(0),
+        OHLCV::new(
+            Price::from(100.0),
+            Price::from(105.0),
+            Price::from(95.0),
+            Price::from(102.0),
+            Volume::from(1.0),
+        ),
+    )]);
+
+    let mut renderer = dummy_renderer();
+    let (_, _, dark) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(dark.bullish_color, Theme::dark().bullish);
+
+    renderer.set_theme(Theme::light());
+    let (_, _, light) = renderer.create_geometry_for_test(&chart);
+    assert_eq!(light.bullish_color, Theme::light().bullish);
+    assert_eq!(light.wick_color, Theme::light().wick);
+    assert_eq!(light.grid_color, Theme::light().grid);
+}
+
+#[wasm_bindgen_test]
+fn clear_color_reflects_configured_background() {
+    let mut renderer = dummy_renderer();
+    let [r, g, b, a] = Theme::dark().background.map(f64::from);
+    assert_eq!(renderer.clear_color(), wgpu::Color { r, g, b, a });
+
+    renderer.set_background_color([0.25, 0.5, 0.75, 1.0]);
+    assert_eq!(renderer.background_color(), [0.25, 0.5, 0.75, 1.0]);
+    assert_eq!(renderer.clear_color(), wgpu::Color { r: 0.25, g: 0.5, b: 0.75, a: 1.0 });
+    // Only the background changes
+    assert_eq!(renderer.theme().bullish, Theme::dark().bullish);
+
+    renderer.set_theme(Theme::light());
+    let [r, g, b, a] = Theme::light().background.map(f64::from);
+    assert_eq!(renderer.clear_color(), wgpu::Color { r, g, b, a });
+}
+
+#[wasm_bindgen_test]
+fn custom_background_keeps_the_light_palette() {
+    let mut renderer = dummy_renderer();
+    renderer.set_theme(Theme::light());
+    renderer.set_background_color([0.9, 0.9, 0.8, 1.0]);
+    assert!(renderer.theme().is_light());
+    assert!(!Theme { background: [0.9, 0.9, 0.8, 1.0], ..Theme::dark() }.is_light());
+}
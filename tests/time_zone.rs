@@ -0,0 +1,66 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{format_candle_time, time_zone};
+use price_chart_wasm::domain::market_data::TimeInterval;
+use price_chart_wasm::time_utils::{
+    TimeZone, format_full_datetime, format_full_datetime_in, format_time_label,
+    format_time_label_in,
+};
+use wasm_bindgen_test::*;
+
+/// 2024-01-15 13:45:07 UTC
+const TS: u64 = 1_705_326_307_000;
+/// 2024-01-15 23:30:00 UTC
+const LATE_TS: u64 = 1_705_361_400_000;
+const PLUS_TWO: TimeZone = TimeZone::Offset(120);
+
+#[wasm_bindgen_test]
+fn labels_shift_with_the_offset() {
+    assert_eq!(format_time_label_in(TS, 2.0, TimeZone::Utc), "13:45");
+    assert_eq!(format_time_label_in(TS, 2.0, PLUS_TWO), "15:45");
+    assert_eq!(format_time_label_in(TS, 2.0, TimeZone::Offset(-330)), "08:15");
+}
+
+#[wasm_bindgen_test]
+fn offset_can_move_the_date() {
+    assert_eq!(format_time_label_in(LATE_TS, 1.5, TimeZone::Utc), "15.01");
+    assert_eq!(format_time_label_in(LATE_TS, 1.5, PLUS_TWO), "16.01");
+    assert_eq!(
+        format_full_datetime_in(LATE_TS, TimeInterval::OneMinute, PLUS_TWO),
+        "2024-01-16 01:30"
+    );
+}
+
+#[wasm_bindgen_test]
+fn tooltip_datetime_shifts_with_the_offset() {
+    assert_eq!(
+        format_full_datetime_in(TS, TimeInterval::TwoSeconds, TimeZone::Utc),
+        "2024-01-15 13:45:07"
+    );
+    assert_eq!(
+        format_full_datetime_in(TS, TimeInterval::TwoSeconds, PLUS_TWO),
+        "2024-01-15 15:45:07"
+    );
+}
+
+#[wasm_bindgen_test]
+fn selected_zone_applies_only_where_the_ui_passes_it() {
+    time_zone().set(PLUS_TWO);
+    assert_eq!(format_candle_time(TS, TimeInterval::OneHour), "2024-01-15 15:45");
+    // The plain formatters stay in UTC whatever the settings say
+    assert_eq!(format_time_label(TS, 2.0), "13:45");
+    assert_eq!(format_full_datetime(TS, TimeInterval::OneHour), "2024-01-15 13:45");
+    time_zone().set(TimeZone::Utc);
+    assert_eq!(format_candle_time(TS, TimeInterval::OneHour), "2024-01-15 13:45");
+}
+
+#[wasm_bindgen_test]
+fn zones_round_trip_through_their_labels() {
+    for zone in TimeZone::options() {
+        assert_eq!(zone.to_string().parse::<TimeZone>(), Ok(zone));
+    }
+    assert_eq!(PLUS_TWO.to_string(), "UTC+02:00");
+    assert_eq!("UTC-05:30".parse::<TimeZone>(), Ok(TimeZone::Offset(-330)));
+    assert!("UTC+2".parse::<TimeZone>().is_err());
+    assert!("EST".parse::<TimeZone>().is_err());
+}
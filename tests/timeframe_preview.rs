@@ -0,0 +1,71 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::{
+    Candle, CandleSeries, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+/// 400 two-second candles starting mid-minute, so buckets are partly filled
+fn sample_chart() -> Chart {
+    let candles = (0..400u64)
+        .map(|i| {
+            let price = 100.0 + (i as f64 * 0.1).sin() * 3.0;
+            Candle::new(
+                Timestamp::from_millis(30_000 + i * 2_000),
+                OHLCV::new(
+                    Price::from(price),
+                    Price::from(price + 1.0),
+                    Price::from(price - 1.0),
+                    Price::from(price + 0.5),
+                    Volume::from(1.0),
+                ),
+            )
+        })
+        .collect();
+    let mut chart = Chart::new("preview".to_string(), ChartType::Candlestick, 1000);
+    chart.set_historical_data(candles);
+    chart
+}
+
+#[wasm_bindgen_test]
+fn aggregation_matches_committed_buckets() {
+    let chart = sample_chart();
+    for interval in [TimeInterval::OneMinute, TimeInterval::FiveMinutes, TimeInterval::OneHour] {
+        let preview = chart.aggregated_series(interval);
+        let committed = chart.get_series(interval).unwrap();
+        assert_eq!(preview.count(), committed.count(), "{interval:?}");
+        assert_eq!(preview.get_candles(), committed.get_candles(), "{interval:?}");
+    }
+}
+
+#[wasm_bindgen_test]
+fn preview_leaves_the_chart_untouched() {
+    let mut chart = sample_chart();
+    // Drop the live one-minute aggregates to see the preview rebuild them
+    chart.series.insert(TimeInterval::OneMinute, CandleSeries::new(1000));
+    let preview = chart.preview(TimeInterval::OneMinute);
+
+    assert_eq!(chart.get_series(TimeInterval::OneMinute).unwrap().count(), 0);
+    assert_eq!(preview.get_series(TimeInterval::OneMinute).unwrap().count(), 14);
+    assert_eq!(
+        preview.get_series(TimeInterval::TwoSeconds).unwrap().count(),
+        chart.get_series(TimeInterval::TwoSeconds).unwrap().count()
+    );
+}
+
+#[wasm_bindgen_test]
+fn renderer_draws_the_previewed_timeframe() {
+    let chart = sample_chart();
+    let mut renderer = dummy_renderer();
+    renderer.set_preview_interval(Some(TimeInterval::FiveMinutes));
+    assert_eq!(renderer.series_interval(), TimeInterval::FiveMinutes);
+
+    let (_, _, uniforms) =
+        renderer.create_geometry_for_test(&chart.preview(TimeInterval::FiveMinutes));
+    let buckets = chart.aggregated_series(TimeInterval::FiveMinutes).count();
+    assert_eq!(uniforms.time_range[1] as usize, buckets);
+
+    renderer.set_preview_interval(None);
+    assert_eq!(renderer.preview_interval(), None);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "render")]
+use leptos::SignalSet;
+use price_chart_wasm::app::{TooltipData, current_interval};
+use price_chart_wasm::domain::logging::TimeProvider;
+use price_chart_wasm::domain::market_data::{
+    Candle, OHLCV, Price, TimeInterval, Timestamp, Volume,
+};
+use price_chart_wasm::time_utils::format_full_datetime;
+use wasm_bindgen_test::*;
+
+/// 2024-01-15 13:45:07 UTC
+const TS: u64 = 1_705_326_307_000;
+
+struct FixedTimeProvider;
+
+impl TimeProvider for FixedTimeProvider {
+    fn current_timestamp(&self) -> u64 {
+        TS
+    }
+
+    fn format_timestamp(&self, timestamp: u64) -> String {
+        timestamp.to_string()
+    }
+}
+
+#[wasm_bindgen_test]
+fn precision_follows_interval() {
+    assert_eq!(format_full_datetime(TS, TimeInterval::TwoSeconds), "2024-01-15 13:45:07");
+    assert_eq!(format_full_datetime(TS, TimeInterval::OneMinute), "2024-01-15 13:45");
+    assert_eq!(format_full_datetime(TS, TimeInterval::FourHours), "2024-01-15 13:45");
+    assert_eq!(format_full_datetime(TS, TimeInterval::OneDay), "2024-01-15");
+    assert_eq!(format_full_datetime(TS, TimeInterval::OneWeek), "2024-01-15");
+}
+
+#[wasm_bindgen_test]
+fn provider_formats_datetime() {
+    let provider = FixedTimeProvider;
+    assert_eq!(
+        provider.format_datetime(provider.current_timestamp(), TimeInterval::OneMinute),
+        "2024-01-15 13:45"
+    );
+}
+
+#[wasm_bindgen_test]
+fn tooltip_shows_readable_time() {
+    current_interval().set(TimeInterval::OneMinute);
+    let candle = Candle::new(
+        Timestamp::from_millis(TS - 7_000),
+        OHLCV::new(
+            Price::from(100.0),
+            Price::from(105.0),
+            Price::from(95.0),
+            Price::from(102.0),
+            Volume::from(1.0),
+        ),
+    );
+    let tooltip = TooltipData::new(candle, 0.0, 0.0);
+    assert!(tooltip.formatted_text.ends_with("Time: 2024-01-15 13:45"));
+    assert!(!tooltip.formatted_text.contains(&(TS - 7_000).to_string()));
+}
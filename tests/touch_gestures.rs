@@ -0,0 +1,50 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::touch_gestures::{
+    TouchAction, TouchGesture, TouchPoint, pinch_zoom_factor, touch_distance,
+};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn pinch_factor_is_distance_ratio() {
+    assert!((pinch_zoom_factor(100.0, 150.0) - 1.5).abs() < 1e-6);
+    assert!((pinch_zoom_factor(200.0, 100.0) - 0.5).abs() < 1e-6);
+    // Clamped per event and neutral for degenerate input
+    assert_eq!(pinch_zoom_factor(10.0, 100.0), 2.0);
+    assert_eq!(pinch_zoom_factor(100.0, 10.0), 0.5);
+    assert_eq!(pinch_zoom_factor(0.0, 50.0), 1.0);
+    assert_eq!(pinch_zoom_factor(50.0, f64::NAN), 1.0);
+    assert_eq!(touch_distance(&TouchPoint::new(0, 0.0, 0.0), &TouchPoint::new(1, 3.0, 4.0)), 5.0);
+}
+
+#[wasm_bindgen_test]
+fn one_finger_pans_and_two_fingers_zoom_around_midpoint() {
+    let mut gesture = TouchGesture::from_touches(&[TouchPoint::new(7, 100.0, 50.0)]);
+    assert_eq!(gesture.update(&[TouchPoint::new(7, 130.0, 60.0)]), Some(TouchAction::Pan(30.0)));
+    assert_eq!(gesture.update(&[TouchPoint::new(7, 120.0, 60.0)]), Some(TouchAction::Pan(-10.0)));
+
+    let mut gesture = TouchGesture::from_touches(&[
+        TouchPoint::new(1, 100.0, 100.0),
+        TouchPoint::new(2, 200.0, 100.0),
+    ]);
+    let action =
+        gesture.update(&[TouchPoint::new(2, 250.0, 100.0), TouchPoint::new(1, 50.0, 100.0)]);
+    assert_eq!(action, Some(TouchAction::Zoom { factor: 2.0, center_x: 150.0 }));
+}
+
+#[wasm_bindgen_test]
+fn stale_identifiers_restart_the_gesture() {
+    let mut gesture = TouchGesture::from_touches(&[
+        TouchPoint::new(1, 100.0, 100.0),
+        TouchPoint::new(2, 200.0, 100.0),
+    ]);
+    // The first finger lifts and a move arrives for the remaining one
+    assert_eq!(gesture.update(&[TouchPoint::new(2, 210.0, 100.0)]), None);
+    assert_eq!(gesture, TouchGesture::Pan { id: 2, last_x: 210.0 });
+    assert_eq!(gesture.update(&[TouchPoint::new(2, 215.0, 100.0)]), Some(TouchAction::Pan(5.0)));
+
+    // A different finger replaces the tracked one
+    assert_eq!(gesture.update(&[TouchPoint::new(3, 40.0, 0.0)]), None);
+    assert_eq!(gesture, TouchGesture::Pan { id: 3, last_x: 40.0 });
+    assert_eq!(gesture.update(&[]), None);
+    assert_eq!(gesture, TouchGesture::Idle);
+}
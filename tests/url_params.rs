@@ -0,0 +1,60 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::market_data::{Symbol, TimeInterval};
+use price_chart_wasm::infrastructure::url_params::{UrlChartParams, with_chart_params};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn parses_symbol_and_interval() {
+    let params = UrlChartParams::parse("?symbol=ethusdt&interval=5m");
+    assert_eq!(params.symbol, Some(Symbol::from("ETHUSDT")));
+    assert_eq!(params.interval, Some(TimeInterval::FiveMinutes));
+}
+
+#[wasm_bindgen_test]
+fn accepts_coinbase_products() {
+    let params = UrlChartParams::parse("?symbol=eth-usdt");
+    assert_eq!(params.symbol, Some(Symbol::from("ETH-USDT")));
+    assert_eq!(UrlChartParams::parse("?symbol=DOGE-USD").symbol, None);
+}
+
+#[wasm_bindgen_test]
+fn interval_is_case_sensitive() {
+    assert_eq!(UrlChartParams::parse("interval=1M").interval, Some(TimeInterval::OneMonth));
+    assert_eq!(UrlChartParams::parse("interval=1m").interval, Some(TimeInterval::OneMinute));
+}
+
+#[wasm_bindgen_test]
+fn rejects_unknown_values() {
+    let params = UrlChartParams::parse("?symbol=DOGEUSDT&interval=7m&foo=bar");
+    assert_eq!(params, UrlChartParams::default());
+    assert_eq!(UrlChartParams::parse(""), UrlChartParams::default());
+    assert_eq!(UrlChartParams::parse("?symbol=&interval"), UrlChartParams::default());
+}
+
+#[wasm_bindgen_test]
+fn keeps_valid_param_when_other_is_invalid() {
+    let params = UrlChartParams::parse("?symbol=SOLUSDT&interval=bogus");
+    assert_eq!(params.symbol, Some(Symbol::from("SOLUSDT")));
+    assert_eq!(params.interval, None);
+}
+
+#[wasm_bindgen_test]
+fn builds_shareable_query() {
+    let symbol = Symbol::from("ETHUSDT");
+    assert_eq!(
+        with_chart_params("", &symbol, TimeInterval::OneHour),
+        "?symbol=ETHUSDT&interval=1h"
+    );
+    assert_eq!(
+        with_chart_params("?debug&symbol=BTCUSDT&interval=1m", &symbol, TimeInterval::OneHour),
+        "?debug&symbol=ETHUSDT&interval=1h"
+    );
+}
+
+#[wasm_bindgen_test]
+fn built_query_round_trips() {
+    let query = with_chart_params("?a=1", &Symbol::from("SOLUSDT"), TimeInterval::FourHours);
+    let params = UrlChartParams::parse(&query);
+    assert_eq!(params.symbol, Some(Symbol::from("SOLUSDT")));
+    assert_eq!(params.interval, Some(TimeInterval::FourHours));
+}
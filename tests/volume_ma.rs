@@ -0,0 +1,92 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    LineVisibility, VOLUME_MA_NAME, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn candles(volumes: &[f64]) -> Vec<Candle> {
+    volumes
+        .iter()
+        .enumerate()
+        .map(|(i, &volume)| {
+            let price = 100.0 + i as f64;
+            Candle::new(
+                Timestamp::from_millis(i as u64 * 60_000),
+                OHLCV::new(
+                    Price::from(price),
+                    Price::from(price + 1.0),
+                    Price::from(price - 1.0),
+                    Price::from(price + 0.5),
+                    Volume::from(volume),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn volume_ma_vertices(verts: &[CandleVertex]) -> Vec<&CandleVertex> {
+    verts.iter().filter(|v| (v.element_type - 16.0).abs() < f32::EPSILON).collect()
+}
+
+#[wasm_bindgen_test]
+fn volume_sma_averages_complete_windows() {
+    let service = MarketAnalysisService::new();
+    let values: Vec<f64> = service
+        .calculate_volume_sma(&candles(&[1.0, 2.0, 3.0, 4.0, 5.0]), 3)
+        .iter()
+        .map(|v| v.value())
+        .collect();
+    assert_eq!(values, vec![2.0, 3.0, 4.0]);
+    assert!(service.calculate_volume_sma(&candles(&[1.0, 2.0]), 3).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn volume_ma_is_hidden_by_default() {
+    assert!(!LineVisibility::default().is_visible(VOLUME_MA_NAME));
+    let mut chart = Chart::new("volma".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles(&[10.0; 30]));
+    let (_, verts, _) = dummy_renderer().create_geometry_for_test(&chart);
+    assert!(volume_ma_vertices(&verts).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn volume_ma_starts_after_its_first_window() {
+    let mut chart = Chart::new("volma".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles(&[10.0; 30]));
+    let mut renderer = dummy_renderer();
+    renderer.toggle_line_visibility(VOLUME_MA_NAME);
+    renderer.set_volume_ma_period(10);
+    assert_eq!(renderer.volume_ma_period(), 10);
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    // One segment of six vertices between each pair of the 21 averaged candles
+    assert_eq!(volume_ma_vertices(&verts).len(), 20 * 6);
+
+    renderer.set_volume_ma_period(0);
+    assert_eq!(renderer.volume_ma_period(), 1);
+}
+
+#[wasm_bindgen_test]
+fn volume_ma_shares_the_volume_bar_scale() {
+    let mut chart = Chart::new("volma".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles(&[10.0; 30]));
+    let mut renderer = dummy_renderer();
+    renderer.toggle_line_visibility(VOLUME_MA_NAME);
+
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    let bars: Vec<f32> = verts
+        .iter()
+        .filter(|v| (v.element_type - 5.0).abs() < f32::EPSILON)
+        .map(|v| v.position_y)
+        .collect();
+    let bar_top = bars.iter().cloned().fold(f32::MIN, f32::max);
+
+    // Constant volume averages to the bar height, so the line runs along the bar tops
+    let line = volume_ma_vertices(&verts);
+    assert!(!line.is_empty());
+    assert!(line.iter().all(|v| (v.position_y - bar_top).abs() < 0.02));
+}
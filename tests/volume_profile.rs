@@ -0,0 +1,84 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::{MarketAnalysisService, VolumeProfile};
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::CandleVertex;
+use price_chart_wasm::infrastructure::rendering::renderer::{
+    LineVisibility, VOLUME_PROFILE_MAX_WIDTH, VOLUME_PROFILE_NAME, dummy_renderer,
+};
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, low: f64, high: f64, volume: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(low),
+            Price::from(high),
+            Price::from(low),
+            Price::from(high),
+            Volume::from(volume),
+        ),
+    )
+}
+
+fn profile_vertices(verts: &[CandleVertex]) -> Vec<&CandleVertex> {
+    verts.iter().filter(|v| (v.element_type - 17.0).abs() < f32::EPSILON).collect()
+}
+
+#[wasm_bindgen_test]
+fn volume_is_spread_over_each_candles_range() {
+    // Bins of 10 between 100 and 140
+    let candles = [
+        candle(0, 100.0, 120.0, 10.0), // 5 + 5 in the two lowest bins
+        candle(1, 115.0, 135.0, 8.0),  // 2 + 4 + 2
+        candle(2, 125.0, 125.0, 3.0),  // no range: all at its typical price
+    ];
+    let profile = MarketAnalysisService::new().calculate_volume_profile(&candles, 100.0, 140.0, 4);
+    assert_eq!(profile.volumes, vec![5.0, 7.0, 7.0, 2.0]);
+    assert_eq!(profile.bin_range(2), (120.0, 130.0));
+    assert_eq!(profile.volumes.iter().sum::<f64>(), 21.0);
+}
+
+#[wasm_bindgen_test]
+fn volume_outside_the_range_is_dropped() {
+    let candles = [candle(0, 90.0, 110.0, 10.0), candle(1, 150.0, 160.0, 4.0)];
+    let profile = MarketAnalysisService::new().calculate_volume_profile(&candles, 100.0, 120.0, 2);
+    assert_eq!(profile.volumes, vec![5.0, 0.0]);
+    let empty = MarketAnalysisService::new().calculate_volume_profile(&candles, 100.0, 100.0, 2);
+    assert_eq!(empty, VolumeProfile::default());
+}
+
+#[wasm_bindgen_test]
+fn point_of_control_is_the_busiest_bin() {
+    let profile =
+        VolumeProfile { min_price: 0.0, bin_size: 1.0, volumes: vec![1.0, 4.0, 4.0, 2.0] };
+    assert_eq!(profile.point_of_control(), Some(1));
+    let empty = VolumeProfile { min_price: 0.0, bin_size: 1.0, volumes: vec![0.0; 3] };
+    assert_eq!(empty.point_of_control(), None);
+}
+
+#[wasm_bindgen_test]
+fn profile_draws_from_the_right_edge_when_enabled() {
+    let mut chart = Chart::new("profile".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(
+        (0..30).map(|i| candle(i, 100.0 + i as f64, 104.0 + i as f64, 1.0 + i as f64)).collect(),
+    );
+    assert!(!LineVisibility::default().is_visible(VOLUME_PROFILE_NAME));
+    let mut renderer = dummy_renderer();
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(profile_vertices(&verts).is_empty());
+
+    renderer.toggle_line_visibility(VOLUME_PROFILE_NAME);
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    let bars = profile_vertices(&verts);
+    assert!(!bars.is_empty());
+    assert!(
+        bars.iter().all(|v| v.position_x <= 1.0 && v.position_x >= 1.0 - VOLUME_PROFILE_MAX_WIDTH)
+    );
+
+    // Only the Point of Control is highlighted, and it is the longest bar
+    let poc: Vec<_> = bars.iter().filter(|v| v.color_type > 0.5).collect();
+    assert_eq!(poc.len(), 6);
+    let poc_left = poc.iter().map(|v| v.position_x).fold(f32::MAX, f32::min);
+    assert!((poc_left - (1.0 - VOLUME_PROFILE_MAX_WIDTH)).abs() < 1e-5);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+const DAY_MS: u64 = 86_400_000;
+
+fn candle(ts: u64, high: f64, low: f64, close: f64, volume: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(ts),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Volume::from(volume),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn vwap_matches_hand_computed_values_and_resets_daily() {
+    let candles = vec![
+        // Typical prices 10, 20 and 30 late on day 0
+        candle(DAY_MS - 180_000, 11.0, 9.0, 10.0, 1.0),
+        candle(DAY_MS - 120_000, 21.0, 19.0, 20.0, 3.0),
+        candle(DAY_MS - 60_000, 31.0, 29.0, 30.0, 0.0),
+        // New UTC day starts a new session
+        candle(DAY_MS, 42.0, 39.0, 39.0, 2.0),
+        candle(DAY_MS + 60_000, 52.0, 50.0, 51.0, 2.0),
+    ];
+    let vwap: Vec<f64> =
+        MarketAnalysisService::new().calculate_vwap(&candles).iter().map(|p| p.value()).collect();
+
+    // (10*1 + 20*3) / 4 = 17.5; a zero-volume candle leaves it unchanged
+    let expected = [10.0, 17.5, 17.5, 40.0, (40.0 * 2.0 + 51.0 * 2.0) / 4.0];
+    assert_eq!(vwap.len(), expected.len());
+    for (actual, expected) in vwap.iter().zip(expected) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+}
+
+#[wasm_bindgen_test]
+fn vwap_line_is_drawn_and_fits_the_price_range() {
+    let candles: Vec<Candle> = (0..20)
+        .map(|i| {
+            let base = 100.0 + i as f64;
+            // Heavy volume early keeps VWAP well below the latest candles
+            candle(i * 60_000, base + 0.5, base - 0.5, base, if i < 5 { 100.0 } else { 1.0 })
+        })
+        .collect();
+    let mut chart = Chart::new("vwap".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data(candles.clone());
+    chart.viewport.start_time = (15 * 60_000) as f64;
+    let mut renderer = dummy_renderer();
+    renderer.set_zoom_params(6.4, 0.0);
+    // Only candles and VWAP decide the price range
+    renderer.set_ma_configs(&[]);
+
+    let is_vwap = |color_type: f32| (color_type - 22.0).abs() < f32::EPSILON;
+    let (_, verts, plain) = renderer.create_geometry_for_test(&chart);
+    assert!(!verts.iter().any(|v| is_vwap(v.color_type)));
+
+    renderer.toggle_line_visibility("vwap");
+    let (_, verts, fitted) = renderer.create_geometry_for_test(&chart);
+    assert!(verts.iter().any(|v| is_vwap(v.color_type)));
+
+    let vwap = MarketAnalysisService::new().calculate_vwap(&candles);
+    let lowest_visible = vwap[15..20].iter().map(|p| p.value() as f32).fold(f32::MAX, f32::min);
+    assert!(lowest_visible < plain.viewport[2]);
+    assert!(fitted.viewport[2] <= lowest_visible);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::domain::chart::{Chart, value_objects::ChartType};
+use price_chart_wasm::domain::market_data::services::MarketAnalysisService;
+use price_chart_wasm::domain::market_data::{Candle, OHLCV, Price, Timestamp, Volume};
+use price_chart_wasm::infrastructure::rendering::renderer::dummy_renderer;
+use wasm_bindgen_test::*;
+
+fn candle(i: u64, close: f64, volume: f64) -> Candle {
+    Candle::new(
+        Timestamp::from_millis(i * 60_000),
+        OHLCV::new(
+            Price::from(close),
+            Price::from(close + 1.0),
+            Price::from(close - 1.0),
+            Price::from(close),
+            Volume::from(volume),
+        ),
+    )
+}
+
+#[wasm_bindgen_test]
+fn vwma_weights_closes_by_volume() {
+    let candles = vec![candle(0, 10.0, 1.0), candle(1, 20.0, 3.0), candle(2, 30.0, 1.0)];
+    let vwma: Vec<f64> = MarketAnalysisService::new()
+        .calculate_vwma(&candles, 2)
+        .iter()
+        .map(|p| p.value())
+        .collect();
+
+    // (10*1 + 20*3) / 4 = 17.5 and (20*3 + 30*1) / 4 = 22.5
+    assert_eq!(vwma.len(), 2);
+    assert!((vwma[0] - 17.5).abs() < 1e-9);
+    assert!((vwma[1] - 22.5).abs() < 1e-9);
+}
+
+#[wasm_bindgen_test]
+fn zero_volume_window_falls_back_to_sma() {
+    let candles = vec![candle(0, 10.0, 0.0), candle(1, 20.0, 0.0), candle(2, 30.0, 2.0)];
+    let service = MarketAnalysisService::new();
+    let vwma = service.calculate_vwma(&candles, 2);
+    let sma = service.calculate_sma(&candles, 2);
+
+    assert_eq!(vwma[0].value(), sma[0].value());
+    assert!(vwma[0].value().is_finite());
+    // Only the last candle has volume in the second window
+    assert!((vwma[1].value() - 30.0).abs() < 1e-9);
+    assert!(service.calculate_vwma(&candles, 4).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn vwma_line_follows_its_visibility_flag() {
+    let mut chart = Chart::new("vwma".to_string(), ChartType::Candlestick, 100);
+    chart.set_historical_data((0..40).map(|i| candle(i, 100.0 + i as f64, 1.0)).collect());
+    let mut renderer = dummy_renderer();
+    renderer.set_ma_configs(&[]);
+
+    let is_vwma = |color_type: f32| (color_type - 24.0).abs() < f32::EPSILON;
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(!verts.iter().any(|v| is_vwma(v.color_type)));
+
+    renderer.toggle_line_visibility("vwma");
+    let (_, verts, _) = renderer.create_geometry_for_test(&chart);
+    assert!(verts.iter().any(|v| is_vwma(v.color_type)));
+}
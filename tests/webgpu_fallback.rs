@@ -0,0 +1,37 @@
+#![cfg(feature = "render")]
+use leptos::*;
+use price_chart_wasm::app::{
+    create_renderer_or_demo, current_symbol, demo_mode, report_webgpu_failure, webgpu_error,
+};
+use price_chart_wasm::global_state::get_chart_signal;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn failed_renderer_init_raises_the_banner_and_enters_demo_mode() {
+    webgpu_error().set(None);
+    demo_mode().set(false);
+    let (status, set_status) = create_signal(String::new());
+
+    // No canvas carries this id, so `WebGpuRenderer::new` errors out
+    let renderer = create_renderer_or_demo("missing-chart-canvas", 800, 600, set_status).await;
+    assert!(renderer.is_none());
+    assert!(
+        webgpu_error().get_untracked().is_some_and(|e| e.contains("missing-chart-canvas")),
+        "banner reason: {:?}",
+        webgpu_error().get_untracked()
+    );
+    assert!(demo_mode().get_untracked());
+    assert!(status.get_untracked().contains("Demo mode"));
+    let chart = get_chart_signal(&current_symbol().get_untracked()).unwrap();
+    assert_eq!(chart.with_untracked(|c| c.get_candle_count()), 50);
+}
+
+#[wasm_bindgen_test]
+fn non_string_errors_are_still_reported() {
+    webgpu_error().set(None);
+    report_webgpu_failure(&JsValue::from_f64(3.0));
+    assert!(webgpu_error().get_untracked().is_some_and(|e| !e.is_empty()));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "render")]
+use price_chart_wasm::app::{
+    DEFAULT_ZOOM_SENSITIVITY, MAX_ZOOM_SENSITIVITY, MIN_ZOOM_SENSITIVITY, WHEEL_ZOOM_STEP,
+    keyboard_zoom_factor, wheel_zoom_delta,
+};
+use price_chart_wasm::view_state::ViewState;
+use wasm_bindgen_test::*;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[wasm_bindgen_test]
+fn wheel_delta_scales_with_sensitivity() {
+    // Only the scroll direction counts, not the size of the delta
+    assert_eq!(wheel_zoom_delta(120.0, DEFAULT_ZOOM_SENSITIVITY), WHEEL_ZOOM_STEP);
+    assert_eq!(wheel_zoom_delta(3.0, DEFAULT_ZOOM_SENSITIVITY), WHEEL_ZOOM_STEP);
+    assert_eq!(wheel_zoom_delta(-120.0, DEFAULT_ZOOM_SENSITIVITY), -WHEEL_ZOOM_STEP);
+    assert_eq!(wheel_zoom_delta(120.0, 0.5), WHEEL_ZOOM_STEP * 0.5);
+    assert_eq!(wheel_zoom_delta(-120.0, 2.0), -WHEEL_ZOOM_STEP * 2.0);
+}
+
+#[wasm_bindgen_test]
+fn wheel_notch_changes_candle_width_by_the_scaled_step() {
+    for (sensitivity, expected) in [(0.5, 5.5), (1.0, 6.0), (2.0, 7.0), (MAX_ZOOM_SENSITIVITY, 9.0)]
+    {
+        let mut view = ViewState::new(5.0, 1.0, 20.0);
+        view.zoom_at(wheel_zoom_delta(100.0, sensitivity), 0.5, 800.0);
+        assert_eq!(view.pixels_per_candle, expected);
+    }
+    // The view clamps still bound coarse steps
+    let mut view = ViewState::new(19.0, 1.0, 20.0);
+    view.zoom_at(wheel_zoom_delta(100.0, MAX_ZOOM_SENSITIVITY), 0.5, 800.0);
+    assert_eq!(view.pixels_per_candle, 20.0);
+}
+
+#[wasm_bindgen_test]
+fn keyboard_factors_scale_symmetrically() {
+    assert!(close(keyboard_zoom_factor(1.2, DEFAULT_ZOOM_SENSITIVITY), 1.2));
+    assert!(close(keyboard_zoom_factor(1.2, 2.0), 1.44));
+    assert!(close(keyboard_zoom_factor(1.5, MIN_ZOOM_SENSITIVITY), 1.5f64.powf(0.25)));
+    // Zooming in then out by the same key pair returns to the start at any sensitivity
+    for sensitivity in [MIN_ZOOM_SENSITIVITY, 1.0, MAX_ZOOM_SENSITIVITY] {
+        let round_trip =
+            keyboard_zoom_factor(1.25, sensitivity) * keyboard_zoom_factor(1.0 / 1.25, sensitivity);
+        assert!(close(round_trip, 1.0));
+    }
+}